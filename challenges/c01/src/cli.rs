@@ -0,0 +1,850 @@
+//! A simple joke-telling program that presents random jokes with colorful answers.
+//!
+//! This program selects a random joke from a predefined collection, displays the
+//! question, waits for user input (Enter key), and then shows the answer in a
+//! random color from the active theme. At a terminal the answer follows a drum
+//! roll and is typed out letter by letter, `--reveal-delay` milliseconds
+//! apart, unless `--no-animation` is given.
+//!
+//! The collection lives in `jokes.toml`, which is built into the program.
+//! `--jokes <FILE>` adds the jokes from a file of the same shape, in TOML or
+//! JSON, and a file that does not fit is reported with what is wrong with it.
+//!
+//! Every joke has a category, such as `animal` or `school`. The joke is drawn
+//! from the category given with `--category`, or else from the one the
+//! player picks at the start, or from all of them if they just press Enter.
+//!
+//! After each joke the player can ask for another. The jokes are shuffled and
+//! told in turn, and the ones already told are remembered between runs, so no
+//! joke comes up twice until every one has been told.
+//!
+//! With `--online` the jokes are fetched from a joke service instead, and the
+//! collection is only used when the service cannot be reached.
+//!
+//! After each answer the player can rate the joke from 1 to 5 or mark it as a
+//! favorite. The ratings are kept between runs, and `--favorites` tells only
+//! the favorites and the jokes rated 4 or more.
+//!
+//! `c01 add` asks for a joke of the player's own and appends it to their
+//! personal jokes file in the data directory, whose jokes are told along with
+//! the collection from then on.
+//!
+//! The jokes are child-friendly and sourced from an educational [blog](https://childrenlearningenglishaffectively.blogspot.com/2013/05/50-easy-jokes-for-young-english-learners.html) for
+//! English learners.
+use crate::online;
+use crate::{bundled_jokes, load_jokes, Joke, JokeFile, DEFAULT_CATEGORY};
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::{self, Input};
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use challenge_common::rng::RngArgs;
+use challenge_common::theme::{self, ThemeArgs};
+use challenge_storage::Store;
+use challenge_tui::crossterm::cursor;
+use challenge_tui::crossterm::queue;
+use challenge_tui::crossterm::terminal::{Clear, ClearType};
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Dots in the drum roll before a typed-out answer.
+const DRUM_ROLL: u32 = 3;
+
+/// How many times longer each dot of the drum roll takes than a letter.
+const DRUM_BEAT: u32 = 6;
+
+/// Name of the player's own jokes file in the data directory.
+const PERSONAL_JOKES_FILE: &str = "c01-jokes.toml";
+
+/// The lowest rating that makes a joke one of the favorites.
+const FAVORITE_STARS: u8 = 4;
+
+/// Returns the path of the player's own jokes file.
+fn personal_jokes_path() -> challenge_storage::Result<PathBuf> {
+    Ok(challenge_storage::data_dir()?.join(PERSONAL_JOKES_FILE))
+}
+
+/// Reads the player's own jokes from `path`. A missing file just means they
+/// have not added any yet.
+fn load_personal_jokes(path: &Path) -> Result<Vec<Joke>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    load_jokes(path)
+}
+
+/// Asks the player for a new joke: a question not already among `known`, an
+/// answer and a category.
+fn ask_joke<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    known: &[Joke],
+) -> std::io::Result<Joke> {
+    let question =
+        Prompt::new(l10n::text("c01.new_question"))
+            .inline()
+            .ask(reader, writer, |answer| {
+                if answer.is_empty() {
+                    return Err(l10n::text("c01.text_missing").to_string());
+                }
+                if known
+                    .iter()
+                    .any(|joke| joke.question.eq_ignore_ascii_case(answer))
+                {
+                    return Err(l10n::text("c01.known_question").to_string());
+                }
+                Ok(answer.to_string())
+            })?;
+    let answer =
+        Prompt::new(l10n::text("c01.new_answer"))
+            .inline()
+            .ask(reader, writer, |answer| {
+                if answer.is_empty() {
+                    return Err(l10n::text("c01.text_missing").to_string());
+                }
+                Ok(answer.to_string())
+            })?;
+    let category = Prompt::new(l10n::text("c01.new_category"))
+        .inline()
+        .with_default(DEFAULT_CATEGORY)
+        .ask(reader, writer, |answer| {
+            if !answer.chars().all(char::is_alphabetic) {
+                return Err(l10n::text("c01.category_invalid").to_string());
+            }
+            Ok(answer.to_lowercase())
+        })?;
+    Ok(Joke {
+        question,
+        answer,
+        category,
+    })
+}
+
+/// Appends `joke` to the jokes file at `path`, creating the file and its
+/// directory if needed.
+fn append_joke(path: &Path, joke: &Joke) -> challenge_storage::Result<()> {
+    let entry = toml::to_string(&JokeFile {
+        jokes: vec![joke.clone()],
+    })
+    .map_err(|e| e.to_string())?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    // A blank line keeps the entries apart, as in the bundled jokes.toml.
+    if file.metadata()?.len() > 0 {
+        writeln!(file)?;
+    }
+    file.write_all(entry.as_bytes())?;
+    Ok(())
+}
+
+/// Asks the player for a joke of their own and saves it to their personal
+/// jokes file.
+fn add_joke<R: BufRead, W: Write>(reader: &mut R, writer: &mut W) -> challenge_storage::Result<()> {
+    let path = personal_jokes_path()?;
+    let mut known = bundled_jokes()?;
+    known.extend(load_personal_jokes(&path)?);
+    let joke = ask_joke(reader, writer, &known)?;
+    append_joke(&path, &joke)?;
+    tracing::debug!(question = %joke.question, path = %path.display(), "joke added");
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("c01.added", &[("path", &path.display())])
+    )?;
+    Ok(())
+}
+
+/// Returns the categories of `jokes` in alphabetical order, each once.
+fn categories(jokes: &[Joke]) -> Vec<&str> {
+    let mut categories: Vec<_> = jokes.iter().map(|joke| joke.category.as_str()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+    categories
+}
+
+/// Keeps only the jokes in `category`, ignoring case. Fails if there are
+/// none.
+fn filter_jokes(jokes: &mut Vec<Joke>, category: &str) -> Result<(), String> {
+    let known = categories(jokes).join(", ");
+    jokes.retain(|joke| joke.category.eq_ignore_ascii_case(category));
+    if jokes.is_empty() {
+        return Err(format!(
+            "no jokes in category '{category}' (choose from {known})"
+        ));
+    }
+    Ok(())
+}
+
+/// Asks the player for one of `categories`. Returns `None` if they want a
+/// joke from any of them.
+fn pick_category<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    categories: &[&str],
+) -> std::io::Result<Option<String>> {
+    let message = l10n::format("c01.category", &[("categories", &categories.join(", "))]);
+    let choices: Vec<_> = std::iter::once(("any", None))
+        .chain(categories.iter().map(|c| (*c, Some(c.to_string()))))
+        .collect();
+    Prompt::new(&message)
+        .inline()
+        .with_default("any")
+        .ask(reader, writer, prompt::choice(&choices))
+}
+
+/// Returns the jokes not in `told`, shuffled with `rng`. Once every joke has
+/// been told, they are all forgotten from `told` and shuffled again.
+fn shuffled_pool<R: Rng + ?Sized>(
+    jokes: &[Joke],
+    told: &mut Vec<String>,
+    rng: &mut R,
+) -> Vec<Joke> {
+    let mut pool: Vec<_> = jokes
+        .iter()
+        .filter(|joke| !told.contains(&joke.question))
+        .cloned()
+        .collect();
+    if pool.is_empty() {
+        told.retain(|question| !jokes.iter().any(|joke| joke.question == *question));
+        pool = jokes.to_vec();
+    }
+    pool.shuffle(rng);
+    pool
+}
+
+/// What the player thought of a joke.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Feedback {
+    Stars(u8),
+    Favorite,
+}
+
+/// Parses the answer to the rating prompt. Nothing means no rating.
+fn parse_feedback(answer: &str) -> Result<Option<Feedback>, String> {
+    if answer.is_empty() {
+        return Ok(None);
+    }
+    if answer.eq_ignore_ascii_case("f") {
+        return Ok(Some(Feedback::Favorite));
+    }
+    prompt::parsed(l10n::text("c01.rating"), |stars| (1..=5).contains(stars))(answer)
+        .map(|stars| Some(Feedback::Stars(stars)))
+}
+
+/// The player's rating of a joke. The joke is kept whole so that jokes
+/// fetched online can be told again from the favorites.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Rating {
+    joke: Joke,
+    /// From 1 to 5, if the player gave one.
+    stars: Option<u8>,
+    favorite: bool,
+}
+
+/// What the game remembers between runs.
+#[derive(Debug, Default)]
+struct Memory {
+    /// Questions of the jokes told since the collection was last gone
+    /// through.
+    told: Vec<String>,
+    ratings: Vec<Rating>,
+}
+
+impl Memory {
+    fn load() -> challenge_storage::Result<Memory> {
+        let store = Store::open("c01")?;
+        Ok(Memory {
+            told: store.get_or_default("told")?,
+            ratings: store.get_or_default("ratings")?,
+        })
+    }
+
+    fn save(&self) -> challenge_storage::Result<()> {
+        let mut store = Store::open("c01")?;
+        store.set("told", &self.told)?;
+        store.set("ratings", &self.ratings)?;
+        store.save()
+    }
+
+    /// Records `feedback` on `joke`, replacing an earlier rating but keeping
+    /// it a favorite.
+    fn rate(&mut self, joke: &Joke, feedback: Feedback) {
+        let index = match self
+            .ratings
+            .iter()
+            .position(|rating| rating.joke.question == joke.question)
+        {
+            Some(index) => index,
+            None => {
+                self.ratings.push(Rating {
+                    joke: joke.clone(),
+                    stars: None,
+                    favorite: false,
+                });
+                self.ratings.len() - 1
+            }
+        };
+        let rating = &mut self.ratings[index];
+        match feedback {
+            Feedback::Stars(stars) => rating.stars = Some(stars),
+            Feedback::Favorite => rating.favorite = true,
+        }
+    }
+
+    /// Returns the jokes marked as favorites or rated [`FAVORITE_STARS`] or
+    /// more.
+    fn favorites(&self) -> Vec<Joke> {
+        self.ratings
+            .iter()
+            .filter(|rating| rating.favorite || rating.stars >= Some(FAVORITE_STARS))
+            .map(|rating| rating.joke.clone())
+            .collect()
+    }
+}
+
+/// Treats the player quitting, or the input ending, as the end of the
+/// session rather than an error.
+fn until_done<T>(answer: std::io::Result<T>) -> std::io::Result<Option<T>> {
+    match answer {
+        Ok(answer) => Ok(Some(answer)),
+        Err(e) if prompt::is_abort(&e) || e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Writes `answer` in `color` one letter at a time, `delay` apart, after a
+/// drum roll of dots that is erased again.
+fn type_out<W: Write>(
+    writer: &mut W,
+    answer: &str,
+    color: colored::Color,
+    delay: Duration,
+) -> std::io::Result<()> {
+    queue!(writer, cursor::Hide)?;
+    for _ in 0..DRUM_ROLL {
+        write!(writer, ".")?;
+        writer.flush()?;
+        std::thread::sleep(delay * DRUM_BEAT);
+    }
+    queue!(
+        writer,
+        cursor::MoveToColumn(0),
+        Clear(ClearType::CurrentLine)
+    )?;
+    for letter in answer.chars() {
+        write!(writer, "{}", letter.to_string().color(color))?;
+        writer.flush()?;
+        std::thread::sleep(delay);
+    }
+    queue!(writer, cursor::Show)?;
+    writeln!(writer)
+}
+
+/// Tells `joke`, revealing the answer when the player presses Enter in a
+/// color picked with `rng`. The answer is typed out `animation` apart if
+/// given, and shown at once if not.
+fn tell<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    joke: &Joke,
+    animation: Option<Duration>,
+    rng: &mut G,
+) -> std::io::Result<()> {
+    write!(writer, "{} {} ", joke.question, l10n::text("c01.reveal"))?;
+    // We flush to ensure the message gets printed immediately.
+    writer.flush()?;
+
+    // Wait for the user to press enter.
+    input::read_line(reader)?;
+
+    let color = get_random_color(rng);
+    match animation {
+        Some(delay) => type_out(writer, &joke.answer, color, delay),
+        None => writeln!(writer, "{}", joke.answer.color(color)),
+    }
+}
+
+/// Tells jokes until the player wants no more, asking for a rating after
+/// each. The jokes are fetched from the joke service at `online` if given,
+/// falling back to `jokes` for the rest of the session once it fails. Each
+/// joke told from `jokes` is added to the ones `memory` has told, and those
+/// already in it are skipped, and the rest are shuffled with `rng`. Answers
+/// are revealed as [`tell`] does with `animation`.
+fn tell_jokes<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    jokes: &[Joke],
+    memory: &mut Memory,
+    mut online: Option<&str>,
+    animation: Option<Duration>,
+    rng: &mut G,
+) -> std::io::Result<()> {
+    let mut pool = Vec::new();
+    loop {
+        let fetched = match online.map(|url| online::fetch(url, online::TIMEOUT)) {
+            Some(Ok(joke)) => Some(joke),
+            Some(Err(e)) => {
+                writeln!(writer, "{}", l10n::format("c01.offline", &[("error", &e)]))?;
+                online = None;
+                None
+            }
+            None => None,
+        };
+        let from_collection = fetched.is_none();
+        let joke = match fetched {
+            Some(joke) => joke,
+            None => {
+                if pool.is_empty() {
+                    pool = shuffled_pool(jokes, &mut memory.told, rng);
+                }
+                pool.pop().expect("there is always a joke to tell")
+            }
+        };
+        tell(reader, writer, &joke, animation, rng)?;
+        if from_collection {
+            memory.told.push(joke.question.clone());
+        }
+
+        let rate = Prompt::new(l10n::text("c01.rate")).inline();
+        let Some(feedback) = until_done(rate.ask(reader, writer, parse_feedback))? else {
+            return Ok(());
+        };
+        if let Some(feedback) = feedback {
+            tracing::debug!(question = %joke.question, ?feedback, "joke rated");
+            memory.rate(&joke, feedback);
+        }
+
+        let another = Prompt::new(l10n::text("c01.another")).inline();
+        if until_done(another.ask(reader, writer, prompt::yes_no))? != Some(true) {
+            return Ok(());
+        }
+    }
+}
+
+fn get_random_color<R: Rng + ?Sized>(rng: &mut R) -> colored::Color {
+    let palette = theme::current().palette();
+    *palette.choose(rng).unwrap_or(&colored::Color::White)
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Add a joke of your own, told along with the others from then on
+    Add,
+}
+
+/// Tell a random child-friendly joke and reveal the answer in color.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Only tell jokes from this category, e.g. `animal` or `school`
+    #[arg(short, long)]
+    category: Option<String>,
+
+    /// File of more jokes to tell, in TOML or JSON like the bundled jokes.toml
+    #[arg(long, value_name = "FILE")]
+    jokes: Option<PathBuf>,
+
+    /// Fetch the jokes from an online joke service, falling back to the
+    /// collection when it cannot be reached
+    #[arg(long, conflicts_with = "category")]
+    online: bool,
+
+    /// Only tell your favorite jokes and the ones you rated 4 or 5
+    #[arg(long, conflicts_with_all = ["online", "jokes"])]
+    favorites: bool,
+
+    /// Show the answer at once instead of typing it out
+    #[arg(long)]
+    no_animation: bool,
+
+    /// Milliseconds between the letters of a typed-out answer
+    #[arg(long, value_name = "MS", default_value_t = 40)]
+    reveal_delay: u64,
+
+    /// JokeAPI endpoint used by --online
+    #[arg(long, value_name = "URL", default_value = online::DEFAULT_URL, hide_default_value = true)]
+    joke_api: String,
+
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+    theme::init(&args.theme);
+
+    if let Some(Command::Add) = args.command {
+        if let Err(e) = add_joke(&mut input, &mut std::io::stdout()) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let mut memory = Memory::load().unwrap_or_else(|e| {
+        eprintln!(
+            "Warning: unable to read the jokes told and rated before: {}",
+            e
+        );
+        Memory::default()
+    });
+    let mut jokes = if args.favorites {
+        memory.favorites()
+    } else {
+        let mut jokes = match bundled_jokes() {
+            Ok(jokes) => jokes,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        match personal_jokes_path()
+            .map_err(|e| e.to_string())
+            .and_then(|path| load_personal_jokes(&path))
+        {
+            Ok(personal) => jokes.extend(personal),
+            Err(e) => eprintln!("Warning: unable to read your own jokes: {}", e),
+        }
+        jokes
+    };
+    if jokes.is_empty() {
+        eprintln!("Error: no favorite jokes yet; rate a joke 4 or 5, or mark it with 'f'");
+        return ExitCode::FAILURE;
+    }
+    if let Some(path) = &args.jokes {
+        match load_jokes(path) {
+            Ok(more) => jokes.extend(more),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let category = match args.category {
+        Some(category) => Some(category),
+        None if args.online => None,
+        None => {
+            let categories = categories(&jokes);
+            match pick_category(&mut input, &mut std::io::stdout(), &categories) {
+                Ok(category) => category,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+    if let Some(category) = category {
+        if let Err(e) = filter_jokes(&mut jokes, &category) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    // Sort the jokes so that a given seed always shuffles them the same way.
+    jokes.sort();
+
+    let online = args.online.then_some(args.joke_api.as_str());
+    // Typing out only makes sense to someone watching a terminal.
+    let animation = (!args.no_animation && std::io::stdout().is_terminal())
+        .then(|| Duration::from_millis(args.reveal_delay));
+    let result = tell_jokes(
+        &mut input,
+        &mut std::io::stdout(),
+        &jokes,
+        &mut memory,
+        online,
+        animation,
+        &mut rng,
+    );
+    if let Err(e) = memory.save() {
+        eprintln!("Warning: unable to save the jokes told and rated: {}", e);
+    }
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn filter_jokes_keeps_the_category() {
+        let mut jokes = bundled_jokes().unwrap();
+        filter_jokes(&mut jokes, "Spooky").unwrap();
+        assert_eq!(jokes.len(), 2);
+        assert!(jokes.iter().all(|joke| joke.category == "spooky"));
+    }
+
+    #[test]
+    fn filter_jokes_rejects_an_unknown_category() {
+        let mut jokes = bundled_jokes().unwrap();
+        assert_eq!(
+            filter_jokes(&mut jokes, "sports").unwrap_err(),
+            "no jokes in category 'sports' (choose from animal, everyday, food, nature, school, spooky, technology)"
+        );
+    }
+
+    #[test]
+    fn pick_category_defaults_to_any_and_reprompts() {
+        let categories = ["animal", "food"];
+        let mut output = Vec::new();
+
+        let any = pick_category(&mut "\n".as_bytes(), &mut output, &categories).unwrap();
+        let food =
+            pick_category(&mut "sports\nFOOD\n".as_bytes(), &mut output, &categories).unwrap();
+
+        assert_eq!(any, None);
+        assert_eq!(food.as_deref(), Some("food"));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("Pick a category (animal, food) or press Enter for any: "));
+        assert!(output.contains("Invalid input. Please enter 'any', 'animal' or 'food'."));
+    }
+
+    #[test]
+    fn ask_joke_reprompts_for_empty_and_known_questions() {
+        let mut output = Vec::new();
+
+        let joke = ask_joke(
+            &mut "\nwhy is six afraid of seven?\nWhy did the cookie cry?\n\nIts mom was a wafer so long.\n\n"
+                .as_bytes(),
+            &mut output,
+            &bundled_jokes().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            joke,
+            Joke {
+                question: "Why did the cookie cry?".to_string(),
+                answer: "Its mom was a wafer so long.".to_string(),
+                category: DEFAULT_CATEGORY.to_string(),
+            }
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output
+                .matches("Invalid input. Please enter some text.")
+                .count(),
+            2
+        );
+        assert!(output.contains("There is already a joke with that question."));
+    }
+
+    #[test]
+    fn append_joke_adds_to_the_personal_file() {
+        let dir = std::env::temp_dir().join(format!("c01-personal-{}", std::process::id()));
+        let path = dir.join(PERSONAL_JOKES_FILE);
+        let quoted = Joke {
+            question: "What did the \"0\" say to the \"8\"?".to_string(),
+            answer: "Nice belt!".to_string(),
+            category: "school".to_string(),
+        };
+
+        assert_eq!(load_personal_jokes(&path).unwrap(), []);
+        append_joke(&path, &joke("A?")).unwrap();
+        append_joke(&path, &quoted).unwrap();
+        let jokes = load_personal_jokes(&path);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(jokes.unwrap(), [joke("A?"), quoted]);
+    }
+
+    fn joke(question: &str) -> Joke {
+        Joke {
+            question: question.to_string(),
+            answer: "Because.".to_string(),
+            category: DEFAULT_CATEGORY.to_string(),
+        }
+    }
+
+    #[test]
+    fn tell_jokes_never_repeats_until_every_joke_is_told() {
+        let jokes = [joke("A?"), joke("B?"), joke("C?")];
+        let mut memory = Memory {
+            told: vec!["B?".to_string()],
+            ..Memory::default()
+        };
+        let mut output = Vec::new();
+
+        tell_jokes(
+            &mut "\n\ny\n\n\ny\n\n\nn\n".as_bytes(),
+            &mut output,
+            &jokes,
+            &mut memory,
+            None,
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        // A and C are told before B, whose turn comes only once the third
+        // joke starts a new cycle with every joke forgotten.
+        let output = String::from_utf8(output).unwrap();
+        let mut asked: Vec<_> = output
+            .split("Another joke? (y/n) ")
+            .filter_map(|told| told.split(' ').next())
+            .filter(|question| !question.is_empty())
+            .collect();
+        assert_eq!(asked.len(), 3);
+        asked.truncate(2);
+        asked.sort();
+        assert_eq!(asked, ["A?", "C?"]);
+        assert_eq!(memory.told.len(), 1);
+        assert!(memory.ratings.is_empty());
+    }
+
+    #[test]
+    fn shuffled_pool_starts_over_once_every_joke_is_told() {
+        let jokes = [joke("A?"), joke("B?")];
+        let mut told = vec!["A?".to_string(), "B?".to_string(), "Z?".to_string()];
+
+        let pool = shuffled_pool(&jokes, &mut told, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(pool.len(), 2);
+        assert_eq!(told, ["Z?"]);
+    }
+
+    #[test]
+    fn tell_jokes_stops_when_input_ends() {
+        let mut memory = Memory::default();
+        tell_jokes(
+            &mut "\n".as_bytes(),
+            &mut std::io::sink(),
+            &[joke("A?")],
+            &mut memory,
+            None,
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+        assert_eq!(memory.told, ["A?"]);
+    }
+
+    #[test]
+    fn tell_jokes_records_ratings() {
+        let mut memory = Memory::default();
+        let mut output = Vec::new();
+
+        tell_jokes(
+            &mut "\n6\n4\nn\n".as_bytes(),
+            &mut output,
+            &[joke("A?")],
+            &mut memory,
+            None,
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output
+            .contains("Rate it from 1 to 5, f to make it a favorite, or press Enter to skip: "));
+        assert!(
+            output.contains("Invalid input. Please enter a rating from 1 to 5, 'f' or nothing.")
+        );
+        assert_eq!(memory.favorites(), [joke("A?")]);
+    }
+
+    #[test]
+    fn parse_feedback_reads_stars_favorites_and_nothing() {
+        assert_eq!(parse_feedback(""), Ok(None));
+        assert_eq!(parse_feedback("F"), Ok(Some(Feedback::Favorite)));
+        assert_eq!(parse_feedback("3"), Ok(Some(Feedback::Stars(3))));
+        assert!(parse_feedback("0").is_err());
+        assert!(parse_feedback("great").is_err());
+    }
+
+    #[test]
+    fn favorites_are_marked_or_highly_rated() {
+        let mut memory = Memory::default();
+        memory.rate(&joke("A?"), Feedback::Stars(5));
+        memory.rate(&joke("B?"), Feedback::Stars(3));
+        memory.rate(&joke("C?"), Feedback::Favorite);
+        memory.rate(&joke("C?"), Feedback::Stars(1));
+        memory.rate(&joke("A?"), Feedback::Stars(2));
+
+        assert_eq!(memory.ratings.len(), 3);
+        assert_eq!(memory.favorites(), [joke("C?")]);
+    }
+
+    #[test]
+    fn tell_jokes_falls_back_to_the_collection_when_offline() {
+        // Nothing listens on a port that was just freed.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/joke/Any", listener.local_addr().unwrap());
+        drop(listener);
+        let mut memory = Memory::default();
+        let mut output = Vec::new();
+
+        tell_jokes(
+            &mut "\n\ny\n\n\nn\n".as_bytes(),
+            &mut output,
+            &[joke("A?"), joke("B?")],
+            &mut memory,
+            Some(&url),
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Unable to fetch a joke online").count(), 1);
+        assert!(output.contains("), so here is one of ours.\n"));
+        assert_eq!(memory.told.len(), 2);
+    }
+
+    #[test]
+    fn type_out_drums_then_types_the_answer() {
+        let mut output = Vec::new();
+
+        type_out(&mut output, "Hi!", colored::Color::Red, Duration::ZERO).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let (drum_roll, answer) = output.split_once("\x1b[2K").unwrap();
+        assert!(drum_roll.contains("..."));
+        assert!(answer.ends_with("\x1b[?25h\n"));
+        let shown: String = answer.chars().filter(|c| "Hi!".contains(*c)).collect();
+        assert_eq!(shown, "Hi!");
+    }
+}
@@ -0,0 +1,142 @@
+//! # Jokes
+//!
+//! The joke collection and the jokes files that add to it, kept apart from
+//! the command line so that other challenges can tell the same jokes.
+//!
+//! A jokes file lists `[[joke]]` entries, each with a question, an answer and
+//! an optional category, in TOML or JSON. The collection that comes with the
+//! game is `jokes.toml`, built into the program.
+mod cli;
+mod online;
+
+pub use cli::run_cli;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The jokes that come with the game.
+const BUNDLED_JOKES: &str = include_str!("../jokes.toml");
+
+/// Category of the jokes that do not name one.
+pub const DEFAULT_CATEGORY: &str = "other";
+
+/// A joke: a question, the answer revealed after it, and what it is about.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Joke {
+    pub question: String,
+    pub answer: String,
+    #[serde(default = "default_category")]
+    pub category: String,
+}
+
+fn default_category() -> String {
+    DEFAULT_CATEGORY.to_string()
+}
+
+/// The layout of a jokes file.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JokeFile {
+    #[serde(rename = "joke")]
+    pub jokes: Vec<Joke>,
+}
+
+/// Parses a jokes file. `json` selects JSON instead of TOML. Every joke needs
+/// a question and an answer, and the file at least one joke.
+pub fn parse_jokes(text: &str, json: bool) -> Result<Vec<Joke>, String> {
+    let file: JokeFile = if json {
+        serde_json::from_str(text).map_err(|e| e.to_string())?
+    } else {
+        toml::from_str(text).map_err(|e| e.message().to_string())?
+    };
+    if file.jokes.is_empty() {
+        return Err("the file has no jokes".to_string());
+    }
+    for (i, joke) in file.jokes.iter().enumerate() {
+        if joke.question.trim().is_empty() {
+            return Err(format!("joke {} has an empty question", i + 1));
+        }
+        if joke.answer.trim().is_empty() {
+            return Err(format!("joke {} has an empty answer", i + 1));
+        }
+        if joke.category.trim().is_empty() {
+            return Err(format!("joke {} has an empty category", i + 1));
+        }
+    }
+    Ok(file.jokes)
+}
+
+/// Reads the jokes in the file at `path`, which is JSON if its name ends in
+/// `.json` and TOML otherwise.
+pub fn load_jokes(path: &Path) -> Result<Vec<Joke>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let json = path.extension().is_some_and(|ext| ext == "json");
+    parse_jokes(&text, json).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Returns the jokes that come with the game.
+pub fn bundled_jokes() -> Result<Vec<Joke>, String> {
+    parse_jokes(BUNDLED_JOKES, false).map_err(|e| format!("jokes.toml: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_jokes_parse() {
+        let jokes = bundled_jokes().unwrap();
+        assert_eq!(jokes.len(), 25);
+        assert!(jokes.contains(&Joke {
+            question: "Why is six afraid of seven?".to_string(),
+            answer: "Because 7 ATE 9".to_string(),
+            category: "school".to_string(),
+        }));
+    }
+
+    #[test]
+    fn parse_jokes_reads_json() {
+        let jokes = parse_jokes(
+            r#"{"joke": [{"question": "What do you call a sleeping bull?", "answer": "A bulldozer."}]}"#,
+            true,
+        )
+        .unwrap();
+        assert_eq!(jokes[0].answer, "A bulldozer.");
+        assert_eq!(jokes[0].category, DEFAULT_CATEGORY);
+    }
+
+    #[test]
+    fn parse_jokes_rejects_malformed_files() {
+        assert_eq!(
+            parse_jokes("joke = []\n", false).unwrap_err(),
+            "the file has no jokes"
+        );
+        assert_eq!(
+            parse_jokes("[[joke]]\nquestion = \"Why?\"\nanswer = \" \"\n", false).unwrap_err(),
+            "joke 1 has an empty answer"
+        );
+        assert!(parse_jokes("[[joke]]\nquestion = \"Why?\"\n", false)
+            .unwrap_err()
+            .contains("missing field `answer`"));
+        assert!(parse_jokes(
+            "[[joke]]\nquestion = \"Why?\"\nanswer = \"Because.\"\npunchline = 1\n",
+            false
+        )
+        .unwrap_err()
+        .contains("unknown field `punchline`"));
+    }
+
+    #[test]
+    fn load_jokes_names_the_file_in_errors() {
+        let dir = std::env::temp_dir().join(format!("c01-jokes-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("jokes.json");
+        std::fs::write(&path, "{\"joke\": [{\"question\": \"Why?\"}]}").unwrap();
+
+        let error = load_jokes(&path).unwrap_err();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(error.starts_with(&format!("{}: ", path.display())));
+        assert!(error.contains("missing field `answer`"));
+    }
+}
@@ -0,0 +1,174 @@
+//! Jokes fetched from [JokeAPI](https://jokeapi.dev) for `--online`.
+//!
+//! The request is made with `ureq`, over HTTPS or plain HTTP, following any
+//! redirects. The whole request, from looking up the host to reading the
+//! reply, is bounded by a timeout, and any failure is returned as a message
+//! so the caller can fall back to the jokes it has.
+use crate::{Joke, DEFAULT_CATEGORY};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Two-part jokes in any category, in safe mode so that they are all
+/// suitable for children.
+pub const DEFAULT_URL: &str = "https://v2.jokeapi.dev/joke/Any?type=twopart&safe-mode";
+
+/// How long the whole request may take.
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A reply from JokeAPI, which reports errors in the body.
+#[derive(Deserialize)]
+struct Reply {
+    #[serde(default)]
+    error: bool,
+    message: Option<String>,
+    category: Option<String>,
+    setup: Option<String>,
+    delivery: Option<String>,
+}
+
+/// Fetches `url` and returns the body of a successful reply.
+fn get(url: &str, timeout: Duration) -> Result<String, String> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .user_agent("lbpc-c01")
+        .build()
+        .into();
+    let error = |e: ureq::Error| format!("{url}: {e}");
+    agent
+        .get(url)
+        .header("Accept", "application/json")
+        .call()
+        .map_err(error)?
+        .body_mut()
+        .read_to_string()
+        .map_err(error)
+}
+
+/// Reads the joke in a JokeAPI reply.
+fn parse_reply(body: &str) -> Result<Joke, String> {
+    let reply: Reply = serde_json::from_str(body).map_err(|e| format!("unexpected reply: {e}"))?;
+    if reply.error {
+        return Err(reply
+            .message
+            .unwrap_or_else(|| "the joke service reported an error".to_string()));
+    }
+    match (reply.setup, reply.delivery) {
+        (Some(question), Some(answer)) => Ok(Joke {
+            question,
+            answer,
+            category: reply
+                .category
+                .map_or_else(|| DEFAULT_CATEGORY.to_string(), |c| c.to_lowercase()),
+        }),
+        _ => Err("the reply has no two-part joke".to_string()),
+    }
+}
+
+/// Fetches a joke from the JokeAPI endpoint at `url`.
+pub fn fetch(url: &str, timeout: Duration) -> Result<Joke, String> {
+    let joke = parse_reply(&get(url, timeout)?)?;
+    tracing::debug!(question = %joke.question, "joke fetched");
+    Ok(joke)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Serves each of `replies` to one request on localhost, with `{addr}` in
+    /// a reply replaced by the server's address. Returns the URL to fetch and
+    /// the request lines the server got.
+    fn serve(replies: &'static [&'static str]) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let url = format!("http://{addr}/joke/Any");
+        let server = std::thread::spawn(move || {
+            let mut request_lines = Vec::new();
+            for reply in replies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                // Read the rest of the request too: closing the connection
+                // with some of it unread resets it before the reply arrives.
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+                let reply = reply.replace("{addr}", &addr.to_string());
+                stream.write_all(reply.as_bytes()).unwrap();
+                request_lines.push(request_line);
+            }
+            request_lines
+        });
+        (url, server)
+    }
+
+    const JOKE_REPLY: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                              Connection: close\r\n\r\n\
+                              {\"error\": false, \"category\": \"Pun\", \"type\": \"twopart\", \
+                              \"setup\": \"What do you call a fish with no eyes?\", \"delivery\": \"A fsh.\"}";
+
+    fn fish_joke() -> Joke {
+        Joke {
+            question: "What do you call a fish with no eyes?".to_string(),
+            answer: "A fsh.".to_string(),
+            category: "pun".to_string(),
+        }
+    }
+
+    #[test]
+    fn fetch_reads_a_two_part_joke() {
+        let (url, server) = serve(&[JOKE_REPLY]);
+
+        let joke = fetch(&url, TIMEOUT).unwrap();
+
+        assert_eq!(server.join().unwrap(), ["GET /joke/Any HTTP/1.1\r\n"]);
+        assert_eq!(joke, fish_joke());
+    }
+
+    #[test]
+    fn fetch_follows_redirects() {
+        let (url, server) = serve(&[
+            "HTTP/1.1 301 Moved Permanently\r\nLocation: http://{addr}/joke/Pun\r\n\
+             Content-Length: 0\r\nConnection: close\r\n\r\n",
+            JOKE_REPLY,
+        ]);
+
+        let joke = fetch(&url, TIMEOUT).unwrap();
+
+        assert_eq!(
+            server.join().unwrap(),
+            ["GET /joke/Any HTTP/1.1\r\n", "GET /joke/Pun HTTP/1.1\r\n"]
+        );
+        assert_eq!(joke, fish_joke());
+    }
+
+    #[test]
+    fn fetch_reports_failed_requests() {
+        let (url, server) = serve(&["HTTP/1.1 503 Service Unavailable\r\n\
+                                     Content-Length: 0\r\nConnection: close\r\n\r\n"]);
+        let error = fetch(&url, TIMEOUT).unwrap_err();
+        assert!(error.starts_with(&url), "{error}");
+        assert!(error.contains("503"), "{error}");
+        server.join().unwrap();
+
+        let (url, server) = serve(&["HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n\
+                                     {\"error\": true, \"message\": \"No matching joke found\"}"]);
+        assert_eq!(fetch(&url, TIMEOUT).unwrap_err(), "No matching joke found");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn parse_reply_needs_a_setup_and_a_delivery() {
+        assert_eq!(
+            parse_reply("{\"error\": false, \"type\": \"single\", \"joke\": \"Ha.\"}").unwrap_err(),
+            "the reply has no two-part joke"
+        );
+        assert!(parse_reply("<html>")
+            .unwrap_err()
+            .starts_with("unexpected reply: "));
+    }
+}
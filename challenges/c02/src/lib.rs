@@ -0,0 +1,413 @@
+//! A simple command-line name greeting program.
+//!
+//! This program demonstrates basic Rust I/O operations by:
+//! - Prompting the user for their name
+//! - Reading input from stdin
+//! - Checking that the input looks like a name, and asking again if not
+//! - Printing a personalized greeting for the time of day
+//!
+//! A name is made of letters, spaces, hyphens, apostrophes and periods, so
+//! "Mary-Jane O'Neil" and "J.R.R. Tolkien" are fine. It can be shown in title
+//! case with `--title-case`, as initials with `--initials`, and after an
+//! honorific such as `--title Dr.`.
+//!
+//! Greeting templates from the `[c02.templates]` table of the config file
+//! replace the built-in greeting: one is picked at random, or by name with
+//! `--template`, and its `{name}`, `{time}`, `{date}` and `{weekday}`
+//! placeholders are filled in.
+//!
+//! The greeting follows the local clock, "Good morning" until noon, "Good
+//! afternoon" until six and "Good evening" after that, and is in the language
+//! picked with `--lang`. `--greeting-lang` picks it separately, from more
+//! languages than the rest of the messages are translated into.
+//!
+//! The program uses proper error handling with Result types and
+//! efficient I/O operations with buffered input/output.
+use challenge_common::cli::CommonArgs;
+use challenge_common::config;
+use challenge_common::input::Input;
+use challenge_common::l10n::{self, Lang};
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use challenge_common::rng::RngArgs;
+use chrono::{Local, NaiveDateTime, Timelike};
+use clap::Parser;
+use rand::seq::IteratorRandom;
+use rand::Rng;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+/// The parts of the day that have a greeting of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PartOfDay {
+    Morning,
+    Afternoon,
+    Evening,
+}
+
+impl PartOfDay {
+    /// Returns the part of the day `hour`, from 0 to 23, falls in. The night
+    /// counts as evening until 5 in the morning.
+    fn from_hour(hour: u32) -> Self {
+        match hour {
+            5..=11 => PartOfDay::Morning,
+            12..=17 => PartOfDay::Afternoon,
+            _ => PartOfDay::Evening,
+        }
+    }
+}
+
+/// A language the greeting can be in.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum GreetingLang {
+    /// English
+    En,
+    /// Spanish
+    Es,
+    /// French
+    Fr,
+    /// German
+    De,
+    /// Portuguese
+    Pt,
+}
+
+impl GreetingLang {
+    /// The language the rest of the messages are in.
+    fn from_lang(lang: Lang) -> Self {
+        match lang {
+            Lang::En => GreetingLang::En,
+            Lang::Es => GreetingLang::Es,
+        }
+    }
+
+    /// The greeting for `part` of the day, with a `{name}` placeholder.
+    fn template(self, part: PartOfDay) -> &'static str {
+        let [morning, afternoon, evening] = match self {
+            GreetingLang::En => ["Good morning", "Good afternoon", "Good evening"],
+            GreetingLang::Es => ["Buenos días", "Buenas tardes", "Buenas noches"],
+            GreetingLang::Fr => ["Bonjour", "Bon après-midi", "Bonsoir"],
+            GreetingLang::De => ["Guten Morgen", "Guten Tag", "Guten Abend"],
+            GreetingLang::Pt => ["Bom dia", "Boa tarde", "Boa noite"],
+        };
+        match part {
+            PartOfDay::Morning => morning,
+            PartOfDay::Afternoon => afternoon,
+            PartOfDay::Evening => evening,
+        }
+    }
+}
+
+/// Greets `name` as suits `part` of the day, in `lang`.
+fn greeting(part: PartOfDay, name: &str, lang: GreetingLang) -> String {
+    format!("{}, {}", lang.template(part), name.trim())
+}
+
+/// Picks the template named `name` from `templates`, or one picked with `rng`
+/// if no name is given. Returns `None` if there are no templates to pick
+/// from.
+fn pick_template<'a, R: Rng + ?Sized>(
+    templates: &'a BTreeMap<String, String>,
+    name: Option<&str>,
+    rng: &mut R,
+) -> Result<Option<&'a str>, String> {
+    let Some(name) = name else {
+        return Ok(templates.values().choose(rng).map(String::as_str));
+    };
+    match templates.get(name) {
+        Some(template) => Ok(Some(template)),
+        None if templates.is_empty() => Err(format!(
+            "no greeting template '{name}': the config file has no [c02.templates]"
+        )),
+        None => Err(format!(
+            "no greeting template '{name}' (choose from {})",
+            templates.keys().cloned().collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// Fills the placeholders of `template` for greeting `name` at `now`.
+fn fill_template(template: &str, name: &str, now: NaiveDateTime) -> String {
+    [
+        ("{name}", name.to_string()),
+        ("{time}", now.format("%H:%M").to_string()),
+        ("{date}", now.format("%Y-%m-%d").to_string()),
+        ("{weekday}", now.format("%A").to_string()),
+    ]
+    .iter()
+    .fold(template.to_string(), |text, (placeholder, value)| {
+        text.replace(placeholder, value)
+    })
+}
+
+/// Checks that `answer` looks like a name and returns it with its words one
+/// space apart.
+fn parse_name(answer: &str) -> Result<String, String> {
+    let allowed = |c: char| c.is_alphabetic() || c.is_whitespace() || "-'.".contains(c);
+    if !answer.chars().any(char::is_alphabetic) || !answer.chars().all(allowed) {
+        return Err(l10n::text("c02.name_invalid").to_string());
+    }
+    Ok(answer.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Asks for a name until one passes [`parse_name`].
+fn ask_name<R: BufRead, W: Write>(reader: &mut R, writer: &mut W) -> std::io::Result<String> {
+    Prompt::new(l10n::text("c02.name")).ask(reader, writer, parse_name)
+}
+
+/// Capitalizes the first letter of each part of `name`, the parts being
+/// separated by spaces or hyphens, and lowercases the rest.
+fn title_case(name: &str) -> String {
+    let mut start = true;
+    name.chars()
+        .flat_map(|c| {
+            let cased: Vec<_> = if start {
+                c.to_uppercase().collect()
+            } else {
+                c.to_lowercase().collect()
+            };
+            start = c == ' ' || c == '-';
+            cased
+        })
+        .collect()
+}
+
+/// Returns the initials of `name`, e.g. "A. L." for "Ada Lovelace".
+fn initials(name: &str) -> String {
+    name.split([' ', '-'])
+        .filter_map(|part| part.chars().find(|c| c.is_alphabetic()))
+        .map(|c| format!("{}.", c.to_uppercase()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// How the name is shown in the greeting.
+#[derive(Debug, Clone, Default)]
+struct NameFormat {
+    title_case: bool,
+    initials: bool,
+    /// An honorific shown before the name.
+    title: Option<String>,
+}
+
+impl NameFormat {
+    fn apply(&self, name: &str) -> String {
+        let name = if self.initials {
+            initials(name)
+        } else if self.title_case {
+            title_case(name)
+        } else {
+            name.to_string()
+        };
+        match &self.title {
+            Some(title) => format!("{} {}", title.trim(), name),
+            None => name,
+        }
+    }
+}
+
+/// Greet the user by name.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Name to greet instead of prompting for one
+    #[arg(short, long, value_parser = parse_name)]
+    name: Option<String>,
+    /// Capitalize each part of the name, e.g. "Mary-Jane O'neil"
+    #[arg(long)]
+    title_case: bool,
+    /// Greet by initials only, e.g. "A. L."
+    #[arg(long, conflicts_with = "title_case")]
+    initials: bool,
+    /// Honorific to put before the name, e.g. `Dr.`
+    #[arg(long)]
+    title: Option<String>,
+    /// Greet as if it were this hour, from 0 to 23 [default: the local time]
+    #[arg(long, value_parser = clap::value_parser!(u32).range(0..24))]
+    hour: Option<u32>,
+    /// Greeting template from the config file to use [default: a random one]
+    #[arg(long, value_name = "NAME")]
+    template: Option<String>,
+    /// Language of the greeting [default: the language of --lang]
+    #[arg(long, value_enum, value_name = "LANG")]
+    greeting_lang: Option<GreetingLang>,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let template = match pick_template(
+        &config::get().c02.templates,
+        args.template.as_deref(),
+        &mut rng,
+    ) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let name = match args.name {
+        Some(name) => name,
+        None => match ask_name(&mut input, &mut std::io::stdout()) {
+            Ok(name) => name,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    let format = NameFormat {
+        title_case: args.title_case,
+        initials: args.initials,
+        title: args.title,
+    };
+
+    let mut now = Local::now().naive_local();
+    if let Some(hour) = args.hour {
+        now = now.with_hour(hour).unwrap_or(now);
+    }
+    let name = format.apply(&name);
+    match template {
+        Some(template) => println!("{}", fill_template(template, &name, now)),
+        None => {
+            let lang = args
+                .greeting_lang
+                .unwrap_or_else(|| GreetingLang::from_lang(l10n::lang()));
+            println!(
+                "{}",
+                greeting(PartOfDay::from_hour(now.hour()), &name, lang)
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn from_hour_splits_the_day() {
+        assert_eq!(PartOfDay::from_hour(4), PartOfDay::Evening);
+        assert_eq!(PartOfDay::from_hour(5), PartOfDay::Morning);
+        assert_eq!(PartOfDay::from_hour(12), PartOfDay::Afternoon);
+        assert_eq!(PartOfDay::from_hour(18), PartOfDay::Evening);
+        assert_eq!(PartOfDay::from_hour(23), PartOfDay::Evening);
+    }
+
+    #[test]
+    fn greeting_trims_the_name() {
+        assert_eq!(
+            greeting(PartOfDay::Afternoon, " Ada\n", GreetingLang::En),
+            "Good afternoon, Ada"
+        );
+        assert_eq!(
+            greeting(PartOfDay::Evening, "Ada", GreetingLang::Pt),
+            "Boa noite, Ada"
+        );
+    }
+
+    #[test]
+    fn fill_template_replaces_every_placeholder() {
+        let now = chrono::NaiveDate::from_ymd_opt(2025, 3, 14)
+            .unwrap()
+            .and_hms_opt(9, 5, 0)
+            .unwrap();
+        assert_eq!(
+            fill_template(
+                "Yo {name}, it's {weekday}! {time} on {date}, {name}.",
+                "Ada",
+                now
+            ),
+            "Yo Ada, it's Friday! 09:05 on 2025-03-14, Ada."
+        );
+    }
+
+    #[test]
+    fn pick_template_by_name_or_at_random() {
+        let templates = BTreeMap::from([
+            ("hi".to_string(), "Hi {name}".to_string()),
+            ("yo".to_string(), "Yo {name}".to_string()),
+        ]);
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(
+            pick_template(&templates, Some("yo"), &mut rng),
+            Ok(Some("Yo {name}"))
+        );
+        assert!(pick_template(&templates, None, &mut rng).unwrap().is_some());
+        assert_eq!(
+            pick_template(&templates, Some("hey"), &mut rng).unwrap_err(),
+            "no greeting template 'hey' (choose from hi, yo)"
+        );
+        assert_eq!(pick_template(&BTreeMap::new(), None, &mut rng), Ok(None));
+    }
+
+    #[test]
+    fn ask_name_reprompts_until_the_name_is_valid() {
+        let mut output = Vec::new();
+
+        let name = ask_name(
+            &mut "\nR2-D2\n  Mary-Jane   O'Neil \n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(name, "Mary-Jane O'Neil");
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output
+                .matches("Invalid input. Please enter a name made of letters.")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn parse_name_accepts_initials_and_rejects_punctuation_only() {
+        assert_eq!(parse_name("J.R.R. Tolkien").unwrap(), "J.R.R. Tolkien");
+        assert!(parse_name("--").is_err());
+        assert!(parse_name("ada@example.com").is_err());
+    }
+
+    #[test]
+    fn name_format_applies_case_initials_and_title() {
+        let title_case = NameFormat {
+            title_case: true,
+            ..NameFormat::default()
+        };
+        assert_eq!(title_case.apply("mary-jane o'NEIL"), "Mary-Jane O'neil");
+
+        let initials = NameFormat {
+            initials: true,
+            title: Some("Dr.".to_string()),
+            ..NameFormat::default()
+        };
+        assert_eq!(initials.apply("Ada King-Lovelace"), "Dr. A. K. L.");
+        assert_eq!(NameFormat::default().apply("ada"), "ada");
+    }
+}
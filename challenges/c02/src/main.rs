@@ -1,20 +1,7 @@
-//! A simple command-line name greeting program.
-//!
-//! This program demonstrates basic Rust I/O operations by:
-//! - Prompting the user for their name
-//! - Reading input from stdin
-//! - Trimming whitespace from the input
-//! - Printing a personalized greeting
-//!
-//! The program uses proper error handling with Result types and
-//! efficient I/O operations with buffered input/output.
-fn main() -> Result<(), std::io::Error> {
-    println!("What is you name?");
+//! The `c02` binary. The program itself is in the library, so that the
+//! `lbpc` launcher can run it in-process too.
+use std::process::ExitCode;
 
-    let mut name = String::new();
-    std::io::stdin().read_line(&mut name)?;
-
-    println!("Hello, {}", name.trim());
-
-    Ok(())
+fn main() -> ExitCode {
+    c02::run_cli(std::env::args_os().collect())
 }
@@ -0,0 +1,223 @@
+//! Batch mode: the shapes in a CSV file, one per row, and their results as
+//! CSV.
+//!
+//! Each row names a shape as on the command line and lists its dimensions in
+//! the order the shape asks for them, e.g. `rectangle,2.5,4` or
+//! `cuboid,1,2,3`. A polygon lists the x and y of each vertex in turn, e.g.
+//! `polygon,0,0,4,0,4,3`. Blank lines, lines starting with `#` and a header row
+//! starting with `shape` are skipped. A field may be quoted as in the results,
+//! e.g. `"rectangle","2.5",4`. A row that cannot be worked out gets its error
+//! in the results instead of stopping the batch.
+use super::shapes::{self, Shape, SHAPES};
+use super::{measure as measure_of, parse_dimension, Measure, Unit};
+use challenge_error::ChallengeError;
+use std::io::{BufRead, Write};
+
+/// The header of the results.
+const HEADER: &str = "line,shape,measure,value,unit,error";
+
+/// Splits a row of the input into its fields, without the quotes around a
+/// quoted field and with its doubled quotes made single.
+fn split_row(row: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted || field.trim().is_empty() => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+/// Reads the shape in a row of the input.
+fn parse_row(row: &str) -> challenge_error::Result<Box<dyn Shape>> {
+    let fields = split_row(row);
+    let mut fields = fields.iter().map(String::as_str);
+    let name = fields.next().unwrap_or_default().to_lowercase();
+    let Some(kind) = shapes::find(&name) else {
+        let known: Vec<_> = SHAPES.iter().map(|kind| kind.name).collect();
+        return Err(ChallengeError::validation(
+            "shape",
+            &format!("'{}' is not one of {}", name, known.join(", ")),
+        ));
+    };
+    let fields: Vec<_> = fields.collect();
+    if kind.vertices {
+        if fields.len() % 2 != 0 {
+            return Err(ChallengeError::validation(
+                kind.name,
+                "needs an x and a y for each vertex",
+            ));
+        }
+        let coords = fields
+            .into_iter()
+            .map(challenge_error::parse)
+            .collect::<challenge_error::Result<Vec<_>>>()?;
+        return (kind.build)(&coords);
+    }
+    if fields.len() != kind.dimensions.len() {
+        return Err(ChallengeError::validation(
+            kind.name,
+            &format!(
+                "needs {} dimensions ({}), not {}",
+                kind.dimensions.len(),
+                kind.dimensions.join(", "),
+                fields.len()
+            ),
+        ));
+    }
+    let dims = kind
+        .dimensions
+        .iter()
+        .zip(fields)
+        .map(|(dimension, field)| parse_dimension(dimension, field))
+        .collect::<challenge_error::Result<Vec<_>>>()?;
+    (kind.build)(&dims)
+}
+
+/// Quotes `field` if it would otherwise not read back as one CSV field.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Works out `measure` of every shape in the CSV read from `reader` and
+/// writes the results to `writer`. With `units`, the dimensions are in the
+/// first unit and the results in the second. Returns the line number and
+/// error of each row that could not be worked out.
+pub fn run<R: BufRead, W: Write>(
+    reader: R,
+    writer: &mut W,
+    measure: Measure,
+    units: Option<(Unit, Unit)>,
+) -> std::io::Result<Vec<(usize, String)>> {
+    let mut failures = Vec::new();
+    writeln!(writer, "{}", HEADER)?;
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let row = line.trim();
+        let first = split_row(row).swap_remove(0);
+        if row.is_empty() || row.starts_with('#') || first.eq_ignore_ascii_case("shape") {
+            continue;
+        }
+        let number = index + 1;
+        let name = csv_field(&first.to_lowercase());
+        match parse_row(row) {
+            Ok(shape) => {
+                let mut measurement = measure_of(shape.as_ref(), measure);
+                if let Some((from, to)) = units {
+                    measurement = measurement.convert(from, to);
+                }
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},",
+                    number,
+                    name,
+                    measurement.key(),
+                    measurement.value,
+                    measurement.unit_symbol()
+                )?;
+            }
+            Err(e) => {
+                let error = e.to_string();
+                writeln!(writer, "{},{},,,,{}", number, name, csv_field(&error))?;
+                failures.push((number, error));
+            }
+        }
+    }
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_reports_bad_rows_and_carries_on() {
+        let input = "shape,dimensions\n\
+                     rectangle,2.5,4\n\
+                     \n\
+                     # a comment\n\
+                     hexagon,1\n\
+                     cuboid, 1, 2, 3\n\
+                     circle,0\n\
+                     cone,1\n\
+                     polygon,0,0,1\n";
+        let mut output = Vec::new();
+
+        let failures = run(input.as_bytes(), &mut output, Measure::Volume, None).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "line,shape,measure,value,unit,error\n\
+             2,rectangle,area,10,units²,\n\
+             5,hexagon,,,,\"shape 'hexagon' is not one of rectangle, cuboid, circle, \
+             triangle, triangle-sides, sphere, cylinder, cone, polygon\"\n\
+             6,cuboid,volume,6,units³,\n\
+             7,circle,,,,radius must be greater than zero\n\
+             8,cone,,,,\"cone needs 2 dimensions (radius, height), not 1\"\n\
+             9,polygon,,,,polygon needs an x and a y for each vertex\n"
+        );
+        let lines: Vec<_> = failures.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, [5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn split_row_unquotes_fields() {
+        assert_eq!(
+            split_row(r#""cuboid", 1,"2" ,"say ""3"", ok""#),
+            ["cuboid", "1", "2", r#"say "3", ok"#]
+        );
+        assert_eq!(split_row("rectangle,,4"), ["rectangle", "", "4"]);
+    }
+
+    #[test]
+    fn run_reads_quoted_fields() {
+        let mut output = Vec::new();
+
+        let failures = run(
+            "\"shape\",\"dimensions\"\n\"rectangle\",\"2.5\",4\n".as_bytes(),
+            &mut output,
+            Measure::Volume,
+            None,
+        )
+        .unwrap();
+
+        assert!(failures.is_empty());
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "line,shape,measure,value,unit,error\n2,rectangle,area,10,units²,\n"
+        );
+    }
+
+    #[test]
+    fn run_applies_the_measure_and_units() {
+        let mut output = Vec::new();
+
+        let failures = run(
+            "cuboid,1,2,3\ntriangle-sides,3,4,5\npolygon,0,0,1,0,1,1,0,1\n".as_bytes(),
+            &mut output,
+            Measure::SurfaceArea,
+            Some((Unit::M, Unit::Cm)),
+        )
+        .unwrap();
+
+        assert!(failures.is_empty());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("1,cuboid,surface_area,220000,cm²,\n"));
+        assert!(output.contains("2,triangle-sides,area,60000,cm²,\n"));
+        assert!(output.contains("3,polygon,area,10000,cm²,\n"));
+    }
+}
@@ -0,0 +1,158 @@
+//! Compare mode: two shapes, and which of them is bigger and by how much.
+//!
+//! Both shapes are measured the same way, so two solids are compared by
+//! volume or both by surface area. A flat shape can be compared with the
+//! surface area of a solid, but not with its volume.
+use super::shapes::Shape;
+use super::{prompt_for_measure, prompt_for_shape, Measure, Measurement};
+use challenge_common::l10n;
+use challenge_error::ChallengeError;
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{BufRead, Write};
+
+/// The measurements of two shapes, in the same unit.
+#[derive(Debug)]
+pub struct Comparison {
+    pub first: Measurement,
+    pub second: Measurement,
+}
+
+impl Comparison {
+    /// Checks that `first` and `second` are both areas or both volumes.
+    pub fn new(first: Measurement, second: Measurement) -> challenge_error::Result<Self> {
+        if first.exponent != second.exponent {
+            return Err(ChallengeError::validation(
+                "shapes",
+                "cannot be compared when one is measured by area and the other by volume",
+            ));
+        }
+        Ok(Comparison { first, second })
+    }
+
+    /// How the first shape compares with the second.
+    pub fn ordering(&self) -> Ordering {
+        self.first.value.total_cmp(&self.second.value)
+    }
+
+    /// How much bigger the larger shape is.
+    pub fn difference(&self) -> f64 {
+        (self.first.value - self.second.value).abs()
+    }
+
+    /// How much bigger the larger shape is, as a percentage of the smaller.
+    pub fn percent(&self) -> f64 {
+        self.difference() / self.first.value.min(self.second.value) * 100.0
+    }
+
+    /// `first` or `second` for the larger shape, or `None` when they are the
+    /// same size.
+    pub fn larger(&self) -> Option<&'static str> {
+        match self.ordering() {
+            Ordering::Greater => Some("first"),
+            Ordering::Less => Some("second"),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let key = match self.ordering() {
+            Ordering::Greater => "c03.first_bigger",
+            Ordering::Less => "c03.second_bigger",
+            Ordering::Equal => return write!(f, "{}", l10n::text("c03.same_size")),
+        };
+        let percent = format!("{:.1}", self.percent());
+        write!(
+            f,
+            "{}",
+            l10n::format(
+                key,
+                &[
+                    ("difference", &self.difference()),
+                    ("unit", &self.first.unit_text()),
+                    ("percent", &percent),
+                ],
+            )
+        )
+    }
+}
+
+/// Prompts for the two shapes to compare and how to measure them. A solid
+/// is measured by `measure` if given, and otherwise the way the player
+/// picks for the first solid.
+pub fn prompt<R: BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    measure: Option<Measure>,
+) -> challenge_error::Result<[(Box<dyn Shape>, Measure); 2]> {
+    let mut measure = measure;
+    let mut shape = |heading: &str| -> challenge_error::Result<(Box<dyn Shape>, Measure)> {
+        writeln!(writer, "{}", l10n::text(heading))?;
+        let shape = prompt_for_shape(reader, writer)?;
+        let chosen = match measure {
+            Some(measure) => measure,
+            None if shape.volume().is_some() => {
+                let chosen = prompt_for_measure(reader, writer)?;
+                measure = Some(chosen);
+                chosen
+            }
+            None => Measure::default(),
+        };
+        Ok((shape, chosen))
+    };
+    Ok([shape("c03.first")?, shape("c03.second")?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparison_reports_the_larger_shape() {
+        let comparison =
+            Comparison::new(Measurement::volume(24.0), Measurement::volume(27.0)).unwrap();
+        assert_eq!(comparison.larger(), Some("second"));
+        assert_eq!(
+            comparison.to_string(),
+            "The second shape is bigger by 3 units³ (12.5% more)"
+        );
+
+        let comparison = Comparison::new(Measurement::area(3.0), Measurement::area(2.0)).unwrap();
+        assert_eq!(
+            comparison.to_string(),
+            "The first shape is bigger by 1 units² (50.0% more)"
+        );
+
+        let comparison =
+            Comparison::new(Measurement::area(6.0), Measurement::surface_area(6.0)).unwrap();
+        assert_eq!(comparison.larger(), None);
+        assert_eq!(comparison.to_string(), "The two shapes are the same size");
+    }
+
+    #[test]
+    fn comparison_needs_the_same_kind_of_measurement() {
+        assert_eq!(
+            Comparison::new(Measurement::area(1.0), Measurement::volume(1.0))
+                .unwrap_err()
+                .to_string(),
+            "shapes cannot be compared when one is measured by area and the other by volume"
+        );
+    }
+
+    #[test]
+    fn prompt_measures_both_solids_the_same_way() {
+        let mut output = Vec::new();
+        let [first, second] =
+            prompt(&mut "2\n2\n3\n4\n2\n6\n3\n".as_bytes(), &mut output, None).unwrap();
+
+        assert_eq!(first.1, Measure::SurfaceArea);
+        assert_eq!(second.0.kind().name, "sphere");
+        assert_eq!(second.1, Measure::SurfaceArea);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("First shape:\nEnter 1 for Rectangle"));
+        assert!(output.contains("Second shape:\n"));
+        assert_eq!(output.matches("Enter 1 for Volume").count(), 1);
+    }
+}
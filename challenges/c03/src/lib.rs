@@ -0,0 +1,948 @@
+//! A geometric shape calculator that computes areas and volumes.
+//!
+//! This program allows users to calculate:
+//! - Rectangle areas by providing width and height
+//! - Circle areas by providing the radius
+//! - Triangle areas by providing base and height, or the three sides (using
+//!   Heron's formula)
+//! - Cuboid volumes by providing width, height, and depth
+//! - Sphere volumes by providing the radius
+//! - Cylinder and cone volumes by providing radius and height
+//! - Polygon areas by providing the vertices (using the shoelace formula)
+//! - The surface area of any of the solid shapes instead of its volume
+//! - Results in the unit the dimensions are measured in (`--unit`), or
+//!   converted to another one (`--to`), from millimeters up to feet
+//!
+//! # Features
+//! - Interactive command-line interface
+//! - Input validation for dimensions (positive numbers only)
+//! - Error handling for invalid inputs
+//! - Support for floating-point dimensions
+//! - `--format json` output of the dimensions and the result
+//!
+//! # Usage
+//! The program prompts users to:
+//! 1. Choose a shape type (1 for Rectangle, 2 for Cuboid, 3 for Circle, and
+//!    so on)
+//! 2. Enter dimensions when prompted
+//! 3. For a solid shape, choose between its volume and its surface area
+//! 4. Displays the calculated area or volume, in square or cubic units
+//! 5. Choose a new shape, the last shape again with new dimensions, or
+//!    quitting, which ends the session with a summary of every calculation
+//!    (see the [`session`] module)
+//!
+//! With `--format json` there is a single calculation, so that the output
+//! is one document.
+//!
+//! The shape and its dimensions can also be given on the command line, e.g.
+//! `c03 cuboid --width 2 --height 3 --depth 4`. Any dimension left out is
+//! prompted for. A solid shape given this way has its volume calculated,
+//! unless `--measure surface-area` asks for its surface area.
+//!
+//! `--input shapes.csv` works out every shape in a CSV file instead, as
+//! described in the [`batch`] module, and writes the results as CSV to
+//! stdout or to the file named by `--output`.
+//!
+//! The shapes themselves are in the [`shapes`] module, and the menu and the
+//! subcommands are made from its registry.
+//!
+//! # Error Handling
+//! The program validates all inputs and asks again after:
+//! - Non-numeric inputs
+//! - Negative dimensions
+//! - Zero dimensions
+//! - Triangle sides that cannot meet, such as 1, 2 and 5
+//! - Invalid shape choices
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs, Value};
+use challenge_common::prompt::{self, Prompt};
+use challenge_error::ChallengeError;
+use clap::{CommandFactory, FromArgMatches, Parser, ValueEnum};
+use shapes::{Kind, Shape, SHAPES};
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod batch;
+mod compare;
+mod session;
+mod shapes;
+
+/// What to calculate for a solid shape.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Measure {
+    #[default]
+    Volume,
+    SurfaceArea,
+}
+
+/// A unit of length the dimensions can be measured in.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Mm,
+    Cm,
+    M,
+    In,
+    Ft,
+}
+
+impl Unit {
+    /// The length of the unit in meters.
+    fn meters(self) -> f64 {
+        match self {
+            Unit::Mm => 0.001,
+            Unit::Cm => 0.01,
+            Unit::M => 1.0,
+            Unit::In => 0.0254,
+            Unit::Ft => 0.3048,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Unit::Mm => "mm",
+            Unit::Cm => "cm",
+            Unit::M => "m",
+            Unit::In => "in",
+            Unit::Ft => "ft",
+        }
+    }
+}
+
+/// A calculated area or volume.
+#[derive(Debug, PartialEq)]
+struct Measurement {
+    /// "Area", "Surface area" or "Volume".
+    label: &'static str,
+    value: f64,
+    /// The power of the unit: 2 for areas and 3 for volumes.
+    exponent: u32,
+    /// The unit of length the value is in the square or cube of, or `None`
+    /// for plain units.
+    unit: Option<Unit>,
+}
+
+impl Measurement {
+    fn area(value: f64) -> Self {
+        Measurement {
+            label: "Area",
+            value,
+            exponent: 2,
+            unit: None,
+        }
+    }
+
+    fn surface_area(value: f64) -> Self {
+        Measurement {
+            label: "Surface area",
+            value,
+            exponent: 2,
+            unit: None,
+        }
+    }
+
+    fn volume(value: f64) -> Self {
+        Measurement {
+            label: "Volume",
+            value,
+            exponent: 3,
+            unit: None,
+        }
+    }
+
+    /// Converts a measurement of dimensions given in `from` to the square or
+    /// cube of `to`.
+    fn convert(self, from: Unit, to: Unit) -> Self {
+        let factor = (from.meters() / to.meters()).powi(self.exponent as i32);
+        Measurement {
+            value: self.value * factor,
+            unit: Some(to),
+            ..self
+        }
+    }
+
+    /// The key of the value in the JSON result, e.g. `surface_area`.
+    fn key(&self) -> String {
+        self.label.to_lowercase().replace(' ', "_")
+    }
+
+    /// The unit of the value, e.g. `cm²`, or `units²` without a unit of
+    /// length.
+    fn unit_symbol(&self) -> String {
+        self.unit_or("units")
+    }
+
+    /// The unit of the value as the player reads it, with plain units in
+    /// their language.
+    fn unit_text(&self) -> String {
+        self.unit_or(l10n::text("c03.units"))
+    }
+
+    /// The unit of the value, with `plain` as the unit without a unit of
+    /// length.
+    fn unit_or(&self, plain: &'static str) -> String {
+        let power = match self.exponent {
+            2 => "²",
+            3 => "³",
+            _ => "",
+        };
+        let unit = self.unit.map_or(plain, Unit::symbol);
+        format!("{unit}{power}")
+    }
+}
+
+impl std::fmt::Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let label = l10n::text(&format!("c03.{}", self.key()));
+        write!(f, "{}: {} {}", label, self.value, self.unit_text())
+    }
+}
+
+/// Calculates the area of a flat shape, or the `measure` of a solid one.
+fn measure(shape: &dyn Shape, measure: Measure) -> Measurement {
+    match shape.volume() {
+        Some(volume) if measure == Measure::Volume => Measurement::volume(volume),
+        Some(_) => Measurement::surface_area(shape.area()),
+        None => Measurement::area(shape.area()),
+    }
+}
+
+/// The shape's name and dimensions, as reported by `--format json`.
+fn to_json(shape: &dyn Shape) -> Value {
+    let kind = shape.kind();
+    let mut json = json!({ "shape": kind.name });
+    if kind.vertices {
+        let vertices: Vec<_> = shape.dimensions().chunks(2).map(|xy| json!(xy)).collect();
+        json["vertices"] = json!(vertices);
+    }
+    for (dimension, value) in kind.dimensions.iter().zip(shape.dimensions()) {
+        json[dimension.replace(' ', "_")] = json!(value);
+    }
+    json
+}
+
+fn parse_dimension(dimension: &str, answer: &str) -> challenge_error::Result<f64> {
+    let dim = challenge_error::parse(answer)?;
+    if dim <= 0.0 {
+        return Err(ChallengeError::validation(
+            dimension,
+            "must be greater than zero",
+        ));
+    }
+
+    Ok(dim)
+}
+
+fn prompt_for_dimension<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    dimension: &str,
+) -> challenge_error::Result<f64> {
+    let name = l10n::text(&format!("c03.{dimension}"));
+    let message = l10n::format("c03.dimension", &[("dimension", &name)]);
+    Ok(Prompt::new(&message)
+        .inline()
+        .ask(reader, writer, |answer| parse_dimension(dimension, answer))?)
+}
+
+fn dimension_or_prompt<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    dimension: &str,
+    given: Option<f64>,
+) -> challenge_error::Result<f64> {
+    match given {
+        Some(value) => Ok(value),
+        None => prompt_for_dimension(reader, writer, dimension),
+    }
+}
+
+/// Reads vertices given as `x,y` pairs separated by spaces, e.g.
+/// `0,0 4,0 4,3`, into their coordinates in turn.
+fn parse_vertices(answer: &str) -> challenge_error::Result<Vec<f64>> {
+    let mut coords = Vec::new();
+    for pair in answer.split_whitespace() {
+        let Some((x, y)) = pair.split_once(',') else {
+            return Err(ChallengeError::validation(
+                "vertex",
+                &format!("'{pair}' is not an x,y pair"),
+            ));
+        };
+        coords.push(challenge_error::parse(x)?);
+        coords.push(challenge_error::parse(y)?);
+    }
+    Ok(coords)
+}
+
+/// Prompts for the vertices of a `kind` of shape until they make one.
+fn prompt_for_vertices<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    kind: &Kind,
+) -> challenge_error::Result<Box<dyn Shape>> {
+    Ok(Prompt::new(l10n::text("c03.vertices"))
+        .inline()
+        .ask(reader, writer, |answer| {
+            (kind.build)(&parse_vertices(answer)?)
+        })?)
+}
+
+/// A kind of shape picked on the command line or from the menu, with the
+/// dimensions given so far.
+struct ShapeRequest {
+    kind: &'static Kind,
+    /// A value for each of the kind's dimensions, if it was given.
+    given: Vec<Option<f64>>,
+    /// The coordinates of the vertices of a polygon, if they were given.
+    vertices: Option<Vec<f64>>,
+}
+
+impl ShapeRequest {
+    /// A request for `kind` with none of its dimensions given.
+    fn new(kind: &'static Kind) -> Self {
+        ShapeRequest {
+            kind,
+            given: vec![None; kind.dimensions.len()],
+            vertices: None,
+        }
+    }
+}
+
+/// Builds the shape requested, prompting for the dimensions or vertices that
+/// were not given.
+fn complete_shape<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    request: ShapeRequest,
+) -> challenge_error::Result<Box<dyn Shape>> {
+    if request.kind.vertices {
+        return match request.vertices {
+            Some(coords) => (request.kind.build)(&coords),
+            None => prompt_for_vertices(reader, writer, request.kind),
+        };
+    }
+    let dims = request
+        .kind
+        .dimensions
+        .iter()
+        .zip(request.given)
+        .map(|(dimension, given)| dimension_or_prompt(reader, writer, dimension, given))
+        .collect::<challenge_error::Result<Vec<_>>>()?;
+    (request.kind.build)(&dims)
+}
+
+fn prompt_for_shape<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> challenge_error::Result<Box<dyn Shape>> {
+    let numbers: Vec<_> = (1..=SHAPES.len()).map(|n| n.to_string()).collect();
+    let menu: Vec<_> = numbers
+        .iter()
+        .zip(SHAPES)
+        .map(|(number, kind)| {
+            let shape = l10n::text(&format!("c03.{}", kind.name));
+            l10n::format("c03.choice", &[("number", number), ("shape", &shape)])
+        })
+        .collect();
+    let message = l10n::format("c03.shape", &[("choices", &menu.join(", "))]);
+    let choices: Vec<_> = numbers
+        .iter()
+        .map(String::as_str)
+        .zip(SHAPES.iter().copied())
+        .collect();
+    let kind = Prompt::new(&message).ask_choice(reader, writer, &choices)?;
+    complete_shape(reader, writer, ShapeRequest::new(kind))
+}
+
+fn prompt_for_measure<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> challenge_error::Result<Measure> {
+    Ok(Prompt::new(l10n::text("c03.measure")).ask_choice(
+        reader,
+        writer,
+        &[("1", Measure::Volume), ("2", Measure::SurfaceArea)],
+    )?)
+}
+
+/// Calculate the area of a flat shape or the volume of a solid one.
+///
+/// Without a shape, the shape and its dimensions are prompted for
+/// interactively.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// What to calculate for a solid shape [default: volume, or asked for
+    /// along with the shape]
+    #[arg(long, value_enum, global = true)]
+    measure: Option<Measure>,
+    /// Unit the dimensions are measured in [default: plain units]
+    #[arg(long, value_enum, global = true)]
+    unit: Option<Unit>,
+    /// Unit to give the result in [default: the --unit of the dimensions]
+    #[arg(long, value_enum, global = true, requires = "unit")]
+    to: Option<Unit>,
+    /// CSV file of shapes to work out in one go, one shape per row
+    #[arg(long, value_name = "FILE")]
+    input: Option<PathBuf>,
+    /// File to write the results of --input to [default: stdout]
+    #[arg(long, value_name = "FILE", requires = "input")]
+    output: Option<PathBuf>,
+    /// Prompt for two shapes and report which of them is bigger
+    #[arg(long, conflicts_with = "input")]
+    compare: bool,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// The command line: [`Args`] with a subcommand for each kind of shape,
+/// taking its dimensions as options, e.g. `--side-a` for `side a`, or its
+/// vertices as `--vertices`.
+fn command() -> clap::Command {
+    Args::command().subcommands(SHAPES.iter().map(|kind| {
+        clap::Command::new(kind.name)
+            .about(kind.about)
+            .args(kind.dimensions.iter().map(|&dimension| {
+                clap::Arg::new(dimension)
+                    .long(dimension.replace(' ', "-"))
+                    .value_name(dimension.replace(' ', "_").to_uppercase())
+                    .value_parser(move |s: &str| parse_dimension(dimension, s))
+            }))
+            .args(kind.vertices.then(|| {
+                clap::Arg::new("vertices")
+                    .long("vertices")
+                    .value_name("X,Y ...")
+                    .help("The vertices in order around the shape, e.g. \"0,0 4,0 4,3\"")
+                    .value_parser(parse_vertices)
+            }))
+    }))
+}
+
+/// Parses the command line into the arguments and the shape requested, if
+/// any.
+fn parse_args<I, T>(args: I) -> Result<(Args, Option<ShapeRequest>), clap::Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let matches = command().try_get_matches_from(args)?;
+    let parsed = Args::from_arg_matches(&matches)?;
+    let request = matches.subcommand().map(|(name, matches)| {
+        let kind = shapes::find(name).expect("every subcommand is a shape");
+        ShapeRequest {
+            kind,
+            given: kind
+                .dimensions
+                .iter()
+                .map(|dimension| matches.get_one::<f64>(dimension).copied())
+                .collect(),
+            vertices: kind
+                .vertices
+                .then(|| matches.get_one::<Vec<f64>>("vertices").cloned())
+                .flatten(),
+        }
+    });
+    Ok((parsed, request))
+}
+
+/// Measures `shape`, converting the result with `units` if given. Returns the
+/// measurement with the shape and the result as reported by `--format json`.
+fn work_out(
+    shape: &dyn Shape,
+    chosen: Measure,
+    units: Option<(Unit, Unit)>,
+) -> (Measurement, Value, Value) {
+    let mut measurement = measure(shape, chosen);
+    let mut input = to_json(shape);
+    let mut result = json!({ measurement.key(): measurement.value });
+    if let Some((from, to)) = units {
+        measurement = measurement.convert(from, to);
+        input["unit"] = json!(from.symbol());
+        result = json!({
+            measurement.key(): measurement.value,
+            "unit": measurement.unit_symbol(),
+        });
+    }
+    (measurement, input, result)
+}
+
+/// Prompts for two shapes and reports which of them is bigger, as described
+/// in the [`compare`] module.
+fn run_compare(args: &Args, units: Option<(Unit, Unit)>) -> challenge_error::Result<()> {
+    let mut input = Input::from_args(&args.common)?;
+    let format = args.format.format();
+    let [first, second] = compare::prompt(&mut input, &mut format.prompts(), args.measure)?;
+    let (first, first_input, first_result) = work_out(first.0.as_ref(), first.1, units);
+    let (second, second_input, second_result) = work_out(second.0.as_ref(), second.1, units);
+    let comparison = compare::Comparison::new(first, second)?;
+    match format {
+        Format::Text => {
+            println!("{} {}", l10n::text("c03.first"), comparison.first);
+            println!("{} {}", l10n::text("c03.second"), comparison.second);
+            println!("{}", comparison);
+        }
+        Format::Json => output::write_json(
+            &mut std::io::stdout(),
+            "c03",
+            json!({ "first": first_input, "second": second_input }),
+            json!({
+                "first": first_result,
+                "second": second_result,
+                "larger": comparison.larger(),
+                "difference": comparison.difference(),
+                "percent": comparison.percent(),
+            }),
+        )?,
+    }
+    Ok(())
+}
+
+/// Works out the shapes in the CSV file at `path` and writes the results to
+/// `output`, or to stdout. Returns the rows that failed, as
+/// [`batch::run`] does.
+fn run_batch(
+    path: &Path,
+    output: Option<&Path>,
+    measure: Option<Measure>,
+    units: Option<(Unit, Unit)>,
+) -> Result<Vec<(usize, String)>, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let reader = std::io::BufReader::new(file);
+    let measure = measure.unwrap_or_default();
+    match output {
+        Some(output) => {
+            let mut writer = std::fs::File::create(output)
+                .map(std::io::BufWriter::new)
+                .map_err(|e| format!("{}: {e}", output.display()))?;
+            batch::run(reader, &mut writer, measure, units)
+                .and_then(|failures| writer.flush().map(|()| failures))
+                .map_err(|e| format!("{}: {e}", output.display()))
+        }
+        None => batch::run(reader, &mut std::io::stdout().lock(), measure, units)
+            .map_err(|e| format!("{}: {e}", path.display())),
+    }
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let (args, request) = match parse_args(args) {
+        Ok(parsed) => parsed,
+        Err(e) => return challenge_common::cli::report(&e),
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let units = args.unit.map(|unit| (unit, args.to.unwrap_or(unit)));
+
+    if let Some(path) = &args.input {
+        if request.is_some() {
+            eprintln!("Error: --input cannot be combined with a shape");
+            return ExitCode::SUCCESS;
+        }
+        if args.format.format() == Format::Json {
+            eprintln!("Error: --input writes its results as CSV, not --format json");
+            return ExitCode::SUCCESS;
+        }
+        match run_batch(path, args.output.as_deref(), args.measure, units) {
+            Ok(failures) => {
+                for (line, e) in failures {
+                    eprintln!("Warning: {} line {}: {}", path.display(), line, e);
+                }
+            }
+            Err(e) => eprintln!("Error: {}", e),
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if args.compare {
+        if request.is_some() {
+            eprintln!("Error: --compare cannot be combined with a shape");
+            return ExitCode::SUCCESS;
+        }
+        if let Err(e) = run_compare(&args, units) {
+            eprintln!("Error: {}", e);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if request.is_none() && args.format.format() == Format::Text {
+        let result = Input::from_args(&args.common)
+            .map_err(Into::into)
+            .and_then(|mut input| {
+                session::run(&mut input, &mut std::io::stdout(), args.measure, units)
+            });
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let shape = match Input::from_args(&args.common) {
+        Ok(mut input) => {
+            let mut prompts = args.format.format().prompts();
+            match request {
+                Some(request) => complete_shape(&mut input, &mut prompts, request)
+                    .map(|shape| (shape, args.measure.unwrap_or_default())),
+                None => prompt_for_shape(&mut input, &mut prompts).and_then(|shape| {
+                    let measure = match args.measure {
+                        Some(measure) => measure,
+                        None if shape.volume().is_some() => {
+                            prompt_for_measure(&mut input, &mut prompts)?
+                        }
+                        None => Measure::default(),
+                    };
+                    Ok((shape, measure))
+                }),
+            }
+        }
+        Err(e) => Err(e.into()),
+    };
+    let result = shape.and_then(|(shape, chosen)| {
+        let (measurement, input, result) = work_out(shape.as_ref(), chosen, units);
+        match args.format.format() {
+            Format::Text => println!("{}", measurement),
+            Format::Json => output::write_json(&mut std::io::stdout(), "c03", input, result)?,
+        }
+        Ok(())
+    });
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::{Cone, Cuboid, Rectangle};
+    use std::f64::consts::PI;
+    use std::io::BufReader;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    /// The name and dimensions of `shape`, for comparing shapes.
+    fn described(shape: &dyn Shape) -> (&'static str, Vec<f64>) {
+        (shape.kind().name, shape.dimensions())
+    }
+
+    #[test]
+    fn measurements_show_the_unit_exponent() {
+        let cuboid = Cuboid {
+            width: 2.0,
+            height: 3.0,
+            depth: 4.0,
+        };
+        assert_eq!(
+            measure(&cuboid, Measure::Volume).to_string(),
+            "Volume: 24 units³"
+        );
+        let surface = measure(&cuboid, Measure::SurfaceArea);
+        assert_eq!(surface.to_string(), "Surface area: 52 units²");
+        assert_eq!(surface.key(), "surface_area");
+        // A flat shape only has an area.
+        let rectangle = Rectangle {
+            width: 2.0,
+            height: 3.0,
+        };
+        assert_eq!(
+            measure(&rectangle, Measure::Volume).to_string(),
+            "Area: 6 units²"
+        );
+    }
+
+    #[test]
+    fn convert_scales_by_the_square_or_cube_of_the_unit() {
+        let area = Measurement::area(2.0).convert(Unit::M, Unit::Cm);
+        assert_close(area.value, 20_000.0);
+        assert_eq!(area.unit_symbol(), "cm²");
+
+        let volume = Measurement::volume(1.0).convert(Unit::Ft, Unit::In);
+        assert_close(volume.value, 1728.0);
+        assert_eq!(volume.unit_symbol(), "in³");
+
+        let same = Measurement::surface_area(5.0).convert(Unit::Mm, Unit::Mm);
+        assert_eq!(same.to_string(), "Surface area: 5 mm²");
+    }
+
+    #[test]
+    fn args_need_a_unit_to_convert_from() {
+        assert!(parse_args(["c03", "--to", "m"]).is_err());
+        let (args, _) = parse_args(["c03", "circle", "--unit", "in", "--to", "cm"]).unwrap();
+        assert_eq!((args.unit, args.to), (Some(Unit::In), Some(Unit::Cm)));
+    }
+
+    #[test]
+    fn prompt_for_measure_reprompts() {
+        let mut reader = BufReader::new("3\n2\n".as_bytes());
+        let mut output = Vec::new();
+
+        let measure = prompt_for_measure(&mut reader, &mut output).unwrap();
+
+        assert_eq!(measure, Measure::SurfaceArea);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Invalid input. Please enter '1' or '2'."));
+    }
+
+    #[test]
+    fn prompt_for_shape_creates_each_new_shape() {
+        let cases = [
+            ("3\n2\n", ("circle", vec![2.0])),
+            ("4\n4\n3\n", ("triangle", vec![4.0, 3.0])),
+            ("5\n3\n4\n5\n", ("triangle-sides", vec![3.0, 4.0, 5.0])),
+            ("6\n1\n", ("sphere", vec![1.0])),
+            ("7\n1\n2\n", ("cylinder", vec![1.0, 2.0])),
+            ("8\n1\n2\n", ("cone", vec![1.0, 2.0])),
+            (
+                "9\n0,0 4,0 4,3\n",
+                ("polygon", vec![0.0, 0.0, 4.0, 0.0, 4.0, 3.0]),
+            ),
+        ];
+        for (input, expected) in cases {
+            let mut reader = BufReader::new(input.as_bytes());
+            let shape = prompt_for_shape(&mut reader, &mut std::io::sink()).unwrap();
+            assert_eq!(described(shape.as_ref()), expected, "for input {input:?}");
+        }
+    }
+
+    #[test]
+    fn prompt_for_shape_checks_that_the_sides_meet() {
+        let mut reader = BufReader::new("5\n1\n2\n5\n".as_bytes());
+        let result = prompt_for_shape(&mut reader, &mut std::io::sink());
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "sides must each be shorter than the other two together"
+        );
+    }
+
+    #[test]
+    fn measure_names_area_or_volume() {
+        assert_eq!(
+            measure(&shapes::Circle { radius: 1.0 }, Measure::Volume).label,
+            "Area"
+        );
+        let cone = Cone {
+            radius: 1.0,
+            height: 3.0,
+        };
+        let volume = measure(&cone, Measure::Volume);
+        assert_eq!(volume.label, "Volume");
+        assert_close(volume.value, PI);
+    }
+
+    #[test]
+    fn to_json_names_the_shape_and_its_dimensions() {
+        let shape = shapes::TriangleSides::new(3.0, 4.0, 5.0).unwrap();
+        assert_eq!(
+            to_json(&shape),
+            json!({ "shape": "triangle-sides", "side_a": 3.0, "side_b": 4.0, "side_c": 5.0 })
+        );
+    }
+
+    #[test]
+    fn to_json_lists_the_vertices_of_a_polygon() {
+        let shape = shapes::Polygon::new(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 3.0)]).unwrap();
+        assert_eq!(
+            to_json(&shape),
+            json!({ "shape": "polygon", "vertices": [[0.0, 0.0], [4.0, 0.0], [4.0, 3.0]] })
+        );
+    }
+
+    #[test]
+    fn parse_vertices_reads_x_y_pairs() {
+        assert_eq!(
+            parse_vertices(" 0,0  -1.5,2 ").unwrap(),
+            [0.0, 0.0, -1.5, 2.0]
+        );
+        assert_eq!(
+            parse_vertices("0,0 4").unwrap_err().to_string(),
+            "vertex '4' is not an x,y pair"
+        );
+        assert!(parse_vertices("0,0 a,1").is_err());
+    }
+
+    #[test]
+    fn prompt_for_shape_asks_again_for_vertices_on_a_line() {
+        let mut reader = BufReader::new("9\n0,0 1,1\n0,0 1,1 2,2\n0,0 2,0 0,2\n".as_bytes());
+        let mut output = Vec::new();
+        let shape = prompt_for_shape(&mut reader, &mut output).unwrap();
+
+        assert_close(shape.area(), 2.0);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("vertices must be at least three points\n"));
+        assert!(output.contains("vertices must not all lie on one line\n"));
+    }
+
+    #[test]
+    fn parse_dimension_accepts_positive_input() {
+        assert_eq!(parse_dimension("width", "5.5").unwrap(), 5.5);
+    }
+
+    #[test]
+    fn parse_dimension_rejects_zero() {
+        assert_eq!(
+            parse_dimension("height", "0.0").unwrap_err().to_string(),
+            "height must be greater than zero"
+        );
+    }
+
+    #[test]
+    fn parse_dimension_rejects_negative_values() {
+        assert_eq!(
+            parse_dimension("length", "-2.5").unwrap_err().to_string(),
+            "length must be greater than zero"
+        );
+    }
+
+    #[test]
+    fn parse_dimension_rejects_non_numeric_input() {
+        assert!(matches!(
+            parse_dimension("width", "not_a_number"),
+            Err(ChallengeError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_dimension_rejects_empty_input() {
+        assert!(parse_dimension("height", "").is_err());
+    }
+
+    #[test]
+    fn prompt_for_shape_explains_an_invalid_choice() {
+        let mut reader = BufReader::new("10\n1\n2\n4\n".as_bytes());
+        let mut output = Vec::new();
+        let result = prompt_for_shape(&mut reader, &mut output);
+
+        assert_eq!(
+            described(result.unwrap().as_ref()),
+            ("rectangle", vec![2.0, 4.0])
+        );
+        assert!(String::from_utf8(output).unwrap().starts_with(
+            "Enter 1 for Rectangle, 2 for Cuboid, 3 for Circle, 4 for Triangle, \
+             5 for Triangle from three sides, 6 for Sphere, 7 for Cylinder, 8 for Cone, \
+             9 for Polygon\n\
+             Invalid input. Please enter '1', '2', '3', '4', '5', '6', '7', '8' or '9'.\n"
+        ));
+    }
+
+    #[test]
+    fn prompt_for_dimension_asks_again_after_invalid_input() {
+        let mut reader = BufReader::new("0.0\n-1\n5.5\n".as_bytes());
+        let mut output = Vec::new();
+        let result = prompt_for_dimension(&mut reader, &mut output, "width");
+
+        assert_eq!(result.unwrap(), 5.5);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Enter width:width must be greater than zero\n\
+             Enter width:width must be greater than zero\n\
+             Enter width:"
+        );
+    }
+
+    #[test]
+    fn prompt_for_shape_creates_valid_rectangle() {
+        let input = "1\n5.0\n3.0\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_shape(&mut reader, &mut std::io::sink());
+
+        assert_eq!(
+            described(result.unwrap().as_ref()),
+            ("rectangle", vec![5.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn prompt_for_shape_creates_valid_cuboid() {
+        let input = "2\n2.0\n3.0\n4.0\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_shape(&mut reader, &mut std::io::sink());
+
+        assert_eq!(
+            described(result.unwrap().as_ref()),
+            ("cuboid", vec![2.0, 3.0, 4.0])
+        );
+    }
+
+    #[test]
+    fn prompt_for_shape_asks_again_after_invalid_choice() {
+        let input = "10\nabc\n1\n2.0\n3.0\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_shape(&mut reader, &mut std::io::sink());
+
+        assert_eq!(
+            described(result.unwrap().as_ref()),
+            ("rectangle", vec![2.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn prompt_for_shape_asks_again_after_invalid_dimensions() {
+        let input = "2\n2.0\n0.0\n-3\n3.0\n4.0\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_shape(&mut reader, &mut std::io::sink());
+
+        assert_eq!(
+            described(result.unwrap().as_ref()),
+            ("cuboid", vec![2.0, 3.0, 4.0])
+        );
+    }
+
+    #[test]
+    fn complete_shape_prompts_only_for_missing_dimensions() {
+        let request = ShapeRequest {
+            kind: &Cuboid::KIND,
+            given: vec![Some(2.0), None, Some(4.0)],
+            vertices: None,
+        };
+        let mut reader = BufReader::new("3\n".as_bytes());
+        let mut output = Vec::new();
+        let result = complete_shape(&mut reader, &mut output, request);
+
+        assert_eq!(
+            described(result.unwrap().as_ref()),
+            ("cuboid", vec![2.0, 3.0, 4.0])
+        );
+        assert_eq!(String::from_utf8(output).unwrap(), "Enter height:");
+    }
+
+    #[test]
+    fn args_accept_a_shape_and_its_dimensions() {
+        let (_, request) =
+            parse_args(["c03", "rectangle", "--width", "2", "--height", "3.5"]).unwrap();
+        let request = request.unwrap();
+        assert_eq!(request.kind.name, "rectangle");
+        assert_eq!(request.given, [Some(2.0), Some(3.5)]);
+        let (_, request) = parse_args(["c03", "triangle-sides", "--side-b", "4"]).unwrap();
+        assert_eq!(request.unwrap().given, [None, Some(4.0), None]);
+        assert!(parse_args(["c03", "cuboid", "--depth", "0"]).is_err());
+        let (_, request) = parse_args(["c03", "polygon", "--vertices", "0,0 4,0 4,3"]).unwrap();
+        assert_eq!(
+            request.unwrap().vertices,
+            Some(vec![0.0, 0.0, 4.0, 0.0, 4.0, 3.0])
+        );
+        assert!(parse_args(["c03"]).unwrap().1.is_none());
+    }
+
+    #[test]
+    fn prompt_for_shape_stops_at_end_of_input() {
+        let input = "\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_shape(&mut reader, &mut std::io::sink());
+
+        assert!(result.is_err());
+    }
+}
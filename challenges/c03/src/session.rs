@@ -0,0 +1,210 @@
+//! The interactive session: one calculation after another until the player
+//! quits, then a summary of them all.
+//!
+//! After each result the player picks a new shape, the last shape again
+//! with new dimensions, or quitting. The input ending, or the abort
+//! command, at that point quits too.
+use super::shapes::Shape;
+use super::{
+    complete_shape, measure as measure_of, prompt_for_measure, prompt_for_shape, Measure,
+    Measurement, ShapeRequest, Unit,
+};
+use challenge_common::l10n;
+use challenge_common::prompt::{self, Prompt};
+use std::io::{BufRead, Write};
+
+/// What to do after a result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Next {
+    NewShape,
+    Repeat,
+    Quit,
+}
+
+/// A calculation made during the session.
+struct Entry {
+    shape: Box<dyn Shape>,
+    measure: Measure,
+    measurement: Measurement,
+}
+
+/// Treats the player quitting, or the input ending, as the end of the
+/// session rather than an error.
+fn until_done<T>(answer: std::io::Result<T>) -> std::io::Result<Option<T>> {
+    match answer {
+        Ok(answer) => Ok(Some(answer)),
+        Err(e) if prompt::is_abort(&e) || e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// The dimensions of `shape` with their names, e.g. `width 2, height 3`, or
+/// its vertices, e.g. `(0, 0), (4, 0), (4, 3)`.
+fn describe(shape: &dyn Shape) -> String {
+    let kind = shape.kind();
+    let dims = shape.dimensions();
+    let parts: Vec<_> = if kind.vertices {
+        dims.chunks(2)
+            .map(|xy| format!("({}, {})", xy[0], xy[1]))
+            .collect()
+    } else {
+        kind.dimensions
+            .iter()
+            .zip(dims)
+            .map(|(dimension, value)| {
+                let name = l10n::text(&format!("c03.name.{dimension}"));
+                format!("{name} {value}")
+            })
+            .collect()
+    };
+    parts.join(", ")
+}
+
+/// Writes a table of every calculation in `entries`, with a column for the
+/// number, the shape, its dimensions and the result.
+fn write_summary<W: Write + ?Sized>(writer: &mut W, entries: &[Entry]) -> std::io::Result<()> {
+    let mut rows = vec![[
+        "#".to_string(),
+        l10n::text("c03.column_shape").to_string(),
+        l10n::text("c03.column_dimensions").to_string(),
+        l10n::text("c03.column_result").to_string(),
+    ]];
+    for (number, entry) in (1..).zip(entries) {
+        rows.push([
+            number.to_string(),
+            l10n::text(&format!("c03.name.{}", entry.shape.kind().name)).to_string(),
+            describe(entry.shape.as_ref()),
+            entry.measurement.to_string(),
+        ]);
+    }
+    let widths: Vec<_> = (0..3)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    writeln!(writer, "{}", l10n::text("c03.summary"))?;
+    for [number, shape, dimensions, result] in &rows {
+        writeln!(
+            writer,
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            number,
+            shape,
+            dimensions,
+            result,
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        )?;
+    }
+    Ok(())
+}
+
+/// Runs calculations until the player quits, then writes a summary of them.
+/// `measure` is used for every solid if given, and otherwise asked for with
+/// each new one. With `units`, the dimensions are in the first unit and the
+/// results in the second.
+pub fn run<R: BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    measure: Option<Measure>,
+    units: Option<(Unit, Unit)>,
+) -> challenge_error::Result<()> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut next = Next::NewShape;
+    while next != Next::Quit {
+        let (shape, chosen) = match (next, entries.last()) {
+            (Next::Repeat, Some(last)) => {
+                let request = ShapeRequest::new(last.shape.kind());
+                (complete_shape(reader, writer, request)?, last.measure)
+            }
+            _ => {
+                let shape = prompt_for_shape(reader, writer)?;
+                let chosen = match measure {
+                    Some(measure) => measure,
+                    None if shape.volume().is_some() => prompt_for_measure(reader, writer)?,
+                    None => Measure::default(),
+                };
+                (shape, chosen)
+            }
+        };
+        let mut measurement = measure_of(shape.as_ref(), chosen);
+        if let Some((from, to)) = units {
+            measurement = measurement.convert(from, to);
+        }
+        writeln!(writer, "{}", measurement)?;
+        entries.push(Entry {
+            shape,
+            measure: chosen,
+            measurement,
+        });
+
+        let answer = Prompt::new(l10n::text("c03.next")).ask_choice(
+            reader,
+            writer,
+            &[
+                ("1", Next::NewShape),
+                ("2", Next::Repeat),
+                ("3", Next::Quit),
+            ],
+        );
+        next = until_done(answer)?.unwrap_or(Next::Quit);
+    }
+    write_summary(writer, &entries)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(input: &str) -> (challenge_error::Result<()>, String) {
+        let mut output = Vec::new();
+        let result = run(&mut input.as_bytes(), &mut output, None, None);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn run_repeats_the_last_shape_and_sums_up() {
+        // A cuboid's volume, the same cuboid resized, then a rectangle.
+        let (result, output) = session("2\n2\n3\n4\n1\n2\n1\n1\n1\n1\n1\n5\n3\n3\n");
+
+        result.unwrap();
+        assert!(output.ends_with(
+            "Summary:\n\
+             #  Shape      Dimensions                  Result\n\
+             1  cuboid     width 2, height 3, depth 4  Volume: 24 units³\n\
+             2  cuboid     width 1, height 1, depth 1  Volume: 1 units³\n\
+             3  rectangle  width 5, height 3           Area: 15 units²\n"
+        ));
+        // The measure is asked for with the new cuboid, not the resized one.
+        assert_eq!(output.matches("Enter 1 for Volume").count(), 1);
+    }
+
+    #[test]
+    fn run_quits_at_the_end_of_input() {
+        let (result, output) = session("9\n0,0 4,0 4,3\n");
+
+        result.unwrap();
+        assert!(output.contains("Area: 6 units²\nEnter 1 for a new shape, "));
+        assert!(output.ends_with("1  polygon  (0, 0), (4, 0), (4, 3)  Area: 6 units²\n"));
+    }
+
+    #[test]
+    fn run_converts_every_result() {
+        let mut output = Vec::new();
+        run(
+            &mut "3\n1\n".as_bytes(),
+            &mut output,
+            None,
+            Some((Unit::M, Unit::Cm)),
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("1  circle  radius 1    Area: 31415.926535897932 cm²\n"));
+    }
+}
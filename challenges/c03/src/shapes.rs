@@ -0,0 +1,590 @@
+//! The shapes the calculator knows.
+//!
+//! Each shape is a type of its own implementing [`Shape`], with a [`Kind`]
+//! that names it and lists the dimensions it is built from. The menu, the
+//! command-line subcommands and the rows of a batch file are all made from
+//! the kinds in [`SHAPES`], so adding a shape takes a new type and an entry
+//! there, plus a `c03.<name>` message for the menu and one for each new
+//! dimension. A [`Polygon`] is the exception, built from a list of vertices
+//! of any length instead.
+use challenge_error::ChallengeError;
+use std::f64::consts::PI;
+use std::fmt::Debug;
+
+/// A shape with its dimensions.
+pub trait Shape: Debug {
+    /// The kind of shape this is.
+    fn kind(&self) -> &'static Kind;
+
+    /// The dimensions, in the order of [`Kind::dimensions`].
+    fn dimensions(&self) -> Vec<f64>;
+
+    /// The area of a flat shape, or the surface area of a solid one.
+    fn area(&self) -> f64;
+
+    /// The volume of a solid shape, or `None` for a flat one.
+    fn volume(&self) -> Option<f64> {
+        None
+    }
+}
+
+/// A kind of shape the calculator can be asked for.
+pub struct Kind {
+    /// The name of its subcommand and in batch rows, e.g. `triangle-sides`.
+    pub name: &'static str,
+    /// The help of its subcommand.
+    pub about: &'static str,
+    /// The dimensions it is built from, in the order they are asked for.
+    /// `c03.<dimension>` names each of them in prompts.
+    pub dimensions: &'static [&'static str],
+    /// Whether it is built from a list of (x, y) vertices instead of
+    /// `dimensions`, in which case `build` gets their coordinates in turn.
+    pub vertices: bool,
+    /// Builds the shape from a value for each of `dimensions`, checking that
+    /// they fit together.
+    pub build: fn(&[f64]) -> challenge_error::Result<Box<dyn Shape>>,
+}
+
+/// Every kind of shape, in menu order.
+pub const SHAPES: &[&Kind] = &[
+    &Rectangle::KIND,
+    &Cuboid::KIND,
+    &Circle::KIND,
+    &Triangle::KIND,
+    &TriangleSides::KIND,
+    &Sphere::KIND,
+    &Cylinder::KIND,
+    &Cone::KIND,
+    &Polygon::KIND,
+];
+
+/// Returns the kind of shape called `name`.
+pub fn find(name: &str) -> Option<&'static Kind> {
+    SHAPES.iter().copied().find(|kind| kind.name == name)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    pub const KIND: Kind = Kind {
+        name: "rectangle",
+        about: "Calculate the area of a rectangle",
+        dimensions: &["width", "height"],
+        vertices: false,
+        build: |dims| {
+            Ok(Box::new(Rectangle {
+                width: dims[0],
+                height: dims[1],
+            }))
+        },
+    };
+}
+
+impl Shape for Rectangle {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.width, self.height]
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Circle {
+    pub const KIND: Kind = Kind {
+        name: "circle",
+        about: "Calculate the area of a circle",
+        dimensions: &["radius"],
+        vertices: false,
+        build: |dims| Ok(Box::new(Circle { radius: dims[0] })),
+    };
+}
+
+impl Shape for Circle {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.radius]
+    }
+
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Triangle {
+    pub base: f64,
+    pub height: f64,
+}
+
+impl Triangle {
+    pub const KIND: Kind = Kind {
+        name: "triangle",
+        about: "Calculate the area of a triangle from its base and height",
+        dimensions: &["base", "height"],
+        vertices: false,
+        build: |dims| {
+            Ok(Box::new(Triangle {
+                base: dims[0],
+                height: dims[1],
+            }))
+        },
+    };
+}
+
+impl Shape for Triangle {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.base, self.height]
+    }
+
+    fn area(&self) -> f64 {
+        self.base * self.height / 2.0
+    }
+}
+
+/// A triangle given by its three sides, whose area comes from Heron's
+/// formula.
+#[derive(Debug, PartialEq)]
+pub struct TriangleSides {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl TriangleSides {
+    pub const KIND: Kind = Kind {
+        name: "triangle-sides",
+        about: "Calculate the area of a triangle from its three sides",
+        dimensions: &["side a", "side b", "side c"],
+        vertices: false,
+        build: |dims| Ok(Box::new(TriangleSides::new(dims[0], dims[1], dims[2])?)),
+    };
+
+    /// Checks that sides `a`, `b` and `c` can meet as a triangle: each must
+    /// be shorter than the other two together.
+    pub fn new(a: f64, b: f64, c: f64) -> challenge_error::Result<Self> {
+        if a >= b + c || b >= a + c || c >= a + b {
+            return Err(ChallengeError::validation(
+                "sides",
+                "must each be shorter than the other two together",
+            ));
+        }
+        Ok(TriangleSides { a, b, c })
+    }
+}
+
+impl Shape for TriangleSides {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.a, self.b, self.c]
+    }
+
+    fn area(&self) -> f64 {
+        let TriangleSides { a, b, c } = *self;
+        let s = (a + b + c) / 2.0;
+        // Rounding can take a degenerate triangle slightly below zero.
+        (s * (s - a) * (s - b) * (s - c)).max(0.0).sqrt()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cuboid {
+    pub width: f64,
+    pub height: f64,
+    pub depth: f64,
+}
+
+impl Cuboid {
+    pub const KIND: Kind = Kind {
+        name: "cuboid",
+        about: "Calculate the volume of a cuboid",
+        dimensions: &["width", "height", "depth"],
+        vertices: false,
+        build: |dims| {
+            Ok(Box::new(Cuboid {
+                width: dims[0],
+                height: dims[1],
+                depth: dims[2],
+            }))
+        },
+    };
+}
+
+impl Shape for Cuboid {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.width, self.height, self.depth]
+    }
+
+    fn area(&self) -> f64 {
+        let Cuboid {
+            width,
+            height,
+            depth,
+        } = *self;
+        2.0 * (width * height + width * depth + height * depth)
+    }
+
+    fn volume(&self) -> Option<f64> {
+        Some(self.width * self.height * self.depth)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sphere {
+    pub radius: f64,
+}
+
+impl Sphere {
+    pub const KIND: Kind = Kind {
+        name: "sphere",
+        about: "Calculate the volume of a sphere",
+        dimensions: &["radius"],
+        vertices: false,
+        build: |dims| Ok(Box::new(Sphere { radius: dims[0] })),
+    };
+}
+
+impl Shape for Sphere {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.radius]
+    }
+
+    fn area(&self) -> f64 {
+        4.0 * PI * self.radius * self.radius
+    }
+
+    fn volume(&self) -> Option<f64> {
+        Some(4.0 / 3.0 * PI * self.radius.powi(3))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cylinder {
+    pub radius: f64,
+    pub height: f64,
+}
+
+impl Cylinder {
+    pub const KIND: Kind = Kind {
+        name: "cylinder",
+        about: "Calculate the volume of a cylinder",
+        dimensions: &["radius", "height"],
+        vertices: false,
+        build: |dims| {
+            Ok(Box::new(Cylinder {
+                radius: dims[0],
+                height: dims[1],
+            }))
+        },
+    };
+}
+
+impl Shape for Cylinder {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.radius, self.height]
+    }
+
+    /// The two ends and the side.
+    fn area(&self) -> f64 {
+        2.0 * PI * self.radius * (self.radius + self.height)
+    }
+
+    fn volume(&self) -> Option<f64> {
+        Some(PI * self.radius * self.radius * self.height)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cone {
+    pub radius: f64,
+    pub height: f64,
+}
+
+impl Cone {
+    pub const KIND: Kind = Kind {
+        name: "cone",
+        about: "Calculate the volume of a cone",
+        dimensions: &["radius", "height"],
+        vertices: false,
+        build: |dims| {
+            Ok(Box::new(Cone {
+                radius: dims[0],
+                height: dims[1],
+            }))
+        },
+    };
+}
+
+impl Shape for Cone {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        vec![self.radius, self.height]
+    }
+
+    /// The base and the sloping side.
+    fn area(&self) -> f64 {
+        let slant = self.radius.hypot(self.height);
+        PI * self.radius * (self.radius + slant)
+    }
+
+    fn volume(&self) -> Option<f64> {
+        Some(PI * self.radius * self.radius * self.height / 3.0)
+    }
+}
+
+/// How small a polygon's area can be, relative to the square of its size,
+/// before its vertices count as on one line.
+const FLAT_TOLERANCE: f64 = 1e-9;
+
+/// A polygon given by its vertices in order around it, whose area comes from
+/// the shoelace formula.
+#[derive(Debug, PartialEq)]
+pub struct Polygon {
+    pub vertices: Vec<(f64, f64)>,
+}
+
+impl Polygon {
+    pub const KIND: Kind = Kind {
+        name: "polygon",
+        about: "Calculate the area of a polygon from its vertices",
+        dimensions: &[],
+        vertices: true,
+        build: |coords| {
+            let vertices = coords.chunks_exact(2).map(|xy| (xy[0], xy[1])).collect();
+            Ok(Box::new(Polygon::new(vertices)?))
+        },
+    };
+
+    /// Checks that `vertices` make a polygon: at least three points, not all
+    /// on one line.
+    ///
+    /// The points count as on one line when the polygon's area is next to
+    /// nothing beside the square of its width or height, whichever is larger,
+    /// so that rounding in the coordinates does not let a line through.
+    pub fn new(vertices: Vec<(f64, f64)>) -> challenge_error::Result<Self> {
+        if vertices.len() < 3 {
+            return Err(ChallengeError::validation(
+                "vertices",
+                "must be at least three points",
+            ));
+        }
+        let span = |coord: fn(&(f64, f64)) -> f64| {
+            let (min, max) = vertices
+                .iter()
+                .map(coord)
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), c| {
+                    (min.min(c), max.max(c))
+                });
+            max - min
+        };
+        let size = span(|&(x, _)| x).max(span(|&(_, y)| y));
+        let polygon = Polygon { vertices };
+        if polygon.area() <= FLAT_TOLERANCE * size * size {
+            return Err(ChallengeError::validation(
+                "vertices",
+                "must not all lie on one line",
+            ));
+        }
+        Ok(polygon)
+    }
+}
+
+impl Shape for Polygon {
+    fn kind(&self) -> &'static Kind {
+        &Self::KIND
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        self.vertices.iter().flat_map(|&(x, y)| [x, y]).collect()
+    }
+
+    fn area(&self) -> f64 {
+        let next = self.vertices.iter().cycle().skip(1);
+        let twice: f64 = self
+            .vertices
+            .iter()
+            .zip(next)
+            .map(|(&(x0, y0), &(x1, y1))| x0 * y1 - x1 * y0)
+            .sum();
+        twice.abs() / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn every_kind_builds_a_shape_of_its_own() {
+        for kind in SHAPES {
+            let dims: Vec<_> = match kind.vertices {
+                true => vec![0.0, 0.0, 4.0, 0.0, 4.0, 3.0],
+                false => (0..kind.dimensions.len()).map(|i| 3.0 + i as f64).collect(),
+            };
+            let shape = (kind.build)(&dims).unwrap();
+            assert_eq!(shape.kind().name, kind.name);
+            assert_eq!(shape.dimensions(), dims);
+            assert_eq!(find(kind.name).unwrap().dimensions, kind.dimensions);
+        }
+        assert!(find("hexagon").is_none());
+    }
+
+    #[test]
+    fn rectangle() {
+        let shape = Rectangle {
+            width: 2.0,
+            height: 3.5,
+        };
+        assert_close(shape.area(), 7.0);
+        assert_eq!(shape.volume(), None);
+    }
+
+    #[test]
+    fn circle() {
+        assert_close(Circle { radius: 2.0 }.area(), 4.0 * PI);
+    }
+
+    #[test]
+    fn triangle() {
+        let shape = Triangle {
+            base: 4.0,
+            height: 3.0,
+        };
+        assert_close(shape.area(), 6.0);
+    }
+
+    #[test]
+    fn triangle_sides() {
+        assert_close(TriangleSides::new(3.0, 4.0, 5.0).unwrap().area(), 6.0);
+        assert_close(
+            TriangleSides::new(2.0, 2.0, 2.0).unwrap().area(),
+            3f64.sqrt(),
+        );
+        assert_eq!(
+            TriangleSides::new(1.0, 2.0, 5.0).unwrap_err().to_string(),
+            "sides must each be shorter than the other two together"
+        );
+        assert!(TriangleSides::new(1.0, 2.0, 3.0).is_err());
+    }
+
+    #[test]
+    fn cuboid() {
+        let shape = Cuboid {
+            width: 2.0,
+            height: 3.0,
+            depth: 4.0,
+        };
+        assert_close(shape.area(), 52.0);
+        assert_close(shape.volume().unwrap(), 24.0);
+    }
+
+    #[test]
+    fn sphere() {
+        let shape = Sphere { radius: 3.0 };
+        assert_close(shape.area(), 36.0 * PI);
+        assert_close(shape.volume().unwrap(), 36.0 * PI);
+    }
+
+    #[test]
+    fn cylinder() {
+        let shape = Cylinder {
+            radius: 2.0,
+            height: 5.0,
+        };
+        assert_close(shape.area(), 28.0 * PI);
+        assert_close(shape.volume().unwrap(), 20.0 * PI);
+    }
+
+    #[test]
+    fn cone() {
+        // A 3-4-5 cone has a slant of 5.
+        let shape = Cone {
+            radius: 3.0,
+            height: 4.0,
+        };
+        assert_close(shape.area(), 24.0 * PI);
+        assert_close(shape.volume().unwrap(), 12.0 * PI);
+    }
+
+    #[test]
+    fn polygon() {
+        let square = Polygon::new(vec![(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0)]).unwrap();
+        assert_close(square.area(), 4.0);
+        // An L shape, which is not convex, taken the other way round.
+        let l_shape = Polygon::new(vec![
+            (0.0, 0.0),
+            (3.0, 0.0),
+            (3.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 3.0),
+            (0.0, 3.0),
+        ])
+        .unwrap();
+        assert_close(l_shape.area(), 5.0);
+    }
+
+    #[test]
+    fn polygon_needs_three_points_off_one_line() {
+        assert_eq!(
+            Polygon::new(vec![(0.0, 0.0), (1.0, 1.0)])
+                .unwrap_err()
+                .to_string(),
+            "vertices must be at least three points"
+        );
+        assert_eq!(
+            Polygon::new(vec![(0.0, 0.0), (1.0, 1.0), (-2.0, -2.0)])
+                .unwrap_err()
+                .to_string(),
+            "vertices must not all lie on one line"
+        );
+        assert!(Polygon::new(vec![(1.0, 1.0), (1.0, 1.0), (1.0, 1.0)]).is_err());
+        // Rounding keeps these off one line exactly.
+        assert!(Polygon::new(vec![(0.0, 0.0), (0.1, 0.1), (0.3, 0.3)]).is_err());
+        assert!(Polygon::new(vec![(0.0, 0.0), (1e-6, 0.0), (0.0, 1e-6)]).is_ok());
+    }
+}
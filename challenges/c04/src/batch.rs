@@ -0,0 +1,284 @@
+//! Batch mode: queries read from a file, one per line, and a table of their
+//! results.
+//!
+//! Each line names the query and gives its values in the order the query
+//! asks for them: `distance SPEED TIME`, `speed DISTANCE TIME`,
+//! `time DISTANCE SPEED`, `fuel DISTANCE ECONOMY PRICE`,
+//! `final-speed INITIAL_SPEED ACCELERATION TIME` or
+//! `accelerated-distance INITIAL_SPEED ACCELERATION TIME`, e.g.
+//! `distance 60 2`. A value can have a unit as
+//! long as it has no spaces, e.g. `speed 10km 1h30m`. Blank lines and lines
+//! starting with `#` are skipped. A line that cannot be calculated gets its
+//! error in the table instead of stopping the batch.
+use super::{calculate_query, parse_param, unit_name, Quantity, Query, SECONDS_PER_HOUR};
+use challenge_common::l10n;
+use challenge_error::ChallengeError;
+use std::io::{BufRead, Write};
+
+/// The queries a line can name, with the quantities each takes in order.
+const QUERIES: &[(&str, &[Quantity])] = &[
+    ("distance", &[Quantity::Speed, Quantity::Time]),
+    ("speed", &[Quantity::Distance, Quantity::Time]),
+    ("time", &[Quantity::Distance, Quantity::Speed]),
+    (
+        "fuel",
+        &[Quantity::Distance, Quantity::Economy, Quantity::Price],
+    ),
+    (
+        "final-speed",
+        &[
+            Quantity::InitialSpeed,
+            Quantity::Acceleration,
+            Quantity::Time,
+        ],
+    ),
+    (
+        "accelerated-distance",
+        &[
+            Quantity::InitialSpeed,
+            Quantity::Acceleration,
+            Quantity::Time,
+        ],
+    ),
+];
+
+/// Reads the query on a line of the file.
+fn parse_line(line: &str) -> challenge_error::Result<Query> {
+    let mut words = line.split_whitespace();
+    let name = words.next().unwrap_or_default().to_lowercase();
+    let Some((name, quantities)) = QUERIES.iter().find(|(query, _)| *query == name) else {
+        let known: Vec<_> = QUERIES.iter().map(|(query, _)| *query).collect();
+        return Err(ChallengeError::validation(
+            "query",
+            &format!("'{}' is not one of {}", name, known.join(", ")),
+        ));
+    };
+    let words: Vec<_> = words.collect();
+    if words.len() != quantities.len() {
+        let names: Vec<_> = quantities.iter().map(|quantity| quantity.name()).collect();
+        return Err(ChallengeError::validation(
+            name,
+            &format!(
+                "needs {} values ({}), not {}",
+                quantities.len(),
+                names.join(", "),
+                words.len()
+            ),
+        ));
+    }
+    let values = quantities
+        .iter()
+        .zip(words)
+        .map(|(&quantity, word)| parse_param(quantity, word))
+        .collect::<challenge_error::Result<Vec<_>>>()?;
+    Ok(match *name {
+        "distance" => Query::Distance {
+            speed_mph: values[0],
+            time_hr: values[1],
+        },
+        "speed" => Query::Speed {
+            distance_miles: values[0],
+            time_hr: values[1],
+        },
+        "time" => Query::Time {
+            distance_miles: values[0],
+            speed_mph: values[1],
+        },
+        "fuel" => Query::Fuel {
+            distance_miles: values[0],
+            economy_mpg: values[1],
+            price_per_gallon: values[2],
+        },
+        "final-speed" => Query::FinalSpeed {
+            initial_speed_mph: values[0],
+            acceleration_mph_per_s: values[1],
+            time_hr: values[2],
+        },
+        _ => Query::AcceleratedDistance {
+            initial_speed_mph: values[0],
+            acceleration_mph_per_s: values[1],
+            time_hr: values[2],
+        },
+    })
+}
+
+/// `value` to at most two decimal places, e.g. `6.21` or `60`.
+fn short(value: f64) -> String {
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// The values of `query` with their units, e.g. `60 mph, 2 hours`. The time
+/// spent accelerating is in seconds, e.g. `0 mph, 6 mph/s, 10 seconds`.
+fn describe(query: &Query) -> String {
+    match *query {
+        Query::Distance { speed_mph, time_hr } => values(&[(speed_mph, "mph"), (time_hr, "hours")]),
+        Query::Speed {
+            distance_miles,
+            time_hr,
+        } => values(&[(distance_miles, "miles"), (time_hr, "hours")]),
+        Query::Time {
+            distance_miles,
+            speed_mph,
+        } => values(&[(distance_miles, "miles"), (speed_mph, "mph")]),
+        Query::Fuel {
+            distance_miles,
+            economy_mpg,
+            price_per_gallon,
+        } => values(&[
+            (distance_miles, "miles"),
+            (economy_mpg, "mpg"),
+            (price_per_gallon, "per_gallon"),
+        ]),
+        Query::FinalSpeed {
+            initial_speed_mph,
+            acceleration_mph_per_s,
+            time_hr,
+        }
+        | Query::AcceleratedDistance {
+            initial_speed_mph,
+            acceleration_mph_per_s,
+            time_hr,
+        } => values(&[
+            (initial_speed_mph, "mph"),
+            (acceleration_mph_per_s, "mph/s"),
+            (time_hr * SECONDS_PER_HOUR, "seconds"),
+        ]),
+    }
+}
+
+/// Each value followed by the name of its unit, e.g. `60 mph, 2 hours`.
+fn values(values: &[(f64, &str)]) -> String {
+    let values: Vec<_> = values
+        .iter()
+        .map(|(value, unit)| format!("{} {}", short(*value), unit_name(unit)))
+        .collect();
+    values.join(", ")
+}
+
+/// Calculates every query read from `reader` and writes a table of the
+/// results to `writer`. Returns the line number and error of each line that
+/// could not be calculated.
+pub fn run<R: BufRead, W: Write>(
+    reader: R,
+    writer: &mut W,
+) -> std::io::Result<Vec<(usize, String)>> {
+    let mut rows = vec![[
+        l10n::text("c04.column_line").to_string(),
+        l10n::text("c04.column_query").to_string(),
+        l10n::text("c04.column_values").to_string(),
+        l10n::text("c04.column_result").to_string(),
+    ]];
+    let mut failures = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let number = index + 1;
+        let name = line.split_whitespace().next().unwrap_or_default();
+        match parse_line(line) {
+            Ok(query) => rows.push([
+                number.to_string(),
+                name.to_lowercase(),
+                describe(&query),
+                calculate_query(&query).to_string(),
+            ]),
+            Err(e) => {
+                let error = e.to_string();
+                rows.push([
+                    number.to_string(),
+                    name.to_lowercase(),
+                    String::new(),
+                    format!("error: {error}"),
+                ]);
+                failures.push((number, error));
+            }
+        }
+    }
+
+    let widths: Vec<_> = (0..3)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    for [number, query, values, result] in &rows {
+        writeln!(
+            writer,
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            number,
+            query,
+            values,
+            result,
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        )?;
+    }
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_tabulates_every_query() {
+        let input = "# homework\n\
+                     distance 60 2\n\
+                     \n\
+                     speed 10km 1h30m\n\
+                     time 150 60\n\
+                     SPEED 120 2\n\
+                     final-speed 0 6 10s\n";
+        let mut output = Vec::new();
+
+        let failures = run(input.as_bytes(), &mut output).unwrap();
+
+        assert!(failures.is_empty());
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Line  Query        Values                      Result\n\
+             2     distance     60 mph, 2 hours             120.00 miles\n\
+             4     speed        6.21 miles, 1.5 hours       4.14 mph\n\
+             5     time         150 miles, 60 mph           2.50 hours (2 hours 30 minutes)\n\
+             6     speed        120 miles, 2 hours          60.00 mph\n\
+             7     final-speed  0 mph, 6 mph/s, 10 seconds  60.00 mph\n"
+        );
+    }
+
+    #[test]
+    fn run_reports_bad_lines_and_carries_on() {
+        let input = "accelerate 1 2\ndistance 60\ntime 10 0\ndistance 30 0.5\n";
+        let mut output = Vec::new();
+
+        let failures = run(input.as_bytes(), &mut output).unwrap();
+
+        assert_eq!(
+            failures,
+            [
+                (
+                    1,
+                    "query 'accelerate' is not one of distance, speed, time, fuel, final-speed, \
+                     accelerated-distance"
+                        .to_string()
+                ),
+                (
+                    2,
+                    "distance needs 2 values (speed (mph), time (hours)), not 1".to_string()
+                ),
+                (3, "speed (mph) must be positive".to_string()),
+            ]
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(&format!(
+            "3     time{}error: speed (mph) must be positive\n",
+            " ".repeat(27)
+        )));
+        assert!(output.ends_with("4     distance    30 mph, 0.5 hours  15.00 miles\n"));
+    }
+}
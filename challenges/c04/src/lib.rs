@@ -0,0 +1,977 @@
+//! A speed and distance calculator that provides interactive calculations for:
+//!
+//! - Distance (given speed and time)
+//! - Speed (given distance and time)
+//! - Time (given distance and speed), shown in hours and minutes as well
+//! - Fuel used and the cost of a trip (given distance, fuel economy and fuel
+//!   price)
+//! - Final speed and distance covered under a constant acceleration (given
+//!   initial speed, acceleration and time)
+//!
+//! # Usage
+//!
+//! The program prompts the user to:
+//! 1. Select calculation type (distance, speed or time)
+//! 2. Input required parameters (two of speed, distance and time)
+//!
+//! A value is in miles, miles per hour or hours unless it names another
+//! unit, e.g. `90 km/h`, `5 km` or `1h30m`, as described in the
+//! [`quantity`] module.
+//!
+//! Invalid answers are explained and asked for again.
+//!
+//! The query can also be given on the command line, e.g.
+//! `c04 distance --speed 60 --time 2`. Any value left out is prompted for.
+//! `--batch FILE` calculates a query on each line of a file instead, as
+//! described in the [`batch`] module, and prints a table of the results.
+//!
+//! With `--format json` the query and its result are printed as JSON.
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use challenge_error::ChallengeError;
+use clap::{Parser, Subcommand};
+use quantity::Quantity;
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod batch;
+mod quantity;
+
+/// Seconds in an hour, for accelerations given per second.
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+#[derive(Debug, PartialEq)]
+enum Query {
+    Distance {
+        speed_mph: f64,
+        time_hr: f64,
+    },
+    Speed {
+        distance_miles: f64,
+        time_hr: f64,
+    },
+    Time {
+        distance_miles: f64,
+        speed_mph: f64,
+    },
+    Fuel {
+        distance_miles: f64,
+        economy_mpg: f64,
+        price_per_gallon: f64,
+    },
+    FinalSpeed {
+        initial_speed_mph: f64,
+        acceleration_mph_per_s: f64,
+        time_hr: f64,
+    },
+    AcceleratedDistance {
+        initial_speed_mph: f64,
+        acceleration_mph_per_s: f64,
+        time_hr: f64,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+struct CalculationResult {
+    value: f64,
+    unit: String,
+    /// What the fuel for a trip costs, in the currency of the price.
+    cost: Option<f64>,
+}
+
+/// The speed in mph and the distance in miles after accelerating from
+/// `initial_speed_mph` by `acceleration_mph_per_s` for `time_hr`. Braking
+/// stops at a standstill rather than going on into reverse.
+fn accelerate(initial_speed_mph: f64, acceleration_mph_per_s: f64, time_hr: f64) -> (f64, f64) {
+    let acceleration = acceleration_mph_per_s * SECONDS_PER_HOUR;
+    let final_speed = initial_speed_mph + acceleration * time_hr;
+    if final_speed < 0.0 {
+        // Stopped after initial_speed / -acceleration hours, at the average
+        // speed of half the initial speed.
+        let stopping_time = initial_speed_mph / -acceleration;
+        return (0.0, initial_speed_mph * stopping_time / 2.0);
+    }
+    let distance = initial_speed_mph * time_hr + acceleration * time_hr * time_hr / 2.0;
+    (final_speed, distance)
+}
+
+fn calculate_query(query: &Query) -> CalculationResult {
+    match query {
+        Query::Distance { speed_mph, time_hr } => CalculationResult {
+            value: speed_mph * time_hr,
+            unit: "miles".to_string(),
+            cost: None,
+        },
+        Query::Speed {
+            distance_miles,
+            time_hr,
+        } => CalculationResult {
+            value: distance_miles / time_hr,
+            unit: "mph".to_string(),
+            cost: None,
+        },
+        Query::Time {
+            distance_miles,
+            speed_mph,
+        } => CalculationResult {
+            value: distance_miles / speed_mph,
+            unit: "hours".to_string(),
+            cost: None,
+        },
+        Query::Fuel {
+            distance_miles,
+            economy_mpg,
+            price_per_gallon,
+        } => {
+            let gallons = distance_miles / economy_mpg;
+            CalculationResult {
+                value: gallons,
+                unit: "gallons".to_string(),
+                cost: Some(gallons * price_per_gallon),
+            }
+        }
+        Query::FinalSpeed {
+            initial_speed_mph,
+            acceleration_mph_per_s,
+            time_hr,
+        } => CalculationResult {
+            value: accelerate(*initial_speed_mph, *acceleration_mph_per_s, *time_hr).0,
+            unit: "mph".to_string(),
+            cost: None,
+        },
+        Query::AcceleratedDistance {
+            initial_speed_mph,
+            acceleration_mph_per_s,
+            time_hr,
+        } => CalculationResult {
+            value: accelerate(*initial_speed_mph, *acceleration_mph_per_s, *time_hr).1,
+            unit: "miles".to_string(),
+            cost: None,
+        },
+    }
+}
+
+/// The value to two decimal places with its unit, a time in hours and
+/// minutes as well, e.g. `2.50 hours (2 hours 30 minutes)`, and fuel in
+/// litres as well with its cost, e.g. `2.00 gallons (7.57 litres), costing
+/// 7.00`.
+impl std::fmt::Display for CalculationResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:.2} {}", self.value, unit_name(&self.unit))?;
+        match self.unit.as_str() {
+            "hours" => write!(f, " ({})", format_hours(self.value))?,
+            "gallons" => {
+                let litres = format!("{:.2}", self.value * quantity::LITRES_PER_GALLON);
+                write!(
+                    f,
+                    " ({})",
+                    l10n::format("c04.litres", &[("litres", &litres)])
+                )?
+            }
+            _ => {}
+        }
+        if let Some(cost) = self.cost {
+            let cost = format!("{:.2}", cost);
+            write!(f, "{}", l10n::format("c04.costing", &[("cost", &cost)]))?;
+        }
+        Ok(())
+    }
+}
+
+/// The name of `unit`, e.g. `hours`, in the player's language.
+fn unit_name(unit: &str) -> &'static str {
+    l10n::text(&format!("c04.unit.{unit}"))
+}
+
+/// Formats a time in hours as whole hours and minutes, e.g. `2 hours 30
+/// minutes`, rounded to the nearest minute.
+fn format_hours(hours: f64) -> String {
+    let total = (hours * 60.0).round() as u64;
+    let plural = |n: u64, unit: &str| {
+        let key = format!("c04.{}{}", unit, if n == 1 { "" } else { "s" });
+        l10n::format(&key, &[("n", &n)])
+    };
+    match (total / 60, total % 60) {
+        (0, minutes) => plural(minutes, "minute"),
+        (hours, 0) => plural(hours, "hour"),
+        (hours, minutes) => format!("{} {}", plural(hours, "hour"), plural(minutes, "minute")),
+    }
+}
+
+fn parse_param(quantity: Quantity, answer: &str) -> challenge_error::Result<f64> {
+    let value = quantity.parse(answer)?;
+    match quantity {
+        // Braking is a negative acceleration.
+        Quantity::Acceleration => {}
+        Quantity::InitialSpeed if value < 0.0 => {
+            return Err(ChallengeError::validation(
+                quantity.name(),
+                "must not be negative",
+            ));
+        }
+        Quantity::InitialSpeed => {}
+        _ if value <= 0.0 => {
+            return Err(ChallengeError::validation(
+                quantity.name(),
+                "must be positive",
+            ));
+        }
+        _ => {}
+    }
+
+    Ok(value)
+}
+
+fn prompt_for_param<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    quantity: Quantity,
+) -> challenge_error::Result<f64> {
+    let name = l10n::text(&format!("c04.{}", quantity.key()));
+    let message = l10n::format("c04.param", &[("param", &name)]);
+    Ok(Prompt::new(&message)
+        .inline()
+        .ask(reader, writer, |answer| parse_param(quantity, answer))?)
+}
+
+fn param_or_prompt<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    quantity: Quantity,
+    given: Option<f64>,
+) -> challenge_error::Result<f64> {
+    match given {
+        Some(value) => Ok(value),
+        None => prompt_for_param(reader, writer, quantity),
+    }
+}
+
+/// Builds the query picked on the command line, prompting for the values
+/// that were not given.
+fn complete_query<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    command: QueryCommand,
+) -> challenge_error::Result<Query> {
+    match command {
+        QueryCommand::Distance { speed, time } => Ok(Query::Distance {
+            speed_mph: param_or_prompt(reader, writer, Quantity::Speed, speed)?,
+            time_hr: param_or_prompt(reader, writer, Quantity::Time, time)?,
+        }),
+        QueryCommand::Speed { distance, time } => Ok(Query::Speed {
+            distance_miles: param_or_prompt(reader, writer, Quantity::Distance, distance)?,
+            time_hr: param_or_prompt(reader, writer, Quantity::Time, time)?,
+        }),
+        QueryCommand::Time { distance, speed } => Ok(Query::Time {
+            distance_miles: param_or_prompt(reader, writer, Quantity::Distance, distance)?,
+            speed_mph: param_or_prompt(reader, writer, Quantity::Speed, speed)?,
+        }),
+        QueryCommand::Fuel {
+            distance,
+            economy,
+            price,
+        } => Ok(Query::Fuel {
+            distance_miles: param_or_prompt(reader, writer, Quantity::Distance, distance)?,
+            economy_mpg: param_or_prompt(reader, writer, Quantity::Economy, economy)?,
+            price_per_gallon: param_or_prompt(reader, writer, Quantity::Price, price)?,
+        }),
+        QueryCommand::FinalSpeed {
+            initial_speed,
+            acceleration,
+            time,
+        } => Ok(Query::FinalSpeed {
+            initial_speed_mph: param_or_prompt(
+                reader,
+                writer,
+                Quantity::InitialSpeed,
+                initial_speed,
+            )?,
+            acceleration_mph_per_s: param_or_prompt(
+                reader,
+                writer,
+                Quantity::Acceleration,
+                acceleration,
+            )?,
+            time_hr: param_or_prompt(reader, writer, Quantity::Time, time)?,
+        }),
+        QueryCommand::AcceleratedDistance {
+            initial_speed,
+            acceleration,
+            time,
+        } => Ok(Query::AcceleratedDistance {
+            initial_speed_mph: param_or_prompt(
+                reader,
+                writer,
+                Quantity::InitialSpeed,
+                initial_speed,
+            )?,
+            acceleration_mph_per_s: param_or_prompt(
+                reader,
+                writer,
+                Quantity::Acceleration,
+                acceleration,
+            )?,
+            time_hr: param_or_prompt(reader, writer, Quantity::Time, time)?,
+        }),
+    }
+}
+
+fn prompt_for_query<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> challenge_error::Result<Query> {
+    let command = Prompt::new(l10n::text("c04.query")).inline().ask_choice(
+        reader,
+        writer,
+        &[
+            (
+                "1",
+                QueryCommand::Distance {
+                    speed: None,
+                    time: None,
+                },
+            ),
+            (
+                "2",
+                QueryCommand::Speed {
+                    distance: None,
+                    time: None,
+                },
+            ),
+            (
+                "3",
+                QueryCommand::Time {
+                    distance: None,
+                    speed: None,
+                },
+            ),
+            (
+                "4",
+                QueryCommand::Fuel {
+                    distance: None,
+                    economy: None,
+                    price: None,
+                },
+            ),
+            (
+                "5",
+                QueryCommand::FinalSpeed {
+                    initial_speed: None,
+                    acceleration: None,
+                    time: None,
+                },
+            ),
+            (
+                "6",
+                QueryCommand::AcceleratedDistance {
+                    initial_speed: None,
+                    acceleration: None,
+                    time: None,
+                },
+            ),
+        ],
+    )?;
+    complete_query(reader, writer, command)
+}
+
+fn parse_speed(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::Speed, s)
+}
+
+fn parse_distance(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::Distance, s)
+}
+
+fn parse_time(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::Time, s)
+}
+
+fn parse_initial_speed(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::InitialSpeed, s)
+}
+
+fn parse_acceleration(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::Acceleration, s)
+}
+
+fn parse_economy(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::Economy, s)
+}
+
+fn parse_price(s: &str) -> challenge_error::Result<f64> {
+    parse_param(Quantity::Price, s)
+}
+
+/// A query given on the command line.
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+enum QueryCommand {
+    /// Calculate the distance covered at a speed in a time
+    Distance {
+        /// Speed in miles per hour, or with a unit, e.g. "90 km/h"
+        #[arg(long, value_parser = parse_speed)]
+        speed: Option<f64>,
+        /// Time in hours, or as a duration, e.g. "1h30m"
+        #[arg(long, value_parser = parse_time)]
+        time: Option<f64>,
+    },
+    /// Calculate the speed needed to cover a distance in a time
+    Speed {
+        /// Distance in miles, or with a unit, e.g. "5 km"
+        #[arg(long, value_parser = parse_distance)]
+        distance: Option<f64>,
+        /// Time in hours, or as a duration, e.g. "1h30m"
+        #[arg(long, value_parser = parse_time)]
+        time: Option<f64>,
+    },
+    /// Calculate the time taken to cover a distance at a speed
+    Time {
+        /// Distance in miles, or with a unit, e.g. "5 km"
+        #[arg(long, value_parser = parse_distance)]
+        distance: Option<f64>,
+        /// Speed in miles per hour, or with a unit, e.g. "90 km/h"
+        #[arg(long, value_parser = parse_speed)]
+        speed: Option<f64>,
+    },
+    /// Estimate the fuel used on a trip and what it costs
+    Fuel {
+        /// Distance in miles, or with a unit, e.g. "5 km"
+        #[arg(long, value_parser = parse_distance)]
+        distance: Option<f64>,
+        /// Fuel economy in miles per gallon, or with a unit, e.g. "6.5 L/100km"
+        #[arg(long, value_parser = parse_economy)]
+        economy: Option<f64>,
+        /// Fuel price per gallon, or per litre, e.g. "1.80/L"
+        #[arg(long, value_parser = parse_price)]
+        price: Option<f64>,
+    },
+    /// Calculate the speed reached by accelerating for a time
+    FinalSpeed {
+        /// Initial speed in miles per hour, or with a unit, e.g. "0" or "50 km/h"
+        #[arg(long, value_parser = parse_initial_speed)]
+        initial_speed: Option<f64>,
+        /// Acceleration in mph per second, or with a unit, e.g. "3 m/s²";
+        /// negative when braking
+        #[arg(long, value_parser = parse_acceleration, allow_hyphen_values = true)]
+        acceleration: Option<f64>,
+        /// Time in hours, or as a duration, e.g. "10s"
+        #[arg(long, value_parser = parse_time)]
+        time: Option<f64>,
+    },
+    /// Calculate the distance covered while accelerating for a time
+    AcceleratedDistance {
+        /// Initial speed in miles per hour, or with a unit, e.g. "0" or "50 km/h"
+        #[arg(long, value_parser = parse_initial_speed)]
+        initial_speed: Option<f64>,
+        /// Acceleration in mph per second, or with a unit, e.g. "3 m/s²";
+        /// negative when braking
+        #[arg(long, value_parser = parse_acceleration, allow_hyphen_values = true)]
+        acceleration: Option<f64>,
+        /// Time in hours, or as a duration, e.g. "10s"
+        #[arg(long, value_parser = parse_time)]
+        time: Option<f64>,
+    },
+}
+
+/// Calculate a distance, a speed or a time from the other two quantities.
+///
+/// Without a query, the query and its values are prompted for
+/// interactively.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    query: Option<QueryCommand>,
+    /// File of queries to calculate in one go, one per line, e.g.
+    /// "distance 60 2"
+    #[arg(long, value_name = "FILE")]
+    batch: Option<PathBuf>,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Calculates the queries in the file at `path` and prints a table of the
+/// results. Returns the lines that failed, as [`batch::run`] does.
+fn run_batch(path: &Path) -> Result<Vec<(usize, String)>, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    batch::run(std::io::BufReader::new(file), &mut std::io::stdout().lock())
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+
+    if let Some(path) = &args.batch {
+        if args.query.is_some() {
+            eprintln!("Error: --batch cannot be combined with a query");
+            return ExitCode::FAILURE;
+        }
+        match run_batch(path) {
+            Ok(failures) => {
+                for (line, e) in failures {
+                    eprintln!("Warning: {} line {}: {}", path.display(), line, e);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let query = match Input::from_args(&args.common) {
+        Ok(mut input) => {
+            let mut prompts = args.format.format().prompts();
+            match args.query {
+                Some(command) => complete_query(&mut input, &mut prompts, command),
+                None => prompt_for_query(&mut input, &mut prompts),
+            }
+        }
+        Err(e) => Err(e.into()),
+    };
+    let query = match query {
+        Ok(query) => query,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = calculate_query(&query);
+    let (metric_type, input) = match query {
+        Query::Distance { speed_mph, time_hr } => (
+            "c04.result.distance",
+            json!({ "query": "distance", "speed_mph": speed_mph, "time_hr": time_hr }),
+        ),
+        Query::Speed {
+            distance_miles,
+            time_hr,
+        } => (
+            "c04.result.speed",
+            json!({ "query": "speed", "distance_miles": distance_miles, "time_hr": time_hr }),
+        ),
+        Query::Time {
+            distance_miles,
+            speed_mph,
+        } => (
+            "c04.result.time",
+            json!({ "query": "time", "distance_miles": distance_miles, "speed_mph": speed_mph }),
+        ),
+        Query::Fuel {
+            distance_miles,
+            economy_mpg,
+            price_per_gallon,
+        } => (
+            "c04.result.fuel",
+            json!({
+                "query": "fuel",
+                "distance_miles": distance_miles,
+                "economy_mpg": economy_mpg,
+                "price_per_gallon": price_per_gallon,
+            }),
+        ),
+        Query::FinalSpeed {
+            initial_speed_mph,
+            acceleration_mph_per_s,
+            time_hr,
+        } => (
+            "c04.result.final_speed",
+            json!({
+                "query": "final-speed",
+                "initial_speed_mph": initial_speed_mph,
+                "acceleration_mph_per_s": acceleration_mph_per_s,
+                "time_hr": time_hr,
+            }),
+        ),
+        Query::AcceleratedDistance {
+            initial_speed_mph,
+            acceleration_mph_per_s,
+            time_hr,
+        } => (
+            "c04.result.distance",
+            json!({
+                "query": "accelerated-distance",
+                "initial_speed_mph": initial_speed_mph,
+                "acceleration_mph_per_s": acceleration_mph_per_s,
+                "time_hr": time_hr,
+            }),
+        ),
+    };
+    match args.format.format() {
+        Format::Text => println!("{}: {}", l10n::text(metric_type), result),
+        Format::Json => {
+            let mut json = json!({ "value": result.value, "unit": result.unit });
+            if let Some(cost) = result.cost {
+                json["cost"] = json!(cost);
+            }
+            if let Err(e) = output::write_json(&mut std::io::stdout(), "c04", input, json) {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn calculate_query_computes_distance() {
+        let query = Query::Distance {
+            speed_mph: 60.0,
+            time_hr: 2.0,
+        };
+
+        let result = calculate_query(&query);
+
+        assert_eq!(result.value, 120.0);
+        assert_eq!(result.unit, "miles");
+    }
+
+    #[test]
+    fn calculate_query_computes_speed() {
+        let query = Query::Speed {
+            distance_miles: 120.0,
+            time_hr: 2.0,
+        };
+
+        let result = calculate_query(&query);
+
+        assert_eq!(result.value, 60.0);
+        assert_eq!(result.unit, "mph");
+    }
+
+    #[test]
+    fn calculate_query_handles_zero_time() {
+        let query = Query::Speed {
+            distance_miles: 100.0,
+            time_hr: 0.0,
+        };
+
+        let result = calculate_query(&query);
+
+        assert!(result.value.is_infinite());
+        assert_eq!(result.unit, "mph");
+    }
+
+    #[test]
+    fn calculate_query_handles_zero_distance() {
+        let query = Query::Speed {
+            distance_miles: 0.0,
+            time_hr: 2.0,
+        };
+
+        let result = calculate_query(&query);
+
+        assert_eq!(result.value, 0.0);
+        assert_eq!(result.unit, "mph");
+    }
+
+    #[test]
+    fn calculate_query_handles_fractional_values() {
+        let query = Query::Distance {
+            speed_mph: 0.5,
+            time_hr: 0.5,
+        };
+
+        let result = calculate_query(&query);
+
+        assert_eq!(result.value, 0.25);
+        assert_eq!(result.unit, "miles");
+    }
+
+    #[test]
+    fn calculate_query_computes_time() {
+        let query = Query::Time {
+            distance_miles: 150.0,
+            speed_mph: 60.0,
+        };
+
+        let result = calculate_query(&query);
+
+        assert_eq!(result.value, 2.5);
+        assert_eq!(result.unit, "hours");
+    }
+
+    #[test]
+    fn calculate_query_computes_fuel_and_cost() {
+        let query = Query::Fuel {
+            distance_miles: 100.0,
+            economy_mpg: 25.0,
+            price_per_gallon: 3.5,
+        };
+
+        let result = calculate_query(&query);
+
+        assert_eq!(result.value, 4.0);
+        assert_eq!(result.unit, "gallons");
+        assert_eq!(result.cost, Some(14.0));
+        assert_eq!(
+            result.to_string(),
+            "4.00 gallons (15.14 litres), costing 14.00"
+        );
+    }
+
+    #[test]
+    fn calculate_query_accelerates_from_rest() {
+        // 6 mph/s for 10 seconds.
+        let time_hr = 10.0 / SECONDS_PER_HOUR;
+        let final_speed = calculate_query(&Query::FinalSpeed {
+            initial_speed_mph: 0.0,
+            acceleration_mph_per_s: 6.0,
+            time_hr,
+        });
+        assert_eq!(final_speed.value, 60.0);
+        assert_eq!(final_speed.unit, "mph");
+
+        let distance = calculate_query(&Query::AcceleratedDistance {
+            initial_speed_mph: 0.0,
+            acceleration_mph_per_s: 6.0,
+            time_hr,
+        });
+        // An average of 30 mph for 10 seconds.
+        assert!((distance.value - 30.0 * time_hr).abs() < 1e-12);
+        assert_eq!(distance.unit, "miles");
+    }
+
+    #[test]
+    fn calculate_query_stops_when_braking() {
+        // 60 mph braking at 10 mph/s stops after 6 of the 10 seconds.
+        let time_hr = 10.0 / SECONDS_PER_HOUR;
+        let final_speed = calculate_query(&Query::FinalSpeed {
+            initial_speed_mph: 60.0,
+            acceleration_mph_per_s: -10.0,
+            time_hr,
+        });
+        assert_eq!(final_speed.value, 0.0);
+
+        let distance = calculate_query(&Query::AcceleratedDistance {
+            initial_speed_mph: 60.0,
+            acceleration_mph_per_s: -10.0,
+            time_hr,
+        });
+        assert!((distance.value - 30.0 * 6.0 / SECONDS_PER_HOUR).abs() < 1e-12);
+    }
+
+    #[test]
+    fn format_hours_shows_hours_and_minutes() {
+        assert_eq!(format_hours(2.5), "2 hours 30 minutes");
+        assert_eq!(format_hours(1.0), "1 hour");
+        assert_eq!(format_hours(0.75), "45 minutes");
+        assert_eq!(format_hours(1.0 + 1.0 / 60.0), "1 hour 1 minute");
+        // 59.6 minutes rounds up to the next hour.
+        assert_eq!(format_hours(0.9934), "1 hour");
+        assert_eq!(format_hours(0.001), "0 minutes");
+    }
+
+    #[test]
+    fn prompt_for_query_handles_time_query() {
+        let input = "3\n150\n60\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_query(&mut reader, &mut std::io::sink());
+        assert_eq!(
+            result.unwrap(),
+            Query::Time {
+                distance_miles: 150.0,
+                speed_mph: 60.0
+            }
+        );
+    }
+
+    #[test]
+    fn prompt_for_query_handles_fuel_query() {
+        let input = "4\n300\n0\n30 mpg\n3\n";
+        let mut output = Vec::new();
+        let result = prompt_for_query(&mut BufReader::new(input.as_bytes()), &mut output);
+        assert_eq!(
+            result.unwrap(),
+            Query::Fuel {
+                distance_miles: 300.0,
+                economy_mpg: 30.0,
+                price_per_gallon: 3.0
+            }
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("fuel economy (mpg) must be positive\n"));
+        assert!(output.ends_with("Enter fuel price (per gallon): "));
+    }
+
+    #[test]
+    fn parse_param_accepts_valid_positive_number() {
+        assert_eq!(parse_param(Quantity::Distance, "42.5").unwrap(), 42.5);
+    }
+
+    #[test]
+    fn parse_param_rejects_negative_number() {
+        let result = parse_param(Quantity::Distance, "-5.0");
+        assert!(matches!(
+            result,
+            Err(ChallengeError::ValidationError { ref field, .. }) if field == "distance (miles)"
+        ));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "distance (miles) must be positive"
+        );
+    }
+
+    #[test]
+    fn parse_param_accepts_units() {
+        assert_eq!(parse_param(Quantity::Time, "1h30m").unwrap(), 1.5);
+        assert_eq!(
+            parse_param(Quantity::Time, "0min").unwrap_err().to_string(),
+            "time (hours) must be positive"
+        );
+    }
+
+    #[test]
+    fn parse_param_allows_standing_starts_and_braking() {
+        assert_eq!(parse_param(Quantity::InitialSpeed, "0").unwrap(), 0.0);
+        assert_eq!(parse_param(Quantity::Acceleration, "-3").unwrap(), -3.0);
+        assert_eq!(
+            parse_param(Quantity::InitialSpeed, "-1")
+                .unwrap_err()
+                .to_string(),
+            "initial speed (mph) must not be negative"
+        );
+    }
+
+    #[test]
+    fn parse_param_rejects_zero() {
+        assert_eq!(
+            parse_param(Quantity::Distance, "0.0")
+                .unwrap_err()
+                .to_string(),
+            "distance (miles) must be positive"
+        );
+    }
+
+    #[test]
+    fn parse_param_rejects_non_numeric_input() {
+        assert!(matches!(
+            parse_param(Quantity::Distance, "not_a_number"),
+            Err(ChallengeError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn prompt_for_param_asks_again_after_invalid_input() {
+        let mut reader = BufReader::new("abc\n0\n42.5\n".as_bytes());
+        let mut output = Vec::new();
+        let result = prompt_for_param(&mut reader, &mut output, Quantity::Speed);
+
+        assert_eq!(result.unwrap(), 42.5);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Enter speed (mph): ").count(), 3);
+        assert!(output.contains("speed (mph) must be positive\n"));
+    }
+
+    #[test]
+    fn prompt_for_query_handles_distance_query() {
+        let input = "1\n10.0\n2.5\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_query(&mut reader, &mut std::io::sink()).unwrap();
+        match result {
+            Query::Distance { speed_mph, time_hr } => {
+                assert_eq!(speed_mph, 10.0);
+                assert_eq!(time_hr, 2.5);
+            }
+            _ => panic!("Expected Distance query"),
+        }
+    }
+
+    #[test]
+    fn prompt_for_query_handles_speed_query() {
+        let input = "2\n100.0\n2.0\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_query(&mut reader, &mut std::io::sink()).unwrap();
+        match result {
+            Query::Speed {
+                distance_miles,
+                time_hr,
+            } => {
+                assert_eq!(distance_miles, 100.0);
+                assert_eq!(time_hr, 2.0);
+            }
+            _ => panic!("Expected Speed query"),
+        }
+    }
+
+    #[test]
+    fn prompt_for_query_asks_again_after_invalid_query_type() {
+        let input = "7\n1\n-10.0\n10.0\n2.0\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_query(&mut reader, &mut std::io::sink());
+        assert_eq!(
+            result.unwrap(),
+            Query::Distance {
+                speed_mph: 10.0,
+                time_hr: 2.0
+            }
+        );
+    }
+
+    #[test]
+    fn complete_query_prompts_only_for_missing_values() {
+        let command = QueryCommand::Speed {
+            distance: None,
+            time: Some(2.0),
+        };
+        let mut reader = BufReader::new("120\n".as_bytes());
+        let mut output = Vec::new();
+        let result = complete_query(&mut reader, &mut output, command);
+
+        assert_eq!(
+            result.unwrap(),
+            Query::Speed {
+                distance_miles: 120.0,
+                time_hr: 2.0
+            }
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Enter distance (miles): "
+        );
+    }
+
+    #[test]
+    fn args_accept_a_query_and_its_values() {
+        let args =
+            Args::try_parse_from(["c04", "distance", "--speed", "60", "--time", "2"]).unwrap();
+        assert_eq!(
+            args.query,
+            Some(QueryCommand::Distance {
+                speed: Some(60.0),
+                time: Some(2.0)
+            })
+        );
+        assert!(Args::try_parse_from(["c04", "speed", "--time", "-1"]).is_err());
+        assert_eq!(Args::try_parse_from(["c04"]).unwrap().query, None);
+    }
+
+    #[test]
+    fn prompt_for_query_stops_at_end_of_input() {
+        let input = "1\nabc\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = prompt_for_query(&mut reader, &mut std::io::sink());
+        assert!(result.is_err());
+    }
+}
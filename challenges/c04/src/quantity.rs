@@ -0,0 +1,335 @@
+//! The quantities the calculator works with, and the units they can be given
+//! in.
+//!
+//! Every answer is converted to miles, miles per hour, hours, miles per hour
+//! per second, miles per gallon or a price per gallon. A bare number is
+//! already in those units, and otherwise a unit follows it, e.g. `90 km/h`,
+//! `5km`, `3 m/s²`, `6.5 L/100km` or `1.80/L`. A time can also be a duration
+//! made of hours, minutes and seconds, e.g. `1h30m`, `90min` or `2h 15m 30s`.
+//! Gallons are US gallons.
+use challenge_error::ChallengeError;
+
+/// Kilometers in a mile.
+const KM_PER_MILE: f64 = 1.609344;
+
+/// Litres in a US gallon.
+pub const LITRES_PER_GALLON: f64 = 3.785411784;
+
+/// A quantity that is prompted for or given on the command line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantity {
+    Speed,
+    Distance,
+    Time,
+    /// The speed at the start of a time spent accelerating, which can be
+    /// zero.
+    InitialSpeed,
+    /// How much faster, in miles per hour, each second makes. Braking is a
+    /// negative acceleration.
+    Acceleration,
+    /// Fuel economy, in miles per gallon.
+    Economy,
+    /// The price of fuel per gallon, in any currency.
+    Price,
+}
+
+impl Quantity {
+    /// The name of the quantity with its unit, as used in prompts and
+    /// errors.
+    pub fn name(self) -> &'static str {
+        match self {
+            Quantity::Speed => "speed (mph)",
+            Quantity::Distance => "distance (miles)",
+            Quantity::Time => "time (hours)",
+            Quantity::InitialSpeed => "initial speed (mph)",
+            Quantity::Acceleration => "acceleration (mph/s)",
+            Quantity::Economy => "fuel economy (mpg)",
+            Quantity::Price => "fuel price (per gallon)",
+        }
+    }
+
+    /// The key of the quantity's name in the message catalog, `c04.<key>`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Quantity::Speed => "speed",
+            Quantity::Distance => "distance",
+            Quantity::Time => "time",
+            Quantity::InitialSpeed => "initial_speed",
+            Quantity::Acceleration => "acceleration",
+            Quantity::Economy => "economy",
+            Quantity::Price => "price",
+        }
+    }
+
+    /// The units an answer can be given in, with how many of the canonical
+    /// unit each is.
+    fn units(self) -> &'static [(&'static str, f64)] {
+        match self {
+            Quantity::Speed | Quantity::InitialSpeed => &[
+                ("mph", 1.0),
+                ("mi/h", 1.0),
+                ("km/h", 1.0 / KM_PER_MILE),
+                ("kph", 1.0 / KM_PER_MILE),
+                ("m/s", 3.6 / KM_PER_MILE),
+            ],
+            Quantity::Distance => &[
+                ("miles", 1.0),
+                ("mile", 1.0),
+                ("mi", 1.0),
+                ("km", 1.0 / KM_PER_MILE),
+                ("m", 0.001 / KM_PER_MILE),
+            ],
+            Quantity::Time => &[
+                ("hours", 1.0),
+                ("hour", 1.0),
+                ("hr", 1.0),
+                ("h", 1.0),
+                ("minutes", 1.0 / 60.0),
+                ("minute", 1.0 / 60.0),
+                ("min", 1.0 / 60.0),
+                ("m", 1.0 / 60.0),
+                ("seconds", 1.0 / 3600.0),
+                ("second", 1.0 / 3600.0),
+                ("sec", 1.0 / 3600.0),
+                ("s", 1.0 / 3600.0),
+            ],
+            Quantity::Acceleration => &[
+                ("mph/s", 1.0),
+                ("km/h/s", 1.0 / KM_PER_MILE),
+                ("m/s²", 3.6 / KM_PER_MILE),
+                ("m/s^2", 3.6 / KM_PER_MILE),
+                ("m/s2", 3.6 / KM_PER_MILE),
+            ],
+            // L/100km, which goes down as mpg goes up, is converted in
+            // `parse` instead.
+            Quantity::Economy => &[
+                ("mpg", 1.0),
+                ("mi/gal", 1.0),
+                ("km/l", LITRES_PER_GALLON / KM_PER_MILE),
+            ],
+            Quantity::Price => &[
+                ("/gal", 1.0),
+                ("per gallon", 1.0),
+                ("/l", LITRES_PER_GALLON),
+                ("per litre", LITRES_PER_GALLON),
+                ("per liter", LITRES_PER_GALLON),
+            ],
+        }
+    }
+
+    /// How many of the canonical unit `unit` is.
+    fn factor(self, unit: &str) -> challenge_error::Result<f64> {
+        self.units()
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|&(_, factor)| factor)
+            .ok_or_else(|| {
+                ChallengeError::validation(
+                    self.name(),
+                    &format!(
+                        "has an unsupported unit '{}' (use {})",
+                        unit,
+                        self.examples()
+                    ),
+                )
+            })
+    }
+
+    /// The units to suggest when one is not supported.
+    fn examples(self) -> &'static str {
+        match self {
+            Quantity::Speed | Quantity::InitialSpeed => "mph, km/h or m/s",
+            Quantity::Distance => "miles, km or m",
+            Quantity::Time => "h, min or s, e.g. 1h30m",
+            Quantity::Acceleration => "mph/s, km/h/s or m/s²",
+            Quantity::Economy => "mpg, km/L or L/100km",
+            Quantity::Price => "per gallon or per litre, e.g. 1.80/L",
+        }
+    }
+
+    /// Reads an answer, with or without a unit, in the canonical unit.
+    pub fn parse(self, answer: &str) -> challenge_error::Result<f64> {
+        let answer = answer.trim();
+        if self == Quantity::Time {
+            return self.parse_duration(answer);
+        }
+        let (value, unit) = split_number(answer)?;
+        match unit.trim() {
+            "" => Ok(value),
+            unit if self == Quantity::Economy && unit.eq_ignore_ascii_case("l/100km") => {
+                // A consumption of zero or less is left for the caller to
+                // reject.
+                if value > 0.0 {
+                    Ok(100.0 / KM_PER_MILE * LITRES_PER_GALLON / value)
+                } else {
+                    Ok(value)
+                }
+            }
+            unit => Ok(value * self.factor(unit)?),
+        }
+    }
+
+    /// Reads a bare number of hours, or a number with a unit after it for
+    /// each part of a duration, each unit at most once.
+    fn parse_duration(self, answer: &str) -> challenge_error::Result<f64> {
+        let (hours, rest) = split_number(answer)?;
+        if rest.trim().is_empty() {
+            return Ok(hours);
+        }
+        let mut total = 0.0;
+        let mut seen = Vec::new();
+        let mut rest = answer;
+        while !rest.is_empty() {
+            let (number, after) = split_number(rest)?;
+            let given = &rest[..rest.len() - after.len()];
+            let after = after.trim_start();
+            let end = after
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(after.len());
+            let (unit, after) = after.split_at(end);
+            if unit.is_empty() {
+                return Err(ChallengeError::validation(
+                    self.name(),
+                    &format!("needs a unit after '{}' (use {})", given, self.examples()),
+                ));
+            }
+            // Units of the same size, such as h and hours, count as one.
+            let factor = self.factor(unit)?;
+            if seen.contains(&factor) {
+                return Err(ChallengeError::validation(
+                    self.name(),
+                    &format!("has the unit '{}' more than once", unit),
+                ));
+            }
+            seen.push(factor);
+            total += number * factor;
+            rest = after.trim_start();
+        }
+        Ok(total)
+    }
+}
+
+/// Splits `text` into the number at its start, the longest one that reads
+/// as an `f64`, and whatever follows it.
+fn split_number(text: &str) -> challenge_error::Result<(f64, &str)> {
+    let (value, end) = text
+        .char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .rev()
+        .find_map(|end| Some((text[..end].parse::<f64>().ok()?, end)))
+        .ok_or_else(|| ChallengeError::parse(text, "expected a number first"))?;
+    if !value.is_finite() {
+        return Err(ChallengeError::parse(
+            &text[..end],
+            "the number is not finite",
+        ));
+    }
+    Ok((value, &text[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn parse_converts_to_the_canonical_unit() {
+        assert_close(Quantity::Speed.parse("60").unwrap(), 60.0);
+        assert_close(Quantity::Speed.parse("60 mph").unwrap(), 60.0);
+        assert_close(
+            Quantity::Speed.parse("90 km/h").unwrap(),
+            90.0 / KM_PER_MILE,
+        );
+        assert_close(Quantity::Speed.parse("10m/s").unwrap(), 36.0 / KM_PER_MILE);
+        assert_close(Quantity::Distance.parse("5KM").unwrap(), 5.0 / KM_PER_MILE);
+        assert_close(Quantity::Distance.parse("1609.344 m").unwrap(), 1.0);
+        assert_close(Quantity::Distance.parse("1e3").unwrap(), 1000.0);
+        assert_close(
+            Quantity::Distance.parse("1.5e3m").unwrap(),
+            1.5 / KM_PER_MILE,
+        );
+    }
+
+    #[test]
+    fn parse_converts_fuel_economy_and_price() {
+        assert_close(Quantity::Economy.parse("30 mpg").unwrap(), 30.0);
+        // 100 km on 100/42.5 L is 42.5 km/L.
+        assert_close(
+            Quantity::Economy
+                .parse("2.3529411764705883 L/100km")
+                .unwrap(),
+            Quantity::Economy.parse("42.5 km/L").unwrap(),
+        );
+        let economy = Quantity::Economy.parse("10 l/100km").unwrap();
+        assert!((economy - 23.5215).abs() < 1e-4, "{economy}");
+        assert_eq!(Quantity::Economy.parse("0 L/100km").unwrap(), 0.0);
+        assert_close(
+            Quantity::Price.parse("1.5/L").unwrap(),
+            1.5 * LITRES_PER_GALLON,
+        );
+        assert_close(Quantity::Price.parse("3.50 per gallon").unwrap(), 3.5);
+    }
+
+    #[test]
+    fn parse_converts_acceleration() {
+        assert_close(Quantity::Acceleration.parse("6").unwrap(), 6.0);
+        assert_close(
+            Quantity::Acceleration.parse("-2 m/s²").unwrap(),
+            -7.2 / KM_PER_MILE,
+        );
+        assert_close(
+            Quantity::Acceleration.parse("10 KM/H/S").unwrap(),
+            10.0 / KM_PER_MILE,
+        );
+        assert_close(
+            Quantity::InitialSpeed.parse("36 km/h").unwrap(),
+            36.0 / KM_PER_MILE,
+        );
+    }
+
+    #[test]
+    fn parse_reads_durations() {
+        assert_close(Quantity::Time.parse("1.5").unwrap(), 1.5);
+        assert_close(Quantity::Time.parse("2h15m").unwrap(), 2.25);
+        assert_close(Quantity::Time.parse("90min").unwrap(), 1.5);
+        assert_close(Quantity::Time.parse("1 h 30 min 36 s").unwrap(), 1.51);
+        assert_close(Quantity::Time.parse("2 hours").unwrap(), 2.0);
+        assert_close(Quantity::Time.parse("1e3").unwrap(), 1000.0);
+        assert_eq!(
+            Quantity::Time.parse("1h1h").unwrap_err().to_string(),
+            "time (hours) has the unit 'h' more than once"
+        );
+        assert!(Quantity::Time.parse("1h 30m 1hour").is_err());
+    }
+
+    #[test]
+    fn parse_explains_unsupported_units() {
+        assert_eq!(
+            Quantity::Speed.parse("3 knots").unwrap_err().to_string(),
+            "speed (mph) has an unsupported unit 'knots' (use mph, km/h or m/s)"
+        );
+        assert_eq!(
+            Quantity::Time.parse("1h30").unwrap_err().to_string(),
+            "time (hours) needs a unit after '30' (use h, min or s, e.g. 1h30m)"
+        );
+        assert!(Quantity::Time.parse("1d").is_err());
+        assert!(matches!(
+            Quantity::Distance.parse("km"),
+            Err(ChallengeError::ParseError { .. })
+        ));
+        assert_eq!(
+            Quantity::Time.parse("h").unwrap_err().to_string(),
+            "invalid input 'h': expected a number first"
+        );
+        assert_eq!(
+            Quantity::Speed.parse("inf").unwrap_err().to_string(),
+            "invalid input 'inf': the number is not finite"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Date arithmetic: a date moved forwards or backwards by a span of years,
+//! months, weeks and days.
+//!
+//! A span is written as whole numbers with a unit after each, `y` for years,
+//! `m` for months, `w` for weeks and `d` for days, e.g. `90d`, `6m` or
+//! `1y2m3d`. Years and months are added first, ending on the last day of a
+//! shorter month where the day does not exist, so a month after 31 January
+//! is 28 or 29 February. Weeks and days are added after them.
+use challenge_error::ChallengeError;
+use chrono::{Days, Months, NaiveDate};
+use std::fmt;
+
+/// A span of time in calendar units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+}
+
+/// Reads a span such as `90d` or `1y6m`.
+pub fn parse_span(text: &str) -> challenge_error::Result<Span> {
+    let field = "span";
+    let mut span = Span::default();
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return Err(ChallengeError::validation(field, "must not be empty"));
+    }
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(end);
+        let Some(unit) = after.chars().next() else {
+            return Err(ChallengeError::validation(
+                field,
+                &format!("needs a unit after '{number}' (use y, m, w or d, e.g. 90d or 1y6m)"),
+            ));
+        };
+        if number.is_empty() {
+            return Err(ChallengeError::parse(text, "expected a number, e.g. 90d"));
+        }
+        let number: u32 = challenge_error::parse(number)?;
+        let part = match unit.to_ascii_lowercase() {
+            'y' => &mut span.years,
+            'm' => &mut span.months,
+            'w' => &mut span.weeks,
+            'd' => &mut span.days,
+            _ => {
+                return Err(ChallengeError::validation(
+                    field,
+                    &format!("has an unknown unit '{unit}' (use y, m, w or d)"),
+                ))
+            }
+        };
+        *part = part.saturating_add(number);
+        rest = after[unit.len_utf8()..].trim_start();
+    }
+    Ok(span)
+}
+
+impl Span {
+    /// Whether the span is no time at all.
+    pub fn is_zero(&self) -> bool {
+        *self == Span::default()
+    }
+
+    /// The span `times` over, e.g. `2w` three times is `6w`.
+    pub fn times(&self, times: u32) -> Span {
+        Span {
+            years: self.years.saturating_mul(times),
+            months: self.months.saturating_mul(times),
+            weeks: self.weeks.saturating_mul(times),
+            days: self.days.saturating_mul(times),
+        }
+    }
+
+    /// `date` moved forwards by the span, or backwards if not `forward`.
+    pub fn shift(&self, date: NaiveDate, forward: bool) -> challenge_error::Result<NaiveDate> {
+        let months = Months::new(self.years.saturating_mul(12).saturating_add(self.months));
+        let days = Days::new(u64::from(self.weeks) * 7 + u64::from(self.days));
+        let shifted = if forward {
+            date.checked_add_months(months)
+                .and_then(|date| date.checked_add_days(days))
+        } else {
+            date.checked_sub_months(months)
+                .and_then(|date| date.checked_sub_days(days))
+        };
+        shifted.ok_or_else(|| {
+            ChallengeError::validation("date", "is out of the range of supported dates")
+        })
+    }
+}
+
+/// The span in its shortest written form, e.g. `1y6m`, or `0d` when empty.
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts = [
+            (self.years, 'y'),
+            (self.months, 'm'),
+            (self.weeks, 'w'),
+            (self.days, 'd'),
+        ];
+        if self.is_zero() {
+            return write!(f, "0d");
+        }
+        for (count, unit) in parts {
+            if count > 0 {
+                write!(f, "{count}{unit}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    fn shift(from: NaiveDate, span: &str, forward: bool) -> NaiveDate {
+        parse_span(span).unwrap().shift(from, forward).unwrap()
+    }
+
+    #[test]
+    fn parse_span_reads_each_unit() {
+        assert_eq!(
+            parse_span("1y2m3w4d").unwrap(),
+            Span {
+                years: 1,
+                months: 2,
+                weeks: 3,
+                days: 4
+            }
+        );
+        assert_eq!(parse_span(" 90D ").unwrap().days, 90);
+        assert_eq!(parse_span("6m 1d").unwrap().to_string(), "6m1d");
+    }
+
+    #[test]
+    fn parse_span_explains_bad_spans() {
+        assert_eq!(
+            parse_span("90").unwrap_err().to_string(),
+            "span needs a unit after '90' (use y, m, w or d, e.g. 90d or 1y6m)"
+        );
+        assert_eq!(
+            parse_span("3h").unwrap_err().to_string(),
+            "span has an unknown unit 'h' (use y, m, w or d)"
+        );
+        assert!(matches!(
+            parse_span("-3d"),
+            Err(ChallengeError::ParseError { .. })
+        ));
+        assert!(parse_span("").is_err());
+    }
+
+    #[test]
+    fn shift_adds_and_subtracts() {
+        assert_eq!(shift(date(2024, 1, 1), "90d", true), date(2024, 3, 31));
+        assert_eq!(shift(date(2024, 3, 31), "90d", false), date(2024, 1, 1));
+        assert_eq!(shift(date(2024, 10, 15), "6m", false), date(2024, 4, 15));
+        assert_eq!(shift(date(2024, 1, 1), "1y2w", true), date(2025, 1, 15));
+    }
+
+    #[test]
+    fn shift_ends_on_the_last_day_of_short_months() {
+        assert_eq!(shift(date(2023, 1, 31), "1m", true), date(2023, 2, 28));
+        assert_eq!(shift(date(2024, 2, 29), "1y", true), date(2025, 2, 28));
+        assert_eq!(shift(date(2024, 5, 31), "3m", false), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn shift_reports_dates_out_of_range() {
+        let span = parse_span("1000000y").unwrap();
+        assert_eq!(
+            span.shift(date(2024, 1, 1), true).unwrap_err().to_string(),
+            "date is out of the range of supported dates"
+        );
+    }
+}
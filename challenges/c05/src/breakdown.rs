@@ -0,0 +1,123 @@
+//! The time between two dates in calendar terms, e.g. `31 years, 4 months,
+//! 1 week, 5 days`.
+//!
+//! Whole months are counted first, each from the start date rather than from
+//! the month before, so a month always ends on the same day of the month as
+//! the start date, or on the last day of a shorter month. The start of a
+//! year from 29 February is 28 February in a common year. Any days left over
+//! are split into weeks and days.
+use challenge_common::l10n;
+use chrono::{Months, NaiveDate};
+use std::fmt;
+
+/// Years, months, weeks and days between two dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+}
+
+impl Breakdown {
+    /// The time from `from` to `to`, in whichever order they are given.
+    pub fn between(from: NaiveDate, to: NaiveDate) -> Self {
+        let (from, to) = if from <= to { (from, to) } else { (to, from) };
+        let add_months = |months: u32| from.checked_add_months(Months::new(months));
+        // Whole years first, then months, to keep the search short.
+        let mut months = (to.years_since(from).unwrap_or_default()) * 12;
+        while add_months(months + 1).is_some_and(|date| date <= to) {
+            months += 1;
+        }
+        let rest = (to - add_months(months).unwrap_or(from)).num_days() as u32;
+        Breakdown {
+            years: months / 12,
+            months: months % 12,
+            weeks: rest / 7,
+            days: rest % 7,
+        }
+    }
+}
+
+/// `count` of `unit`, e.g. `1 year` or `3 days`.
+fn count_of(count: u32, unit: &str) -> String {
+    let key = format!("c05.{}{}", unit, if count == 1 { "" } else { "s" });
+    l10n::format(&key, &[("count", &count)])
+}
+
+/// The parts that are not zero, e.g. `2 years, 1 day`, or `0 days` when
+/// they all are.
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts: Vec<_> = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.weeks, "week"),
+            (self.days, "day"),
+        ]
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .map(|(count, unit)| count_of(count, unit))
+        .collect();
+        if parts.is_empty() {
+            write!(f, "{}", count_of(0, "day"))
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    fn between(from: NaiveDate, to: NaiveDate) -> String {
+        Breakdown::between(from, to).to_string()
+    }
+
+    #[test]
+    fn between_counts_years_months_weeks_and_days() {
+        assert_eq!(
+            between(date(1993, 6, 3), date(2024, 10, 15)),
+            "31 years, 4 months, 1 week, 5 days"
+        );
+        assert_eq!(between(date(2024, 1, 1), date(2024, 1, 1)), "0 days");
+        assert_eq!(between(date(2024, 1, 1), date(2024, 1, 8)), "1 week");
+        assert_eq!(
+            Breakdown::between(date(2024, 10, 15), date(1993, 6, 3)),
+            Breakdown::between(date(1993, 6, 3), date(2024, 10, 15))
+        );
+    }
+
+    #[test]
+    fn between_handles_short_months() {
+        // A month from 31 January ends on the last day of February.
+        assert_eq!(between(date(2023, 1, 31), date(2023, 2, 28)), "1 month");
+        assert_eq!(
+            between(date(2023, 1, 31), date(2023, 2, 27)),
+            "3 weeks, 6 days"
+        );
+        // The second month ends on 31 March, counted from 31 January rather
+        // than from 28 February.
+        assert_eq!(
+            between(date(2023, 1, 31), date(2023, 3, 30)),
+            "1 month, 4 weeks, 2 days"
+        );
+        assert_eq!(between(date(2023, 1, 31), date(2023, 3, 31)), "2 months");
+    }
+
+    #[test]
+    fn between_handles_leap_days() {
+        assert_eq!(between(date(2000, 2, 29), date(2001, 2, 28)), "1 year");
+        assert_eq!(
+            between(date(2000, 2, 29), date(2004, 2, 28)),
+            "3 years, 11 months, 4 weeks, 2 days"
+        );
+        assert_eq!(between(date(2000, 2, 29), date(2004, 2, 29)), "4 years");
+        assert_eq!(
+            between(date(2023, 2, 28), date(2024, 3, 1)),
+            "1 year, 2 days"
+        );
+    }
+}
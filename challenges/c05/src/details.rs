@@ -0,0 +1,111 @@
+//! Calendar details of a date: its ISO week, its day of the year and its
+//! quarter.
+//!
+//! ISO weeks start on a Monday, and the first week of a year is the one with
+//! its Thursday in that year, so the first days of January can belong to
+//! the last week of the year before, e.g. 1 January 2021 is in `2020-W53`.
+use challenge_common::l10n;
+use challenge_common::output::{json, Value};
+use chrono::{Datelike, NaiveDate};
+
+/// A detail that can be asked for.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detail {
+    IsoWeek,
+    DayOfYear,
+    Quarter,
+}
+
+impl Detail {
+    /// Every detail, in the order they are shown.
+    pub const ALL: [Detail; 3] = [Detail::IsoWeek, Detail::DayOfYear, Detail::Quarter];
+
+    /// The name the detail is shown with.
+    pub fn label(self) -> &'static str {
+        l10n::text(&format!("c05.{}", self.key()))
+    }
+
+    /// The detail's key in JSON output.
+    pub fn key(self) -> &'static str {
+        match self {
+            Detail::IsoWeek => "iso_week",
+            Detail::DayOfYear => "day_of_year",
+            Detail::Quarter => "quarter",
+        }
+    }
+
+    /// The detail of `date` as text, e.g. `2024-W42`, `289` or `Q4`.
+    pub fn text(self, date: NaiveDate) -> String {
+        match self {
+            Detail::IsoWeek => {
+                let week = date.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            Detail::DayOfYear => date.ordinal().to_string(),
+            Detail::Quarter => l10n::format("c05.quarter_of", &[("quarter", &quarter(date))]),
+        }
+    }
+
+    /// The detail of `date` as JSON: the ISO week as text, and the day of
+    /// the year and the quarter as numbers.
+    pub fn json(self, date: NaiveDate) -> Value {
+        match self {
+            Detail::IsoWeek => json!(self.text(date)),
+            Detail::DayOfYear => json!(date.ordinal()),
+            Detail::Quarter => json!(quarter(date)),
+        }
+    }
+}
+
+/// The quarter of the year `date` is in, from 1 to 4.
+fn quarter(date: NaiveDate) -> u32 {
+    date.month0() / 3 + 1
+}
+
+/// The details asked for with `--details`, or all of them when none are
+/// named.
+pub fn selected(details: &[Detail]) -> Vec<Detail> {
+    if details.is_empty() {
+        Detail::ALL.to_vec()
+    } else {
+        Detail::ALL
+            .into_iter()
+            .filter(|detail| details.contains(detail))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    fn texts(date: NaiveDate) -> Vec<String> {
+        Detail::ALL.iter().map(|detail| detail.text(date)).collect()
+    }
+
+    #[test]
+    fn text_describes_each_detail() {
+        assert_eq!(texts(date(2024, 10, 15)), ["2024-W42", "289", "Q4"]);
+        assert_eq!(texts(date(2024, 12, 31)), ["2025-W01", "366", "Q4"]);
+        assert_eq!(texts(date(2021, 1, 1)), ["2020-W53", "1", "Q1"]);
+        assert_eq!(texts(date(2023, 6, 30)), ["2023-W26", "181", "Q2"]);
+    }
+
+    #[test]
+    fn json_uses_numbers_where_it_can() {
+        let date = date(2024, 7, 1);
+        assert_eq!(Detail::IsoWeek.json(date), json!("2024-W27"));
+        assert_eq!(Detail::DayOfYear.json(date), json!(183));
+        assert_eq!(Detail::Quarter.json(date), json!(3));
+    }
+
+    #[test]
+    fn selected_keeps_the_usual_order() {
+        assert_eq!(selected(&[]), Detail::ALL);
+        assert_eq!(
+            selected(&[Detail::Quarter, Detail::IsoWeek, Detail::Quarter]),
+            [Detail::IsoWeek, Detail::Quarter]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Birthday insights: the weekday of the birth date, how long it is until
+//! the next birthday, and the next round milestones, such as being 10,000
+//! days or a billion seconds old.
+//!
+//! A birthday on 29 February falls on 28 February in a common year, as in
+//! the [`breakdown`](super::breakdown).
+use challenge_common::l10n;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::fmt;
+
+/// The kinds of milestone, each a round number of some unit: the unit's
+/// name, its length in seconds and how many of it make a milestone.
+const MILESTONES: &[(&str, i64, i64)] = &[
+    ("days", 86_400, 1_000),
+    ("days", 86_400, 10_000),
+    ("weeks", 604_800, 1_000),
+    ("seconds", 1, 1_000_000_000),
+];
+
+/// A round age and the date it is reached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Milestone {
+    /// The age, e.g. `10,000 days old`.
+    pub name: String,
+    /// The age in the player's language.
+    pub label: String,
+    pub date: NaiveDate,
+    /// Days from today until the milestone, zero when it is today.
+    pub days_until: i64,
+}
+
+/// What there is to know about a birth date as of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Insights {
+    pub weekday: Weekday,
+    pub next_birthday: NaiveDate,
+    /// Days from today until the next birthday, zero when it is today.
+    pub days_until_birthday: i64,
+    /// The age at the next birthday.
+    pub turning: i32,
+    /// The next milestone of each kind, soonest first.
+    pub milestones: Vec<Milestone>,
+}
+
+/// The birthday of someone born on `birth` in `year`.
+fn birthday_in(birth: NaiveDate, year: i32) -> NaiveDate {
+    birth
+        .with_year(year)
+        .or_else(|| NaiveDate::from_ymd_opt(year, 2, 28))
+        .unwrap_or(birth)
+}
+
+/// `n` with a comma between each group of three digits, e.g. `10,000`.
+fn with_separators(n: i64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+impl Insights {
+    /// The insights for someone born on `birth`, as of `today`.
+    pub fn new(birth: NaiveDate, today: NaiveDate) -> Self {
+        let mut next_birthday = birthday_in(birth, today.year().max(birth.year()));
+        if next_birthday < today {
+            next_birthday = birthday_in(birth, next_birthday.year() + 1);
+        }
+
+        let elapsed = (today - birth).num_seconds();
+        let mut milestones: Vec<Milestone> = Vec::new();
+        for &(unit, seconds, count) in MILESTONES {
+            let step = seconds * count;
+            // The first multiple of `step` that is not already past.
+            let n = (elapsed + step - 1).div_euclid(step).max(1);
+            let date = birth + Duration::seconds(n * step);
+            let age = with_separators(n * count);
+            let name = format!("{} {} old", age, unit);
+            if milestones.iter().all(|milestone| milestone.name != name) {
+                milestones.push(Milestone {
+                    name,
+                    label: l10n::format(&format!("c05.{unit}_old"), &[("age", &age)]),
+                    date,
+                    days_until: (date - today).num_days(),
+                });
+            }
+        }
+        milestones.sort_by_key(|milestone| milestone.date);
+
+        Insights {
+            weekday: birth.weekday(),
+            next_birthday,
+            days_until_birthday: (next_birthday - today).num_days(),
+            turning: next_birthday.year() - birth.year(),
+            milestones,
+        }
+    }
+}
+
+/// `today` for zero days, or e.g. `in 1 day` or `in 230 days`.
+fn when(days: i64) -> String {
+    match days {
+        0 => l10n::text("c05.today").to_string(),
+        1 => l10n::text("c05.in_one_day").to_string(),
+        days => l10n::format("c05.in_days", &[("days", &days)]),
+    }
+}
+
+/// The insights over several lines, e.g.
+///
+/// ```text
+/// Born on a Saturday
+/// Next birthday: 2028-01-01, in 245 days (turning 28)
+/// Milestones:
+///   2027-05-19  10,000 days old, in 18 days
+///   2031-09-09  1,000,000,000 seconds old, in 1592 days
+///   2038-05-01  2,000 weeks old, in 4018 days
+/// ```
+impl fmt::Display for Insights {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let weekday = weekday_text(self.weekday);
+        writeln!(f, "{}", l10n::format("c05.born", &[("weekday", &weekday)]))?;
+        let until = when(self.days_until_birthday);
+        let next = l10n::format(
+            "c05.next_birthday",
+            &[
+                ("date", &self.next_birthday),
+                ("when", &until),
+                ("age", &self.turning),
+            ],
+        );
+        writeln!(f, "{}", next)?;
+        write!(f, "{}", l10n::text("c05.milestones"))?;
+        for milestone in &self.milestones {
+            write!(
+                f,
+                "\n  {}  {}, {}",
+                milestone.date,
+                milestone.label,
+                when(milestone.days_until)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The name of `weekday` in the player's language, e.g. `Thursday`.
+pub fn weekday_text(weekday: Weekday) -> &'static str {
+    l10n::text(&format!("c05.{}", weekday_name(weekday).to_lowercase()))
+}
+
+/// The full English name of `weekday`, e.g. `Thursday`.
+pub fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    #[test]
+    fn new_finds_the_next_birthday() {
+        let insights = Insights::new(date(1993, 6, 3), date(2024, 10, 15));
+        assert_eq!(insights.weekday, Weekday::Thu);
+        assert_eq!(insights.next_birthday, date(2025, 6, 3));
+        assert_eq!(insights.days_until_birthday, 231);
+        assert_eq!(insights.turning, 32);
+
+        let insights = Insights::new(date(1993, 6, 3), date(2024, 6, 3));
+        assert_eq!(insights.days_until_birthday, 0);
+        assert_eq!(insights.turning, 31);
+
+        let insights = Insights::new(date(2000, 2, 29), date(2025, 1, 1));
+        assert_eq!(insights.next_birthday, date(2025, 2, 28));
+        assert_eq!(insights.turning, 25);
+    }
+
+    #[test]
+    fn new_finds_the_next_milestones() {
+        let insights = Insights::new(date(2000, 1, 1), date(2027, 5, 1));
+        let milestones: Vec<_> = insights
+            .milestones
+            .iter()
+            .map(|milestone| (milestone.date, milestone.name.as_str()))
+            .collect();
+        assert_eq!(
+            milestones,
+            [
+                (date(2027, 5, 19), "10,000 days old"),
+                (date(2031, 9, 9), "1,000,000,000 seconds old"),
+                (date(2038, 5, 1), "2,000 weeks old"),
+            ]
+        );
+        assert_eq!(insights.milestones[0].days_until, 18);
+    }
+
+    #[test]
+    fn display_lists_everything() {
+        let insights = Insights::new(date(2000, 1, 1), date(2027, 5, 1));
+        assert_eq!(
+            insights.to_string(),
+            "Born on a Saturday\n\
+             Next birthday: 2028-01-01, in 245 days (turning 28)\n\
+             Milestones:\n  \
+             2027-05-19  10,000 days old, in 18 days\n  \
+             2031-09-09  1,000,000,000 seconds old, in 1592 days\n  \
+             2038-05-01  2,000 weeks old, in 4018 days"
+        );
+    }
+
+    #[test]
+    fn with_separators_groups_digits() {
+        assert_eq!(with_separators(7), "7");
+        assert_eq!(with_separators(1_000), "1,000");
+        assert_eq!(with_separators(1_000_000_000), "1,000,000,000");
+    }
+}
@@ -0,0 +1,608 @@
+//! A date calculation utility that computes the difference between dates.
+//!
+//! This module provides functionality to calculate the time difference between dates
+//! in both days and seconds, and in calendar terms as years, months, weeks and days
+//! (see [`breakdown`]). With `--working-days` it counts the days from Monday to
+//! Friday as well, leaving out the holidays in a `--holidays` file (see
+//! [`working_days`]), and with `--insights` it tells the weekday of the date, the
+//! days until the next birthday and the next round milestones (see [`insights`]),
+//! and with `--details` the ISO week, day of the year and quarter of the date (see
+//! [`details`]).
+//!
+//! With `--add` or `--sub` it moves the date by a span such as `90d` or `6m`
+//! instead and prints the resulting date (see [`arithmetic`]). With `--every`
+//! it lists the next dates of an event that recurs at that interval from the
+//! date, such as a payday every 14 days (see [`schedule`]).
+//!
+//! It includes interactive input handling for date entry in the YYYY-MM-DD
+//! format, or takes the date as an argument, with proper error handling for
+//! invalid inputs. With `--format json` the date and the differences are
+//! printed as JSON.
+use arithmetic::Span;
+use breakdown::Breakdown;
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use challenge_error::ChallengeError;
+use chrono::{Datelike, Local, NaiveDate};
+use clap::Parser;
+use details::Detail;
+use insights::Insights;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod arithmetic;
+mod breakdown;
+mod details;
+mod insights;
+mod schedule;
+#[cfg(test)]
+mod test_util;
+mod working_days;
+
+fn get_days_difference(input_date: &NaiveDate) -> i64 {
+    let today = Local::now().date_naive();
+    (today - *input_date).num_days()
+}
+
+fn get_seconds_difference(input_date: &NaiveDate) -> i64 {
+    let today = Local::now().date_naive();
+    (today - *input_date).num_seconds()
+}
+
+fn parse_date(answer: &str) -> challenge_error::Result<NaiveDate> {
+    NaiveDate::parse_from_str(answer, "%Y-%m-%d").map_err(|e| ChallengeError::parse(answer, e))
+}
+
+fn read_user_date<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> challenge_error::Result<NaiveDate> {
+    read_date(reader, writer, "prompt.birth_date")
+}
+
+/// Asks for a date with the catalog message `key`.
+fn read_date<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    key: &str,
+) -> challenge_error::Result<NaiveDate> {
+    Ok(Prompt::new(l10n::text(key)).ask(reader, writer, parse_date)?)
+}
+
+/// Show how many days and seconds have passed since your birth date.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Birth date (YYYY-MM-DD), or the date to add to or subtract from,
+    /// prompted for if omitted
+    #[arg(value_parser = parse_date)]
+    birth_date: Option<NaiveDate>,
+    /// Also count the working days, Monday to Friday, since the date
+    #[arg(long)]
+    working_days: bool,
+    /// File of holidays to leave out of the working days, one YYYY-MM-DD
+    /// date per line
+    #[arg(long, value_name = "FILE", requires = "working_days")]
+    holidays: Option<PathBuf>,
+    /// Also tell the weekday of the date, the days until the next birthday
+    /// and the next round milestones, such as 10,000 days old
+    #[arg(long)]
+    insights: bool,
+    /// Also show calendar details of the date: all of them, or those named,
+    /// e.g. --details=iso-week,quarter
+    #[arg(
+        long,
+        value_enum,
+        value_name = "DETAIL",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    details: Option<Vec<Detail>>,
+    /// Add a span to the date instead, e.g. "90d", "6m" or "1y2w", and print
+    /// the resulting date
+    #[arg(
+        long,
+        value_name = "SPAN",
+        value_parser = arithmetic::parse_span,
+        conflicts_with_all = ["sub", "working_days", "insights", "details"]
+    )]
+    add: Option<Span>,
+    /// Subtract a span from the date instead, e.g. "6m", and print the
+    /// resulting date
+    #[arg(
+        long,
+        value_name = "SPAN",
+        value_parser = arithmetic::parse_span,
+        conflicts_with_all = ["every", "working_days", "insights", "details"]
+    )]
+    sub: Option<Span>,
+    /// List the next dates of an event on the date that recurs at this
+    /// interval instead, e.g. "14d" or "1m"
+    #[arg(
+        long,
+        value_name = "SPAN",
+        value_parser = arithmetic::parse_span,
+        conflicts_with_all = ["add", "working_days", "insights", "details"]
+    )]
+    every: Option<Span>,
+    /// How many dates to list with --every
+    #[arg(
+        long,
+        default_value_t = 5,
+        requires = "every",
+        value_parser = clap::value_parser!(u32).range(1..=1000)
+    )]
+    count: u32,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Prints how long ago `birth_date` was in `format`, asking for it if it
+/// was not given. With `holidays`, the working days are counted too,
+/// leaving those days out. With `insights`, the birthday insights are
+/// printed too, and then each of `details`.
+fn run(
+    common: &CommonArgs,
+    birth_date: Option<NaiveDate>,
+    format: Format,
+    holidays: Option<&BTreeSet<NaiveDate>>,
+    insights: bool,
+    details: &[Detail],
+) -> challenge_error::Result<()> {
+    let date = match birth_date {
+        Some(date) => date,
+        None => read_user_date(&mut Input::from_args(common)?, &mut format.prompts())?,
+    };
+    let days = get_days_difference(&date);
+    let seconds = get_seconds_difference(&date);
+    let today = Local::now().date_naive();
+    let breakdown = Breakdown::between(date, today);
+    let working = holidays.map(|holidays| working_days::between(date, today, holidays));
+    let insights = insights.then(|| Insights::new(date, today));
+    match format {
+        Format::Text => {
+            println!(
+                "{}",
+                l10n::format("c05.days_difference", &[("days", &days)])
+            );
+            println!(
+                "{}",
+                l10n::format("c05.seconds_difference", &[("seconds", &seconds)])
+            );
+            println!(
+                "{}",
+                l10n::format("c05.breakdown", &[("breakdown", &breakdown)])
+            );
+            if let Some(working) = working {
+                println!(
+                    "{}",
+                    l10n::format("c05.working_days_difference", &[("days", &working)])
+                );
+            }
+            if let Some(insights) = &insights {
+                println!("{}", insights);
+            }
+            for detail in details {
+                println!("{}: {}", detail.label(), detail.text(date));
+            }
+        }
+        Format::Json => {
+            let mut result = json!({
+                "days": days,
+                "seconds": seconds,
+                "breakdown": {
+                    "years": breakdown.years,
+                    "months": breakdown.months,
+                    "weeks": breakdown.weeks,
+                    "days": breakdown.days,
+                },
+            });
+            if let Some(working) = working {
+                result["working_days"] = json!(working);
+            }
+            if let Some(insights) = &insights {
+                let milestones: Vec<_> = insights
+                    .milestones
+                    .iter()
+                    .map(|milestone| {
+                        json!({
+                            "name": milestone.name,
+                            "date": milestone.date.to_string(),
+                            "days_until": milestone.days_until,
+                        })
+                    })
+                    .collect();
+                result["insights"] = json!({
+                    "weekday": insights::weekday_name(insights.weekday),
+                    "next_birthday": insights.next_birthday.to_string(),
+                    "days_until_birthday": insights.days_until_birthday,
+                    "turning": insights.turning,
+                    "milestones": milestones,
+                });
+            }
+            for detail in details {
+                result[detail.key()] = detail.json(date);
+            }
+            output::write_json(
+                &mut std::io::stdout(),
+                "c05",
+                json!({ "birth_date": date.to_string() }),
+                result,
+            )?
+        }
+    }
+    Ok(())
+}
+
+/// Prints `date`, asking for it if it was not given, moved forwards by
+/// `span`, or backwards if not `forward`.
+fn run_arithmetic(
+    common: &CommonArgs,
+    date: Option<NaiveDate>,
+    span: Span,
+    forward: bool,
+    format: Format,
+) -> challenge_error::Result<()> {
+    let date = match date {
+        Some(date) => date,
+        None => read_date(
+            &mut Input::from_args(common)?,
+            &mut format.prompts(),
+            "c05.date",
+        )?,
+    };
+    let shifted = span.shift(date, forward)?;
+    match format {
+        Format::Text => println!(
+            "{}",
+            l10n::format(
+                "c05.result_date",
+                &[
+                    ("date", &shifted),
+                    ("weekday", &insights::weekday_text(shifted.weekday())),
+                ],
+            )
+        ),
+        Format::Json => output::write_json(
+            &mut std::io::stdout(),
+            "c05",
+            json!({ "date": date.to_string(), if forward { "add" } else { "sub" }: span.to_string() }),
+            json!({ "date": shifted.to_string() }),
+        )?,
+    }
+    Ok(())
+}
+
+/// Prints the next `count` dates, from today, of an event on `date`,
+/// asking for it if it was not given, that recurs `every` span.
+fn run_schedule(
+    common: &CommonArgs,
+    date: Option<NaiveDate>,
+    every: Span,
+    count: u32,
+    format: Format,
+) -> challenge_error::Result<()> {
+    let date = match date {
+        Some(date) => date,
+        None => read_date(
+            &mut Input::from_args(common)?,
+            &mut format.prompts(),
+            "c05.date",
+        )?,
+    };
+    let today = Local::now().date_naive();
+    let dates = schedule::occurrences(date, every, today, count as usize)?;
+    match format {
+        Format::Text => {
+            let heading = l10n::format(
+                "c05.next_dates",
+                &[("count", &count), ("every", &every), ("date", &date)],
+            );
+            println!("{}", heading);
+            for date in &dates {
+                println!("{} ({})", date, insights::weekday_text(date.weekday()));
+            }
+        }
+        Format::Json => {
+            let dates: Vec<_> = dates
+                .iter()
+                .map(|date| {
+                    json!({
+                        "date": date.to_string(),
+                        "weekday": insights::weekday_name(date.weekday()),
+                    })
+                })
+                .collect();
+            output::write_json(
+                &mut std::io::stdout(),
+                "c05",
+                json!({ "date": date.to_string(), "every": every.to_string(), "count": count }),
+                json!({ "dates": dates }),
+            )?
+        }
+    }
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+
+    let arithmetic = match (args.add, args.sub) {
+        (Some(span), _) => Some((span, true)),
+        (None, Some(span)) => Some((span, false)),
+        (None, None) => None,
+    };
+    if let Some((span, forward)) = arithmetic {
+        if let Err(e) = run_arithmetic(
+            &args.common,
+            args.birth_date,
+            span,
+            forward,
+            args.format.format(),
+        ) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+    if let Some(every) = args.every {
+        if let Err(e) = run_schedule(
+            &args.common,
+            args.birth_date,
+            every,
+            args.count,
+            args.format.format(),
+        ) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let holidays = match (&args.holidays, args.working_days) {
+        (Some(path), _) => match working_days::load_holidays(path) {
+            Ok(holidays) => Some(holidays),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        (None, true) => Some(BTreeSet::new()),
+        (None, false) => None,
+    };
+    if let Err(e) = run(
+        &args.common,
+        args.birth_date,
+        args.format.format(),
+        holidays.as_ref(),
+        args.insights,
+        &args
+            .details
+            .as_deref()
+            .map(details::selected)
+            .unwrap_or_default(),
+    ) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::io::BufReader;
+
+    #[test]
+    fn get_days_difference_computes_past_date() {
+        let past_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let days = get_days_difference(&past_date);
+        assert!(
+            days > 0,
+            "Past date should return positive days, got: {}",
+            days
+        );
+    }
+
+    #[test]
+    fn get_days_difference_computes_future_date() {
+        let today = Local::now().date_naive();
+        let future_date = today + chrono::Duration::days(7);
+        let days = get_days_difference(&future_date);
+        assert_eq!(days, -7, "Future date should return negative days");
+    }
+
+    #[test]
+    fn get_days_difference_handles_today() {
+        let today = Local::now().date_naive();
+        let days = get_days_difference(&today);
+        assert_eq!(days, 0, "Today's date should return zero days");
+    }
+
+    #[test]
+    fn get_days_difference_handles_yesterday() {
+        let today = Local::now().date_naive();
+        let yesterday = today - chrono::Duration::days(1);
+        let days = get_days_difference(&yesterday);
+        assert_eq!(days, 1, "Yesterday should return one day");
+    }
+
+    #[test]
+    fn get_seconds_difference_computes_past_date() {
+        let past_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let seconds = get_seconds_difference(&past_date);
+        assert!(
+            seconds > 0,
+            "Past date should return positive seconds, got: {}",
+            seconds
+        );
+    }
+
+    #[test]
+    fn get_seconds_difference_computes_future_date() {
+        let today = Local::now().date_naive();
+        let future_date = today + chrono::Duration::days(1);
+        let seconds = get_seconds_difference(&future_date);
+        assert!(seconds < 0, "Future date should return negative seconds");
+        assert_eq!(
+            seconds, -86400,
+            "One day in the future should be -86400 seconds"
+        );
+    }
+
+    #[test]
+    fn get_seconds_difference_handles_today() {
+        let today = Local::now().date_naive();
+        let seconds = get_seconds_difference(&today);
+        assert_eq!(seconds, 0, "Today's date should return zero seconds");
+    }
+
+    #[test]
+    fn get_seconds_difference_handles_yesterday() {
+        let today = Local::now().date_naive();
+        let yesterday = today - chrono::Duration::days(1);
+        let seconds = get_seconds_difference(&yesterday);
+        assert_eq!(seconds, 86400, "One day ago should be 86400 seconds");
+    }
+
+    #[test]
+    fn read_user_date_accepts_valid_date() {
+        let input = "2023-12-25\n";
+        let mut reader = BufReader::new(input.as_bytes());
+        let result = read_user_date(&mut reader, &mut std::io::sink());
+
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_date_rejects_invalid_format() {
+        let result = parse_date("12/25/2023");
+
+        assert!(matches!(
+            result,
+            Err(ChallengeError::ParseError { ref input, .. }) if input == "12/25/2023"
+        ));
+    }
+
+    #[test]
+    fn parse_date_rejects_invalid_date() {
+        let result = parse_date("2023-13-45");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_date_rejects_empty_input() {
+        let result = parse_date("");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_date_rejects_non_date_input() {
+        let result = parse_date("not a date");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn read_user_date_asks_again_after_invalid_date() {
+        let mut reader = BufReader::new("2023-13-45\n2023-12-25\n".as_bytes());
+        let mut output = Vec::new();
+        let result = read_user_date(&mut reader, &mut output);
+
+        assert_eq!(
+            result.unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output
+            .starts_with("Please enter your birth date (YYYY-MM-DD):\ninvalid input '2023-13-45'"));
+        assert!(output.ends_with("Please enter your birth date (YYYY-MM-DD):\n"));
+    }
+
+    #[test]
+    fn args_accept_a_birth_date() {
+        let args = Args::try_parse_from(["c05", "2000-02-29"]).unwrap();
+        assert_eq!(args.birth_date, NaiveDate::from_ymd_opt(2000, 2, 29));
+        assert!(Args::try_parse_from(["c05", "2001-02-29"]).is_err());
+        assert_eq!(Args::try_parse_from(["c05"]).unwrap().birth_date, None);
+    }
+
+    #[test]
+    fn args_take_one_span_and_no_difference_options() {
+        let args = Args::try_parse_from(["c05", "2024-01-01", "--add", "90d"]).unwrap();
+        assert_eq!(args.add.unwrap().days, 90);
+        assert!(Args::try_parse_from(["c05", "--add", "1d", "--sub", "1d"]).is_err());
+        assert!(Args::try_parse_from(["c05", "--sub", "6m", "--insights"]).is_err());
+        assert!(Args::try_parse_from(["c05", "--add", "6x"]).is_err());
+    }
+
+    #[test]
+    fn args_take_a_count_only_with_an_interval() {
+        let args = Args::try_parse_from(["c05", "--every", "14d"]).unwrap();
+        assert_eq!(args.count, 5);
+        let args = Args::try_parse_from(["c05", "--every", "1m", "--count", "12"]).unwrap();
+        assert_eq!(args.count, 12);
+        assert!(Args::try_parse_from(["c05", "--count", "3"]).is_err());
+        assert!(Args::try_parse_from(["c05", "--every", "1d", "--count", "0"]).is_err());
+        assert!(Args::try_parse_from(["c05", "--every", "1d", "--add", "1d"]).is_err());
+    }
+
+    #[test]
+    fn args_take_all_details_or_those_named() {
+        let details = |args: &[&str]| Args::try_parse_from(args).unwrap().details;
+        assert_eq!(details(&["c05"]), None);
+        assert_eq!(details(&["c05", "--details", "2024-01-01"]), Some(vec![]));
+        assert_eq!(
+            details(&["c05", "--details=quarter,iso-week"]),
+            Some(vec![Detail::Quarter, Detail::IsoWeek])
+        );
+        assert!(Args::try_parse_from(["c05", "--details=week"]).is_err());
+        assert!(Args::try_parse_from(["c05", "--details", "--add", "1d"]).is_err());
+    }
+
+    #[test]
+    fn read_date_asks_with_the_given_message() {
+        let mut output = Vec::new();
+        let date = read_date(&mut "2024-02-29\n".as_bytes(), &mut output, "c05.date").unwrap();
+
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Please enter a date (YYYY-MM-DD):\n"
+        );
+    }
+
+    #[test]
+    fn args_take_holidays_only_with_working_days() {
+        assert!(Args::try_parse_from(["c05", "--holidays", "holidays.txt"]).is_err());
+        let args =
+            Args::try_parse_from(["c05", "--working-days", "--holidays", "holidays.txt"]).unwrap();
+        assert!(args.working_days);
+        assert_eq!(args.holidays, Some(PathBuf::from("holidays.txt")));
+    }
+}
@@ -0,0 +1,81 @@
+//! Recurring events: the next dates of something that happens at a regular
+//! interval from a start date, such as a payday every 14 days or an
+//! anniversary every year.
+//!
+//! Each occurrence is counted from the start date rather than from the one
+//! before it, so a monthly event starting on 31 January falls on the last
+//! day of February and then on 31 March again.
+use super::arithmetic::Span;
+use challenge_error::ChallengeError;
+use chrono::NaiveDate;
+
+/// The first `count` occurrences, on or after `from`, of an event on
+/// `start` and every `every` after it.
+pub fn occurrences(
+    start: NaiveDate,
+    every: Span,
+    from: NaiveDate,
+    count: usize,
+) -> challenge_error::Result<Vec<NaiveDate>> {
+    if every.is_zero() {
+        return Err(ChallengeError::validation("interval", "must not be zero"));
+    }
+    let mut dates = Vec::with_capacity(count);
+    for times in 0.. {
+        if dates.len() == count {
+            break;
+        }
+        let date = every.times(times).shift(start, true)?;
+        if date >= from {
+            dates.push(date);
+        }
+    }
+    Ok(dates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arithmetic::parse_span;
+    use crate::test_util::date;
+
+    #[test]
+    fn occurrences_start_from_the_given_day() {
+        let every = parse_span("14d").unwrap();
+
+        assert_eq!(
+            occurrences(date(2024, 1, 5), every, date(2024, 2, 2), 3).unwrap(),
+            [date(2024, 2, 2), date(2024, 2, 16), date(2024, 3, 1)]
+        );
+        // A start date still to come is the first occurrence.
+        assert_eq!(
+            occurrences(date(2024, 1, 5), every, date(2023, 1, 1), 2).unwrap(),
+            [date(2024, 1, 5), date(2024, 1, 19)]
+        );
+    }
+
+    #[test]
+    fn occurrences_keep_the_day_of_the_month() {
+        let every = parse_span("1m").unwrap();
+
+        assert_eq!(
+            occurrences(date(2024, 1, 31), every, date(2024, 1, 1), 4).unwrap(),
+            [
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                date(2024, 4, 30)
+            ]
+        );
+    }
+
+    #[test]
+    fn occurrences_need_an_interval() {
+        assert_eq!(
+            occurrences(date(2024, 1, 1), Span::default(), date(2024, 1, 1), 1)
+                .unwrap_err()
+                .to_string(),
+            "interval must not be zero"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Helpers shared by the tests of the other modules.
+use chrono::NaiveDate;
+
+/// The date `year`-`month`-`day`, which must exist.
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
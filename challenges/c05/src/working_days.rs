@@ -0,0 +1,108 @@
+//! Working days: the days from Monday to Friday between two dates, leaving
+//! out holidays.
+//!
+//! Holidays are read from a file with one `YYYY-MM-DD` date per line. Blank
+//! lines and lines starting with `#` are skipped, so a holiday can have its
+//! name on the line above it. A holiday on a weekend changes nothing.
+use super::parse_date;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+use std::io::BufRead;
+use std::path::Path;
+
+/// Whether `date` is a Monday to Friday that is not in `holidays`.
+fn is_working_day(date: NaiveDate, holidays: &BTreeSet<NaiveDate>) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(&date)
+}
+
+/// The working days after `from` up to and including `to`, negative when
+/// `to` is before `from`, the same way the difference in days is counted.
+pub fn between(from: NaiveDate, to: NaiveDate, holidays: &BTreeSet<NaiveDate>) -> i64 {
+    let (start, end, sign) = if from <= to {
+        (from, to, 1)
+    } else {
+        (to, from, -1)
+    };
+    let count = start
+        .iter_days()
+        .skip(1)
+        .take_while(|date| *date <= end)
+        .filter(|date| is_working_day(*date, holidays))
+        .count() as i64;
+    sign * count
+}
+
+/// Reads the holidays listed in `reader`.
+fn read_holidays<R: BufRead>(reader: R) -> Result<BTreeSet<NaiveDate>, String> {
+    let mut holidays = BTreeSet::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let date = parse_date(line).map_err(|e| format!("line {}: {e}", index + 1))?;
+        holidays.insert(date);
+    }
+    Ok(holidays)
+}
+
+/// Reads the holidays listed in the file at `path`.
+pub fn load_holidays(path: &Path) -> Result<BTreeSet<NaiveDate>, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    read_holidays(std::io::BufReader::new(file)).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    #[test]
+    fn between_counts_monday_to_friday() {
+        let none = BTreeSet::new();
+        // Friday 4 October to Friday 11 October 2024.
+        assert_eq!(between(date(2024, 10, 4), date(2024, 10, 11), &none), 5);
+        // Saturday to Sunday.
+        assert_eq!(between(date(2024, 10, 5), date(2024, 10, 6), &none), 0);
+        assert_eq!(between(date(2024, 10, 7), date(2024, 10, 7), &none), 0);
+        assert_eq!(between(date(2024, 10, 11), date(2024, 10, 4), &none), -5);
+        assert_eq!(between(date(2024, 1, 1), date(2025, 1, 1), &none), 262);
+    }
+
+    #[test]
+    fn between_leaves_out_holidays() {
+        let holidays = BTreeSet::from([
+            date(2024, 12, 25),
+            date(2024, 12, 26),
+            // A Saturday.
+            date(2024, 12, 28),
+        ]);
+        assert_eq!(
+            between(date(2024, 12, 20), date(2024, 12, 31), &holidays),
+            5
+        );
+    }
+
+    #[test]
+    fn read_holidays_skips_comments_and_blank_lines() {
+        let input = "# Christmas\n2024-12-25\n\n  2024-12-26  \n2024-12-25\n";
+
+        let holidays = read_holidays(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            holidays,
+            BTreeSet::from([date(2024, 12, 25), date(2024, 12, 26)])
+        );
+    }
+
+    #[test]
+    fn read_holidays_reports_the_bad_line() {
+        let error = read_holidays("2024-12-25\n25/12/2024\n".as_bytes()).unwrap_err();
+
+        assert!(
+            error.starts_with("line 2: invalid input '25/12/2024'"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Distractions shown while the player waits, to make the time harder to
+//! judge.
+//!
+//! A distraction runs on its own thread from the start of a round until the
+//! player presses Enter again, printing a line now and then: a random remark,
+//! a joke from c01's collection, or a counter that ticks at an uneven pace so
+//! that counting along with it leads the player astray.
+use challenge_common::l10n;
+use rand::Rng;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The keys of the remarks made by the `text` distraction.
+const REMARKS: &[&str] = &[
+    "c06.oven",
+    "c06.sum",
+    "c06.bird",
+    "c06.blink",
+    "c06.capital",
+    "c06.warm",
+    "c06.elephants",
+    "c06.almost",
+];
+
+/// What to show while the player waits.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distraction {
+    /// Random remarks
+    Text,
+    /// Jokes from c01's collection
+    Jokes,
+    /// A counter that does not count seconds
+    Counter,
+}
+
+/// The jokes c01 tells, each as its question followed by its answer.
+fn jokes() -> Result<Vec<String>, String> {
+    Ok(c01::bundled_jokes()?
+        .into_iter()
+        .map(|joke| format!("{} {}", joke.question, joke.answer))
+        .collect())
+}
+
+impl Distraction {
+    /// The pause before the `tick`th line, counting from 1, and the line,
+    /// both picked with `rng`.
+    fn next<R: Rng + ?Sized>(self, tick: u32, jokes: &[String], rng: &mut R) -> (Duration, String) {
+        match self {
+            Distraction::Text => (
+                Duration::from_millis(rng.random_range(1_500..=3_000)),
+                l10n::text(REMARKS[rng.random_range(0..REMARKS.len())]).to_string(),
+            ),
+            Distraction::Jokes => (
+                Duration::from_millis(rng.random_range(2_500..=4_000)),
+                jokes[rng.random_range(0..jokes.len())].clone(),
+            ),
+            Distraction::Counter => (
+                Duration::from_millis(rng.random_range(600..=1_400)),
+                format!("{tick}..."),
+            ),
+        }
+    }
+
+    /// Starts showing the distraction until [`Running::stop`] is called,
+    /// picking its lines with `rng`.
+    pub fn start<R: Rng + Send + 'static>(self, mut rng: R) -> Running {
+        let (stop, stopped) = mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            // Without jokes to tell, remarks are the next best distraction.
+            let (distraction, jokes) = match self {
+                Distraction::Jokes => match jokes() {
+                    Ok(jokes) => (self, jokes),
+                    Err(e) => {
+                        tracing::warn!(error = %e, "unable to read the jokes");
+                        (Distraction::Text, Vec::new())
+                    }
+                },
+                _ => (self, Vec::new()),
+            };
+            for tick in 1.. {
+                let (pause, line) = distraction.next(tick, &jokes, &mut rng);
+                match stopped.recv_timeout(pause) {
+                    Err(RecvTimeoutError::Timeout) => println!("{line}"),
+                    _ => break,
+                }
+            }
+        });
+        Running { stop, handle }
+    }
+}
+
+/// A distraction being shown.
+pub struct Running {
+    stop: Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl Running {
+    /// Stops the distraction, without waiting out the pause before its next
+    /// line.
+    pub fn stop(self) {
+        // The thread only ends once told to, so it is still listening.
+        let _ = self.stop.send(());
+        let _ = self.handle.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::time::Instant;
+
+    #[test]
+    fn jokes_come_from_c01() {
+        let jokes = jokes().unwrap();
+        assert_eq!(jokes.len(), 25);
+        assert!(jokes.contains(&"Why is six afraid of seven? Because 7 ATE 9".to_string()));
+    }
+
+    #[test]
+    fn next_counts_at_an_uneven_pace() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for tick in 1..=20 {
+            let (pause, line) = Distraction::Counter.next(tick, &[], &mut rng);
+            assert_eq!(line, format!("{tick}..."));
+            assert!((600..=1_400).contains(&pause.as_millis()));
+        }
+        let (_, remark) = Distraction::Text.next(1, &[], &mut rng);
+        assert!(REMARKS.iter().any(|key| l10n::text(key) == remark));
+    }
+
+    #[test]
+    fn stop_does_not_wait_for_the_next_line() {
+        let running = Distraction::Jokes.start(StdRng::seed_from_u64(42));
+        let start = Instant::now();
+        running.stop();
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
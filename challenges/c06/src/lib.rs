@@ -0,0 +1,295 @@
+//! A simple timing game where players try to estimate a 10-second interval.
+//!
+//! The game prompts users to press Enter twice: once to start the timer and
+//! once when they think 10 seconds have elapsed. It then tells them how long
+//! they waited, to the millisecond, and how accurate that was as a
+//! percentage. The smallest error for each target is remembered
+//! between runs.
+//!
+//! With `--rounds` the game is played several times in a row, and then the
+//! errors of the rounds are summed up, as described in the [`stats`] module.
+//! With `--random-targets` every round has a new target, picked at random and
+//! announced before the round starts, and the rounds are scored by their
+//! accuracy together. With `--distract` something is shown while the player waits, as described
+//! in the [`distraction`] module.
+use challenge_common::cli::{CommonArgs, NumRange};
+use challenge_common::input::{self, Input};
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::rng::RngArgs;
+use challenge_storage::Store;
+use clap::Parser;
+use distraction::Distraction;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use stats::Summary;
+use std::cmp::Ordering;
+use std::ffi::OsString;
+use std::io::BufRead;
+use std::process::ExitCode;
+
+mod distraction;
+mod stats;
+
+/// Test how well you can judge when a number of seconds has elapsed.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number of seconds to estimate
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    target: u64,
+    /// Pick a new target for each round at random, from 3 to 15 seconds or
+    /// in the range given, e.g. --random-targets=5..30
+    #[arg(
+        long,
+        value_name = "MIN..MAX",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "3..15",
+        value_parser = parse_target_range,
+        conflicts_with = "target"
+    )]
+    random_targets: Option<NumRange<u64>>,
+    /// Number of rounds to play before summing up how accurate you were; 1,
+    /// or 5 with --random-targets
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    rounds: Option<u32>,
+    /// Show something while you wait, to make it harder
+    #[arg(short, long, value_enum, value_name = "KIND")]
+    distract: Option<Distraction>,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+}
+
+fn parse_target_range(s: &str) -> Result<NumRange<u64>, String> {
+    let range: NumRange<u64> = s.parse()?;
+    if range.min == 0 {
+        return Err("the shortest target must be at least 1 second".to_string());
+    }
+    Ok(range)
+}
+
+/// The target of each round.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Targets {
+    /// The same number of seconds every round.
+    Fixed(u64),
+    /// A number of seconds in the range, picked at random for each round.
+    Random(NumRange<u64>),
+}
+
+impl Targets {
+    /// The target of the next round, picked with `rng` if random.
+    fn next<R: Rng + ?Sized>(self, rng: &mut R) -> u64 {
+        match self {
+            Targets::Fixed(target) => target,
+            Targets::Random(range) => rng.random_range(range.min..=range.max),
+        }
+    }
+}
+
+/// Records the error of this attempt and returns the previous best error.
+fn record_error(target: u64, error: f64) -> challenge_storage::Result<Option<f64>> {
+    let mut store = Store::open("c06")?;
+    let previous = store.record_min(&format!("best_error_{target}s"), error)?;
+    store.save()?;
+    Ok(previous)
+}
+
+/// What to tell the player who waited `elapsed_ms` milliseconds for `target`
+/// seconds, e.g. `Not long enough! You waited 9.874 seconds, 0.126 seconds
+/// short.`
+fn feedback(elapsed_ms: u64, target: u64) -> String {
+    let target_ms = target * 1000;
+    let seconds = |ms: u64| format!("{}.{:03}", ms / 1000, ms % 1000);
+    match elapsed_ms.cmp(&target_ms) {
+        Ordering::Greater => l10n::format(
+            "c06.too_long",
+            &[
+                ("waited", &seconds(elapsed_ms)),
+                ("off", &seconds(elapsed_ms - target_ms)),
+            ],
+        ),
+        Ordering::Less => l10n::format(
+            "c06.too_short",
+            &[
+                ("waited", &seconds(elapsed_ms)),
+                ("off", &seconds(target_ms - elapsed_ms)),
+            ],
+        ),
+        Ordering::Equal => l10n::format("c06.spot_on", &[("waited", &seconds(elapsed_ms))]),
+    }
+}
+
+/// Plays one round and returns how many seconds after `target` the player
+/// stopped, negative if they stopped before it. `distraction` is shown
+/// while the player waits, if given, with a generator seeded from `rng`.
+fn play_round<R: BufRead, G: Rng>(
+    reader: &mut R,
+    target: u64,
+    distraction: Option<Distraction>,
+    rng: &mut G,
+) -> std::io::Result<f64> {
+    println!("{}", l10n::text("prompt.start"));
+    println!("{}", l10n::format("c06.stop", &[("seconds", &target)]));
+
+    input::read_line(reader)?;
+    println!("{}", l10n::text("c06.start"));
+    let start_time = std::time::Instant::now();
+
+    let running = distraction.map(|distraction| distraction.start(StdRng::from_rng(rng)));
+    let stopped = input::read_line(reader);
+    if let Some(running) = running {
+        running.stop();
+    }
+    stopped?;
+    println!("{}", l10n::text("c06.stopped"));
+    let elapsed_time = start_time.elapsed();
+    tracing::debug!(?elapsed_time, target, "timer stopped");
+
+    let elapsed_ms = u64::try_from(elapsed_time.as_millis()).unwrap_or(u64::MAX);
+    println!("{}", feedback(elapsed_ms, target));
+    let signed_error = (elapsed_ms as f64 - (target * 1000) as f64) / 1000.0;
+    let accuracy = format!("{:.1}", stats::accuracy(signed_error, target));
+    println!(
+        "{}",
+        l10n::format("c06.accuracy", &[("accuracy", &accuracy)])
+    );
+
+    let error = signed_error.abs();
+    let off = |key, seconds: f64| l10n::format(key, &[("off", &format!("{:.3}", seconds))]);
+    match record_error(target, error) {
+        Ok(Some(best)) if error < best => println!("{}", off("c06.new_best", error)),
+        Ok(Some(best)) => println!("{}", off("c06.best", best)),
+        Ok(None) => println!("{}", off("c06.off", error)),
+        Err(e) => eprintln!("Warning: unable to save your score: {}", e),
+    }
+    Ok(signed_error)
+}
+
+fn run<R: BufRead, G: Rng>(
+    reader: &mut R,
+    targets: Targets,
+    rounds: u32,
+    distraction: Option<Distraction>,
+    rng: &mut G,
+) -> std::io::Result<()> {
+    match targets {
+        Targets::Fixed(target) => {
+            println!("{}", l10n::format("c06.intro", &[("seconds", &target)]))
+        }
+        Targets::Random(range) => println!(
+            "{}",
+            l10n::format(
+                "c06.intro_random",
+                &[("min", &range.min), ("max", &range.max)]
+            )
+        ),
+    }
+    let mut played = Vec::new();
+    for round in 1..=rounds {
+        let target = targets.next(rng);
+        match targets {
+            Targets::Random(_) => println!(
+                "{}",
+                l10n::format(
+                    "c06.round_target",
+                    &[("round", &round), ("rounds", &rounds), ("seconds", &target)]
+                )
+            ),
+            Targets::Fixed(_) if rounds > 1 => println!(
+                "{}",
+                l10n::format("c06.round", &[("round", &round), ("rounds", &rounds)])
+            ),
+            Targets::Fixed(_) => {}
+        }
+        let error = play_round(reader, target, distraction, rng)?;
+        played.push((target, error));
+        if rounds > 1 && round < rounds {
+            let accuracy = format!("{:.1}", stats::mean_accuracy(&played));
+            println!(
+                "{}",
+                l10n::format("c06.accuracy_so_far", &[("accuracy", &accuracy)])
+            );
+        }
+    }
+    if rounds > 1 {
+        if let Some(summary) = Summary::new(&played) {
+            println!("{}", summary);
+        }
+    }
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let (targets, rounds) = match args.random_targets {
+        Some(range) => (Targets::Random(range), args.rounds.unwrap_or(5)),
+        None => (Targets::Fixed(args.target), args.rounds.unwrap_or(1)),
+    };
+    if let Err(e) = run(&mut input, targets, rounds, args.distract, &mut rng) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_targets_stay_in_range() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let targets = Targets::Random(NumRange { min: 3, max: 5 });
+        for _ in 0..50 {
+            assert!((3..=5).contains(&targets.next(&mut rng)));
+        }
+        assert_eq!(Targets::Fixed(10).next(&mut rng), 10);
+    }
+
+    #[test]
+    fn args_take_an_optional_target_range() {
+        let args = Args::try_parse_from(["c06", "--random-targets"]).unwrap();
+        assert_eq!(args.random_targets, Some(NumRange { min: 3, max: 15 }));
+        let args = Args::try_parse_from(["c06", "--random-targets=5..30", "-r", "3"]).unwrap();
+        assert_eq!(args.random_targets, Some(NumRange { min: 5, max: 30 }));
+        assert_eq!(args.rounds, Some(3));
+        assert!(Args::try_parse_from(["c06", "--random-targets=0..5"]).is_err());
+        assert!(Args::try_parse_from(["c06", "--random-targets", "-t", "5"]).is_err());
+    }
+
+    #[test]
+    fn feedback_reports_milliseconds() {
+        assert_eq!(
+            feedback(10_254, 10),
+            "Too long! You waited 10.254 seconds, 0.254 seconds over."
+        );
+        assert_eq!(
+            feedback(9_874, 10),
+            "Not long enough! You waited 9.874 seconds, 0.126 seconds short."
+        );
+        assert_eq!(
+            feedback(3_000, 3),
+            "Spot on! You waited exactly 3.000 seconds."
+        );
+    }
+}
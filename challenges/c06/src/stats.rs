@@ -0,0 +1,135 @@
+//! Accuracy statistics over several rounds.
+//!
+//! Each round's error is how many seconds the player stopped after the
+//! target, negative when they stopped before it, to the millisecond. Its
+//! accuracy is the error as a share of the target, so rounds with different
+//! targets can be compared.
+use challenge_common::l10n;
+use std::fmt;
+
+/// How accurate the player was over a number of rounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub rounds: usize,
+    /// The error closest to zero.
+    pub best: f64,
+    /// The error furthest from zero.
+    pub worst: f64,
+    /// The mean of how far off each round was, early or late.
+    pub mean_absolute: f64,
+    /// The standard deviation of the errors, which is small when the player
+    /// is consistent even if they are consistently early or late.
+    pub std_dev: f64,
+    /// The mean of the accuracy of each round, as a percentage.
+    pub accuracy: f64,
+}
+
+impl Summary {
+    /// Sums up the target and error of each round played, or `None` if there
+    /// were none.
+    pub fn new(rounds: &[(u64, f64)]) -> Option<Self> {
+        let errors: Vec<f64> = rounds.iter().map(|&(_, error)| error).collect();
+        let by_size = |a: &&f64, b: &&f64| a.abs().total_cmp(&b.abs());
+        let best = *errors.iter().min_by(by_size)?;
+        let worst = *errors.iter().max_by(by_size)?;
+        let n = errors.len() as f64;
+        let mean = errors.iter().sum::<f64>() / n;
+        let variance = errors.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / n;
+        Some(Summary {
+            rounds: errors.len(),
+            best,
+            worst,
+            mean_absolute: errors.iter().map(|e| e.abs()).sum::<f64>() / n,
+            std_dev: variance.sqrt(),
+            accuracy: mean_accuracy(rounds),
+        })
+    }
+}
+
+/// How close an error is to the target, as a percentage: 100 for spot on,
+/// falling to 0 for an error as long as the target itself or longer.
+pub fn accuracy(error: f64, target: u64) -> f64 {
+    (100.0 * (1.0 - error.abs() / target as f64)).max(0.0)
+}
+
+/// The mean accuracy of the rounds with these targets and errors, or 0 if
+/// there were none.
+pub fn mean_accuracy(rounds: &[(u64, f64)]) -> f64 {
+    if rounds.is_empty() {
+        return 0.0;
+    }
+    let total: f64 = rounds
+        .iter()
+        .map(|&(target, error)| accuracy(error, target))
+        .sum();
+    total / rounds.len() as f64
+}
+
+/// An error with its sign, e.g. `+0.250` for late or `-1.500` for early.
+fn signed(error: f64) -> String {
+    format!("{error:+.3}")
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line = |key, value: String| l10n::format(key, &[("value", &value)]);
+        writeln!(f, "{}", line("c06.summary", self.rounds.to_string()))?;
+        writeln!(f, "  {}", line("c06.summary_best", signed(self.best)))?;
+        writeln!(f, "  {}", line("c06.summary_worst", signed(self.worst)))?;
+        let mean = format!("{:.3}", self.mean_absolute);
+        writeln!(f, "  {}", line("c06.summary_average", mean))?;
+        let std_dev = format!("{:.3}", self.std_dev);
+        writeln!(f, "  {}", line("c06.summary_std_dev", std_dev))?;
+        let accuracy = format!("{:.1}", self.accuracy);
+        write!(f, "  {}", line("c06.summary_accuracy", accuracy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sums_up_the_errors() {
+        let summary = Summary::new(&[(10, 0.5), (10, -0.25), (10, -2.0), (10, 1.75)]).unwrap();
+
+        assert_eq!(summary.rounds, 4);
+        assert_eq!(summary.best, -0.25);
+        assert_eq!(summary.worst, -2.0);
+        assert_eq!(summary.mean_absolute, 1.125);
+        // The mean is 0, so the variance is (0.25 + 0.0625 + 4 + 3.0625) / 4.
+        assert_eq!(summary.std_dev, 1.84375_f64.sqrt());
+        assert_eq!(summary.accuracy, 88.75);
+        assert_eq!(Summary::new(&[]), None);
+    }
+
+    #[test]
+    fn display_lists_each_statistic() {
+        let summary = Summary::new(&[(4, 1.0), (12, 3.0)]).unwrap();
+
+        assert_eq!(
+            summary.to_string(),
+            "Summary of 2 rounds:\n  \
+             Best: +1.000 seconds\n  \
+             Worst: +3.000 seconds\n  \
+             Average error: 2.000 seconds\n  \
+             Standard deviation: 1.000 seconds\n  \
+             Overall accuracy: 75.0%"
+        );
+    }
+
+    #[test]
+    fn mean_accuracy_weighs_each_round_by_its_target() {
+        assert_eq!(mean_accuracy(&[(3, -1.5), (15, 1.5)]), 70.0);
+        assert_eq!(mean_accuracy(&[]), 0.0);
+    }
+
+    #[test]
+    fn accuracy_falls_with_the_error() {
+        assert_eq!(accuracy(0.0, 10), 100.0);
+        assert_eq!(accuracy(-0.5, 10), 95.0);
+        assert_eq!(accuracy(2.5, 10), 75.0);
+        assert_eq!(accuracy(-10.0, 10), 0.0);
+        assert_eq!(accuracy(25.0, 10), 0.0);
+    }
+}
@@ -0,0 +1,217 @@
+//! Where a failed attempt went wrong, character by character.
+//!
+//! The typed text is lined up with the expected one so that a missed or an
+//! extra character only marks that character, not everything after it. The
+//! two are shown one above the other with a `^` under each mistake, and the
+//! mistakes are also colored when colors are on:
+//!
+//! ```text
+//! Expected:  Talk is cheap.
+//! You typed: Tlak is che p.
+//!             ^^        ^
+//! ```
+use challenge_common::{l10n, theme};
+use std::fmt;
+
+/// How one character of the typed text compares with the expected text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Typed as expected.
+    Same(char),
+    /// Typed `typed` where `expected` was expected.
+    Wrong { expected: char, typed: char },
+    /// Expected but not typed.
+    Missing(char),
+    /// Typed but not expected.
+    Extra(char),
+}
+
+/// The mistakes of an attempt at a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    pub steps: Vec<Step>,
+}
+
+impl Diff {
+    /// Lines `typed` up with `expected` with as few mistakes as possible.
+    pub fn new(expected: &str, typed: &str) -> Self {
+        let expected: Vec<char> = expected.chars().collect();
+        let typed: Vec<char> = typed.chars().collect();
+
+        // costs[i][j] is the fewest mistakes in typing the first i expected
+        // characters as the first j typed ones.
+        let mut costs = vec![vec![0; typed.len() + 1]; expected.len() + 1];
+        for (i, row) in costs.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        for (j, cost) in costs[0].iter_mut().enumerate() {
+            *cost = j;
+        }
+        for i in 1..=expected.len() {
+            for j in 1..=typed.len() {
+                let change = usize::from(expected[i - 1] != typed[j - 1]);
+                costs[i][j] = (costs[i - 1][j - 1] + change)
+                    .min(costs[i - 1][j] + 1)
+                    .min(costs[i][j - 1] + 1);
+            }
+        }
+
+        // Walk back from the end, preferring a same or wrong character to a
+        // missing or extra one.
+        let mut steps = Vec::new();
+        let (mut i, mut j) = (expected.len(), typed.len());
+        while i > 0 || j > 0 {
+            if i > 0 && j > 0 {
+                let change = usize::from(expected[i - 1] != typed[j - 1]);
+                if costs[i][j] == costs[i - 1][j - 1] + change {
+                    steps.push(if change == 0 {
+                        Step::Same(typed[j - 1])
+                    } else {
+                        Step::Wrong {
+                            expected: expected[i - 1],
+                            typed: typed[j - 1],
+                        }
+                    });
+                    i -= 1;
+                    j -= 1;
+                    continue;
+                }
+            }
+            if i > 0 && costs[i][j] == costs[i - 1][j] + 1 {
+                steps.push(Step::Missing(expected[i - 1]));
+                i -= 1;
+            } else {
+                steps.push(Step::Extra(typed[j - 1]));
+                j -= 1;
+            }
+        }
+        steps.reverse();
+        Diff { steps }
+    }
+
+    /// How many characters were wrong, missing or extra.
+    pub fn mistakes(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| !matches!(step, Step::Same(_)))
+            .count()
+    }
+
+    /// The expected text, the typed text and the markers under the mistakes,
+    /// as three lines. With `color` the mistakes are colored too.
+    pub fn render(&self, color: bool) -> String {
+        let mut expected = String::new();
+        let mut typed = String::new();
+        let mut markers = String::new();
+        for step in &self.steps {
+            let (above, below) = match *step {
+                Step::Same(c) => {
+                    expected.push(c);
+                    typed.push(c);
+                    markers.push(' ');
+                    continue;
+                }
+                Step::Wrong { expected, typed } => (expected, typed),
+                Step::Missing(c) => (c, ' '),
+                Step::Extra(c) => (' ', c),
+            };
+            if color {
+                expected.push_str(&theme::hint(&above.to_string()).to_string());
+                typed.push_str(&theme::lose(&below.to_string()).to_string());
+            } else {
+                expected.push(above);
+                typed.push(below);
+            }
+            markers.push('^');
+        }
+        let labels = [l10n::text("c07.expected"), l10n::text("c07.you_typed")];
+        let width = labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        format!(
+            "{:<width$}{}\n{:<width$}{}\n{:<width$}{}",
+            labels[0],
+            expected,
+            labels[1],
+            typed,
+            "",
+            markers.trim_end()
+        )
+    }
+}
+
+/// The diff as three lines, colored when colors are on.
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(theme::is_enabled()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_marks_only_the_mistakes() {
+        let diff = Diff::new("cat", "cut");
+        assert_eq!(
+            diff.steps,
+            [
+                Step::Same('c'),
+                Step::Wrong {
+                    expected: 'a',
+                    typed: 'u'
+                },
+                Step::Same('t'),
+            ]
+        );
+        assert_eq!(diff.mistakes(), 1);
+        assert_eq!(Diff::new("same", "same").mistakes(), 0);
+    }
+
+    #[test]
+    fn new_keeps_the_rest_in_line_after_a_missed_character() {
+        let diff = Diff::new("quick", "qick");
+        assert_eq!(diff.steps[1], Step::Missing('u'));
+        assert_eq!(diff.mistakes(), 1);
+
+        let diff = Diff::new("quick", "quicks");
+        assert_eq!(diff.steps[5], Step::Extra('s'));
+        assert_eq!(diff.mistakes(), 1);
+    }
+
+    #[test]
+    fn new_handles_empty_texts() {
+        assert_eq!(
+            Diff::new("ab", "").steps,
+            [Step::Missing('a'), Step::Missing('b')]
+        );
+        assert_eq!(Diff::new("", "a").steps, [Step::Extra('a')]);
+    }
+
+    #[test]
+    fn render_lines_the_texts_up() {
+        assert_eq!(
+            Diff::new("Talk is cheap.", "Tlak is chep.").render(false),
+            "Expected:  Talk is cheap.\n\
+             You typed: Tlak is che p.\n            \
+             ^^        ^"
+        );
+        assert_eq!(
+            Diff::new("abc", "abcd").render(false),
+            "Expected:  abc \nYou typed: abcd\n              ^"
+        );
+    }
+
+    #[test]
+    fn render_colors_the_mistakes() {
+        colored::control::set_override(true);
+        let rendered = Diff::new("ab", "xb").render(true);
+        colored::control::unset_override();
+        assert!(rendered.contains(&theme::lose("x").to_string()));
+        assert!(rendered.ends_with("\n           ^"));
+    }
+}
@@ -0,0 +1,102 @@
+//! Drills for focused practice: random sequences of characters from a row
+//! of the keyboard or from a set of the player's own.
+//!
+//! A drill is typed in groups of five characters with a space between them,
+//! and the groups are spaced like words, so a drill of 12 characters from
+//! the home row might be `fjdka ;lsgh jf`. Every drill is new, so unlike
+//! other texts no best is kept for them.
+use rand::Rng;
+
+/// Characters in each group of a drill.
+const GROUP: usize = 5;
+
+/// A row of the keyboard to drill.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Row {
+    /// asdfghjkl;
+    Home,
+    /// qwertyuiop
+    Top,
+    /// zxcvbnm,./
+    Bottom,
+    /// 1234567890
+    Numbers,
+}
+
+impl Row {
+    /// The characters of the row.
+    pub fn chars(self) -> Charset {
+        let chars = match self {
+            Row::Home => "asdfghjkl;",
+            Row::Top => "qwertyuiop",
+            Row::Bottom => "zxcvbnm,./",
+            Row::Numbers => "1234567890",
+        };
+        Charset(chars.chars().collect())
+    }
+}
+
+/// The characters a drill is made of, each once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Charset(Vec<char>);
+
+/// Reads the characters to drill, leaving out spaces and repeats.
+pub fn parse_charset(s: &str) -> Result<Charset, String> {
+    let mut chars: Vec<char> = Vec::new();
+    for c in s.chars().filter(|c| !c.is_whitespace()) {
+        if !chars.contains(&c) {
+            chars.push(c);
+        }
+    }
+    if chars.is_empty() {
+        return Err("a drill needs at least one character that is not a space".to_string());
+    }
+    Ok(Charset(chars))
+}
+
+impl Charset {
+    /// A drill of `length` characters picked with `rng`, in groups.
+    pub fn drill<R: Rng + ?Sized>(&self, length: usize, rng: &mut R) -> String {
+        let mut drill = String::new();
+        for i in 0..length {
+            if i > 0 && i.is_multiple_of(GROUP) {
+                drill.push(' ');
+            }
+            drill.push(self.0[rng.random_range(0..self.0.len())]);
+        }
+        drill
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn parse_charset_leaves_out_spaces_and_repeats() {
+        assert_eq!(
+            parse_charset("a b\taa;").unwrap(),
+            Charset(vec!['a', 'b', ';'])
+        );
+        assert_eq!(
+            parse_charset("  ").unwrap_err(),
+            "a drill needs at least one character that is not a space"
+        );
+    }
+
+    #[test]
+    fn drill_groups_characters_from_the_set() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let drill = Row::Home.chars().drill(12, &mut rng);
+        assert_eq!(drill.len(), 14);
+        let groups: Vec<&str> = drill.split(' ').collect();
+        assert_eq!(
+            groups.iter().map(|group| group.len()).collect::<Vec<_>>(),
+            [5, 5, 2]
+        );
+        assert!(drill.chars().all(|c| c == ' ' || "asdfghjkl;".contains(c)));
+        assert_eq!(parse_charset("x").unwrap().drill(5, &mut rng), "xxxxx");
+    }
+}
@@ -0,0 +1,79 @@
+//! The ghost of the best live attempt at a text, raced with `--ghost`.
+//!
+//! A live attempt keeps the time of each correct key from the first key
+//! pressed, and the best attempt at each text keeps those times with its
+//! record. Racing it, a bar above the typed text shows how far the ghost had
+//! got at the same moment, one `=` for each character it had typed, so the
+//! bar lines up with the player's own characters.
+use challenge_common::l10n;
+use std::time::Duration;
+
+/// The best live attempt at a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghost {
+    /// When each character was typed, from the first key pressed.
+    keys: Vec<Duration>,
+}
+
+impl Ghost {
+    /// The ghost of an attempt whose keys were typed at these milliseconds,
+    /// or `None` if there are none, as for an attempt not typed live.
+    pub fn new(keys_ms: &[u64]) -> Option<Self> {
+        if keys_ms.is_empty() {
+            return None;
+        }
+        Some(Ghost {
+            keys: keys_ms.iter().copied().map(Duration::from_millis).collect(),
+        })
+    }
+
+    /// How many characters the ghost had typed `elapsed` into its attempt.
+    pub fn position(&self, elapsed: Duration) -> usize {
+        self.keys.partition_point(|&key| key <= elapsed)
+    }
+
+    /// The bar showing where the ghost was `elapsed` into its attempt at a
+    /// text of `len` characters, e.g. `=====------- ghost`.
+    pub fn bar(&self, elapsed: Duration, len: usize) -> String {
+        let position = self.position(elapsed).min(len);
+        format!(
+            "{}{} {}",
+            "=".repeat(position),
+            "-".repeat(len - position),
+            l10n::text("c07.ghost")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn new_needs_keys() {
+        assert_eq!(Ghost::new(&[]), None);
+        assert!(Ghost::new(&[0, 150]).is_some());
+    }
+
+    #[test]
+    fn position_counts_the_keys_typed_so_far() {
+        let ghost = Ghost::new(&[0, 150, 300, 600]).unwrap();
+        assert_eq!(ghost.position(ms(0)), 1);
+        assert_eq!(ghost.position(ms(299)), 2);
+        assert_eq!(ghost.position(ms(300)), 3);
+        assert_eq!(ghost.position(ms(10_000)), 4);
+    }
+
+    #[test]
+    fn bar_lines_up_with_the_text() {
+        let ghost = Ghost::new(&[0, 150, 300, 600]).unwrap();
+        assert_eq!(ghost.bar(ms(200), 4), "==-- ghost");
+        assert_eq!(ghost.bar(ms(900), 4), "==== ghost");
+        // A ghost of a longer text stops at the end of this one.
+        assert_eq!(ghost.bar(ms(900), 3), "=== ghost");
+    }
+}
@@ -0,0 +1,623 @@
+//! # Alphabet Typing Speed Game
+//!
+//! This module implements a simple game that measures how quickly you can type the entire
+//! alphabet correctly. The program tracks your best time across multiple attempts.
+//!
+//! ## Features
+//!
+//! - Measures typing speed for the complete alphabet
+//! - Validates input to ensure the entire alphabet is typed correctly
+//! - Tracks best performance across multiple attempts
+//! - Remembers your all-time best time between runs
+//! - Handles various input formats including mixed case and whitespace
+//!
+//! ## How to Play
+//!
+//! 1. Run the program and press Enter to start
+//! 2. Type the complete alphabet (a-z) as quickly as possible
+//! 3. Press Enter to submit your attempt
+//! 4. The program will show your time if successful, or prompt you to try again
+//! 5. Press Enter to play again or 'q' to quit and see your best time
+//!
+//! With `--sentences` or `--texts` each attempt is a sentence to type
+//! instead, picked at random, as described in the [`texts`] module. After a
+//! failed attempt the game shows where it went wrong, as described in the
+//! [`diff`] module. The best time and speed for every text are kept
+//! between runs, as described in the [`records`] module. With `--live` each
+//! key is checked as it is pressed, as described in the [`live`] module.
+//! With `--rounds` a session of that many rounds is played without asking
+//! in between, and then reported on, as described in the [`session`] module.
+//! `--difficulty medium` or `hard` makes the alphabet harder to type, with
+//! an all-time best of its own. `--drill` or `--drill-chars` makes each
+//! attempt a drill of random characters, as described in the [`drill`]
+//! module.
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::{self, Input};
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::rng::RngArgs;
+use challenge_common::theme::{self, ThemeArgs};
+use challenge_storage::{achievements, Store};
+use clap::Parser;
+use diff::Diff;
+use drill::{Charset, Row};
+#[cfg(feature = "live")]
+use ghost::Ghost;
+use rand::Rng;
+use records::{Best, Bests};
+use session::Report;
+use std::ffi::OsString;
+use std::io::BufRead;
+#[cfg(feature = "live")]
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod diff;
+mod drill;
+#[cfg(feature = "live")]
+mod ghost;
+#[cfg(feature = "live")]
+mod live;
+mod records;
+mod session;
+mod texts;
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn is_valid_alphabet(input: &str) -> bool {
+    let input = input.trim().to_lowercase();
+    input == ALPHABET
+}
+
+/// How hard the alphabet is to type.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Difficulty {
+    /// The lowercase alphabet, typed in any case
+    #[default]
+    Easy,
+    /// The alphabet in alternating case, typed exactly
+    Medium,
+    /// The alphabet followed by the digits and symbols, typed exactly
+    Hard,
+}
+
+impl Difficulty {
+    /// The text to type at this difficulty.
+    fn target(self) -> &'static str {
+        match self {
+            Difficulty::Easy => ALPHABET,
+            Difficulty::Medium => "aBcDeFgHiJkLmNoPqRsTuVwXyZ",
+            Difficulty::Hard => "abcdefghijklmnopqrstuvwxyz0123456789!@#$%^&*()",
+        }
+    }
+
+    /// The key of the all-time best at this difficulty, which for the easy
+    /// alphabet is the one kept before there were difficulties.
+    fn best_time_key(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "best_time",
+            Difficulty::Medium => "best_time_medium",
+            Difficulty::Hard => "best_time_hard",
+        }
+    }
+}
+
+/// What the player types in each attempt.
+enum Texts {
+    /// The alphabet, at a difficulty.
+    Alphabet(Difficulty),
+    /// One of these texts, picked at random for each attempt.
+    Pick(Vec<String>),
+    /// A new drill of `length` of these characters for each attempt.
+    Drill { chars: Charset, length: usize },
+}
+
+impl Texts {
+    /// The text of the next attempt, picked with `rng` if it varies.
+    fn next<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        match self {
+            Texts::Alphabet(difficulty) => difficulty.target().to_string(),
+            Texts::Pick(texts) => texts::pick(texts, rng).to_string(),
+            Texts::Drill { chars, length } => chars.drill(*length, rng),
+        }
+    }
+
+    /// Whether the best attempt at each text is kept, which it is not for
+    /// drills, as no two are the same.
+    fn keeps_bests(&self) -> bool {
+        !matches!(self, Texts::Drill { .. })
+    }
+
+    /// Whether `typed` is a correct attempt at `text`.
+    fn is_correct(&self, typed: &str, text: &str) -> bool {
+        match self {
+            Texts::Alphabet(Difficulty::Easy) => is_valid_alphabet(typed),
+            Texts::Alphabet(_) => typed.trim() == text,
+            Texts::Pick(_) | Texts::Drill { .. } => texts::matches(typed, text),
+        }
+    }
+
+    /// Whether a letter typed in the other case counts.
+    fn ignores_case(&self) -> bool {
+        matches!(self, Texts::Alphabet(Difficulty::Easy))
+    }
+
+    /// Where `typed` differs from `text`, in the parts that count: the case
+    /// of the easy alphabet and the spacing between words do not.
+    fn diff(&self, typed: &str, text: &str) -> Diff {
+        match self {
+            Texts::Alphabet(_) if self.ignores_case() => {
+                Diff::new(text, &typed.trim().to_lowercase())
+            }
+            Texts::Alphabet(_) => Diff::new(text, typed.trim()),
+            Texts::Pick(_) | Texts::Drill { .. } => {
+                let words = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+                Diff::new(&words(text), &words(typed))
+            }
+        }
+    }
+}
+
+/// Records `time` as a candidate all-time best at `difficulty` and returns
+/// the previous best.
+fn record_best_time(difficulty: Difficulty, time: f64) -> challenge_storage::Result<Option<f64>> {
+    let mut store = Store::open("c07")?;
+    let previous = store.record_min(difficulty.best_time_key(), time)?;
+    store.save()?;
+    Ok(previous)
+}
+
+/// Records `seconds` as an attempt at `text`, with its keys typed at
+/// `keys_ms` if typed live, and returns the best attempt at it before this
+/// one.
+fn record_text_best(
+    text: &str,
+    seconds: f64,
+    keys_ms: &[u64],
+) -> challenge_storage::Result<Option<Best>> {
+    let mut store = Store::open("c07")?;
+    let mut bests: Bests = store.get_or_default("texts")?;
+    let previous = records::record(&mut bests, text, seconds, keys_ms);
+    store.set("texts", &bests)?;
+    store.save()?;
+    Ok(previous)
+}
+
+/// The ghost of the best attempt at `text`, if it was typed live.
+#[cfg(feature = "live")]
+fn load_ghost(text: &str) -> challenge_storage::Result<Option<Ghost>> {
+    let bests: Bests = Store::open("c07")?.get_or_default("texts")?;
+    Ok(bests.get(text).and_then(|best| Ghost::new(&best.keys_ms)))
+}
+
+/// How an attempt ended.
+enum Outcome {
+    /// The text was typed correctly in this many seconds, with its keys
+    /// typed at these milliseconds if typed live.
+    Typed { seconds: f64, keys_ms: Vec<u64> },
+    /// The text was typed wrongly.
+    Failed,
+    /// The player left, or their input could not be read.
+    Quit,
+}
+
+/// Has the player type `text` and press Enter, then tells them how they did.
+fn type_line<R: BufRead>(reader: &mut R, texts: &Texts, text: &str) -> Outcome {
+    println!("{}", l10n::text("c07.type"));
+    let start_time = std::time::Instant::now();
+
+    let input = match input::read_line(reader) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error reading input: {}", e);
+            return Outcome::Quit;
+        }
+    };
+
+    let elapsed_time = start_time.elapsed();
+    let valid = texts.is_correct(&input, text);
+    tracing::debug!(?elapsed_time, valid, "attempt submitted");
+    let seconds = format!("{:.2}", elapsed_time.as_secs_f64());
+    match (texts, valid) {
+        (Texts::Alphabet(_), true) => println!(
+            "{}",
+            l10n::format("c07.typed_alphabet", &[("seconds", &seconds)])
+        ),
+        (_, true) => println!("{}", l10n::format("c07.typed", &[("seconds", &seconds)])),
+        (Texts::Alphabet(_), false) => println!("{}", l10n::text("c07.wrong_alphabet")),
+        (_, false) => println!("{}", l10n::text("c07.wrong")),
+    }
+    if valid {
+        return Outcome::Typed {
+            seconds: elapsed_time.as_secs_f64(),
+            keys_ms: Vec::new(),
+        };
+    }
+    let diff = texts.diff(&input, text);
+    match diff.mistakes() {
+        1 => println!("{}", l10n::text("c07.mistake")),
+        n => println!("{}", l10n::format("c07.mistakes", &[("mistakes", &n)])),
+    }
+    println!("{}", diff);
+    Outcome::Failed
+}
+
+/// Has the player type `text` live, racing `ghost` if given, then tells
+/// them how they did, with how long each character took if `histogram` is
+/// set.
+#[cfg(feature = "live")]
+fn type_live(texts: &Texts, text: &str, histogram: bool, ghost: Option<&Ghost>) -> Outcome {
+    println!("{}", l10n::text("c07.type_live"));
+    match live::run(text, texts.ignores_case(), ghost) {
+        Ok(Some(typing)) => {
+            let seconds = typing.elapsed().as_secs_f64();
+            tracing::debug!(seconds, mistakes = typing.mistakes, "live attempt typed");
+            let typed = format!("{:.2}", seconds);
+            let message = match typing.mistakes {
+                0 => l10n::format("c07.typed_clean", &[("seconds", &typed)]),
+                1 => l10n::format("c07.typed_wrong_key", &[("seconds", &typed)]),
+                n => l10n::format(
+                    "c07.typed_wrong_keys",
+                    &[("seconds", &typed), ("mistakes", &n)],
+                ),
+            };
+            println!("{}", message);
+            if histogram {
+                println!("{}", live::histogram(&typing.latencies));
+            }
+            let keys_ms = typing
+                .keys
+                .iter()
+                .map(|key| u64::try_from(key.as_millis()).unwrap_or(u64::MAX))
+                .collect();
+            Outcome::Typed { seconds, keys_ms }
+        }
+        Ok(None) => Outcome::Quit,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            Outcome::Quit
+        }
+    }
+}
+
+/// Type the alphabet as fast as you can.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Type sentences and pangrams picked at random instead of the alphabet
+    #[arg(long)]
+    sentences: bool,
+    /// Type texts picked at random from a file, one per line, instead of the
+    /// alphabet
+    #[arg(long, value_name = "FILE", conflicts_with = "sentences")]
+    texts: Option<PathBuf>,
+    /// How hard the alphabet is to type
+    #[arg(long, value_enum, default_value_t, conflicts_with_all = ["sentences", "texts"])]
+    difficulty: Difficulty,
+    /// Practise drills of random characters from a row of the keyboard
+    #[arg(
+        long,
+        value_enum,
+        value_name = "ROW",
+        conflicts_with_all = ["sentences", "texts", "difficulty"]
+    )]
+    drill: Option<Row>,
+    /// Practise drills of random characters from these
+    #[arg(
+        long,
+        value_name = "CHARS",
+        value_parser = drill::parse_charset,
+        conflicts_with_all = ["sentences", "texts", "difficulty", "drill"]
+    )]
+    drill_chars: Option<Charset>,
+    /// Number of characters in each drill
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..=200))]
+    length: u32,
+    /// Play this many rounds in a row, then report on the session
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    rounds: Option<u32>,
+    /// Check each key as it is pressed, starting the timer with the first
+    #[cfg(feature = "live")]
+    #[arg(long)]
+    live: bool,
+    /// Show how long each character took after a live attempt
+    #[cfg(feature = "live")]
+    #[arg(long, requires = "live")]
+    histogram: bool,
+    /// Race the ghost of your best live attempt at each text
+    #[cfg(feature = "live")]
+    #[arg(long, requires = "live")]
+    ghost: bool,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    theme::init(&args.theme);
+    let mut reader = match Input::from_args(&args.common) {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(reader.default_seed());
+    #[cfg(feature = "live")]
+    if args.live && !std::io::stdin().is_terminal() {
+        eprintln!("Error: --live needs a terminal to type in");
+        return ExitCode::FAILURE;
+    }
+
+    let drill = args.drill_chars.clone().or(args.drill.map(Row::chars));
+    let texts = match (&args.texts, args.sentences, drill) {
+        (Some(path), _, _) => match texts::load_texts(path) {
+            Ok(texts) => Texts::Pick(texts),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        (None, true, _) => Texts::Pick(texts::bundled()),
+        (None, false, Some(chars)) => Texts::Drill {
+            chars,
+            length: args.length as usize,
+        },
+        (None, false, None) => Texts::Alphabet(args.difficulty),
+    };
+
+    match texts {
+        Texts::Alphabet(_) => println!("{}", l10n::text("c07.intro_alphabet")),
+        Texts::Pick(_) => println!("{}", l10n::text("c07.intro_sentence")),
+        Texts::Drill { .. } => println!("{}", l10n::text("c07.intro_drill")),
+    }
+    println!("{}", l10n::text("prompt.start"));
+
+    let _ = input::read_line(&mut reader);
+    let mut best_time: f64 = f64::INFINITY;
+    let mut played = Vec::new();
+    for round in 1.. {
+        if let Some(rounds) = args.rounds {
+            println!(
+                "{}",
+                l10n::format("c07.round", &[("round", &round), ("rounds", &rounds)])
+            );
+        }
+        let text = &texts.next(&mut rng);
+        #[cfg(feature = "live")]
+        let live = args.live;
+        #[cfg(not(feature = "live"))]
+        let live = false;
+        // Typing live, the easy alphabet is shown too, to keep track of
+        // where the player is.
+        if live || !matches!(texts, Texts::Alphabet(Difficulty::Easy)) {
+            println!("{}", l10n::format("c07.text", &[("text", &text)]));
+        }
+        #[cfg(feature = "live")]
+        let outcome = if args.live {
+            let ghost = if args.ghost && texts.keeps_bests() {
+                load_ghost(text).unwrap_or_else(|e| {
+                    eprintln!("Warning: unable to load your best attempt: {}", e);
+                    None
+                })
+            } else {
+                None
+            };
+            if args.ghost && texts.keeps_bests() && ghost.is_none() {
+                println!("{}", l10n::text("c07.no_ghost"));
+            }
+            type_live(&texts, text, args.histogram, ghost.as_ref())
+        } else {
+            type_line(&mut reader, &texts, text)
+        };
+        #[cfg(not(feature = "live"))]
+        let outcome = type_line(&mut reader, &texts, text);
+
+        match outcome {
+            Outcome::Typed { seconds, keys_ms } => {
+                best_time = best_time.min(seconds);
+                let wpm = format!("{:.1}", records::wpm(text, seconds));
+                println!("{}", l10n::format("c07.speed", &[("wpm", &wpm)]));
+                if texts.keeps_bests() {
+                    match record_text_best(text, seconds, &keys_ms) {
+                        Ok(previous) => {
+                            println!("{}", records::improvement(text, seconds, previous.as_ref()))
+                        }
+                        Err(e) => eprintln!("Warning: unable to save your best time: {}", e),
+                    }
+                }
+                played.push(Some(seconds));
+            }
+            Outcome::Failed => played.push(None),
+            Outcome::Quit => break,
+        }
+
+        match args.rounds {
+            Some(rounds) if round >= rounds => break,
+            Some(_) => continue,
+            None => {}
+        }
+        println!("{}", l10n::text("c07.again"));
+        match input::read_line(&mut reader) {
+            Ok(input) if input.trim() == "q" => break,
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Error reading input: {}", e);
+                break;
+            }
+        }
+    }
+
+    if args.rounds.is_some() {
+        println!("{}", Report::new(&played));
+    }
+    if best_time != f64::INFINITY {
+        let best = format!("{:.2}", best_time);
+        println!("{}", l10n::format("c07.best_time", &[("seconds", &best)]));
+    }
+    // Texts differ in length, so only the alphabet has an all-time best.
+    let Texts::Alphabet(difficulty) = texts else {
+        return ExitCode::SUCCESS;
+    };
+    if best_time != f64::INFINITY {
+        match record_best_time(difficulty, best_time) {
+            Ok(Some(all_time)) if best_time < all_time => {
+                println!("{}", l10n::text("c07.new_all_time_best"))
+            }
+            Ok(Some(all_time)) => {
+                let all_time = format!("{:.2}", all_time);
+                println!(
+                    "{}",
+                    l10n::format("c07.all_time_best", &[("seconds", &all_time)])
+                )
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Warning: unable to save your best time: {}", e),
+        }
+    }
+    if best_time < 5.0 {
+        match achievements::unlock("alphabet_under_5s") {
+            Ok(Some(achievement)) => {
+                let name = l10n::text(&format!("achievement.{}", achievement.id));
+                println!(
+                    "{}",
+                    l10n::format("achievement.unlocked", &[("name", &name)])
+                )
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Warning: unable to save your achievement: {}", e),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn is_valid_alphabet_accepts_correct_alphabet() {
+        let input = "abcdefghijklmnopqrstuvwxyz";
+        assert!(is_valid_alphabet(input), "Should accept correct alphabet");
+    }
+
+    #[test]
+    fn is_valid_alphabet_accepts_with_whitespace() {
+        let input = "abcdefghijklmnopqrstuvwxyz\n";
+        assert!(
+            is_valid_alphabet(input),
+            "Should accept alphabet with trailing whitespace"
+        );
+    }
+
+    #[test]
+    fn is_valid_alphabet_accepts_uppercase() {
+        let input = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        assert!(is_valid_alphabet(input), "Should accept uppercase alphabet");
+    }
+
+    #[test]
+    fn is_valid_alphabet_accepts_mixed_case() {
+        let input = "AbCdEfGhIjKlMnOpQrStUvWxYz";
+        assert!(
+            is_valid_alphabet(input),
+            "Should accept mixed case alphabet"
+        );
+    }
+
+    #[test]
+    fn is_valid_alphabet_rejects_incorrect_order() {
+        let input = "abcdefghijklmnopqrstuvwzyx";
+        assert!(!is_valid_alphabet(input), "Should reject incorrect order");
+    }
+
+    #[test]
+    fn is_valid_alphabet_rejects_missing_letters() {
+        let input = "abcdefghijklmnopqrstuvwxy";
+        assert!(!is_valid_alphabet(input), "Should reject missing letters");
+    }
+
+    #[test]
+    fn is_valid_alphabet_rejects_duplicate_letters() {
+        let input = "abcdefghijklmnopqrstuvwxyzz";
+        assert!(!is_valid_alphabet(input), "Should reject duplicate letters");
+    }
+
+    #[test]
+    fn is_valid_alphabet_rejects_extra_characters() {
+        let input = "abcdefghijklmnopqrstuvwxyz123";
+        assert!(!is_valid_alphabet(input), "Should reject extra characters");
+    }
+
+    #[test]
+    fn is_valid_alphabet_rejects_empty_string() {
+        let input = "";
+        assert!(!is_valid_alphabet(input), "Should reject empty string");
+    }
+
+    #[test]
+    fn harder_difficulties_need_the_exact_text() {
+        for difficulty in [Difficulty::Medium, Difficulty::Hard] {
+            let texts = Texts::Alphabet(difficulty);
+            let target = &texts.next(&mut StdRng::seed_from_u64(42));
+            assert!(texts.is_correct(&format!("{target}\n"), target));
+            assert!(!texts.is_correct(ALPHABET, target));
+            assert!(!texts.is_correct(&target.to_uppercase(), target));
+        }
+        let texts = Texts::Alphabet(Difficulty::Easy);
+        assert!(texts.is_correct("ABCDEFGHIJKLMNOPQRSTUVWXYZ", ALPHABET));
+    }
+
+    #[test]
+    fn args_pick_one_kind_of_text() {
+        let args = Args::try_parse_from(["c07", "--drill", "home", "--length", "8"]).unwrap();
+        assert_eq!(args.drill, Some(Row::Home));
+        assert_eq!(args.length, 8);
+        assert!(Args::try_parse_from(["c07", "--drill-chars", "xyz"]).is_ok());
+        assert!(Args::try_parse_from(["c07", "--drill", "top", "--drill-chars", "x"]).is_err());
+        assert!(Args::try_parse_from(["c07", "--drill", "top", "--sentences"]).is_err());
+        assert!(Args::try_parse_from(["c07", "--drill", "top", "--difficulty", "hard"]).is_err());
+        assert!(Args::try_parse_from(["c07", "--drill-chars", " "]).is_err());
+    }
+
+    #[test]
+    fn each_difficulty_has_its_own_best_time() {
+        assert_eq!(Difficulty::Easy.best_time_key(), "best_time");
+        assert_ne!(
+            Difficulty::Medium.best_time_key(),
+            Difficulty::Hard.best_time_key()
+        );
+    }
+
+    #[test]
+    fn diff_ignores_case_only_when_easy() {
+        let typed = "aBcDeFgHiJkLmNoPqRsTuVwXyz";
+        assert_eq!(
+            Texts::Alphabet(Difficulty::Easy)
+                .diff(typed, ALPHABET)
+                .mistakes(),
+            0
+        );
+        let medium = Texts::Alphabet(Difficulty::Medium);
+        assert_eq!(
+            medium
+                .diff(typed, &medium.next(&mut StdRng::seed_from_u64(42)))
+                .mistakes(),
+            1
+        );
+    }
+}
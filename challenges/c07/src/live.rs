@@ -0,0 +1,292 @@
+//! Live typing, enabled with `--live`: the terminal is switched to raw mode
+//! so that every key is checked as it is pressed.
+//!
+//! The timer starts with the first key rather than when the text is shown,
+//! so reading the text first costs nothing. A wrong key flashes in red and
+//! has to be typed again, so the text is always typed correctly in the end
+//! and an attempt is scored by its time and its wrong keys. The time before
+//! each correct key is kept, for a histogram of the slowest characters, and
+//! so is the time of each from the first key, for the [`ghost`](super::ghost)
+//! of the attempt to be raced.
+use super::ghost::Ghost;
+use challenge_common::{l10n, theme};
+use challenge_tui::crossterm::cursor::{
+    MoveLeft, MoveToColumn, MoveUp, RestorePosition, SavePosition,
+};
+use challenge_tui::crossterm::event::KeyCode;
+use challenge_tui::crossterm::queue;
+use challenge_tui::crossterm::style::Print;
+use challenge_tui::crossterm::terminal;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// How long a wrong key stays on screen.
+const FLASH: Duration = Duration::from_millis(150);
+
+/// How often the ghost's bar is redrawn while no key is pressed.
+const TICK: Duration = Duration::from_millis(50);
+
+/// Width of the longest bar of the histogram.
+const BAR_WIDTH: f64 = 30.0;
+
+/// What a key pressed did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Press {
+    /// It was the next character of the text.
+    Right,
+    /// It was not, and the character has to be typed again.
+    Wrong,
+    /// It was the last character of the text.
+    Done,
+}
+
+/// An attempt at a text, one key at a time.
+#[derive(Debug)]
+pub struct Typing {
+    text: Vec<char>,
+    /// Whether a key in the other case counts, as it does for the alphabet.
+    ignore_case: bool,
+    /// How many characters have been typed correctly.
+    position: usize,
+    started: Option<Instant>,
+    last: Option<Instant>,
+    /// How many wrong keys were pressed.
+    pub mistakes: usize,
+    /// Each character typed after the first, with the time since the one
+    /// before it.
+    pub latencies: Vec<(char, Duration)>,
+    /// When each character was typed, from the first key pressed.
+    pub keys: Vec<Duration>,
+}
+
+impl Typing {
+    pub fn new(text: &str, ignore_case: bool) -> Self {
+        Typing {
+            text: text.chars().collect(),
+            ignore_case,
+            position: 0,
+            started: None,
+            last: None,
+            mistakes: 0,
+            latencies: Vec::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Checks `key`, pressed at `now`, against the next character of the
+    /// text. The first key pressed starts the timer.
+    pub fn press(&mut self, key: char, now: Instant) -> Press {
+        let Some(&expected) = self.text.get(self.position) else {
+            return Press::Done;
+        };
+        let started = *self.started.get_or_insert(now);
+        let right = key == expected || (self.ignore_case && key.eq_ignore_ascii_case(&expected));
+        if !right {
+            self.mistakes += 1;
+            return Press::Wrong;
+        }
+        if let Some(last) = self.last {
+            self.latencies.push((expected, now - last));
+        }
+        self.last = Some(now);
+        self.keys.push(now - started);
+        self.position += 1;
+        if self.position == self.text.len() {
+            Press::Done
+        } else {
+            Press::Right
+        }
+    }
+
+    /// The time since the first key at `now`, or `None` before it.
+    pub fn since_start(&self, now: Instant) -> Option<Duration> {
+        self.started.map(|started| now - started)
+    }
+
+    /// The time from the first key to the last correct one.
+    pub fn elapsed(&self) -> Duration {
+        match (self.started, self.last) {
+            (Some(started), Some(last)) => last - started,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Restores the terminal when dropped, even if typing fails or panics.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> std::io::Result<RawMode> {
+        terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Has the player type `text` live, racing `ghost` if given, and returns the
+/// attempt once it is typed, or `None` if they left with Esc or Ctrl-C.
+pub fn run(
+    text: &str,
+    ignore_case: bool,
+    ghost: Option<&Ghost>,
+) -> std::io::Result<Option<Typing>> {
+    let mut typing = Typing::new(text, ignore_case);
+    let len = text.chars().count();
+    let mut stdout = std::io::stdout();
+    let _raw = RawMode::enable()?;
+    if let Some(ghost) = ghost {
+        queue!(stdout, Print(ghost.bar(Duration::ZERO, len)), Print("\r\n"))?;
+        stdout.flush()?;
+    }
+    loop {
+        if let Some(ghost) = ghost {
+            // The ghost only sets off with the player's first key.
+            let elapsed = typing.since_start(Instant::now()).unwrap_or_default();
+            queue!(
+                stdout,
+                SavePosition,
+                MoveUp(1),
+                MoveToColumn(0),
+                Print(ghost.bar(elapsed, len)),
+                RestorePosition
+            )?;
+            stdout.flush()?;
+        }
+        let Some(key) = challenge_tui::poll_key(TICK)? else {
+            continue;
+        };
+        // Letters are typed, so only Esc and Ctrl-C leave the game.
+        if key.code != KeyCode::Char('q') && challenge_tui::is_quit(&key) {
+            queue!(stdout, Print("\r\n"))?;
+            stdout.flush()?;
+            return Ok(None);
+        }
+        let KeyCode::Char(c) = key.code else {
+            continue;
+        };
+        match typing.press(c, Instant::now()) {
+            Press::Right => queue!(stdout, Print(c))?,
+            Press::Wrong => {
+                queue!(stdout, Print(theme::lose(&c.to_string())))?;
+                stdout.flush()?;
+                std::thread::sleep(FLASH);
+                queue!(stdout, MoveLeft(1), Print(' '), MoveLeft(1))?;
+            }
+            Press::Done => {
+                queue!(stdout, Print(c), Print("\r\n"))?;
+                stdout.flush()?;
+                return Ok(Some(typing));
+            }
+        }
+        stdout.flush()?;
+    }
+}
+
+/// The mean time before each character, slowest first, with a bar for each,
+/// e.g.
+///
+/// ```text
+/// Time before each key, slowest first:
+///   'q'   420 ms ##############################
+///   'z'   210 ms ###############
+/// ```
+pub fn histogram(latencies: &[(char, Duration)]) -> String {
+    let mut totals: BTreeMap<char, (Duration, u32)> = BTreeMap::new();
+    for &(c, latency) in latencies {
+        let total = totals.entry(c).or_default();
+        total.0 += latency;
+        total.1 += 1;
+    }
+    let mut means: Vec<(char, Duration)> = totals
+        .into_iter()
+        .map(|(c, (total, count))| (c, total / count))
+        .collect();
+    means.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let slowest = means.first().map_or(0.0, |&(_, mean)| mean.as_secs_f64());
+    let mut lines = vec![l10n::text("c07.histogram").to_string()];
+    for (c, mean) in means {
+        let bar = if slowest > 0.0 {
+            ((mean.as_secs_f64() / slowest * BAR_WIDTH).round() as usize).max(1)
+        } else {
+            1
+        };
+        lines.push(format!(
+            "  {:<5} {:>5} ms {}",
+            format!("{c:?}"),
+            mean.as_millis(),
+            "#".repeat(bar)
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn press_starts_the_timer_on_the_first_key() {
+        let start = Instant::now();
+        let mut typing = Typing::new("abc", false);
+        assert_eq!(typing.press('a', start + ms(500)), Press::Right);
+        assert_eq!(typing.press('b', start + ms(700)), Press::Right);
+        assert_eq!(typing.press('c', start + ms(1_000)), Press::Done);
+        assert_eq!(typing.elapsed(), ms(500));
+        assert_eq!(typing.latencies, [('b', ms(200)), ('c', ms(300))]);
+        assert_eq!(typing.keys, [ms(0), ms(200), ms(500)]);
+    }
+
+    #[test]
+    fn press_makes_wrong_keys_be_typed_again() {
+        let start = Instant::now();
+        let mut typing = Typing::new("ab", false);
+        assert_eq!(typing.press('x', start), Press::Wrong);
+        assert_eq!(typing.press('A', start + ms(100)), Press::Wrong);
+        assert_eq!(typing.press('a', start + ms(200)), Press::Right);
+        assert_eq!(typing.press('b', start + ms(300)), Press::Done);
+        assert_eq!(typing.mistakes, 2);
+        // The timer started with the first wrong key.
+        assert_eq!(typing.elapsed(), ms(300));
+        assert_eq!(typing.latencies, [('b', ms(100))]);
+        assert_eq!(typing.keys, [ms(200), ms(300)]);
+    }
+
+    #[test]
+    fn press_can_ignore_case() {
+        let mut typing = Typing::new("ab", true);
+        let now = Instant::now();
+        assert_eq!(typing.press('A', now), Press::Right);
+        assert_eq!(typing.press('B', now), Press::Done);
+        assert_eq!(typing.mistakes, 0);
+    }
+
+    #[test]
+    fn histogram_puts_the_slowest_characters_first() {
+        let latencies = [
+            ('a', ms(100)),
+            ('q', ms(400)),
+            ('a', ms(200)),
+            ('q', ms(440)),
+            (' ', ms(10)),
+        ];
+        assert_eq!(
+            histogram(&latencies),
+            "Time before each key, slowest first:\n  \
+             'q'     420 ms ##############################\n  \
+             'a'     150 ms ###########\n  \
+             ' '      10 ms #"
+        );
+        assert_eq!(histogram(&[]), "Time before each key, slowest first:");
+    }
+}
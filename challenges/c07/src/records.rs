@@ -0,0 +1,126 @@
+//! The best time and speed for each text typed, kept between runs.
+//!
+//! Speed is measured in words per minute, counting every five characters
+//! typed as a word, as typing tests usually do, so that texts with long and
+//! short words can be compared. After each correct attempt the game tells
+//! the player how it compares with their best for that text. A best typed
+//! live also keeps when each key was typed, for its ghost to be raced.
+use challenge_common::l10n;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Characters that count as a word when working out the speed.
+const CHARS_PER_WORD: f64 = 5.0;
+
+/// The best attempt at a text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Best {
+    pub seconds: f64,
+    /// Words per minute.
+    pub wpm: f64,
+    /// When each character was typed, in milliseconds from the first key,
+    /// if the attempt was typed live.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys_ms: Vec<u64>,
+}
+
+/// The best attempt at each text, by text.
+pub type Bests = BTreeMap<String, Best>;
+
+/// The speed, in words per minute, of typing `text` in `seconds`.
+pub fn wpm(text: &str, seconds: f64) -> f64 {
+    let words = text.chars().count() as f64 / CHARS_PER_WORD;
+    words / (seconds / 60.0)
+}
+
+/// Records an attempt at `text` that took `seconds`, with its keys typed at
+/// `keys_ms` if typed live, and returns the best before it, if `text` had
+/// been typed before.
+pub fn record(bests: &mut Bests, text: &str, seconds: f64, keys_ms: &[u64]) -> Option<Best> {
+    let previous = bests.get(text).cloned();
+    if previous.as_ref().is_none_or(|best| seconds < best.seconds) {
+        let best = Best {
+            seconds,
+            wpm: wpm(text, seconds),
+            keys_ms: keys_ms.to_vec(),
+        };
+        bests.insert(text.to_string(), best);
+    }
+    previous
+}
+
+/// How an attempt that took `seconds` compares with the `previous` best,
+/// e.g. `0.40 s faster than your best (+3.1 WPM)!`.
+pub fn improvement(text: &str, seconds: f64, previous: Option<&Best>) -> String {
+    let Some(best) = previous else {
+        return l10n::text("c07.first_time").to_string();
+    };
+    if seconds < best.seconds {
+        l10n::format(
+            "c07.faster",
+            &[
+                ("seconds", &format!("{:.2}", best.seconds - seconds)),
+                ("wpm", &format!("{:+.1}", wpm(text, seconds) - best.wpm)),
+            ],
+        )
+    } else {
+        l10n::format(
+            "c07.slower",
+            &[
+                ("seconds", &format!("{:.2}", seconds - best.seconds)),
+                ("best", &format!("{:.2}", best.seconds)),
+                ("wpm", &format!("{:.1}", best.wpm)),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wpm_counts_five_characters_as_a_word() {
+        assert_eq!(wpm("abcdefghijklmnopqrstuvwxyz", 6.5), 48.0);
+        assert_eq!(wpm("Hi!", 0.5), 72.0);
+    }
+
+    #[test]
+    fn record_keeps_the_fastest_attempt() {
+        let mut bests = Bests::new();
+        assert_eq!(record(&mut bests, "Hi!", 1.0, &[]), None);
+        let first = Best {
+            seconds: 1.0,
+            wpm: 36.0,
+            keys_ms: Vec::new(),
+        };
+        assert_eq!(record(&mut bests, "Hi!", 2.0, &[]), Some(first.clone()));
+        assert_eq!(record(&mut bests, "Hi!", 0.5, &[0, 200, 500]), Some(first));
+        assert_eq!(bests["Hi!"].seconds, 0.5);
+        assert_eq!(bests["Hi!"].wpm, 72.0);
+        assert_eq!(bests["Hi!"].keys_ms, [0, 200, 500]);
+        assert_eq!(record(&mut bests, "Bye!", 3.0, &[]), None);
+        assert_eq!(bests.len(), 2);
+    }
+
+    #[test]
+    fn improvement_compares_with_the_best() {
+        let best = Some(&Best {
+            seconds: 1.0,
+            wpm: 36.0,
+            keys_ms: Vec::new(),
+        });
+        assert_eq!(
+            improvement("Hi!", 0.5, best),
+            "0.50 s faster than your best (+36.0 WPM)!"
+        );
+        assert_eq!(
+            improvement("Hi!", 1.25, best),
+            "0.25 s slower than your best of 1.00 s (36.0 WPM)."
+        );
+        assert_eq!(
+            improvement("Hi!", 1.0, None),
+            "That's your first time typing this text."
+        );
+    }
+}
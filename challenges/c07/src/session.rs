@@ -0,0 +1,156 @@
+//! The report at the end of a session of several rounds, played with
+//! `--rounds`.
+//!
+//! Times are only taken from the correct attempts. The trend is the slope of
+//! the line that fits those times best, by least squares, over the rounds
+//! they were typed in, so a player who keeps getting faster sees how much
+//! faster each round.
+use challenge_common::l10n;
+use std::fmt;
+
+/// Seconds per round under which the times count as steady.
+const STEADY: f64 = 0.005;
+
+/// How a session of rounds went.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Report {
+    pub attempts: usize,
+    pub correct: usize,
+    /// The fastest correct attempt, in seconds.
+    pub best: Option<f64>,
+    /// The mean time of the correct attempts, in seconds.
+    pub average: Option<f64>,
+    /// Seconds more each round took than the one before, on average,
+    /// negative when getting faster. Needs two correct attempts.
+    pub trend: Option<f64>,
+}
+
+impl Report {
+    /// Sums up the `rounds` played, each the seconds it took or `None` if it
+    /// was typed wrongly.
+    pub fn new(rounds: &[Option<f64>]) -> Self {
+        let points: Vec<(f64, f64)> = rounds
+            .iter()
+            .enumerate()
+            .filter_map(|(round, seconds)| seconds.map(|seconds| (round as f64, seconds)))
+            .collect();
+        let n = points.len() as f64;
+        let times = || points.iter().map(|&(_, seconds)| seconds);
+        let average = (!points.is_empty()).then(|| times().sum::<f64>() / n);
+        let trend = if points.len() < 2 {
+            None
+        } else {
+            let mean_x = points.iter().map(|&(x, _)| x).sum::<f64>() / n;
+            let mean_y = average.unwrap_or_default();
+            let covariance: f64 = points
+                .iter()
+                .map(|&(x, y)| (x - mean_x) * (y - mean_y))
+                .sum();
+            let variance: f64 = points.iter().map(|&(x, _)| (x - mean_x).powi(2)).sum();
+            Some(covariance / variance)
+        };
+        Report {
+            attempts: rounds.len(),
+            correct: points.len(),
+            best: times().min_by(f64::total_cmp),
+            average,
+            trend,
+        }
+    }
+
+    /// The share of attempts typed correctly, as a percentage.
+    pub fn success_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            return 0.0;
+        }
+        100.0 * self.correct as f64 / self.attempts as f64
+    }
+}
+
+/// The report over several lines, leaving out the times when none were
+/// correct and the trend when there are too few to tell.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let seconds =
+            |key, seconds: f64| l10n::format(key, &[("seconds", &format!("{seconds:.2}"))]);
+        writeln!(f, "{}", l10n::text("c07.report"))?;
+        let attempts = l10n::format("c07.report_attempts", &[("attempts", &self.attempts)]);
+        writeln!(f, "  {}", attempts)?;
+        let correct = l10n::format(
+            "c07.report_correct",
+            &[
+                ("correct", &self.correct),
+                ("percent", &format!("{:.0}", self.success_rate())),
+            ],
+        );
+        write!(f, "  {}", correct)?;
+        if let (Some(best), Some(average)) = (self.best, self.average) {
+            write!(f, "\n  {}", seconds("c07.report_best", best))?;
+            write!(f, "\n  {}", seconds("c07.report_average", average))?;
+        }
+        match self.trend {
+            Some(trend) if trend.abs() < STEADY => {
+                write!(f, "\n  {}", l10n::text("c07.report_steady"))
+            }
+            Some(trend) if trend < 0.0 => {
+                write!(f, "\n  {}", seconds("c07.report_faster", -trend))
+            }
+            Some(trend) => write!(f, "\n  {}", seconds("c07.report_slower", trend)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sums_up_the_correct_attempts() {
+        let report = Report::new(&[Some(6.0), None, Some(5.0), Some(4.0), None]);
+        assert_eq!(report.attempts, 5);
+        assert_eq!(report.correct, 3);
+        assert_eq!(report.success_rate(), 60.0);
+        assert_eq!(report.best, Some(4.0));
+        assert_eq!(report.average, Some(5.0));
+        // The times of rounds 0, 2 and 3 fall by 9/14 of a second a round.
+        let trend = report.trend.unwrap();
+        assert!((trend - -(9.0 / 14.0)).abs() < 1e-9, "{trend}");
+    }
+
+    #[test]
+    fn new_needs_two_correct_attempts_for_a_trend() {
+        let report = Report::new(&[None, Some(3.0)]);
+        assert_eq!(report.trend, None);
+        assert_eq!(report.best, Some(3.0));
+
+        let report = Report::new(&[None, None]);
+        assert_eq!(report.best, None);
+        assert_eq!(report.average, None);
+        assert_eq!(report.success_rate(), 0.0);
+        assert_eq!(Report::new(&[]).success_rate(), 0.0);
+    }
+
+    #[test]
+    fn display_lists_each_statistic() {
+        assert_eq!(
+            Report::new(&[Some(5.0), Some(4.5), None, Some(3.5)]).to_string(),
+            "Session report:\n  \
+             Attempts: 4\n  \
+             Correct: 3 (75%)\n  \
+             Best time: 3.50 seconds\n  \
+             Average time: 4.33 seconds\n  \
+             Trend: 0.50 seconds faster each round"
+        );
+        assert_eq!(
+            Report::new(&[Some(4.0), Some(4.0)]).to_string(),
+            "Session report:\n  Attempts: 2\n  Correct: 2 (100%)\n  \
+             Best time: 4.00 seconds\n  Average time: 4.00 seconds\n  \
+             Trend: steady"
+        );
+        assert_eq!(
+            Report::new(&[None]).to_string(),
+            "Session report:\n  Attempts: 1\n  Correct: 0 (0%)"
+        );
+    }
+}
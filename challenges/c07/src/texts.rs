@@ -0,0 +1,94 @@
+//! Texts to type instead of the alphabet: sentences and pangrams from the
+//! list that comes with the game, or from a file of the player's own.
+//!
+//! A texts file has one text per line. Blank lines and lines starting with
+//! `#` are skipped, and runs of spaces within a text count as one. A typed text is correct when it has the same words as
+//! the text, with the same case and punctuation, however many spaces are
+//! between them.
+use rand::Rng;
+use std::path::Path;
+
+/// The texts that come with the game.
+const BUNDLED_TEXTS: &str = include_str!("../texts.txt");
+
+/// Reads the texts listed in `list`, which must have at least one.
+fn parse_texts(list: &str) -> Result<Vec<String>, String> {
+    let texts: Vec<String> = list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+    if texts.is_empty() {
+        return Err("the file has no texts".to_string());
+    }
+    Ok(texts)
+}
+
+/// The texts that come with the game.
+pub fn bundled() -> Vec<String> {
+    parse_texts(BUNDLED_TEXTS).expect("the bundled texts are valid")
+}
+
+/// Reads the texts listed in the file at `path`.
+pub fn load_texts(path: &Path) -> Result<Vec<String>, String> {
+    let list = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    parse_texts(&list).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// One of `texts`, picked with `rng`.
+pub fn pick<'a, R: Rng + ?Sized>(texts: &'a [String], rng: &mut R) -> &'a str {
+    &texts[rng.random_range(0..texts.len())]
+}
+
+/// Whether `typed` is `text`, ignoring the spacing between words.
+pub fn matches(typed: &str, text: &str) -> bool {
+    typed.split_whitespace().eq(text.split_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn parse_texts_skips_comments_and_blank_lines() {
+        let texts = parse_texts("# Pangrams\n\n  Sphinx of black  quartz, judge my vow.  \nHi!\n");
+        assert_eq!(
+            texts.unwrap(),
+            ["Sphinx of black quartz, judge my vow.", "Hi!"]
+        );
+        assert_eq!(
+            parse_texts("# Nothing yet\n\n").unwrap_err(),
+            "the file has no texts"
+        );
+    }
+
+    #[test]
+    fn bundled_texts_start_with_pangrams() {
+        let texts = bundled();
+        assert_eq!(texts[0], "The quick brown fox jumps over the lazy dog.");
+        assert!(texts.iter().all(|text| !text.starts_with('#')));
+    }
+
+    #[test]
+    fn pick_returns_one_of_the_texts() {
+        let texts = bundled();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            assert!(texts.contains(&pick(&texts, &mut rng).to_string()));
+        }
+    }
+
+    #[test]
+    fn matches_ignores_only_spacing() {
+        let text = "Talk is cheap. Show me the code.";
+        assert!(matches("Talk is cheap. Show me the code.", text));
+        assert!(matches("  Talk is  cheap.\tShow me the code.\n", text));
+        assert!(!matches("talk is cheap. show me the code.", text));
+        assert!(!matches("Talk is cheap Show me the code", text));
+        assert!(!matches("Talk is cheap.", text));
+        assert!(!matches("", text));
+    }
+}
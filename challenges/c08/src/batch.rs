@@ -0,0 +1,220 @@
+//! Batch mode: the people in a CSV file, one per row, and whether each may
+//! vote, as CSV.
+//!
+//! Each row is a name and a birth date, e.g. `Ada Lovelace,1815-12-10`. A
+//! name with a comma in it is quoted, e.g. `"Lovelace, Ada",1815-12-10`.
+//! Blank lines, lines starting with `#` and a header row starting with
+//! `name` are skipped. A row that cannot be read gets its error in the
+//! results instead of stopping the batch.
+use super::birthday::{self, LeapDay};
+use super::parse_date;
+use challenge_common::l10n;
+use challenge_error::ChallengeError;
+use chrono::NaiveDate;
+use std::fmt;
+use std::io::{BufRead, Write};
+
+/// The header of the results.
+const HEADER: &str = "line,name,birth_date,age,eligible,eligible_from,error";
+
+/// How many people in a batch may vote, and the rows that could not be read.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub eligible: usize,
+    pub not_eligible: usize,
+    /// The line number and error of each row that could not be read.
+    pub failures: Vec<(usize, String)>,
+}
+
+/// The counts, e.g. `3 eligible, 2 not eligible, 1 with errors`, leaving out
+/// the errors when there are none.
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts = l10n::format(
+            "c08.summary",
+            &[
+                ("eligible", &self.eligible),
+                ("not_eligible", &self.not_eligible),
+            ],
+        );
+        write!(f, "{}", counts)?;
+        if !self.failures.is_empty() {
+            let errors = l10n::format("c08.summary_errors", &[("errors", &self.failures.len())]);
+            write!(f, "{}", errors)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a row into its fields, unquoting any in double quotes.
+fn fields(row: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+        .iter()
+        .map(|field| field.trim().to_string())
+        .collect()
+}
+
+/// Quotes `field` if it would otherwise not read back as one CSV field.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Reads the name and birth date in a row of the input.
+fn parse_row(row: &str) -> challenge_error::Result<(String, NaiveDate)> {
+    let fields = fields(row);
+    let [name, birth_date] = fields.as_slice() else {
+        return Err(ChallengeError::validation(
+            "row",
+            &format!("needs 2 fields (name, birth_date), not {}", fields.len()),
+        ));
+    };
+    Ok((name.clone(), parse_date(birth_date)?))
+}
+
+/// Works out whether everyone in the CSV read from `reader` may vote at
+/// `voting_age` on `today`, with 29 February birthdays following
+/// `leap_day`, and writes the results to `writer`.
+pub fn run<R: BufRead, W: Write>(
+    reader: R,
+    writer: &mut W,
+    voting_age: i64,
+    leap_day: LeapDay,
+    today: NaiveDate,
+) -> std::io::Result<Summary> {
+    let mut summary = Summary::default();
+    writeln!(writer, "{}", HEADER)?;
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let row = line.trim();
+        if row.is_empty() || row.starts_with('#') || row.to_lowercase().starts_with("name,") {
+            continue;
+        }
+        let number = index + 1;
+        match parse_row(row) {
+            Ok((name, birth_date)) => {
+                let age = birthday::age_on(birth_date, today, leap_day);
+                let eligible = age >= voting_age;
+                if eligible {
+                    summary.eligible += 1;
+                } else {
+                    summary.not_eligible += 1;
+                }
+                let eligible_from = birthday::turns(birth_date, voting_age, leap_day)
+                    .map(|date| date.to_string())
+                    .unwrap_or_default();
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},",
+                    number,
+                    csv_field(&name),
+                    birth_date,
+                    age,
+                    if eligible { "yes" } else { "no" },
+                    eligible_from
+                )?;
+            }
+            Err(e) => {
+                let name = fields(row).swap_remove(0);
+                let error = e.to_string();
+                writeln!(
+                    writer,
+                    "{},{},,,,,{}",
+                    number,
+                    csv_field(&name),
+                    csv_field(&error)
+                )?;
+                summary.failures.push((number, error));
+            }
+        }
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    #[test]
+    fn run_annotates_each_row_and_counts_them() {
+        let input = "name,birth_date\n\
+                     Ada,2000-01-01\n\
+                     \n\
+                     # a comment\n\
+                     \"Doe, Jo\",2008-10-17\n\
+                     Sam,2008-10-16\n\
+                     Max,2008-02-30\n\
+                     Kim\n";
+        let mut output = Vec::new();
+
+        let summary = run(
+            input.as_bytes(),
+            &mut output,
+            18,
+            LeapDay::Feb28,
+            date(2026, 10, 16),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "line,name,birth_date,age,eligible,eligible_from,error\n\
+             2,Ada,2000-01-01,26,yes,2018-01-01,\n\
+             5,\"Doe, Jo\",2008-10-17,17,no,2026-10-17,\n\
+             6,Sam,2008-10-16,18,yes,2026-10-16,\n\
+             7,Max,,,,,invalid input '2008-02-30': input is out of range\n\
+             8,Kim,,,,,\"row needs 2 fields (name, birth_date), not 1\"\n"
+        );
+        assert_eq!(summary.eligible, 2);
+        assert_eq!(summary.not_eligible, 1);
+        let lines: Vec<_> = summary.failures.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, [7, 8]);
+        assert_eq!(
+            summary.to_string(),
+            "2 eligible, 1 not eligible, 2 with errors"
+        );
+    }
+
+    #[test]
+    fn run_follows_the_leap_day_policy() {
+        let input = "Lee,2008-02-29\n";
+        let today = date(2026, 2, 28);
+        let mut feb_28 = Vec::new();
+        let mut mar_1 = Vec::new();
+
+        run(input.as_bytes(), &mut feb_28, 18, LeapDay::Feb28, today).unwrap();
+        run(input.as_bytes(), &mut mar_1, 18, LeapDay::Mar1, today).unwrap();
+
+        assert!(String::from_utf8(feb_28)
+            .unwrap()
+            .ends_with("1,Lee,2008-02-29,18,yes,2026-02-28,\n"));
+        assert!(String::from_utf8(mar_1)
+            .unwrap()
+            .ends_with("1,Lee,2008-02-29,17,no,2026-03-01,\n"));
+    }
+
+    #[test]
+    fn fields_unquotes_quoted_fields() {
+        assert_eq!(fields("a, b ,c"), ["a", "b", "c"]);
+        assert_eq!(fields("\"a, \"\"b\"\"\",c"), ["a, \"b\"", "c"]);
+        assert_eq!(fields(""), [""]);
+    }
+}
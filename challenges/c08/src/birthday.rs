@@ -0,0 +1,105 @@
+//! Ages counted in calendar years, from birthday to birthday, rather than
+//! in blocks of 365 days, which drift by a day every leap year.
+//!
+//! A birthday on 29 February falls on another day in a common year, chosen
+//! by the [`LeapDay`] policy: 28 February by default, as in c05, or 1 March,
+//! the day after the 28th, as the law of some countries has it.
+use chrono::{Datelike, NaiveDate};
+
+/// Where a 29 February birthday falls in a common year.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeapDay {
+    /// On 28 February
+    #[default]
+    #[value(name = "feb-28")]
+    Feb28,
+    /// On 1 March
+    #[value(name = "mar-1")]
+    Mar1,
+}
+
+/// The birthday of someone born on `birth` in `year`, or `None` if the
+/// year is out of the range of supported dates.
+fn birthday_in(birth: NaiveDate, year: i32, leap_day: LeapDay) -> Option<NaiveDate> {
+    birth.with_year(year).or_else(|| match leap_day {
+        LeapDay::Feb28 => NaiveDate::from_ymd_opt(year, 2, 28),
+        LeapDay::Mar1 => NaiveDate::from_ymd_opt(year, 3, 1),
+    })
+}
+
+/// The age on `today` of someone born on `birth`, in whole years, negative
+/// if they are not yet born.
+pub fn age_on(birth: NaiveDate, today: NaiveDate, leap_day: LeapDay) -> i64 {
+    let years = i64::from(today.year() - birth.year());
+    let birthday = birthday_in(birth, today.year(), leap_day);
+    if birthday.is_some_and(|birthday| birthday > today) {
+        years - 1
+    } else {
+        years
+    }
+}
+
+/// The day on which someone born on `birth` turns `age`, or `None` if it is
+/// out of the range of supported dates.
+pub fn turns(birth: NaiveDate, age: i64, leap_day: LeapDay) -> Option<NaiveDate> {
+    let year = i64::from(birth.year()).checked_add(age)?;
+    birthday_in(birth, i32::try_from(year).ok()?, leap_day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    #[test]
+    fn age_on_goes_up_on_the_birthday() {
+        let birth = date(2008, 10, 16);
+        assert_eq!(age_on(birth, date(2026, 10, 15), LeapDay::Feb28), 17);
+        assert_eq!(age_on(birth, date(2026, 10, 16), LeapDay::Feb28), 18);
+        assert_eq!(age_on(birth, date(2008, 10, 16), LeapDay::Feb28), 0);
+        assert_eq!(age_on(birth, date(2007, 10, 17), LeapDay::Feb28), -1);
+    }
+
+    #[test]
+    fn age_on_counts_leap_day_birthdays_from_28_february() {
+        let birth = date(2008, 2, 29);
+        assert_eq!(age_on(birth, date(2026, 2, 27), LeapDay::Feb28), 17);
+        assert_eq!(age_on(birth, date(2026, 2, 28), LeapDay::Feb28), 18);
+        assert_eq!(age_on(birth, date(2028, 2, 28), LeapDay::Feb28), 19);
+        assert_eq!(age_on(birth, date(2028, 2, 29), LeapDay::Feb28), 20);
+    }
+
+    #[test]
+    fn age_on_counts_leap_day_birthdays_from_1_march() {
+        let birth = date(2008, 2, 29);
+        assert_eq!(age_on(birth, date(2026, 2, 28), LeapDay::Mar1), 17);
+        assert_eq!(age_on(birth, date(2026, 3, 1), LeapDay::Mar1), 18);
+        assert_eq!(age_on(birth, date(2028, 2, 29), LeapDay::Mar1), 20);
+        // Only a 29 February birthday moves.
+        assert_eq!(
+            age_on(date(2008, 2, 28), date(2026, 2, 28), LeapDay::Mar1),
+            18
+        );
+    }
+
+    #[test]
+    fn turns_finds_the_birthday() {
+        assert_eq!(
+            turns(date(2010, 5, 1), 18, LeapDay::Feb28),
+            Some(date(2028, 5, 1))
+        );
+        assert_eq!(
+            turns(date(2008, 2, 29), 18, LeapDay::Feb28),
+            Some(date(2026, 2, 28))
+        );
+        assert_eq!(
+            turns(date(2008, 2, 29), 18, LeapDay::Mar1),
+            Some(date(2026, 3, 1))
+        );
+        assert_eq!(
+            turns(date(2008, 2, 29), 20, LeapDay::Mar1),
+            Some(date(2028, 2, 29))
+        );
+        assert_eq!(turns(date(2010, 5, 1), i64::MAX, LeapDay::Feb28), None);
+    }
+}
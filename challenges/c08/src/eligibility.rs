@@ -0,0 +1,180 @@
+//! The age-gated things a report covers with `--report`: voting, driving,
+//! buying alcohol and retiring.
+//!
+//! Each has a minimum age, taken from the jurisdiction named with
+//! `--country` or, where it sets none, from the defaults here, which are
+//! common but far from universal.
+use super::jurisdiction::{Jurisdiction, DEFAULT_VOTING_AGE};
+use challenge_common::l10n;
+use challenge_common::output::{json, Value};
+use std::fmt;
+
+/// Something a person may do from a certain age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item {
+    Vote,
+    Drive,
+    BuyAlcohol,
+    Retire,
+}
+
+impl Item {
+    /// Every item, in the order they are reported.
+    pub const ALL: [Item; 4] = [Item::Vote, Item::Drive, Item::BuyAlcohol, Item::Retire];
+
+    /// The name the item is shown with.
+    pub fn label(self) -> &'static str {
+        l10n::text(&format!("c08.{}", self.key()))
+    }
+
+    /// The item's key in JSON output.
+    pub fn key(self) -> &'static str {
+        match self {
+            Item::Vote => "vote",
+            Item::Drive => "drive",
+            Item::BuyAlcohol => "buy_alcohol",
+            Item::Retire => "retire",
+        }
+    }
+
+    /// The age from which the item is allowed where no jurisdiction says
+    /// otherwise.
+    pub fn default_age(self) -> i64 {
+        match self {
+            Item::Vote => DEFAULT_VOTING_AGE,
+            Item::Drive => 17,
+            Item::BuyAlcohol => 18,
+            Item::Retire => 65,
+        }
+    }
+}
+
+/// Whether a person may do each item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The person's age in years.
+    pub age: i64,
+    /// Each item, with the age it is allowed from and whether the person
+    /// has reached it.
+    pub rows: Vec<(Item, i64, bool)>,
+}
+
+impl Report {
+    /// The report for someone `age` years old in `country`, or where the
+    /// defaults apply if none is given.
+    pub fn new(age: i64, country: Option<&Jurisdiction>) -> Self {
+        let rows = Item::ALL
+            .into_iter()
+            .map(|item| {
+                let from = country.map_or(item.default_age(), |country| country.age(item));
+                (item, from, age >= from)
+            })
+            .collect();
+        Report { age, rows }
+    }
+
+    /// The report as JSON, e.g. `{"vote": {"age": 18, "eligible": true}}`.
+    pub fn json(&self) -> Value {
+        let mut report = json!({});
+        for &(item, from, eligible) in &self.rows {
+            report[item.key()] = json!({ "age": from, "eligible": eligible });
+        }
+        report
+    }
+}
+
+/// The report as a table, e.g.
+///
+/// ```text
+/// Item         From age  Eligible
+/// Vote               18  yes
+/// Drive              17  yes
+/// Buy alcohol        18  yes
+/// Retire             65  no
+/// ```
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let item = l10n::text("c08.column_item");
+        let from_age = l10n::text("c08.column_from_age");
+        let width = Item::ALL
+            .iter()
+            .map(|item| item.label())
+            .chain([item])
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or_default()
+            + 1;
+        let from_width = from_age.chars().count();
+        let eligible = l10n::text("c08.column_eligible");
+        write!(f, "{item:<width$} {from_age:>from_width$}  {eligible}")?;
+        for &(item, from, eligible) in &self.rows {
+            let eligible = l10n::text(if eligible { "c08.yes" } else { "c08.no" });
+            write!(
+                f,
+                "\n{:<width$} {:>from_width$}  {}",
+                item.label(),
+                from,
+                eligible
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jurisdiction;
+
+    #[test]
+    fn new_uses_the_defaults_without_a_country() {
+        let report = Report::new(17, None);
+        assert_eq!(
+            report.rows,
+            [
+                (Item::Vote, 18, false),
+                (Item::Drive, 17, true),
+                (Item::BuyAlcohol, 18, false),
+                (Item::Retire, 65, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_uses_the_ages_of_the_country() {
+        let rules = jurisdiction::built_in();
+        let us = jurisdiction::find(&rules, "us").unwrap();
+        let report = Report::new(20, Some(us));
+        assert_eq!(report.rows[2], (Item::BuyAlcohol, 21, false));
+        let at = jurisdiction::find(&rules, "at").unwrap();
+        let report = Report::new(16, Some(at));
+        assert_eq!(report.rows[0], (Item::Vote, 16, true));
+        assert_eq!(report.rows[1], (Item::Drive, 18, false));
+    }
+
+    #[test]
+    fn display_prints_a_table() {
+        assert_eq!(
+            Report::new(70, None).to_string(),
+            "Item         From age  Eligible\n\
+             Vote               18  yes\n\
+             Drive              17  yes\n\
+             Buy alcohol        18  yes\n\
+             Retire             65  yes"
+        );
+    }
+
+    #[test]
+    fn json_has_an_entry_for_each_item() {
+        let report = Report::new(17, None);
+        assert_eq!(
+            report.json(),
+            json!({
+                "vote": { "age": 18, "eligible": false },
+                "drive": { "age": 17, "eligible": true },
+                "buy_alcohol": { "age": 18, "eligible": false },
+                "retire": { "age": 65, "eligible": false },
+            })
+        );
+    }
+}
@@ -0,0 +1,243 @@
+//! Voting ages by jurisdiction, picked with `--country`, along with the
+//! ages for the rest of the [`eligibility`](super::eligibility) report.
+//!
+//! A handful of jurisdictions come with the program. More can be added, or
+//! the built-in ones changed, with a rules file in TOML, where each entry
+//! replaces any with the same code. Only the voting age is required; the
+//! other ages fall back to the defaults of the report:
+//!
+//! ```toml
+//! [[jurisdiction]]
+//! code = "at"
+//! name = "Austria"
+//! voting_age = 16
+//! driving_age = 18
+//! alcohol_age = 16
+//! retirement_age = 65
+//! ```
+//!
+//! Codes are matched without regard to case, so `AT` and `at` are the same.
+use super::eligibility::Item;
+use challenge_error::ChallengeError;
+use serde::Deserialize;
+use std::path::Path;
+
+/// The voting age when no jurisdiction is named.
+pub const DEFAULT_VOTING_AGE: i64 = 18;
+
+/// The oldest age a rules file may set.
+const MAX_AGE: i64 = 150;
+
+/// The jurisdictions that come with the program: their codes, names, and
+/// the ages for voting in national elections, driving a car unaccompanied,
+/// buying alcohol and drawing a state pension. Where these differ within a
+/// country, the most common age is used.
+const BUILT_IN: &[(&str, &str, [i64; 4])] = &[
+    ("ar", "Argentina", [16, 17, 18, 65]),
+    ("at", "Austria", [16, 18, 16, 65]),
+    ("au", "Australia", [18, 17, 18, 67]),
+    ("br", "Brazil", [16, 18, 18, 65]),
+    ("ca", "Canada", [18, 16, 19, 65]),
+    ("de", "Germany", [18, 18, 16, 67]),
+    ("gb", "the United Kingdom", [18, 17, 18, 66]),
+    ("gr", "Greece", [17, 18, 18, 67]),
+    ("id", "Indonesia", [17, 17, 21, 58]),
+    ("in", "India", [18, 18, 21, 60]),
+    ("jp", "Japan", [18, 18, 20, 65]),
+    ("lb", "Lebanon", [21, 18, 18, 64]),
+    ("mt", "Malta", [16, 18, 17, 65]),
+    ("sg", "Singapore", [21, 18, 18, 63]),
+    ("us", "the United States", [18, 16, 21, 67]),
+];
+
+/// A place with its own voting age, and perhaps its own ages for the rest
+/// of the report.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Jurisdiction {
+    pub code: String,
+    pub name: String,
+    pub voting_age: i64,
+    #[serde(default)]
+    pub driving_age: Option<i64>,
+    #[serde(default)]
+    pub alcohol_age: Option<i64>,
+    #[serde(default)]
+    pub retirement_age: Option<i64>,
+}
+
+impl Jurisdiction {
+    /// The age from which `item` is allowed here.
+    pub fn age(&self, item: Item) -> i64 {
+        let age = match item {
+            Item::Vote => Some(self.voting_age),
+            Item::Drive => self.driving_age,
+            Item::BuyAlcohol => self.alcohol_age,
+            Item::Retire => self.retirement_age,
+        };
+        age.unwrap_or(item.default_age())
+    }
+}
+
+/// The layout of a rules file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(rename = "jurisdiction")]
+    jurisdictions: Vec<Jurisdiction>,
+}
+
+/// The jurisdictions that come with the program.
+pub fn built_in() -> Vec<Jurisdiction> {
+    BUILT_IN
+        .iter()
+        .map(
+            |&(code, name, [vote, drive, alcohol, retire])| Jurisdiction {
+                code: code.to_string(),
+                name: name.to_string(),
+                voting_age: vote,
+                driving_age: Some(drive),
+                alcohol_age: Some(alcohol),
+                retirement_age: Some(retire),
+            },
+        )
+        .collect()
+}
+
+/// Parses a rules file, which needs at least one jurisdiction.
+fn parse_rules(text: &str) -> Result<Vec<Jurisdiction>, String> {
+    let file: RulesFile = toml::from_str(text).map_err(|e| e.message().to_string())?;
+    if file.jurisdictions.is_empty() {
+        return Err("the file has no jurisdictions".to_string());
+    }
+    for (i, jurisdiction) in file.jurisdictions.iter().enumerate() {
+        if jurisdiction.code.trim().is_empty() {
+            return Err(format!("jurisdiction {} has an empty code", i + 1));
+        }
+        for item in Item::ALL {
+            if !(0..=MAX_AGE).contains(&jurisdiction.age(item)) {
+                return Err(format!(
+                    "jurisdiction {} has an age to {} out of the range 0 to {MAX_AGE}",
+                    i + 1,
+                    item.label().to_lowercase()
+                ));
+            }
+        }
+    }
+    Ok(file.jurisdictions)
+}
+
+/// Reads the rules file at `path`.
+pub fn load_rules(path: &Path) -> Result<Vec<Jurisdiction>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    parse_rules(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// `rules` with each of `extra` added, replacing any with the same code.
+pub fn merge(mut rules: Vec<Jurisdiction>, extra: Vec<Jurisdiction>) -> Vec<Jurisdiction> {
+    for jurisdiction in extra {
+        rules.retain(|rule| !rule.code.eq_ignore_ascii_case(&jurisdiction.code));
+        rules.push(jurisdiction);
+    }
+    rules
+}
+
+/// The jurisdiction in `rules` with `code`.
+pub fn find<'a>(
+    rules: &'a [Jurisdiction],
+    code: &str,
+) -> challenge_error::Result<&'a Jurisdiction> {
+    rules
+        .iter()
+        .find(|rule| rule.code.eq_ignore_ascii_case(code.trim()))
+        .ok_or_else(|| {
+            let mut codes: Vec<&str> = rules.iter().map(|rule| rule.code.as_str()).collect();
+            codes.sort_unstable();
+            ChallengeError::validation(
+                "country",
+                &format!("'{code}' is not known (known: {})", codes.join(", ")),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_jurisdictions_differ_in_voting_age() {
+        let rules = built_in();
+        assert_eq!(find(&rules, "at").unwrap().voting_age, 16);
+        assert_eq!(find(&rules, "GR").unwrap().voting_age, 17);
+        assert_eq!(find(&rules, " us ").unwrap().voting_age, 18);
+        assert_eq!(find(&rules, "sg").unwrap().voting_age, 21);
+    }
+
+    #[test]
+    fn age_falls_back_to_the_default() {
+        let rules = parse_rules(
+            "[[jurisdiction]]\ncode = \"a\"\nname = \"A\"\nvoting_age = 16\n\
+             alcohol_age = 21\n",
+        )
+        .unwrap();
+        let a = &rules[0];
+        assert_eq!(a.age(Item::Vote), 16);
+        assert_eq!(a.age(Item::Drive), Item::Drive.default_age());
+        assert_eq!(a.age(Item::BuyAlcohol), 21);
+        assert_eq!(a.age(Item::Retire), Item::Retire.default_age());
+    }
+
+    #[test]
+    fn find_lists_the_known_codes() {
+        let rules = parse_rules(
+            "[[jurisdiction]]\ncode = \"b\"\nname = \"B\"\nvoting_age = 18\n\
+             [[jurisdiction]]\ncode = \"a\"\nname = \"A\"\nvoting_age = 18\n",
+        )
+        .unwrap();
+        assert_eq!(
+            find(&rules, "xx").unwrap_err().to_string(),
+            "country 'xx' is not known (known: a, b)"
+        );
+    }
+
+    #[test]
+    fn merge_replaces_jurisdictions_with_the_same_code() {
+        let extra = parse_rules(
+            "[[jurisdiction]]\ncode = \"US\"\nname = \"Ohio\"\nvoting_age = 17\n\
+             [[jurisdiction]]\ncode = \"xx\"\nname = \"Elsewhere\"\nvoting_age = 20\n",
+        )
+        .unwrap();
+        let rules = merge(built_in(), extra);
+        assert_eq!(rules.len(), BUILT_IN.len() + 1);
+        assert_eq!(find(&rules, "us").unwrap().name, "Ohio");
+        assert_eq!(find(&rules, "us").unwrap().voting_age, 17);
+        assert_eq!(find(&rules, "xx").unwrap().voting_age, 20);
+    }
+
+    #[test]
+    fn parse_rules_explains_bad_files() {
+        assert_eq!(
+            parse_rules("jurisdiction = []").unwrap_err(),
+            "the file has no jurisdictions"
+        );
+        assert_eq!(
+            parse_rules("[[jurisdiction]]\ncode = \"\"\nname = \"A\"\nvoting_age = 18\n")
+                .unwrap_err(),
+            "jurisdiction 1 has an empty code"
+        );
+        assert_eq!(
+            parse_rules("[[jurisdiction]]\ncode = \"a\"\nname = \"A\"\nvoting_age = -1\n")
+                .unwrap_err(),
+            "jurisdiction 1 has an age to vote out of the range 0 to 150"
+        );
+        assert_eq!(
+            parse_rules(
+                "[[jurisdiction]]\ncode = \"a\"\nname = \"A\"\nvoting_age = 18\n\
+                 retirement_age = 200\n"
+            )
+            .unwrap_err(),
+            "jurisdiction 1 has an age to retire out of the range 0 to 150"
+        );
+        assert!(parse_rules("[[jurisdiction]]\ncode = \"a\"\n").is_err());
+    }
+}
@@ -0,0 +1,471 @@
+//! # Voting Eligibility Calculator
+//!
+//! This module provides functionality to determine whether a person is eligible to vote
+//! based on their birth date. It calculates the person's age in calendar years, as
+//! described in the [`birthday`] module, and checks if the person meets the minimum
+//! voting age requirement of 18 years.
+//!
+//! ## Features
+//!
+//! - Calculate the difference in years between dates, with a choice of where a
+//!   29 February birthday falls in other years
+//! - Determine voting eligibility based on age
+//! - Find the date on which a person becomes eligible, and the days until then
+//! - Read and parse user input dates
+//!
+//! ## Usage
+//!
+//! Run the program and enter your birth date in YYYY-MM-DD format when prompted,
+//! or pass it as an argument, e.g. `c08 2000-02-29`.
+//! The program will inform you whether you are eligible to vote based on your age.
+//! With `--format json` the birth date, age, and eligibility are printed as JSON.
+//! With `--country` the voting age of that jurisdiction is used instead of
+//! 18, as described in the [`jurisdiction`] module. With `--report` a table
+//! shows whether you may also drive, buy alcohol and retire, as described in
+//! the [`eligibility`] module.
+//!
+//! `--input people.csv` checks everyone in a CSV file of names and birth
+//! dates instead, as described in the [`batch`] module, and writes the
+//! results as CSV to stdout or to the file named by `--output`, with a
+//! count of who is and is not eligible on stderr.
+use birthday::LeapDay;
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use challenge_error::ChallengeError;
+use chrono::{Local, NaiveDate};
+use clap::Parser;
+use eligibility::Report;
+use jurisdiction::Jurisdiction;
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod batch;
+mod birthday;
+mod eligibility;
+mod jurisdiction;
+#[cfg(test)]
+mod test_util;
+
+fn get_years_difference(input_date: &NaiveDate, leap_day: LeapDay) -> i64 {
+    birthday::age_on(*input_date, Local::now().date_naive(), leap_day)
+}
+
+/// `in 1 day` or e.g. `in 230 days`.
+fn in_days(days: i64) -> String {
+    if days == 1 {
+        l10n::text("c08.in_one_day").to_string()
+    } else {
+        l10n::format("c08.in_days", &[("days", &days)])
+    }
+}
+
+fn is_eligible_to_vote(birth_date: &NaiveDate, voting_age: i64, leap_day: LeapDay) -> bool {
+    get_years_difference(birth_date, leap_day) >= voting_age
+}
+
+fn parse_date(answer: &str) -> challenge_error::Result<NaiveDate> {
+    NaiveDate::parse_from_str(answer, "%Y-%m-%d").map_err(|e| ChallengeError::parse(answer, e))
+}
+
+fn read_user_date<R: std::io::BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> challenge_error::Result<NaiveDate> {
+    Ok(Prompt::new(l10n::text("prompt.birth_date")).ask(reader, writer, parse_date)?)
+}
+
+/// Check whether you are old enough to vote.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Birth date (YYYY-MM-DD), prompted for if omitted
+    #[arg(value_parser = parse_date, conflicts_with = "input")]
+    birth_date: Option<NaiveDate>,
+    /// Code of the jurisdiction whose voting age applies, e.g. at or us
+    #[arg(long, value_name = "CODE")]
+    country: Option<String>,
+    /// TOML file of jurisdictions to add to or replace the built-in ones
+    #[arg(long, value_name = "FILE", requires = "country")]
+    rules: Option<PathBuf>,
+    /// Also show whether you may drive, buy alcohol and retire, as a table
+    #[arg(long, conflicts_with = "input")]
+    report: bool,
+    /// CSV file of names and birth dates to check instead
+    #[arg(long, value_name = "FILE")]
+    input: Option<PathBuf>,
+    /// File to write the results of --input to [default: stdout]
+    #[arg(long, value_name = "FILE", requires = "input")]
+    output: Option<PathBuf>,
+    /// Where a 29 February birthday falls in a common year
+    #[arg(long, value_enum, value_name = "DAY", default_value_t)]
+    leap_day: LeapDay,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// The jurisdiction named with `--country`, looked up among the built-in
+/// ones and those in the `--rules` file.
+fn jurisdiction(args: &Args) -> Result<Option<Jurisdiction>, String> {
+    let Some(code) = &args.country else {
+        return Ok(None);
+    };
+    let mut rules = jurisdiction::built_in();
+    if let Some(path) = &args.rules {
+        rules = jurisdiction::merge(rules, jurisdiction::load_rules(path)?);
+    }
+    let found = jurisdiction::find(&rules, code).map_err(|e| e.to_string())?;
+    Ok(Some(found.clone()))
+}
+
+/// Prints whether someone born on `birth_date` may vote in `format`, in
+/// `country` if given, with a 29 February birthday following `leap_day`,
+/// asking for the date if it was not given. If they may
+/// not, it also prints the day they will be able to and how many days away
+/// that is. The full [`Report`] follows if `report` is set.
+fn run(
+    common: &CommonArgs,
+    birth_date: Option<NaiveDate>,
+    country: Option<&Jurisdiction>,
+    leap_day: LeapDay,
+    report: bool,
+    format: Format,
+) -> challenge_error::Result<()> {
+    let birth_date = match birth_date {
+        Some(date) => date,
+        None => read_user_date(&mut Input::from_args(common)?, &mut format.prompts())?,
+    };
+    let voting_age = country.map_or(jurisdiction::DEFAULT_VOTING_AGE, |country| {
+        country.voting_age
+    });
+    let eligible = is_eligible_to_vote(&birth_date, voting_age, leap_day);
+    let eligible_from = birthday::turns(birth_date, voting_age, leap_day).ok_or_else(|| {
+        ChallengeError::validation("date", "is out of the range of supported dates")
+    })?;
+    let days_until_eligible = (eligible_from - Local::now().date_naive())
+        .num_days()
+        .max(0);
+    let age = get_years_difference(&birth_date, leap_day);
+    let report = report.then(|| Report::new(age, country));
+    match (format, country) {
+        (Format::Text, None) if eligible => println!("{}", l10n::text("c08.eligible")),
+        (Format::Text, None) => println!("{}", l10n::text("c08.not_eligible")),
+        (Format::Text, Some(country)) if eligible => println!(
+            "{}",
+            l10n::format("c08.eligible_in", &[("country", &country.name)])
+        ),
+        (Format::Text, Some(country)) => println!(
+            "{}",
+            l10n::format(
+                "c08.not_eligible_in",
+                &[("country", &country.name), ("age", &country.voting_age)]
+            )
+        ),
+        (Format::Json, _) => {
+            let mut input = json!({ "birth_date": birth_date.to_string() });
+            if let Some(country) = country {
+                input["country"] = json!(country.code);
+            }
+            let mut result = json!({
+                "age_years": age,
+                "voting_age": voting_age,
+                "eligible": eligible,
+                "eligible_from": eligible_from.to_string(),
+                "days_until_eligible": days_until_eligible,
+            });
+            if let Some(report) = &report {
+                result["report"] = report.json();
+            }
+            output::write_json(&mut std::io::stdout(), "c08", input, result)?;
+            return Ok(());
+        }
+    }
+    if !eligible {
+        println!(
+            "{}",
+            l10n::format(
+                "c08.eligible_on",
+                &[
+                    ("date", &eligible_from),
+                    ("when", &in_days(days_until_eligible))
+                ]
+            )
+        );
+    }
+    if let Some(report) = report {
+        println!("\n{report}");
+    }
+    Ok(())
+}
+
+/// Checks the people in the CSV file at `path` and writes the results to
+/// `output`, or to stdout, returning the [`batch::Summary`].
+fn run_batch(
+    path: &Path,
+    output: Option<&Path>,
+    voting_age: i64,
+    leap_day: LeapDay,
+) -> Result<batch::Summary, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let reader = std::io::BufReader::new(file);
+    let today = Local::now().date_naive();
+    match output {
+        Some(output) => {
+            let mut writer = std::fs::File::create(output)
+                .map(std::io::BufWriter::new)
+                .map_err(|e| format!("{}: {e}", output.display()))?;
+            batch::run(reader, &mut writer, voting_age, leap_day, today)
+                .and_then(|summary| writer.flush().map(|()| summary))
+                .map_err(|e| format!("{}: {e}", output.display()))
+        }
+        None => batch::run(
+            reader,
+            &mut std::io::stdout().lock(),
+            voting_age,
+            leap_day,
+            today,
+        )
+        .map_err(|e| format!("{}: {e}", path.display())),
+    }
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+
+    let country = match jurisdiction(&args) {
+        Ok(country) => country,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(path) = &args.input {
+        let voting_age = country
+            .as_ref()
+            .map_or(jurisdiction::DEFAULT_VOTING_AGE, |country| {
+                country.voting_age
+            });
+        match run_batch(path, args.output.as_deref(), voting_age, args.leap_day) {
+            Ok(summary) => {
+                for (line, e) in &summary.failures {
+                    eprintln!("Warning: {} line {}: {}", path.display(), line, e);
+                }
+                eprintln!("{summary}");
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+    if let Err(e) = run(
+        &args.common,
+        args.birth_date,
+        country.as_ref(),
+        args.leap_day,
+        args.report,
+        args.format.format(),
+    ) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Months, NaiveDate};
+
+    /// The date `years` calendar years before today.
+    fn years_ago(years: u32) -> NaiveDate {
+        let today = Local::now().date_naive();
+        today.checked_sub_months(Months::new(years * 12)).unwrap()
+    }
+
+    #[test]
+    fn get_years_difference_computes_past_date() {
+        let past_date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let years = get_years_difference(&past_date, LeapDay::Feb28);
+        assert!(
+            years > 20,
+            "Past date from 2000 should return more than 20 years, got: {}",
+            years
+        );
+    }
+
+    #[test]
+    fn get_years_difference_computes_future_date() {
+        let today = Local::now().date_naive();
+        let future_date = today + chrono::Duration::days(366 * 2); // ~2 years in future
+        let years = get_years_difference(&future_date, LeapDay::Feb28);
+        assert!(years < 0, "Future date should return negative years");
+    }
+
+    #[test]
+    fn get_years_difference_handles_today() {
+        let today = Local::now().date_naive();
+        let years = get_years_difference(&today, LeapDay::Feb28);
+        assert_eq!(years, 0, "Today's date should return zero years");
+    }
+
+    #[test]
+    fn get_years_difference_handles_less_than_year() {
+        let today = Local::now().date_naive();
+        let almost_year_ago = today - chrono::Duration::days(364);
+        let years = get_years_difference(&almost_year_ago, LeapDay::Feb28);
+        assert_eq!(years, 0, "Less than a year ago should return zero years");
+    }
+
+    #[test]
+    fn get_years_difference_handles_one_year() {
+        let today = Local::now().date_naive();
+        let one_year_ago = today - chrono::Duration::days(366);
+        let years = get_years_difference(&one_year_ago, LeapDay::Feb28);
+        assert_eq!(years, 1, "One year ago should return one year");
+    }
+
+    #[test]
+    fn get_years_difference_handles_leap_years() {
+        let today = Local::now().date_naive();
+        // About 4 years ago (including a leap year)
+        let four_years_ago = today - chrono::Duration::days(365 * 4 + 1);
+        let years = get_years_difference(&four_years_ago, LeapDay::Feb28);
+        assert_eq!(years, 4, "Four years ago should return four years");
+    }
+
+    #[test]
+    fn read_user_date_asks_again_until_the_date_is_valid() {
+        let mut reader = "2001-02-29\n2000-02-29\n".as_bytes();
+        assert_eq!(
+            read_user_date(&mut reader, &mut std::io::sink()).unwrap(),
+            NaiveDate::from_ymd_opt(2000, 2, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_date_reports_parse_error() {
+        assert!(matches!(
+            parse_date("2001-02-29"),
+            Err(ChallengeError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn is_eligible_to_vote_accepts_eligible_age() {
+        let birth_date = years_ago(19);
+        assert!(
+            is_eligible_to_vote(
+                &birth_date,
+                jurisdiction::DEFAULT_VOTING_AGE,
+                LeapDay::Feb28
+            ),
+            "19-year-old should be eligible to vote"
+        );
+    }
+
+    #[test]
+    fn is_eligible_to_vote_rejects_underage() {
+        let birth_date = years_ago(17);
+        assert!(
+            !is_eligible_to_vote(
+                &birth_date,
+                jurisdiction::DEFAULT_VOTING_AGE,
+                LeapDay::Feb28
+            ),
+            "17-year-old should not be eligible to vote"
+        );
+    }
+
+    #[test]
+    fn is_eligible_to_vote_accepts_exactly_18_years() {
+        let birth_date = years_ago(18);
+        assert!(
+            is_eligible_to_vote(
+                &birth_date,
+                jurisdiction::DEFAULT_VOTING_AGE,
+                LeapDay::Feb28
+            ),
+            "18-year-old should be eligible to vote"
+        );
+    }
+
+    #[test]
+    fn is_eligible_to_vote_rejects_the_day_before_the_birthday() {
+        let birth_date = years_ago(18) + chrono::Duration::days(1);
+        assert!(
+            !is_eligible_to_vote(
+                &birth_date,
+                jurisdiction::DEFAULT_VOTING_AGE,
+                LeapDay::Feb28
+            ),
+            "Someone turning 18 tomorrow should not be eligible to vote"
+        );
+    }
+
+    #[test]
+    fn is_eligible_to_vote_depends_on_the_voting_age() {
+        let birth_date = years_ago(17);
+        let rules = jurisdiction::built_in();
+        let voting_age = |code| jurisdiction::find(&rules, code).unwrap().voting_age;
+        assert!(is_eligible_to_vote(
+            &birth_date,
+            voting_age("at"),
+            LeapDay::Feb28
+        ));
+        assert!(is_eligible_to_vote(
+            &birth_date,
+            voting_age("gr"),
+            LeapDay::Feb28
+        ));
+        assert!(!is_eligible_to_vote(
+            &birth_date,
+            voting_age("us"),
+            LeapDay::Feb28
+        ));
+        assert!(!is_eligible_to_vote(
+            &birth_date,
+            voting_age("sg"),
+            LeapDay::Feb28
+        ));
+    }
+
+    #[test]
+    fn in_days_counts_the_days() {
+        assert_eq!(in_days(1), "in 1 day");
+        assert_eq!(in_days(230), "in 230 days");
+    }
+
+    #[test]
+    fn args_accept_a_birth_date() {
+        let args = Args::try_parse_from(["c08", "2000-02-29"]).unwrap();
+        assert_eq!(args.birth_date, NaiveDate::from_ymd_opt(2000, 2, 29));
+        assert!(Args::try_parse_from(["c08", "2001-02-29"]).is_err());
+        assert_eq!(Args::try_parse_from(["c08"]).unwrap().birth_date, None);
+    }
+
+    #[test]
+    fn args_take_a_leap_day_policy() {
+        let args = Args::try_parse_from(["c08"]).unwrap();
+        assert_eq!(args.leap_day, LeapDay::Feb28);
+        let args = Args::try_parse_from(["c08", "--leap-day", "mar-1"]).unwrap();
+        assert_eq!(args.leap_day, LeapDay::Mar1);
+        assert!(Args::try_parse_from(["c08", "--leap-day", "feb-29"]).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! Whole hands of cards, dealt at once with `--hand` or by answering the
+//! prompt with a number.
+//!
+//! A hand is sorted by rank, Aces low, and then by suit, with any Jokers
+//! last, and notes what it holds: pairs, three or four of a kind, and a
+//! flush of five or more cards of one suit. Jokers count towards none of
+//! these.
+use super::render::{self, Style};
+use super::shoe::Drawn;
+use challenge_cards::{Card, Rank, Suit};
+use challenge_common::l10n;
+
+/// The most cards a hand can hold: a whole deck.
+pub const MAX_SIZE: usize = 52;
+
+/// The fewest cards of one suit that make a flush.
+const FLUSH: usize = 5;
+
+/// Reads the number of cards in a hand, from 1 to [`MAX_SIZE`].
+pub fn parse_size(text: &str) -> Result<usize, String> {
+    match text.trim().parse() {
+        Ok(size) if (1..=MAX_SIZE).contains(&size) => Ok(size),
+        _ => Err(format!("expected a number of cards from 1 to {MAX_SIZE}")),
+    }
+}
+
+/// Cards dealt together, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<Drawn>,
+}
+
+/// The rank in the plural, e.g. `Aces` or `Sixes`.
+fn plural(rank: Rank) -> &'static str {
+    l10n::text(&format!("c09.plural_{rank:?}").to_lowercase())
+}
+
+impl Hand {
+    /// Sorts `cards` into a hand.
+    pub fn new(mut cards: Vec<Drawn>) -> Self {
+        cards.sort_by_key(|card| match card {
+            Drawn::Card(card) => (false, Some((card.rank, card.suit as u8))),
+            Drawn::Joker => (true, None),
+        });
+        Hand { cards }
+    }
+
+    /// The cards of the hand that are not Jokers.
+    fn plain_cards(&self) -> impl Iterator<Item = &Card> {
+        self.cards.iter().filter_map(|card| match card {
+            Drawn::Card(card) => Some(card),
+            Drawn::Joker => None,
+        })
+    }
+
+    /// What the hand holds, e.g. `a pair of Twos` or `a flush in Hearts`,
+    /// the sets of a kind highest first.
+    pub fn notes(&self) -> Vec<String> {
+        let mut notes = Vec::new();
+        for &rank in Rank::ALL.iter().rev() {
+            let count = self.plain_cards().filter(|card| card.rank == rank).count();
+            let set = match count {
+                0 | 1 => continue,
+                2 => "c09.pair",
+                3 => "c09.three",
+                _ => "c09.four",
+            };
+            notes.push(l10n::format(set, &[("rank", &plural(rank))]));
+        }
+        for suit in Suit::ALL {
+            let count = self.plain_cards().filter(|card| card.suit == suit).count();
+            if count >= FLUSH {
+                notes.push(l10n::format("c09.flush", &[("suit", &suit)]));
+            }
+        }
+        notes
+    }
+}
+
+impl Hand {
+    /// The hand in `style`, then what it holds. Symbols fit on one line,
+    /// plain cards go one to a line and faces go side by side, e.g.
+    ///
+    /// ```text
+    /// Your hand is: 2♥ 2♠ 9♣
+    /// It holds a pair of Twos.
+    ///
+    /// Your hand is:
+    ///   Two of Hearts
+    ///   Two of Spades
+    ///   Nine of Clubs
+    /// It holds a pair of Twos.
+    /// ```
+    pub fn render(&self, style: Style) -> String {
+        let cards = self.cards.iter().map(|&card| render::card(card, style));
+        let heading = l10n::text("c09.hand");
+        let text = match style {
+            Style::Glyphs => {
+                let cards: Vec<_> = cards.map(|card| card.to_string()).collect();
+                format!("{heading} {}", cards.join(" "))
+            }
+            Style::Plain => {
+                let mut text = heading.to_string();
+                for card in cards {
+                    text += &format!("\n  {card}");
+                }
+                text
+            }
+            Style::Faces => format!("{heading}\n{}", render::faces(&self.cards)),
+        };
+        let holds = match self.notes().as_slice() {
+            [] => l10n::text("c09.holds_nothing").to_string(),
+            [note] => l10n::format("c09.holds", &[("notes", note)]),
+            [notes @ .., last] => {
+                let notes = l10n::format("c09.and", &[("rest", &notes.join(", ")), ("last", last)]);
+                l10n::format("c09.holds", &[("notes", &notes)])
+            }
+        };
+        text + "\n" + &holds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &[(Rank, Suit)]) -> Hand {
+        Hand::new(
+            cards
+                .iter()
+                .map(|&(rank, suit)| Drawn::Card(Card::new(rank, suit)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn new_sorts_by_rank_then_suit() {
+        let hand = hand(&[
+            (Rank::King, Suit::Hearts),
+            (Rank::Two, Suit::Spades),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Two, Suit::Hearts),
+        ]);
+        let sorted: Vec<_> = hand.cards.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "Ace of Clubs",
+                "Two of Hearts",
+                "Two of Spades",
+                "King of Hearts"
+            ]
+        );
+    }
+
+    #[test]
+    fn new_puts_jokers_last() {
+        let hand = Hand::new(vec![
+            Drawn::Joker,
+            Drawn::Card(Card::new(Rank::Five, Suit::Clubs)),
+            Drawn::Joker,
+            Drawn::Card(Card::new(Rank::Two, Suit::Clubs)),
+        ]);
+        let sorted: Vec<_> = hand.cards.iter().map(ToString::to_string).collect();
+        assert_eq!(sorted, ["Two of Clubs", "Five of Clubs", "Joker", "Joker"]);
+    }
+
+    #[test]
+    fn notes_finds_sets_and_flushes() {
+        let hand = hand(&[
+            (Rank::Two, Suit::Hearts),
+            (Rank::Two, Suit::Spades),
+            (Rank::Six, Suit::Hearts),
+            (Rank::Six, Suit::Clubs),
+            (Rank::Six, Suit::Diamonds),
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Jack, Suit::Hearts),
+            (Rank::King, Suit::Hearts),
+        ]);
+        assert_eq!(
+            hand.notes(),
+            ["three Sixes", "a pair of Twos", "a flush in Hearts"]
+        );
+        assert!(Hand::new(Vec::new()).notes().is_empty());
+    }
+
+    #[test]
+    fn render_lists_the_cards_and_notes() {
+        colored::control::set_override(false);
+        let pair = hand(&[
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Two, Suit::Spades),
+            (Rank::Two, Suit::Hearts),
+        ]);
+        assert_eq!(
+            pair.render(Style::Plain),
+            "Your hand is:\n  \
+             Two of Hearts\n  \
+             Two of Spades\n  \
+             Nine of Clubs\n\
+             It holds a pair of Twos."
+        );
+        assert_eq!(
+            pair.render(Style::Glyphs),
+            "Your hand is: 2♥ 2♠ 9♣\nIt holds a pair of Twos."
+        );
+        assert!(pair.render(Style::Faces).starts_with(
+            "Your hand is:\n+-------+ +-------+ +-------+\n|2      | |2      | |9      |\n"
+        ));
+        let nothing = hand(&[(Rank::Ace, Suit::Clubs)]);
+        assert!(nothing
+            .render(Style::Glyphs)
+            .ends_with("\nIt holds nothing of note."));
+        colored::control::unset_override();
+    }
+
+    #[test]
+    fn parse_size_needs_a_card_count() {
+        assert_eq!(parse_size(" 5 "), Ok(5));
+        assert_eq!(parse_size("52"), Ok(52));
+        for text in ["0", "53", "five", ""] {
+            assert_eq!(
+                parse_size(text),
+                Err("expected a number of cards from 1 to 52".to_string())
+            );
+        }
+    }
+}
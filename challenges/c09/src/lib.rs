@@ -0,0 +1,329 @@
+//! # Random Card Generator
+//!
+//! This module implements a simple program that generates random playing cards.
+//! It allows users to repeatedly generate cards from a standard deck and
+//! decide whether to continue or exit.
+//!
+//! ## Features
+//!
+//! - Generates random playing cards with suits (Hearts, Diamonds, Clubs, Spades)
+//!   and ranks (Ace through King), shown as e.g. `Q♥` or written out like the
+//!   other card games
+//! - Provides deterministic functions that accept random number generators for testing
+//! - Includes comprehensive test suite to verify randomness and distribution
+//!
+//! The implementation ensures even distribution of both ranks and suits over
+//! a large number of generations, as verified by the test suite.
+//!
+//! Each card is drawn from a full deck, so the same card can come up twice in
+//! a row. With `--deck` the cards are dealt from one shuffled deck instead,
+//! without repeats until all 52 have been dealt, and the deck is shuffled
+//! again once it runs out. `--decks 6` deals from six decks shuffled
+//! together instead, and `--jokers` adds two Jokers to each deck, as
+//! described in the [`shoe`] module. `--odds` shows after each deal the odds
+//! of the next card, as described in the [`odds`] module.
+//!
+//! Cards are shown by rank and suit symbol, e.g. `Q♥`, in the colors of
+//! their suits, written out in full with `--plain`, or drawn as card faces
+//! side by side with `--faces`, as described in the [`render`] module.
+//!
+//! `--hand 5`, or answering the prompt with a number, deals a whole sorted
+//! hand at once instead, as described in the [`hand`] module. Answering `yes`
+//! deals another of the same, card or hand.
+//!
+//! Every card is drawn and every deck shuffled with the generator passed to
+//! [`deal_with_rng`], which is seeded from `--seed`, so the same seed deals
+//! the same cards in the same order each time.
+use challenge_cards::{Card, Rank, Suit};
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use challenge_common::rng::RngArgs;
+use challenge_common::theme::{self, ThemeArgs};
+use clap::Parser;
+use hand::Hand;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use render::Style;
+use shoe::{Drawn, Shoe};
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+mod hand;
+mod odds;
+mod render;
+mod shoe;
+
+/// Picks any card, as if drawn from a full deck that is then put back
+/// together.
+fn get_rand_card_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Card {
+    let rank = *Rank::ALL.choose(rng).unwrap();
+    let suit = *Suit::ALL.choose(rng).unwrap();
+    Card::new(rank, suit)
+}
+
+/// Deals `count` cards, shuffling with `rng`: from `shoe` if there is one,
+/// and otherwise from a full deck, with its Jokers if `jokers`, that is put
+/// back together afterwards. Returns the cards and whether a new shoe was
+/// shuffled.
+fn deal_with_rng<R: Rng + ?Sized>(
+    shoe: Option<&mut Shoe>,
+    count: usize,
+    jokers: bool,
+    rng: &mut R,
+) -> (Vec<Drawn>, bool) {
+    match shoe {
+        Some(shoe) => shoe.deal_with_rng(count, rng),
+        None if count == 1 && !jokers => (vec![Drawn::Card(get_rand_card_with_rng(rng))], false),
+        None => (
+            Shoe::new_with_rng(1, jokers, rng)
+                .deal_with_rng(count, rng)
+                .0,
+            false,
+        ),
+    }
+}
+
+/// What to do after a card or hand is dealt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Choice {
+    /// Deal another of the same, card or hand.
+    Again,
+    /// Deal a hand of this many cards.
+    Hand(usize),
+    Stop,
+}
+
+/// Reads the answer to the prompt after each deal: `yes`, `no`, or the
+/// number of cards in a hand.
+fn parse_choice(answer: &str) -> Result<Choice, &'static str> {
+    match answer {
+        "yes" => Ok(Choice::Again),
+        "no" => Ok(Choice::Stop),
+        _ => hand::parse_size(answer)
+            .map(Choice::Hand)
+            .map_err(|_| l10n::text("c09.invalid")),
+    }
+}
+
+/// `1 card` or e.g. `51 cards`.
+fn cards(count: usize) -> String {
+    if count == 1 {
+        l10n::text("c09.one_card").to_string()
+    } else {
+        l10n::format("c09.cards", &[("count", &count)])
+    }
+}
+
+/// Draw random playing cards.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Deal from a shuffled deck, with no card repeated until all 52 are dealt
+    #[arg(long)]
+    deck: bool,
+    /// Deal from N decks shuffled together, like a casino shoe (implies --deck)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(1..=8))]
+    decks: Option<u8>,
+    /// Add two Jokers to each deck
+    #[arg(long)]
+    jokers: bool,
+    /// Show the odds of the next card after each deal (implies --deck)
+    #[arg(long)]
+    odds: bool,
+    /// Write cards out in full, e.g. Queen of Hearts, instead of Q♥
+    #[arg(long)]
+    plain: bool,
+    /// Draw each card as a card face, with a hand's cards side by side
+    #[arg(long, conflicts_with = "plain")]
+    faces: bool,
+    /// Deal a sorted hand of N cards at a time instead of a single card
+    #[arg(long, value_name = "N", value_parser = hand::parse_size)]
+    hand: Option<usize>,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    theme::init(&args.theme);
+    let mut reader = match Input::from_args(&args.common) {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(reader.default_seed());
+
+    println!("{}", l10n::text("c09.intro"));
+    let decks = usize::from(args.decks.unwrap_or(1));
+    let mut shoe = (args.deck || args.decks.is_some() || args.odds)
+        .then(|| Shoe::new_with_rng(decks, args.jokers, &mut rng));
+    let style = if args.plain {
+        Style::Plain
+    } else if args.faces {
+        Style::Faces
+    } else {
+        Style::Glyphs
+    };
+    let mut hand_size = args.hand;
+    loop {
+        let count = hand_size.unwrap_or(1);
+        let (dealt, reshuffled) = deal_with_rng(shoe.as_mut(), count, args.jokers, &mut rng);
+        if reshuffled && hand_size.is_some() {
+            println!("{}", l10n::text("c09.reshuffled_hand"));
+        } else if reshuffled {
+            println!("{}", l10n::text("c09.reshuffled"));
+        }
+        let left = shoe
+            .as_ref()
+            .map(|shoe| l10n::format("c09.left", &[("cards", &cards(shoe.remaining()))]));
+        let heading = l10n::text("c09.card");
+        let text = match hand_size {
+            Some(_) => Hand::new(dealt).render(style),
+            None if style == Style::Faces => format!("{heading}\n{}", render::faces(&dealt)),
+            None => format!("{heading} {}", render::card(dealt[0], style)),
+        };
+        // A single card on one line has the cards left after it.
+        match left {
+            Some(left) if text.contains('\n') => println!("{text}\n{left}"),
+            Some(left) => println!("{text} {left}"),
+            None => println!("{text}"),
+        }
+        if let (true, Some(shoe)) = (args.odds, &shoe) {
+            println!("{}", odds::Odds::new(shoe.cards()));
+        }
+
+        let choice = Prompt::new(l10n::text("c09.another")).ask(
+            &mut reader,
+            &mut std::io::stdout(),
+            parse_choice,
+        );
+        match choice {
+            Ok(Choice::Again) => continue,
+            Ok(Choice::Hand(size)) => hand_size = Some(size),
+            Ok(Choice::Stop) => break,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn parse_choice_accepts_a_hand_size() {
+        assert_eq!(parse_choice("yes"), Ok(Choice::Again));
+        assert_eq!(parse_choice("no"), Ok(Choice::Stop));
+        assert_eq!(parse_choice("5"), Ok(Choice::Hand(5)));
+        assert_eq!(parse_choice("53"), Err(l10n::text("c09.invalid")));
+        assert_eq!(parse_choice("maybe"), Err(l10n::text("c09.invalid")));
+    }
+
+    #[test]
+    fn cards_counts_the_cards() {
+        assert_eq!(cards(1), "1 card");
+        assert_eq!(cards(0), "0 cards");
+        assert_eq!(cards(51), "51 cards");
+    }
+
+    #[test]
+    fn deal_with_rng_is_reproducible_with_a_seed() {
+        let session = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut shoe = Shoe::new_with_rng(1, false, &mut rng);
+            let mut dealt = Vec::new();
+            for count in [1, 5, 52] {
+                dealt.push(deal_with_rng(Some(&mut shoe), count, false, &mut rng));
+                dealt.push(deal_with_rng(None, count, true, &mut rng));
+            }
+            dealt
+        };
+        assert_eq!(session(7), session(7));
+        assert_ne!(session(7), session(8));
+    }
+
+    #[test]
+    fn get_rand_card_returns_different_cards_with_seeded_rng() {
+        let mut seeded_rng = StdRng::seed_from_u64(42); // Deterministic seed
+        let cards: HashSet<_> = (0..20)
+            .map(|_| get_rand_card_with_rng(&mut seeded_rng))
+            .collect();
+
+        assert!(cards.len() > 1, "Expected multiple random results");
+    }
+
+    #[test]
+    fn get_rand_card_distributes_ranks_evenly() {
+        let mut seeded_rng = StdRng::seed_from_u64(100);
+        let mut rank_counts = HashMap::new();
+
+        // Generate a large number of cards to check distribution
+        const ITERATIONS: usize = 1000;
+        for _ in 0..ITERATIONS {
+            let card = get_rand_card_with_rng(&mut seeded_rng);
+            *rank_counts.entry(card.rank).or_insert(0) += 1;
+        }
+
+        // Check that all 13 ranks appear in the distribution
+        assert_eq!(
+            rank_counts.len(),
+            13,
+            "Should have all 13 ranks represented"
+        );
+
+        // Each rank should appear approximately 1000/13 ≈ 77 times
+        // Allow for some statistical variance (50% margin)
+        for count in rank_counts.values() {
+            assert!(*count > 30, "Each rank should appear multiple times");
+            assert!(*count < 120, "No rank should be overly represented");
+        }
+    }
+
+    #[test]
+    fn get_rand_card_distributes_suits_evenly() {
+        let mut seeded_rng = StdRng::seed_from_u64(100);
+        let mut suit_counts = HashMap::new();
+
+        // Generate a large number of cards to check distribution
+        const ITERATIONS: usize = 1000;
+        for _ in 0..ITERATIONS {
+            let card = get_rand_card_with_rng(&mut seeded_rng);
+            *suit_counts.entry(card.suit).or_insert(0) += 1;
+        }
+
+        // Check that all 4 suits appear in the distribution
+        assert_eq!(suit_counts.len(), 4, "Should have all 4 suits represented");
+
+        // Each suit should appear approximately 1000/4 = 250 times
+        // Allow for some statistical variance (40% margin)
+        for count in suit_counts.values() {
+            assert!(*count > 150, "Each suit should appear multiple times");
+            assert!(*count < 350, "No suit should be overly represented");
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! The odds of the next card, shown after each deal with `--odds`.
+//!
+//! The odds come from the cards left in the shoe, so they shift as cards are
+//! dealt: once three Queens are out, the chance of another is one in however
+//! many cards are left.
+use super::shoe::Drawn;
+use challenge_cards::{Card, Rank};
+use challenge_common::l10n;
+use std::fmt;
+
+/// How the cards left in a shoe break down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Odds {
+    /// The number of cards left.
+    pub total: usize,
+    pub red: usize,
+    /// Jacks, Queens and Kings.
+    pub face: usize,
+    pub jokers: usize,
+    /// The number left of each rank, Ace first.
+    pub ranks: [usize; 13],
+}
+
+/// Whether `card` is a Jack, Queen or King.
+fn is_face(card: &Card) -> bool {
+    matches!(card.rank, Rank::Jack | Rank::Queen | Rank::King)
+}
+
+impl Odds {
+    /// The odds for a shoe with `cards` left in it.
+    pub fn new(cards: &[Drawn]) -> Self {
+        let mut odds = Odds {
+            total: cards.len(),
+            red: 0,
+            face: 0,
+            jokers: 0,
+            ranks: [0; 13],
+        };
+        for drawn in cards {
+            let Drawn::Card(card) = drawn else {
+                odds.jokers += 1;
+                continue;
+            };
+            odds.red += usize::from(card.suit.is_red());
+            odds.face += usize::from(is_face(card));
+            odds.ranks[card.rank as usize] += 1;
+        }
+        odds
+    }
+
+    /// `count` of the cards left as a percentage.
+    fn percent(&self, count: usize) -> f64 {
+        100.0 * count as f64 / self.total as f64
+    }
+
+    /// `count` in the cards left, e.g. `3 in 51 (5.9%)`.
+    fn chance(&self, count: usize) -> String {
+        l10n::format(
+            "c09.chance",
+            &[
+                ("count", &count),
+                ("total", &self.total),
+                ("percent", &format!("{:.1}", self.percent(count))),
+            ],
+        )
+    }
+}
+
+/// The odds over several lines, e.g.
+///
+/// ```text
+/// Odds of the next card:
+///   Red: 25 in 51 (49.0%)
+///   Face card: 11 in 51 (21.6%)
+///   By rank: A 7.8%, 2 7.8%, ... Q 5.9%, K 7.8%
+/// ```
+///
+/// Jokers get a line of their own when there are any.
+impl fmt::Display for Odds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.total == 0 {
+            return write!(f, "{}", l10n::text("c09.odds_empty"));
+        }
+        let line = |key, count| l10n::format(key, &[("chance", &self.chance(count))]);
+        writeln!(f, "{}", l10n::text("c09.odds"))?;
+        writeln!(f, "  {}", line("c09.odds_red", self.red))?;
+        writeln!(f, "  {}", line("c09.odds_face", self.face))?;
+        if self.jokers > 0 {
+            writeln!(f, "  {}", line("c09.odds_joker", self.jokers))?;
+        }
+        let ranks: Vec<_> = Rank::ALL
+            .iter()
+            .zip(self.ranks)
+            .map(|(rank, count)| format!("{} {:.1}%", rank.short_name(), self.percent(count)))
+            .collect();
+        let ranks = ranks.join(", ");
+        write!(
+            f,
+            "  {}",
+            l10n::format("c09.odds_rank", &[("ranks", &ranks)])
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_cards::{Deck, Suit};
+
+    fn full_deck() -> Vec<Drawn> {
+        let mut deck = Deck::new();
+        std::iter::from_fn(|| deck.deal())
+            .map(Drawn::Card)
+            .collect()
+    }
+
+    #[test]
+    fn new_counts_the_cards_left() {
+        let mut cards = full_deck();
+        cards.retain(|&card| card != Drawn::Card(Card::new(Rank::Queen, Suit::Hearts)));
+        cards.push(Drawn::Joker);
+
+        let odds = Odds::new(&cards);
+
+        assert_eq!(odds.total, 52);
+        assert_eq!(odds.red, 25);
+        assert_eq!(odds.face, 11);
+        assert_eq!(odds.jokers, 1);
+        assert_eq!(odds.ranks[Rank::Queen as usize], 3);
+        assert_eq!(odds.ranks[Rank::Ace as usize], 4);
+    }
+
+    #[test]
+    fn display_gives_each_chance() {
+        let mut cards = full_deck();
+        cards.retain(|&card| card != Drawn::Card(Card::new(Rank::Queen, Suit::Hearts)));
+
+        assert_eq!(
+            Odds::new(&cards).to_string(),
+            "Odds of the next card:\n  \
+             Red: 25 in 51 (49.0%)\n  \
+             Face card: 11 in 51 (21.6%)\n  \
+             By rank: A 7.8%, 2 7.8%, 3 7.8%, 4 7.8%, 5 7.8%, 6 7.8%, 7 7.8%, \
+             8 7.8%, 9 7.8%, 10 7.8%, J 7.8%, Q 5.9%, K 7.8%"
+        );
+        assert_eq!(
+            Odds::new(&[]).to_string(),
+            "No cards are left, so the next comes from a new deck."
+        );
+    }
+
+    #[test]
+    fn display_gives_jokers_a_line_when_there_are_any() {
+        let odds = Odds::new(&[Drawn::Joker, Drawn::Card(Card::new(Rank::Two, Suit::Clubs))]);
+        assert!(odds.to_string().contains("\n  Joker: 1 in 2 (50.0%)\n"));
+    }
+}
@@ -0,0 +1,177 @@
+//! How cards are shown: by their corner index and suit symbol, e.g. `Q♥`,
+//! or with `--plain` written out in full, e.g. `Queen of Hearts`, for
+//! terminals without Unicode, or with `--faces` drawn as card faces, e.g.
+//!
+//! ```text
+//! +-------+
+//! |Q      |
+//! |       |
+//! |   ♥   |
+//! |       |
+//! |      Q|
+//! +-------+
+//! ```
+//!
+//! Any way Hearts and Diamonds are red and Clubs and Spades are white, or
+//! black with the light theme, unless colors are turned off.
+use super::shoe::Drawn;
+use challenge_cards::Suit;
+use challenge_common::l10n;
+use challenge_common::theme::{self, Theme};
+use colored::{Color, ColoredString, Colorize};
+
+/// The way cards are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Rank and suit symbol, e.g. `Q♥`
+    Glyphs,
+    /// Rank and suit in words, e.g. `Queen of Hearts`
+    Plain,
+    /// Card faces drawn over several lines by [`faces`]
+    Faces,
+}
+
+/// The width of a card face inside its border.
+const FACE_WIDTH: usize = 7;
+
+/// The most card faces side by side, so a row fits in 80 columns.
+const FACES_PER_ROW: usize = 8;
+
+/// The color of cards of `suit` with `theme`.
+fn suit_color(suit: Suit, theme: Theme) -> Color {
+    match (suit.is_red(), theme) {
+        (true, Theme::HighContrast) => Color::BrightRed,
+        (true, _) => Color::Red,
+        (false, Theme::Light) => Color::Black,
+        (false, Theme::HighContrast) => Color::BrightWhite,
+        (false, _) => Color::White,
+    }
+}
+
+/// `drawn` written in `style` on one line, in the color of its suit. Faces
+/// take several lines, so on one line they are written with symbols.
+pub fn card(drawn: Drawn, style: Style) -> ColoredString {
+    let Drawn::Card(card) = drawn else {
+        return drawn.to_string().normal();
+    };
+    let text = match style {
+        Style::Glyphs | Style::Faces => {
+            format!("{}{}", card.rank.short_name(), card.suit.symbol())
+        }
+        Style::Plain => card.to_string(),
+    };
+    text.color(suit_color(card.suit, theme::current()))
+}
+
+/// The lines of the face of `drawn`: a border, the rank in the top left and
+/// bottom right corners and the suit symbol in the middle. A Joker has its
+/// name in the middle instead.
+fn face(drawn: Drawn) -> Vec<ColoredString> {
+    let (rank, middle) = match drawn {
+        Drawn::Card(card) => (card.rank.short_name(), card.suit.symbol().to_string()),
+        Drawn::Joker => ("", l10n::text("c09.joker").to_uppercase()),
+    };
+    let border = format!("+{}+", "-".repeat(FACE_WIDTH));
+    let blank = format!("|{}|", " ".repeat(FACE_WIDTH));
+    let lines = [
+        border.clone(),
+        format!("|{rank:<FACE_WIDTH$}|"),
+        blank.clone(),
+        format!("|{middle:^FACE_WIDTH$}|"),
+        blank,
+        format!("|{rank:>FACE_WIDTH$}|"),
+        border,
+    ];
+    lines
+        .into_iter()
+        .map(|line| match drawn {
+            Drawn::Card(card) => line.color(suit_color(card.suit, theme::current())),
+            Drawn::Joker => line.normal(),
+        })
+        .collect()
+}
+
+/// The faces of `cards` side by side, in rows of up to [`FACES_PER_ROW`].
+pub fn faces(cards: &[Drawn]) -> String {
+    let mut lines = Vec::new();
+    for row in cards.chunks(FACES_PER_ROW) {
+        let faces: Vec<_> = row.iter().map(|&card| face(card)).collect();
+        for line in 0..faces.first().map_or(0, Vec::len) {
+            let parts: Vec<_> = faces.iter().map(|face| face[line].to_string()).collect();
+            lines.push(parts.join(" "));
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_cards::{Card, Rank};
+
+    #[test]
+    fn card_uses_symbols_unless_plain() {
+        let queen = Drawn::Card(Card::new(Rank::Queen, Suit::Hearts));
+        assert_eq!(card(queen, Style::Glyphs).input, "Q♥");
+        assert_eq!(card(queen, Style::Plain).input, "Queen of Hearts");
+        let ten = Drawn::Card(Card::new(Rank::Ten, Suit::Spades));
+        assert_eq!(card(ten, Style::Glyphs).input, "10♠");
+        assert_eq!(card(Drawn::Joker, Style::Glyphs).input, "Joker");
+        assert_eq!(card(ten, Style::Faces).input, "10♠");
+    }
+
+    #[test]
+    fn faces_draws_cards_side_by_side() {
+        colored::control::set_override(false);
+        let cards = [
+            Drawn::Card(Card::new(Rank::Queen, Suit::Hearts)),
+            Drawn::Card(Card::new(Rank::Ten, Suit::Spades)),
+            Drawn::Joker,
+        ];
+        assert_eq!(
+            faces(&cards),
+            "+-------+ +-------+ +-------+\n\
+             |Q      | |10     | |       |\n\
+             |       | |       | |       |\n\
+             |   ♥   | |   ♠   | | JOKER |\n\
+             |       | |       | |       |\n\
+             |      Q| |     10| |       |\n\
+             +-------+ +-------+ +-------+"
+        );
+        colored::control::unset_override();
+    }
+
+    #[test]
+    fn faces_wraps_rows_of_cards() {
+        colored::control::set_override(false);
+        let cards = [Drawn::Joker; FACES_PER_ROW + 1];
+        let lines: Vec<_> = faces(&cards).lines().map(str::len).collect();
+        assert_eq!(lines.len(), 14);
+        assert_eq!(lines[0], FACES_PER_ROW * 10 - 1);
+        assert_eq!(lines[7], 9);
+        colored::control::unset_override();
+    }
+
+    #[test]
+    fn card_colors_by_suit() {
+        let heart = Drawn::Card(Card::new(Rank::Ace, Suit::Hearts));
+        let club = Drawn::Card(Card::new(Rank::Ace, Suit::Clubs));
+        assert_eq!(card(heart, Style::Glyphs).fgcolor, Some(Color::Red));
+        assert_eq!(card(club, Style::Plain).fgcolor, Some(Color::White));
+        assert_eq!(card(Drawn::Joker, Style::Glyphs).fgcolor, None);
+    }
+
+    #[test]
+    fn suit_color_suits_the_theme() {
+        assert_eq!(suit_color(Suit::Spades, Theme::Light), Color::Black);
+        assert_eq!(suit_color(Suit::Diamonds, Theme::Light), Color::Red);
+        assert_eq!(
+            suit_color(Suit::Clubs, Theme::HighContrast),
+            Color::BrightWhite
+        );
+        assert_eq!(
+            suit_color(Suit::Hearts, Theme::HighContrast),
+            Color::BrightRed
+        );
+    }
+}
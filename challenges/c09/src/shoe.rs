@@ -0,0 +1,169 @@
+//! The cards dealt with `--deck`: one or more standard decks shuffled
+//! together, like the shoe of a casino table, with two Jokers per deck when
+//! `--jokers` is given.
+//!
+//! Cards are dealt from the top without being put back, and a new shoe is
+//! shuffled once too few are left. The `_with_rng` functions shuffle with the
+//! generator they are given, so a seeded one deals the same cards every time.
+use challenge_cards::{Card, Deck};
+use challenge_common::l10n;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt;
+
+/// The Jokers that come with each deck.
+const JOKERS_PER_DECK: usize = 2;
+
+/// A card dealt from a shoe, which may be a Joker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Drawn {
+    Card(Card),
+    Joker,
+}
+
+impl fmt::Display for Drawn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Drawn::Card(card) => write!(f, "{card}"),
+            Drawn::Joker => write!(f, "{}", l10n::text("c09.joker")),
+        }
+    }
+}
+
+/// Shuffled cards dealt from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shoe {
+    decks: usize,
+    jokers: bool,
+    cards: Vec<Drawn>,
+}
+
+impl Shoe {
+    /// A shoe of `decks` decks, with their Jokers if `jokers`, shuffled with
+    /// `rng`.
+    pub fn new_with_rng<R: Rng + ?Sized>(decks: usize, jokers: bool, rng: &mut R) -> Self {
+        let mut shoe = Shoe {
+            decks,
+            jokers,
+            cards: Vec::new(),
+        };
+        shoe.refill(rng);
+        shoe
+    }
+
+    /// Puts every card back and shuffles them with `rng`.
+    fn refill<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut deck = Deck::with_decks(self.decks);
+        self.cards = std::iter::from_fn(|| deck.deal())
+            .map(Drawn::Card)
+            .collect();
+        if self.jokers {
+            let jokers = self.decks * JOKERS_PER_DECK;
+            self.cards.extend(std::iter::repeat_n(Drawn::Joker, jokers));
+        }
+        self.cards.shuffle(rng);
+    }
+
+    /// Deals `count` cards from the top, first shuffling a new shoe with
+    /// `rng` if too few are left. Returns the cards and whether a new shoe
+    /// was shuffled.
+    pub fn deal_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        count: usize,
+        rng: &mut R,
+    ) -> (Vec<Drawn>, bool) {
+        let reshuffled = self.cards.len() < count;
+        if reshuffled {
+            self.refill(rng);
+        }
+        let at = self.cards.len().saturating_sub(count);
+        let mut dealt = self.cards.split_off(at);
+        dealt.reverse();
+        (dealt, reshuffled)
+    }
+
+    /// Returns the number of cards left to deal.
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// The cards left to deal, the top one last.
+    pub fn cards(&self) -> &[Drawn] {
+        &self.cards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn deal_repeats_no_card_until_the_shoe_runs_out() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut shoe = Shoe::new_with_rng(1, false, &mut rng);
+        let dealt: Vec<_> = (0..52).map(|_| shoe.deal_with_rng(1, &mut rng)).collect();
+
+        assert!(dealt.iter().all(|(_, reshuffled)| !reshuffled));
+        let unique: HashSet<_> = dealt.iter().map(|(cards, _)| cards[0]).collect();
+        assert_eq!(unique.len(), 52);
+        assert_eq!(shoe.remaining(), 0);
+
+        let (_, reshuffled) = shoe.deal_with_rng(1, &mut rng);
+        assert!(reshuffled);
+        assert_eq!(shoe.remaining(), 51);
+    }
+
+    #[test]
+    fn deal_shuffles_a_new_shoe_when_too_few_are_left() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut shoe = Shoe::new_with_rng(1, false, &mut rng);
+        let (cards, reshuffled) = shoe.deal_with_rng(50, &mut rng);
+        assert!(!reshuffled);
+        assert_eq!(cards.len(), 50);
+        assert_eq!(shoe.remaining(), 2);
+
+        let (cards, reshuffled) = shoe.deal_with_rng(5, &mut rng);
+        assert!(reshuffled);
+        assert_eq!(cards.len(), 5);
+        assert_eq!(shoe.remaining(), 47);
+    }
+
+    #[test]
+    fn new_holds_every_deck_and_its_jokers() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut shoe = Shoe::new_with_rng(6, true, &mut rng);
+        assert_eq!(shoe.remaining(), 6 * 54);
+        let (cards, _) = shoe.deal_with_rng(6 * 54, &mut rng);
+        let jokers = cards.iter().filter(|&&card| card == Drawn::Joker).count();
+        assert_eq!(jokers, 12);
+        let unique: HashSet<_> = cards.iter().collect();
+        assert_eq!(unique.len(), 53);
+
+        assert_eq!(Shoe::new_with_rng(2, false, &mut rng).remaining(), 104);
+    }
+
+    #[test]
+    fn the_same_seed_deals_the_same_cards() {
+        let deal_all = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut shoe = Shoe::new_with_rng(1, true, &mut rng);
+            let mut dealt = shoe.deal_with_rng(54, &mut rng).0;
+            // The second deal shuffles a new shoe with the same generator.
+            dealt.extend(shoe.deal_with_rng(5, &mut rng).0);
+            dealt
+        };
+        assert_eq!(deal_all(42), deal_all(42));
+        assert_ne!(deal_all(42), deal_all(43));
+    }
+
+    #[test]
+    fn deal_takes_from_the_top() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut shoe = Shoe::new_with_rng(1, true, &mut rng);
+        let top: Vec<_> = shoe.cards.iter().rev().take(3).copied().collect();
+        assert_eq!(shoe.deal_with_rng(3, &mut rng).0, top);
+    }
+}
@@ -0,0 +1,153 @@
+//! # Rock, Paper, Scissors Game
+//!
+//! This module implements a simple interactive Rock, Paper, Scissors game.
+//! It allows players to make moves against a computer opponent and tracks
+//! win/loss/tie results.
+//!
+//! ## Features
+//!
+//! - Interactive gameplay with keyboard input
+//! - Random computer move generation
+//! - Game state tracking (win, lose, tie)
+//! - Case-insensitive input handling
+//! - Clear game result feedback
+//! - Lifetime win/loss/tie totals and move choices saved between sessions,
+//!   shown with `--stats`
+//! - Best-of matches with a running score, with `--best-of` or by entering
+//!   an odd number at the first prompt
+//! - Matches against another player over the network with `--host` and
+//!   `--join`
+//!
+//! The implementation follows standard Rock-Paper-Scissors rules where:
+//! Rock beats Scissors, Paper beats Rock, and Scissors beats Paper.
+use crate::net;
+use crate::stats;
+use crate::{run, run_best_of};
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use challenge_common::sound::{self, SoundArgs};
+use challenge_netplay::{Lobby, Mode, NetArgs, Side};
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+/// Play Rock, Paper, Scissors against the computer.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number of rounds in a match over the network
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    rounds: u32,
+
+    /// Play a best-of-N match against the computer, N odd, instead of
+    /// playing until you quit
+    #[arg(long, value_name = "N", value_parser = crate::parse_best_of, conflicts_with_all = ["host", "join"])]
+    best_of: Option<u32>,
+
+    /// Show your lifetime win rate and favorite move instead of playing
+    #[arg(long, conflicts_with_all = ["best_of", "host", "join"])]
+    stats: bool,
+
+    #[command(flatten)]
+    net: NetArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    sound: SoundArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    sound::init(&args.sound);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    if args.stats {
+        match stats::load_lifetime() {
+            Ok(lifetime) => println!("{}", stats::report(&lifetime)),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let mode = match args.net.mode() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(mode) = mode {
+        let mut stdout = std::io::stdout();
+        let result = match mode {
+            Mode::Host(addr) => Lobby::bind(&addr, "c10", &args.net.name(Side::Host))
+                .and_then(|lobby| net::host(&mut input, &mut stdout, &lobby, args.rounds)),
+            Mode::Join(addr) => {
+                net::join(&mut input, &mut stdout, &addr, &args.net.name(Side::Guest))
+            }
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        sound::wait();
+        return ExitCode::SUCCESS;
+    }
+
+    let mut stdout = std::io::stdout();
+    let tally = match match args.best_of {
+        Some(rounds) => run_best_of(&mut input, &mut stdout, rounds, &mut rng),
+        None => run(&mut input, &mut stdout, &mut rng),
+    } {
+        Ok(tally) => tally,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    sound::wait();
+    match stats::record(tally) {
+        Ok(lifetime) => {
+            println!(
+                "{}",
+                l10n::format(
+                    "c10.lifetime",
+                    &[
+                        ("wins", &lifetime.wins),
+                        ("losses", &lifetime.losses),
+                        ("ties", &lifetime.ties),
+                    ],
+                )
+            );
+            println!(
+                "{}",
+                l10n::format("c10.best", &[("best", &lifetime.best_streak)])
+            );
+        }
+        Err(e) => eprintln!("Warning: unable to save your record: {}", e),
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,596 @@
+//! # Rock, Paper, Scissors Game
+//!
+//! The rules and the game, kept apart from the command line so that the
+//! browser build can play them too.
+//!
+//! Rounds go on until the player quits, unless they pick a best-of match,
+//! with `--best-of` or by answering the first prompt with an odd number. A
+//! best-of-5 match goes to whoever wins 3 rounds first; tied rounds do not
+//! count, and the score is shown after each round.
+//!
+//! The computer's moves come from the generator the game is given, so a
+//! seeded one plays the same moves every time.
+mod cli;
+mod net;
+mod stats;
+
+pub use cli::run_cli;
+
+use challenge_common::game::{self, Game};
+use challenge_common::l10n;
+use challenge_common::prompt::Prompt;
+use challenge_common::sound::{self, Event};
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+
+/// The most rounds a best-of match can be played over.
+pub const MAX_BEST_OF: u32 = 99;
+
+/// Reads the number of rounds in a best-of match: an odd number from 1 to
+/// [`MAX_BEST_OF`], so that the match cannot end level.
+pub fn parse_best_of(text: &str) -> Result<u32, String> {
+    match text.trim().parse() {
+        Ok(rounds) if rounds % 2 == 1 && rounds <= MAX_BEST_OF => Ok(rounds),
+        _ => Err(format!(
+            "expected an odd number of rounds from 1 to {MAX_BEST_OF}"
+        )),
+    }
+}
+
+/// The rounds a player must win to take a best-of-`rounds` match.
+fn rounds_to_win(rounds: u32) -> u64 {
+    u64::from(rounds / 2 + 1)
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    pub const ALL: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+}
+
+/// The move's name in the player's language, e.g. `Rock`.
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(l10n::text(match self {
+            Move::Rock => "c10.rock",
+            Move::Paper => "c10.paper",
+            Move::Scissors => "c10.scissors",
+        }))
+    }
+}
+
+fn get_move_from_input(input: &str) -> Option<Move> {
+    match input.trim().to_lowercase().as_str() {
+        "rock" => Some(Move::Rock),
+        "paper" => Some(Move::Paper),
+        "scissors" => Some(Move::Scissors),
+        _ => None,
+    }
+}
+
+/// Asks the player for a move until they give a valid one.
+pub fn prompt_for_move<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> std::io::Result<Move> {
+    Prompt::new(l10n::text("c10.move")).ask(reader, writer, |answer| {
+        get_move_from_input(answer).ok_or(l10n::text("c10.invalid"))
+    })
+}
+
+/// Returns `true` if `player` beats `computer`.
+pub fn player_wins(player: &Move, computer: &Move) -> bool {
+    matches!(
+        (player, computer),
+        (Move::Rock, Move::Scissors) | (Move::Paper, Move::Rock) | (Move::Scissors, Move::Paper)
+    )
+}
+
+fn get_rand_move<R: Rng + ?Sized>(rng: &mut R) -> Move {
+    Move::ALL.choose(rng).copied().unwrap_or(Move::Rock)
+}
+
+/// Session results: how many rounds the player won, lost, and tied, which
+/// moves they chose, and their runs of rounds won in a row. A round lost
+/// ends a run; a tie does not.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Tally {
+    pub wins: u64,
+    pub losses: u64,
+    pub ties: u64,
+    /// How many times the player chose each move, in the order of
+    /// [`Move::ALL`].
+    pub moves: [u64; 3],
+    /// Rounds won in a row before the first round lost.
+    pub opening_streak: u64,
+    /// Rounds won in a row since the last round lost.
+    pub streak: u64,
+    /// The most rounds won in a row.
+    pub best_streak: u64,
+}
+
+impl Tally {
+    /// Counts a round won.
+    pub fn win(&mut self) {
+        self.wins += 1;
+        self.streak += 1;
+        if self.losses == 0 {
+            self.opening_streak += 1;
+        }
+        self.best_streak = self.best_streak.max(self.streak);
+    }
+
+    /// Counts a round lost, ending the run of rounds won in a row.
+    pub fn lose(&mut self) {
+        self.losses += 1;
+        self.streak = 0;
+    }
+
+    /// Counts `choice` as one of the player's moves.
+    pub fn choose(&mut self, choice: Move) {
+        self.moves[choice as usize] += 1;
+    }
+
+    /// The number of rounds played.
+    pub fn rounds(&self) -> u64 {
+        self.wins + self.losses + self.ties
+    }
+
+    /// The share of rounds won, from 0 to 1, or `None` before any are played.
+    pub fn win_rate(&self) -> Option<f64> {
+        let rounds = self.rounds();
+        (rounds > 0).then(|| self.wins as f64 / rounds as f64)
+    }
+
+    /// The move the player chose most often, the first of [`Move::ALL`] on a
+    /// tie, or `None` before any are counted.
+    pub fn favorite_move(&self) -> Option<Move> {
+        let (index, &count) = self
+            .moves
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, count)| count)?;
+        (count > 0).then_some(Move::ALL[index])
+    }
+}
+
+/// What the game is waiting for.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum Stage {
+    #[default]
+    Start,
+    Move,
+    Again,
+    Over,
+}
+
+/// A session of rounds against the computer, played until the player quits
+/// or, in a best-of match, until one side has won it. The computer picks its
+/// moves with `rng`.
+pub struct RockPaperScissors<R> {
+    stage: Stage,
+    tally: Tally,
+    /// The rounds of the best-of match, if one is being played.
+    best_of: Option<u32>,
+    rng: R,
+}
+
+impl<R: Rng> RockPaperScissors<R> {
+    pub fn new(rng: R) -> Self {
+        RockPaperScissors {
+            stage: Stage::default(),
+            tally: Tally::default(),
+            best_of: None,
+            rng,
+        }
+    }
+
+    /// A best-of-`rounds` match.
+    pub fn best_of(rounds: u32, rng: R) -> Self {
+        RockPaperScissors {
+            best_of: Some(rounds),
+            ..RockPaperScissors::new(rng)
+        }
+    }
+
+    /// The score of the match so far, e.g. `Score: you 2, computer 1, 1 tie.`
+    fn score(&self) -> String {
+        let Tally {
+            wins, losses, ties, ..
+        } = self.tally;
+        let ties = if ties == 1 {
+            l10n::text("c10.one_tie").to_string()
+        } else {
+            l10n::format("c10.ties", &[("ties", &ties)])
+        };
+        l10n::format(
+            "c10.score",
+            &[("wins", &wins), ("losses", &losses), ("ties", &ties)],
+        )
+    }
+
+    /// The rules of a best-of-`rounds` match.
+    fn rules(rounds: u32) -> String {
+        l10n::format(
+            "c10.rules",
+            &[("rounds", &rounds), ("needed", &rounds_to_win(rounds))],
+        )
+    }
+
+    /// Plays `player_move` and, in a match, adds the score and whether the
+    /// match is over.
+    fn play(&mut self, player_move: Move) -> String {
+        let mut text = self.play_round(player_move);
+        let Some(rounds) = self.best_of else {
+            self.stage = Stage::Again;
+            return text;
+        };
+        text += &format!("\n{}", self.score());
+        let needed = rounds_to_win(rounds);
+        if self.tally.wins == needed {
+            text += &format!("\n{}", l10n::text("c10.match_won"));
+        } else if self.tally.losses == needed {
+            text += &format!("\n{}", l10n::text("c10.match_lost"));
+        } else {
+            self.stage = Stage::Move;
+            return text;
+        }
+        tracing::debug!(tally = ?self.tally, rounds, "match over");
+        self.stage = Stage::Over;
+        text
+    }
+
+    /// Plays `player_move` against a random move and describes the round.
+    fn play_round(&mut self, player_move: Move) -> String {
+        let computer_move = get_rand_move(&mut self.rng);
+        tracing::debug!(?player_move, ?computer_move, "round played");
+        self.tally.choose(player_move);
+
+        if player_wins(&player_move, &computer_move) {
+            self.tally.win();
+            sound::play(Event::Win);
+            l10n::format(
+                "c10.win",
+                &[("mine", &player_move), ("theirs", &computer_move)],
+            )
+        } else if player_move == computer_move {
+            self.tally.ties += 1;
+            l10n::format("c10.tie", &[("mine", &player_move)])
+        } else {
+            self.tally.lose();
+            sound::play(Event::Lose);
+            l10n::format(
+                "c10.lose",
+                &[("mine", &player_move), ("theirs", &computer_move)],
+            )
+        }
+    }
+}
+
+impl<R: Rng> Game for RockPaperScissors<R> {
+    type Result = Tally;
+
+    fn setup(&mut self) -> String {
+        self.stage = Stage::default();
+        self.tally = Tally::default();
+        String::new()
+    }
+
+    fn prompt(&self) -> String {
+        match (self.stage, self.best_of) {
+            (Stage::Start, Some(rounds)) => l10n::format(
+                "c10.match",
+                &[("rounds", &rounds), ("needed", &rounds_to_win(rounds))],
+            ),
+            (Stage::Start, None) => l10n::text("c10.intro").to_string(),
+            (Stage::Move | Stage::Over, _) => l10n::text("c10.move").to_string(),
+            (Stage::Again, _) => l10n::text("c10.again").to_string(),
+        }
+    }
+
+    fn step(&mut self, answer: &str) -> Result<String, String> {
+        match self.stage {
+            Stage::Start if answer.is_empty() || self.best_of.is_some() => self.stage = Stage::Move,
+            Stage::Start => {
+                let rounds = parse_best_of(answer).map_err(|_| l10n::text("c10.best_of"))?;
+                self.best_of = Some(rounds);
+                self.stage = Stage::Move;
+                return Ok(Self::rules(rounds));
+            }
+            Stage::Move => {
+                let player_move = get_move_from_input(answer).ok_or(l10n::text("c10.invalid"))?;
+                return Ok(self.play(player_move));
+            }
+            Stage::Again if answer == "q" => {
+                tracing::debug!(tally = ?self.tally, "session over");
+                self.stage = Stage::Over;
+            }
+            Stage::Again => self.stage = Stage::Move,
+            Stage::Over => {}
+        }
+        Ok(String::new())
+    }
+
+    fn render(&self) -> String {
+        String::new()
+    }
+
+    fn is_over(&self) -> bool {
+        self.stage == Stage::Over
+    }
+
+    fn result(&self) -> Tally {
+        self.tally
+    }
+}
+
+/// Plays rounds until the player quits and returns the session's results.
+pub fn run<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    rng: &mut G,
+) -> std::io::Result<Tally> {
+    game::play(&mut RockPaperScissors::new(rng), reader, writer)
+}
+
+/// Plays a best-of-`rounds` match and returns its results.
+pub fn run_best_of<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    rounds: u32,
+    rng: &mut G,
+) -> std::io::Result<Tally> {
+    game::play(&mut RockPaperScissors::best_of(rounds, rng), reader, writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn get_move_from_input_returns_rock_for_rock_input() {
+        assert_eq!(get_move_from_input("rock"), Some(Move::Rock));
+        assert_eq!(get_move_from_input("Rock"), Some(Move::Rock));
+        assert_eq!(get_move_from_input("ROCK"), Some(Move::Rock));
+        assert_eq!(get_move_from_input("rock "), Some(Move::Rock));
+        assert_eq!(get_move_from_input(" rock"), Some(Move::Rock));
+    }
+
+    #[test]
+    fn get_move_from_input_returns_paper_for_paper_input() {
+        assert_eq!(get_move_from_input("paper"), Some(Move::Paper));
+        assert_eq!(get_move_from_input("Paper"), Some(Move::Paper));
+        assert_eq!(get_move_from_input("PAPER"), Some(Move::Paper));
+        assert_eq!(get_move_from_input("paper "), Some(Move::Paper));
+        assert_eq!(get_move_from_input(" paper"), Some(Move::Paper));
+    }
+
+    #[test]
+    fn get_move_from_input_returns_scissors_for_scissors_input() {
+        assert_eq!(get_move_from_input("scissors"), Some(Move::Scissors));
+        assert_eq!(get_move_from_input("Scissors"), Some(Move::Scissors));
+        assert_eq!(get_move_from_input("SCISSORS"), Some(Move::Scissors));
+        assert_eq!(get_move_from_input("scissors "), Some(Move::Scissors));
+        assert_eq!(get_move_from_input(" scissors"), Some(Move::Scissors));
+    }
+
+    #[test]
+    fn get_move_from_input_returns_none_for_invalid_input() {
+        assert_eq!(get_move_from_input(""), None);
+        assert_eq!(get_move_from_input("invalid"), None);
+        assert_eq!(get_move_from_input("123"), None);
+        assert_eq!(get_move_from_input("scissor"), None);
+    }
+
+    #[test]
+    fn player_wins_returns_true_when_player_wins() {
+        assert!(player_wins(&Move::Rock, &Move::Scissors));
+        assert!(player_wins(&Move::Paper, &Move::Rock));
+        assert!(player_wins(&Move::Scissors, &Move::Paper));
+    }
+
+    #[test]
+    fn player_wins_returns_false_for_same_moves() {
+        assert!(!player_wins(&Move::Rock, &Move::Rock));
+        assert!(!player_wins(&Move::Paper, &Move::Paper));
+        assert!(!player_wins(&Move::Scissors, &Move::Scissors));
+    }
+
+    #[test]
+    fn run_plays_rounds_until_quit() {
+        let mut reader = "\nrock\n\npaper\nq\n".as_bytes();
+        let mut output = Vec::new();
+
+        let tally = run(&mut reader, &mut output, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        assert_eq!(tally.wins + tally.losses + tally.ties, 2);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Press ENTER to play again").count(), 2);
+    }
+
+    #[test]
+    fn run_reprompts_on_invalid_move() {
+        let mut reader = "\nlizard\nrock\nq\n".as_bytes();
+        let mut output = Vec::new();
+
+        let tally = run(&mut reader, &mut output, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        assert_eq!(tally.wins + tally.losses + tally.ties, 1);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid move. Please try again."));
+    }
+
+    #[test]
+    fn run_plays_the_same_moves_for_the_same_seed() {
+        let play = |seed| {
+            let mut output = Vec::new();
+            let mut rng = StdRng::seed_from_u64(seed);
+            run(
+                &mut "\nrock\n\nrock\n\nrock\nq\n".as_bytes(),
+                &mut output,
+                &mut rng,
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        assert_eq!(play(1), play(1));
+    }
+
+    #[test]
+    fn run_fails_when_input_ends() {
+        let mut reader = "\nrock\n".as_bytes();
+        let result = run(
+            &mut reader,
+            &mut std::io::sink(),
+            &mut StdRng::seed_from_u64(42),
+        );
+        assert_eq!(
+            result.unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn step_rejects_unknown_moves_without_playing_a_round() {
+        let mut game = RockPaperScissors::new(StdRng::seed_from_u64(42));
+        game.setup();
+        game.step("").unwrap();
+
+        assert!(game.step("lizard").is_err());
+        assert_eq!(game.result(), Tally::default());
+
+        game.step("rock").unwrap();
+        game.step("q").unwrap();
+        assert!(game.is_over());
+        let tally = game.result();
+        assert_eq!(tally.rounds(), 1);
+        assert_eq!(tally.moves, [1, 0, 0]);
+    }
+
+    #[test]
+    fn tally_finds_the_win_rate_and_favorite_move() {
+        let mut tally = Tally::default();
+        assert_eq!(tally.win_rate(), None);
+        assert_eq!(tally.favorite_move(), None);
+
+        tally.wins = 1;
+        tally.ties = 3;
+        tally.choose(Move::Paper);
+        tally.choose(Move::Scissors);
+        assert_eq!(tally.win_rate(), Some(0.25));
+        assert_eq!(tally.favorite_move(), Some(Move::Paper));
+
+        tally.choose(Move::Scissors);
+        assert_eq!(tally.favorite_move(), Some(Move::Scissors));
+    }
+
+    #[test]
+    fn tally_counts_rounds_won_in_a_row() {
+        let mut tally = Tally::default();
+        tally.win();
+        tally.win();
+        tally.ties += 1;
+        tally.win();
+        tally.lose();
+        tally.win();
+
+        assert_eq!((tally.wins, tally.losses), (4, 1));
+        assert_eq!(tally.opening_streak, 3);
+        assert_eq!(tally.streak, 1);
+        assert_eq!(tally.best_streak, 3);
+    }
+
+    /// Plays `moves` in `game` until it is over or the moves run out.
+    fn play_moves(game: &mut RockPaperScissors<StdRng>, moves: &[&str]) -> String {
+        let mut output = String::new();
+        for answer in moves {
+            if game.is_over() {
+                break;
+            }
+            output += &game.step(answer).unwrap();
+            output += "\n";
+        }
+        output
+    }
+
+    #[test]
+    fn a_match_ends_once_a_side_wins_enough_rounds() {
+        let mut game = RockPaperScissors::best_of(3, StdRng::seed_from_u64(42));
+        game.setup();
+        game.step("").unwrap();
+
+        let output = play_moves(&mut game, &["rock"; 20]);
+
+        assert!(game.is_over());
+        let Tally {
+            wins, losses, ties, ..
+        } = game.result();
+        assert_eq!(wins.max(losses), 2);
+        assert!(wins.min(losses) < 2);
+        assert_eq!(
+            output.matches("Score: you ").count() as u64,
+            wins + losses + ties
+        );
+        assert!(
+            output.ends_with("You win the match!\n")
+                || output.ends_with("The computer wins the match.\n")
+        );
+    }
+
+    #[test]
+    fn the_first_answer_can_start_a_match() {
+        let mut game = RockPaperScissors::new(StdRng::seed_from_u64(42));
+        game.setup();
+        assert_eq!(game.step("4"), Err(l10n::text("c10.best_of").to_string()));
+        assert_eq!(
+            game.step("5").unwrap(),
+            "Best of 5: the first to win 3 rounds wins the match."
+        );
+        assert_eq!(game.best_of, Some(5));
+        assert_eq!(game.prompt(), l10n::text("c10.move"));
+    }
+
+    #[test]
+    fn score_counts_each_side() {
+        let mut game = RockPaperScissors::best_of(5, StdRng::seed_from_u64(42));
+        game.tally = Tally {
+            wins: 2,
+            losses: 1,
+            ties: 1,
+            ..Tally::default()
+        };
+        assert_eq!(game.score(), "Score: you 2, computer 1, 1 tie.");
+        game.tally.ties = 0;
+        assert_eq!(game.score(), "Score: you 2, computer 1, 0 ties.");
+    }
+
+    #[test]
+    fn parse_best_of_needs_an_odd_number() {
+        assert_eq!(parse_best_of("5"), Ok(5));
+        assert_eq!(parse_best_of(" 1 "), Ok(1));
+        for text in ["4", "0", "101", "five"] {
+            assert_eq!(
+                parse_best_of(text),
+                Err("expected an odd number of rounds from 1 to 99".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn player_wins_returns_false_when_player_loses() {
+        assert!(!player_wins(&Move::Scissors, &Move::Rock));
+        assert!(!player_wins(&Move::Rock, &Move::Paper));
+        assert!(!player_wins(&Move::Paper, &Move::Scissors));
+    }
+}
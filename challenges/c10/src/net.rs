@@ -0,0 +1,216 @@
+//! Two-player Rock, Paper, Scissors over the network.
+//!
+//! Both players pick a move each round without seeing the other's: the host
+//! picks first and then waits for the guest's move, which the guest sends
+//! blind. The host judges the round and reports both moves back. After the
+//! agreed number of rounds, whoever won more rounds wins the match.
+use crate::{player_wins, prompt_for_move, Move, Tally};
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_netplay::{Guest, Lobby, Side};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+
+/// Rules the host sends to the guest when it joins.
+#[derive(Debug, Serialize, Deserialize)]
+struct Settings {
+    rounds: u32,
+}
+
+/// The guest's move for a round.
+#[derive(Debug, Serialize, Deserialize)]
+struct Turn {
+    choice: Move,
+}
+
+/// Both moves of a round, sent by the host once the guest has moved.
+#[derive(Debug, Serialize, Deserialize)]
+struct Report {
+    host: Move,
+    guest: Move,
+}
+
+/// Tells the player on `me` how the round in `report` went and adds it to
+/// their `tally`.
+fn show<W: Write>(
+    writer: &mut W,
+    report: &Report,
+    me: Side,
+    opponent: &str,
+    tally: &mut Tally,
+) -> std::io::Result<()> {
+    let (mine, theirs) = match me {
+        Side::Host => (report.host, report.guest),
+        Side::Guest => (report.guest, report.host),
+    };
+    tally.choose(mine);
+    let verdict = if player_wins(&mine, &theirs) {
+        tally.win();
+        sound::play(Event::Win);
+        l10n::text("c10.round_won").to_string()
+    } else if mine == theirs {
+        tally.ties += 1;
+        l10n::text("c10.round_tied").to_string()
+    } else {
+        tally.lose();
+        sound::play(Event::Lose);
+        l10n::format("c10.round_lost", &[("name", &opponent)])
+    };
+    let chose = l10n::format(
+        "c10.chose",
+        &[
+            ("mine", &mine),
+            ("name", &opponent),
+            ("theirs", &theirs),
+            ("verdict", &verdict),
+        ],
+    );
+    writeln!(writer, "{}", chose)
+}
+
+/// The heading of a round, e.g. `Round 1 of 3.`
+fn round_of(round: u32, rounds: u32) -> String {
+    l10n::format("c10.round", &[("round", &round), ("rounds", &rounds)])
+}
+
+/// Reports the final score of the match from `tally`.
+fn show_match<W: Write>(writer: &mut W, tally: &Tally, opponent: &str) -> std::io::Result<()> {
+    let score = l10n::format(
+        "c10.final_score",
+        &[
+            ("wins", &tally.wins),
+            ("name", &opponent),
+            ("losses", &tally.losses),
+            ("ties", &tally.ties),
+        ],
+    );
+    writeln!(writer, "{}", score)?;
+    let outcome = match tally.wins.cmp(&tally.losses) {
+        std::cmp::Ordering::Greater => l10n::text("c10.match_won").to_string(),
+        std::cmp::Ordering::Less => l10n::format("c10.opponent_won", &[("name", &opponent)]),
+        std::cmp::Ordering::Equal => l10n::text("c10.match_drawn").to_string(),
+    };
+    writeln!(writer, "{}", outcome)
+}
+
+/// Hosts a match of `rounds` rounds and plays it once a guest joins `lobby`.
+pub fn host<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    lobby: &Lobby,
+    rounds: u32,
+) -> std::io::Result<Tally> {
+    let addr = lobby.local_addr()?;
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("net.waiting_to_join", &[("addr", &addr)])
+    )?;
+    let mut host = lobby.accept(&Settings { rounds })?;
+    let guest = host.guest().to_string();
+    let joined = l10n::format("c10.joined", &[("name", &guest), ("rounds", &rounds)]);
+    writeln!(writer, "{}", joined)?;
+
+    let mut tally = Tally::default();
+    for round in 1..=rounds {
+        writeln!(writer, "{}", round_of(round, rounds))?;
+        let choice = prompt_for_move(reader, writer)?;
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &guest)])
+        )?;
+        let turn: Turn = host.recv_turn()?;
+        let report = Report {
+            host: choice,
+            guest: turn.choice,
+        };
+        tracing::debug!(round, ?report, "round played");
+        host.send_state(&report)?;
+        show(writer, &report, Side::Host, &guest, &mut tally)?;
+    }
+    show_match(writer, &tally, &guest)?;
+    Ok(tally)
+}
+
+/// Joins the match hosted at `addr` as `name` and plays it.
+pub fn join<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    addr: &str,
+    name: &str,
+) -> std::io::Result<Tally> {
+    let mut guest = Guest::join(addr, "c10", name)?;
+    let Settings { rounds } = guest.settings()?;
+    let host = guest.host().to_string();
+    let joined = l10n::format("c10.joined_game", &[("name", &host), ("rounds", &rounds)]);
+    writeln!(writer, "{}", joined)?;
+
+    let mut tally = Tally::default();
+    for round in 1..=rounds {
+        writeln!(writer, "{}", round_of(round, rounds))?;
+        let choice = prompt_for_move(reader, writer)?;
+        guest.send_turn(&Turn { choice })?;
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &host)])
+        )?;
+        let report: Report = guest.recv_state()?;
+        show(writer, &report, Side::Guest, &host, &mut tally)?;
+    }
+    show_match(writer, &tally, &host)?;
+    Ok(tally)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plays a whole match on localhost with scripted input for both players
+    /// and returns what each of them saw.
+    fn play(rounds: u32, host_input: &str, guest_input: &str) -> (String, String) {
+        let lobby = Lobby::bind("127.0.0.1:0", "c10", "Bob").unwrap();
+        let addr = lobby.local_addr().unwrap().to_string();
+        let host_input = host_input.to_string();
+        let host_thread = std::thread::spawn(move || {
+            let mut output = Vec::new();
+            host(&mut host_input.as_bytes(), &mut output, &lobby, rounds).unwrap();
+            String::from_utf8(output).unwrap()
+        });
+
+        let mut output = Vec::new();
+        join(&mut guest_input.as_bytes(), &mut output, &addr, "Ada").unwrap();
+        (
+            host_thread.join().unwrap(),
+            String::from_utf8(output).unwrap(),
+        )
+    }
+
+    #[test]
+    fn each_player_sees_the_match_from_their_side() {
+        let (host, guest) = play(2, "rock\nrock\n", "scissors\npaper\n");
+
+        assert!(host.contains("Ada joined. The match is 2 rounds.\nRound 1 of 2.\n"));
+        assert!(host.ends_with(
+            "You chose Rock and Ada chose Scissors. You win the round!\n\
+             Round 2 of 2.\n\
+             Enter your move (rock, paper, or scissors): \n\
+             Waiting for Ada...\n\
+             You chose Rock and Ada chose Paper. Ada wins the round.\n\
+             Final score: you 1, Ada 1, 0 ties.\n\
+             The match is a draw.\n"
+        ));
+        assert!(guest.starts_with("Joined Bob's game. The match is 2 rounds.\n"));
+        assert!(guest.contains("You chose Scissors and Bob chose Rock. Bob wins the round.\n"));
+    }
+
+    #[test]
+    fn the_player_who_wins_more_rounds_wins_the_match() {
+        let (host, guest) = play(1, "lizard\npaper\n", "rock\n");
+
+        assert!(host.contains("Invalid move. Please try again.\n"));
+        assert!(host.ends_with("Final score: you 1, Ada 0, 0 ties.\nYou win the match!\n"));
+        assert!(guest.ends_with("Final score: you 0, Bob 1, 0 ties.\nBob wins the match.\n"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Lifetime results, kept in the `c10` store between sessions: the rounds
+//! won, lost and tied, how often each move was chosen, which `--stats`
+//! shows along with the win rate and favorite move, and the current and
+//! longest runs of rounds won in a row.
+use crate::{Move, Tally};
+use challenge_common::l10n;
+use challenge_storage::{data_dir, Store};
+use std::path::Path;
+
+/// The store key counting how often `choice` was played, e.g. `rock_played`.
+fn move_key(choice: Move) -> String {
+    format!("{:?}_played", choice).to_lowercase()
+}
+
+/// Reads the lifetime totals from `store`.
+fn load(store: &Store) -> challenge_storage::Result<Tally> {
+    let mut lifetime = Tally {
+        wins: store.get_or_default("wins")?,
+        losses: store.get_or_default("losses")?,
+        ties: store.get_or_default("ties")?,
+        streak: store.get_or_default("win_streak")?,
+        best_streak: store.get_or_default("best_win_streak")?,
+        ..Tally::default()
+    };
+    for (count, choice) in lifetime.moves.iter_mut().zip(Move::ALL) {
+        *count = store.get_or_default(&move_key(choice))?;
+    }
+    Ok(lifetime)
+}
+
+/// Returns the lifetime totals.
+pub fn load_lifetime() -> challenge_storage::Result<Tally> {
+    load_in(&data_dir()?)
+}
+
+/// Returns the lifetime totals kept in `dir`.
+pub fn load_in(dir: &Path) -> challenge_storage::Result<Tally> {
+    load(&Store::open_in(dir, "c10")?)
+}
+
+/// Adds this session's results to the lifetime totals and returns the totals.
+pub fn record(session: Tally) -> challenge_storage::Result<Tally> {
+    record_in(&data_dir()?, session)
+}
+
+/// Adds this session's results to the lifetime totals in `dir` and returns
+/// the totals.
+pub fn record_in(dir: &Path, session: Tally) -> challenge_storage::Result<Tally> {
+    let mut store = Store::open_in(dir, "c10")?;
+    store.add("wins", session.wins)?;
+    store.add("losses", session.losses)?;
+    store.add("ties", session.ties)?;
+    for (count, choice) in session.moves.into_iter().zip(Move::ALL) {
+        store.add(&move_key(choice), count)?;
+    }
+    // A session without a loss carries on the lifetime run; otherwise its
+    // opening run ends it and a new one starts.
+    let streak: u64 = store.get_or_default("win_streak")?;
+    let (streak, carried) = if session.losses == 0 {
+        (streak + session.streak, streak + session.streak)
+    } else {
+        (session.streak, streak + session.opening_streak)
+    };
+    let best = [
+        store.get_or_default("best_win_streak")?,
+        carried,
+        session.best_streak,
+    ]
+    .into_iter()
+    .max()
+    .unwrap_or_default();
+    store.set("win_streak", &streak)?;
+    store.set("best_win_streak", &best)?;
+    store.save()?;
+    load(&store)
+}
+
+/// The lifetime totals as `--stats` shows them, e.g.
+///
+/// ```text
+/// Rounds played: 12
+/// Wins: 5, losses: 4, ties: 3
+/// Win rate: 41.7%
+/// Favorite move: Rock, chosen 6 times (50.0%)
+/// Moves: Rock 6, Paper 4, Scissors 2
+/// ```
+pub fn report(lifetime: &Tally) -> String {
+    let Some(win_rate) = lifetime.win_rate() else {
+        return l10n::text("c10.no_rounds").to_string();
+    };
+    let text = l10n::format(
+        "c10.report",
+        &[
+            ("rounds", &lifetime.rounds()),
+            ("wins", &lifetime.wins),
+            ("losses", &lifetime.losses),
+            ("ties", &lifetime.ties),
+            ("rate", &format!("{:.1}", 100.0 * win_rate)),
+        ],
+    );
+    let Some(favorite) = lifetime.favorite_move() else {
+        return text + "\n" + l10n::text("c10.no_favorite");
+    };
+    let chosen: u64 = lifetime.moves.iter().sum();
+    let count = lifetime.moves[favorite as usize];
+    let times = if count == 1 {
+        l10n::text("c10.once").to_string()
+    } else {
+        l10n::format("c10.times", &[("count", &count)])
+    };
+    let percent = format!("{:.1}", 100.0 * count as f64 / chosen as f64);
+    let favorite = l10n::format(
+        "c10.favorite",
+        &[
+            ("move", &favorite),
+            ("times", &times),
+            ("percent", &percent),
+        ],
+    );
+    let moves: Vec<_> = Move::ALL
+        .iter()
+        .zip(lifetime.moves)
+        .map(|(choice, count)| format!("{choice} {count}"))
+        .collect();
+    let moves = l10n::format("c10.moves", &[("moves", &moves.join(", "))]);
+    format!("{text}\n{favorite}\n{moves}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_in_adds_to_the_lifetime_totals() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = Tally {
+            ties: 1,
+            ..Tally::default()
+        };
+        session.win();
+        session.choose(Move::Rock);
+        session.choose(Move::Scissors);
+
+        record_in(dir.path(), session).unwrap();
+        let lifetime = record_in(dir.path(), session).unwrap();
+
+        assert_eq!(lifetime.wins, 2);
+        assert_eq!(lifetime.ties, 2);
+        assert_eq!(lifetime.moves, [2, 0, 2]);
+        assert_eq!((lifetime.streak, lifetime.best_streak), (2, 2));
+        assert_eq!(load_in(dir.path()).unwrap(), lifetime);
+        let store = Store::open_in(dir.path(), "c10").unwrap();
+        assert_eq!(store.get::<u64>("scissors_played").unwrap(), Some(2));
+    }
+
+    #[test]
+    fn record_in_carries_the_win_streak_over_until_a_loss() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut unbeaten = Tally::default();
+        unbeaten.win();
+        unbeaten.win();
+        record_in(dir.path(), unbeaten).unwrap();
+
+        let mut session = Tally::default();
+        session.win();
+        session.lose();
+        session.win();
+        session.win();
+        let lifetime = record_in(dir.path(), session).unwrap();
+
+        // The two wins before the session and its first make three in a row.
+        assert_eq!(lifetime.best_streak, 3);
+        assert_eq!(lifetime.streak, 2);
+    }
+
+    #[test]
+    fn report_shows_the_win_rate_and_favorite_move() {
+        let lifetime = Tally {
+            wins: 5,
+            losses: 4,
+            ties: 3,
+            moves: [6, 4, 2],
+            ..Tally::default()
+        };
+        assert_eq!(
+            report(&lifetime),
+            "Rounds played: 12\n\
+             Wins: 5, losses: 4, ties: 3\n\
+             Win rate: 41.7%\n\
+             Favorite move: Rock, chosen 6 times (50.0%)\n\
+             Moves: Rock 6, Paper 4, Scissors 2"
+        );
+    }
+
+    #[test]
+    fn report_copes_with_missing_results() {
+        assert_eq!(report(&Tally::default()), "No rounds played yet.");
+        // Totals saved before moves were counted have no favorite.
+        let lifetime = Tally {
+            wins: 1,
+            ..Tally::default()
+        };
+        assert!(report(&lifetime).ends_with("\nWin rate: 100.0%\nFavorite move: none recorded yet"));
+    }
+}
@@ -0,0 +1,339 @@
+//! # Logic Gate Simulator
+//!
+//! This module implements a simple interactive logic gate simulator that allows users
+//! to create and test various types of digital logic gates.
+//!
+//! ## Available Gate Types
+//!
+//! - **AND Gate**: Outputs true only when both inputs are true
+//! - **OR Gate**: Outputs true when at least one input is true
+//! - **XOR Gate**: Outputs true when inputs are different
+//! - **NAND Gate**: Outputs false only when both inputs are true
+//! - **NOR Gate**: Outputs true only when both inputs are false
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use clap::builder::BoolishValueParser;
+use clap::Parser;
+use std::ffi::OsString;
+use std::io::BufRead;
+use std::process::ExitCode;
+
+trait GateLogic {
+    fn output(&self) -> bool;
+}
+
+struct AndGate {
+    a: bool,
+    b: bool,
+}
+
+impl GateLogic for AndGate {
+    fn output(&self) -> bool {
+        self.a && self.b
+    }
+}
+
+struct OrGate {
+    a: bool,
+    b: bool,
+}
+
+impl GateLogic for OrGate {
+    fn output(&self) -> bool {
+        self.a || self.b
+    }
+}
+
+struct XorGate {
+    a: bool,
+    b: bool,
+}
+
+impl GateLogic for XorGate {
+    fn output(&self) -> bool {
+        self.a ^ self.b
+    }
+}
+
+struct NandGate {
+    a: bool,
+    b: bool,
+}
+
+impl GateLogic for NandGate {
+    fn output(&self) -> bool {
+        !(self.a && self.b)
+    }
+}
+
+struct NorGate {
+    a: bool,
+    b: bool,
+}
+
+impl GateLogic for NorGate {
+    fn output(&self) -> bool {
+        !(self.a || self.b)
+    }
+}
+
+fn create_gate(gate_type: &str, a: bool, b: bool) -> Option<Box<dyn GateLogic>> {
+    match gate_type {
+        "and" => Some(Box::new(AndGate { a, b })),
+        "or" => Some(Box::new(OrGate { a, b })),
+        "xor" => Some(Box::new(XorGate { a, b })),
+        "nand" => Some(Box::new(NandGate { a, b })),
+        "nor" => Some(Box::new(NorGate { a, b })),
+        _ => None,
+    }
+}
+
+fn prompt_for_gate<R: BufRead>(reader: &mut R) -> std::io::Result<String> {
+    Prompt::new(l10n::text("c11.gate")).ask(reader, &mut std::io::stdout(), |answer| {
+        match answer.to_lowercase().as_str() {
+            gate @ ("and" | "or" | "xor" | "nand" | "nor") => Ok(gate.to_string()),
+            _ => Err(l10n::text("c11.gate_invalid")),
+        }
+    })
+}
+
+fn prompt_for_input<R: BufRead>(reader: &mut R, prompt: &str) -> std::io::Result<bool> {
+    Prompt::new(prompt).ask(reader, &mut std::io::stdout(), |answer| match answer {
+        "1" => Ok(true),
+        "0" => Ok(false),
+        _ => Err(l10n::text("c11.input_invalid")),
+    })
+}
+
+/// Simulate a two-input logic gate.
+///
+/// Any value not given on the command line is prompted for interactively.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Type of gate to simulate
+    #[arg(short, long, value_parser = ["and", "or", "xor", "nand", "nor"])]
+    gate: Option<String>,
+
+    /// Value of input A (1 or 0)
+    #[arg(short, value_parser = BoolishValueParser::new())]
+    a: Option<bool>,
+
+    /// Value of input B (1 or 0)
+    #[arg(short, value_parser = BoolishValueParser::new())]
+    b: Option<bool>,
+
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn run<R: BufRead>(reader: &mut R, args: Args) -> std::io::Result<()> {
+    let gate_type = match args.gate {
+        Some(gate) => gate,
+        None => prompt_for_gate(reader)?,
+    };
+    let input_a = match args.a {
+        Some(a) => a,
+        None => prompt_for_input(reader, l10n::text("c11.input_a"))?,
+    };
+    let input_b = match args.b {
+        Some(b) => b,
+        None => prompt_for_input(reader, l10n::text("c11.input_b"))?,
+    };
+    match create_gate(&gate_type, input_a, input_b) {
+        Some(gate) => {
+            println!(
+                "{}",
+                l10n::format("c11.result", &[("output", &gate.output())])
+            );
+        }
+        None => {
+            eprintln!("Failed to create gate.");
+        }
+    };
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = run(&mut input, args) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_gate_returns_and_gate_for_and_input() {
+        let gate = create_gate("and", true, false);
+        assert!(gate.is_some());
+        assert!(!gate.unwrap().output());
+
+        let gate = create_gate("and", true, true);
+        assert!(gate.is_some());
+        assert!(gate.unwrap().output());
+    }
+
+    #[test]
+    fn create_gate_returns_or_gate_for_or_input() {
+        let gate = create_gate("or", false, false);
+        assert!(gate.is_some());
+        assert!(!gate.unwrap().output());
+
+        let gate = create_gate("or", true, false);
+        assert!(gate.is_some());
+        assert!(gate.unwrap().output());
+    }
+
+    #[test]
+    fn create_gate_returns_xor_gate_for_xor_input() {
+        let gate = create_gate("xor", true, true);
+        assert!(gate.is_some());
+        assert!(!gate.unwrap().output());
+
+        let gate = create_gate("xor", true, false);
+        assert!(gate.is_some());
+        assert!(gate.unwrap().output());
+    }
+
+    #[test]
+    fn create_gate_returns_nand_gate_for_nand_input() {
+        let gate = create_gate("nand", true, true);
+        assert!(gate.is_some());
+        assert!(!gate.unwrap().output());
+
+        let gate = create_gate("nand", false, false);
+        assert!(gate.is_some());
+        assert!(gate.unwrap().output());
+    }
+
+    #[test]
+    fn create_gate_returns_nor_gate_for_nor_input() {
+        let gate = create_gate("nor", false, false);
+        assert!(gate.is_some());
+        assert!(gate.unwrap().output());
+
+        let gate = create_gate("nor", true, false);
+        assert!(gate.is_some());
+        assert!(!gate.unwrap().output());
+    }
+
+    #[test]
+    fn create_gate_returns_none_for_invalid_input() {
+        assert!(create_gate("invalid", true, false).is_none());
+        assert!(create_gate("", true, false).is_none());
+        assert!(create_gate("AND", true, false).is_none()); // Case sensitive check
+    }
+    #[test]
+    fn and_gate_output_returns_true_when_both_inputs_are_true() {
+        let gate = AndGate { a: true, b: true };
+        assert!(gate.output());
+    }
+
+    #[test]
+    fn and_gate_output_returns_false_when_any_input_is_false() {
+        let gate = AndGate { a: true, b: false };
+        assert!(!gate.output());
+
+        let gate = AndGate { a: false, b: true };
+        assert!(!gate.output());
+
+        let gate = AndGate { a: false, b: false };
+        assert!(!gate.output());
+    }
+
+    #[test]
+    fn or_gate_output_returns_true_when_any_input_is_true() {
+        let gate = OrGate { a: true, b: false };
+        assert!(gate.output());
+
+        let gate = OrGate { a: false, b: true };
+        assert!(gate.output());
+
+        let gate = OrGate { a: true, b: true };
+        assert!(gate.output());
+    }
+
+    #[test]
+    fn or_gate_output_returns_false_when_both_inputs_are_false() {
+        let gate = OrGate { a: false, b: false };
+        assert!(!gate.output());
+    }
+
+    #[test]
+    fn xor_gate_output_returns_true_when_inputs_are_different() {
+        let gate = XorGate { a: true, b: false };
+        assert!(gate.output());
+
+        let gate = XorGate { a: false, b: true };
+        assert!(gate.output());
+    }
+
+    #[test]
+    fn xor_gate_output_returns_false_when_inputs_are_same() {
+        let gate = XorGate { a: true, b: true };
+        assert!(!gate.output());
+
+        let gate = XorGate { a: false, b: false };
+        assert!(!gate.output());
+    }
+
+    #[test]
+    fn nand_gate_output_returns_false_when_both_inputs_are_true() {
+        let gate = NandGate { a: true, b: true };
+        assert!(!gate.output());
+    }
+
+    #[test]
+    fn nand_gate_output_returns_true_when_any_input_is_false() {
+        let gate = NandGate { a: true, b: false };
+        assert!(gate.output());
+
+        let gate = NandGate { a: false, b: true };
+        assert!(gate.output());
+
+        let gate = NandGate { a: false, b: false };
+        assert!(gate.output());
+    }
+
+    #[test]
+    fn nor_gate_output_returns_true_when_both_inputs_are_false() {
+        let gate = NorGate { a: false, b: false };
+        assert!(gate.output());
+    }
+
+    #[test]
+    fn nor_gate_output_returns_false_when_any_input_is_true() {
+        let gate = NorGate { a: true, b: false };
+        assert!(!gate.output());
+
+        let gate = NorGate { a: false, b: true };
+        assert!(!gate.output());
+
+        let gate = NorGate { a: true, b: true };
+        assert!(!gate.output());
+    }
+}
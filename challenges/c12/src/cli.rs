@@ -0,0 +1,97 @@
+//! # Factor Calculator
+//!
+//! This module implements a utility for finding all factors of a given number.
+//! It provides functionality to calculate and display the complete set of factors
+//! for any non-negative integer input.
+//!
+//! ## Features
+//!
+//! - Efficient factor calculation using square root optimization
+//! - Interactive command-line interface for user input, or the number as an
+//!   argument, e.g. `c12 360`
+//! - Support for large numbers
+//! - Handles special cases (zero, one, prime numbers)
+//! - Clear display of all factors
+//! - `--format json` output of the number, its factors, and whether it is prime
+use crate::factors;
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+/// List all the factors of a number.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number to factor, prompted for if omitted
+    number: Option<u64>,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+
+    let format = args.format.format();
+    let input = match args.number {
+        Some(number) => Ok(number),
+        None => Input::from_args(&args.common).and_then(|mut reader| {
+            Prompt::new(l10n::text("c12.number")).ask(
+                &mut reader,
+                &mut format.prompts(),
+                |answer| answer.parse::<u64>().map_err(|_| l10n::text("c12.invalid")),
+            )
+        }),
+    };
+    let input = match input {
+        Ok(num) => num,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::SUCCESS;
+        }
+    };
+
+    let prime = primal::is_prime(input);
+    match format {
+        Format::Text if prime => {
+            println!("{}", l10n::format("c12.prime", &[("number", &input)]))
+        }
+        Format::Text => println!(
+            "{}",
+            l10n::format(
+                "c12.factors",
+                &[
+                    ("number", &input),
+                    ("factors", &format!("{:?}", factors(input)))
+                ]
+            )
+        ),
+        Format::Json => {
+            let result = json!({ "prime": prime, "factors": factors(input) });
+            if let Err(e) = output::write_json(
+                &mut std::io::stdout(),
+                "c12",
+                json!({ "number": input }),
+                result,
+            ) {
+                eprintln!("Error: {}", e);
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
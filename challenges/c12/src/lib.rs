@@ -0,0 +1,158 @@
+//! # Factor Calculator
+//!
+//! The factor search itself, kept apart from the interactive prompt so that
+//! it can be tested on its own.
+mod cli;
+
+pub use cli::run_cli;
+
+/// Returns every factor of `n` in ascending order, or an empty list for zero.
+pub fn factors(n: u64) -> Vec<u64> {
+    let mut result = Vec::new();
+    let sqrt_n = (n as f64).sqrt() as u64;
+
+    for i in 1..=sqrt_n {
+        if n.is_multiple_of(i) {
+            result.push(i);
+            if i != n / i {
+                // Avoid duplicate for perfect squares
+                result.push(n / i);
+            }
+        }
+    }
+
+    result.sort();
+    result
+}
+
+/// A sieve of the smallest prime factor of every number up to a limit.
+///
+/// Building the sieve costs more than a single trial division, but once built
+/// it factors any number in range from its prime factorization, which pays
+/// off when many numbers are factored.
+pub struct FactorSieve {
+    smallest_prime_factor: Vec<u64>,
+}
+
+impl FactorSieve {
+    /// Sieves every number up to and including `limit`.
+    pub fn new(limit: u64) -> Self {
+        let len = usize::try_from(limit).expect("sieve limit must fit in memory") + 1;
+        let mut smallest_prime_factor = vec![0; len];
+        for i in 2..len {
+            if smallest_prime_factor[i] != 0 {
+                continue;
+            }
+            for multiple in (i..len).step_by(i) {
+                if smallest_prime_factor[multiple] == 0 {
+                    smallest_prime_factor[multiple] = i as u64;
+                }
+            }
+        }
+        FactorSieve {
+            smallest_prime_factor,
+        }
+    }
+
+    /// Returns the largest number the sieve can factor.
+    pub fn limit(&self) -> u64 {
+        self.smallest_prime_factor.len() as u64 - 1
+    }
+
+    /// Returns every factor of `n` in ascending order, like [`factors`], or
+    /// `None` if `n` is beyond the sieve's limit.
+    pub fn factors(&self, n: u64) -> Option<Vec<u64>> {
+        if n > self.limit() {
+            return None;
+        }
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        let mut result = vec![1];
+        let mut rest = n;
+        while rest > 1 {
+            let prime = self.smallest_prime_factor[rest as usize];
+            let mut exponent = 0;
+            while rest.is_multiple_of(prime) {
+                rest /= prime;
+                exponent += 1;
+            }
+
+            // Every factor found so far times each power of the new prime
+            let known = result.len();
+            let mut power = 1;
+            for _ in 0..exponent {
+                power *= prime;
+                for i in 0..known {
+                    result.push(result[i] * power);
+                }
+            }
+        }
+
+        result.sort();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factors_returns_empty_vec_for_zero() {
+        assert_eq!(factors(0), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn factors_returns_one_for_one() {
+        assert_eq!(factors(1), vec![1]);
+    }
+
+    #[test]
+    fn factors_returns_prime_and_self_for_primes() {
+        assert_eq!(factors(2), vec![1, 2]);
+        assert_eq!(factors(3), vec![1, 3]);
+        assert_eq!(factors(5), vec![1, 5]);
+        assert_eq!(factors(7), vec![1, 7]);
+        assert_eq!(factors(11), vec![1, 11]);
+    }
+
+    #[test]
+    fn factors_returns_all_factors_for_composite_numbers() {
+        assert_eq!(factors(4), vec![1, 2, 4]);
+        assert_eq!(factors(6), vec![1, 2, 3, 6]);
+        assert_eq!(factors(8), vec![1, 2, 4, 8]);
+        assert_eq!(factors(9), vec![1, 3, 9]);
+        assert_eq!(factors(12), vec![1, 2, 3, 4, 6, 12]);
+    }
+
+    #[test]
+    fn factors_returns_correct_for_perfect_squares() {
+        assert_eq!(factors(16), vec![1, 2, 4, 8, 16]);
+        assert_eq!(factors(25), vec![1, 5, 25]);
+        assert_eq!(factors(36), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+    }
+
+    #[test]
+    fn factors_handles_large_numbers() {
+        assert_eq!(factors(100), vec![1, 2, 4, 5, 10, 20, 25, 50, 100]);
+        assert_eq!(factors(997), vec![1, 997]); // 997 is prime
+        assert_eq!(factors(1001), vec![1, 7, 11, 13, 77, 91, 143, 1001]);
+    }
+
+    #[test]
+    fn sieve_factors_match_trial_division() {
+        let sieve = FactorSieve::new(1000);
+        for n in 0..=1000 {
+            assert_eq!(sieve.factors(n), Some(factors(n)));
+        }
+    }
+
+    #[test]
+    fn sieve_rejects_numbers_beyond_its_limit() {
+        let sieve = FactorSieve::new(10);
+        assert_eq!(sieve.limit(), 10);
+        assert_eq!(sieve.factors(11), None);
+    }
+}
@@ -0,0 +1,46 @@
+use c12::{factors, FactorSieve};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn every_factor_divides_n(n in 1..10_000_000u64) {
+        for f in factors(n) {
+            prop_assert_eq!(n % f, 0);
+        }
+    }
+
+    #[test]
+    fn factors_pair_up_into_n(n in 1..10_000_000u64) {
+        let factors = factors(n);
+        for &f in &factors {
+            prop_assert_eq!(f * (n / f), n);
+            prop_assert!(factors.binary_search(&(n / f)).is_ok());
+        }
+    }
+
+    #[test]
+    fn factors_are_ascending_and_bounded_by_one_and_n(n in 1..10_000_000u64) {
+        let factors = factors(n);
+        prop_assert!(factors.windows(2).all(|w| w[0] < w[1]));
+        prop_assert_eq!(factors.first(), Some(&1));
+        prop_assert_eq!(factors.last(), Some(&n));
+    }
+
+    #[test]
+    fn perfect_squares_list_their_root_once(root in 1..100_000u64) {
+        let factors = factors(root * root);
+        prop_assert_eq!(factors.iter().filter(|&&f| f == root).count(), 1);
+        prop_assert_eq!(factors.len() % 2, 1);
+    }
+
+    #[test]
+    fn factors_match_a_brute_force_search(n in 1..2_000u64) {
+        let expected: Vec<u64> = (1..=n).filter(|f| n % f == 0).collect();
+        prop_assert_eq!(factors(n), expected);
+    }
+
+    #[test]
+    fn sieve_agrees_with_trial_division(n in 0..100_000u64) {
+        prop_assert_eq!(FactorSieve::new(n).factors(n), Some(factors(n)));
+    }
+}
@@ -0,0 +1,373 @@
+//! # Subtraction Game
+//!
+//! This module implements a simple turn-based number subtraction game where
+//! a player competes against a computer opponent.
+//!
+//! ## Game Rules
+//!
+//! - The game starts with a random number between 20 and 30
+//! - Players take turns subtracting 1-3 from the current number
+//! - The player who reduces the number to exactly 0 loses
+//! - The computer uses a simple strategy for numbers 1-3 and random moves otherwise
+//!
+//! ## Features
+//!
+//! - Interactive command-line interface
+//! - AI opponent whose strategy depends on the difficulty level
+//! - Random starting position for varied gameplay
+//! - Input validation to ensure legal moves
+//! - Clear feedback after each move
+use challenge_common::cli::{CommonArgs, NumRange};
+use challenge_common::difficulty::{Difficulty, DifficultyArgs};
+use challenge_common::game::{self, Game};
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use clap::Parser;
+use rand::Rng;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+fn get_rand_num<R: Rng + ?Sized>(min: u64, max: u64, rng: &mut R) -> u64 {
+    rng.random_range(min..=max)
+}
+
+/// Chooses how many the computer removes from `num`. The easy computer plays
+/// at random, the normal one only sees the winning move near the end, and the
+/// hard one always leaves the player one more than a multiple of four when it
+/// can. Random moves are picked with `rng`.
+fn make_move_ai<R: Rng + ?Sized>(num: u64, level: Difficulty, rng: &mut R) -> u64 {
+    match (level, num) {
+        (Difficulty::Easy, _) => get_rand_num(1, 3, rng),
+        (Difficulty::Normal, 1 | 2) => 1,
+        (Difficulty::Normal, 3) => 2,
+        (Difficulty::Normal, _) => get_rand_num(1, 3, rng),
+        (Difficulty::Hard, _) => match (num - 1) % 4 {
+            0 => get_rand_num(1, 3, rng),
+            winning => winning,
+        },
+    }
+}
+
+/// Play a subtraction game against the computer.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Range the random starting number is drawn from
+    #[arg(short, long, default_value = "20..30", value_parser = parse_start_range)]
+    start: NumRange<u64>,
+
+    #[command(flatten)]
+    difficulty: DifficultyArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+}
+
+fn parse_start_range(s: &str) -> Result<NumRange<u64>, String> {
+    let range: NumRange<u64> = s.parse()?;
+    if range.min == 0 {
+        return Err("the starting number must be at least 1".to_string());
+    }
+    Ok(range)
+}
+
+/// How many the player may remove each turn.
+const LIMITS: (u64, u64) = (1, 3);
+
+/// A game against the computer, started by the player. The starting number
+/// and the computer's random moves come from `rng`.
+struct SubtractionGame<R> {
+    start: NumRange<u64>,
+    level: Difficulty,
+    num: u64,
+    started: bool,
+    player_won: Option<bool>,
+    rng: R,
+}
+
+impl<R: Rng> SubtractionGame<R> {
+    fn new(start: NumRange<u64>, level: Difficulty, rng: R) -> Self {
+        SubtractionGame {
+            start,
+            level,
+            num: start.min,
+            started: false,
+            player_won: None,
+            rng,
+        }
+    }
+
+    /// Removes `deduction` from the number and describes the turn. Returns
+    /// `true` if the turn ended the game.
+    fn take_turn(&mut self, is_player_turn: bool, deduction: u64, lines: &mut Vec<String>) -> bool {
+        let removed = if is_player_turn {
+            "c13.player_removed"
+        } else {
+            "c13.computer_removed"
+        };
+        lines.push(l10n::format(removed, &[("number", &deduction)]));
+        self.num = self.num.saturating_sub(deduction);
+        tracing::debug!(is_player_turn, deduction, num = self.num, "turn taken");
+        lines.push(l10n::format("c13.left", &[("number", &self.num)]));
+
+        if self.num == 0 {
+            lines.push(
+                l10n::text(if is_player_turn {
+                    "c13.lost"
+                } else {
+                    "c13.won"
+                })
+                .to_string(),
+            );
+            self.player_won = Some(!is_player_turn);
+        }
+        self.num == 0
+    }
+}
+
+impl<R: Rng> Game for SubtractionGame<R> {
+    /// `true` if the player won.
+    type Result = bool;
+
+    fn setup(&mut self) -> String {
+        self.started = false;
+        self.player_won = None;
+        self.num = get_rand_num(self.start.min, self.start.max, &mut self.rng);
+        tracing::debug!(num = self.num, "starting number chosen");
+        l10n::text("c13.intro").to_string()
+    }
+
+    fn prompt(&self) -> String {
+        if self.started {
+            l10n::text("c13.remove")
+        } else {
+            l10n::text("prompt.start")
+        }
+        .to_string()
+    }
+
+    fn step(&mut self, answer: &str) -> Result<String, String> {
+        if !self.started {
+            self.started = true;
+            return Ok(String::new());
+        }
+
+        let expected = l10n::format(
+            "prompt.number_between",
+            &[("min", &LIMITS.0), ("max", &LIMITS.1)],
+        );
+        let deduction =
+            prompt::parsed(&expected, |num| (LIMITS.0..=LIMITS.1).contains(num))(answer)?;
+        let mut lines = Vec::new();
+        if !self.take_turn(true, deduction, &mut lines) {
+            lines.push(l10n::format("c13.current", &[("number", &self.num)]));
+            let deduction = make_move_ai(self.num, self.level, &mut self.rng);
+            self.take_turn(false, deduction, &mut lines);
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn render(&self) -> String {
+        if self.started {
+            l10n::format("c13.current", &[("number", &self.num)])
+        } else {
+            String::new()
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        self.player_won.is_some()
+    }
+
+    fn result(&self) -> bool {
+        self.player_won == Some(true)
+    }
+}
+
+/// Plays one game and returns `true` if the player won.
+fn run<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    start: NumRange<u64>,
+    level: Difficulty,
+    rng: &mut G,
+) -> std::io::Result<bool> {
+    game::play(&mut SubtractionGame::new(start, level, rng), reader, writer)
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    if let Err(e) = run(
+        &mut input,
+        &mut std::io::stdout(),
+        args.start,
+        args.difficulty.level(),
+        &mut rng,
+    ) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn make_move_ai_returns_1_when_number_is_1() {
+        assert_eq!(
+            make_move_ai(1, Difficulty::Normal, &mut StdRng::seed_from_u64(42)),
+            1
+        );
+    }
+
+    #[test]
+    fn make_move_ai_returns_1_when_number_is_2() {
+        assert_eq!(
+            make_move_ai(2, Difficulty::Normal, &mut StdRng::seed_from_u64(42)),
+            1
+        );
+    }
+
+    #[test]
+    fn make_move_ai_returns_2_when_number_is_3() {
+        assert_eq!(
+            make_move_ai(3, Difficulty::Normal, &mut StdRng::seed_from_u64(42)),
+            2
+        );
+    }
+
+    #[test]
+    fn run_player_loses_by_reaching_zero() {
+        let mut reader = "\n1\n".as_bytes();
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut reader,
+            &mut output,
+            NumRange { min: 1, max: 1 },
+            Difficulty::Normal,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert!(!won);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("0 left.\nYou lost!\n"));
+    }
+
+    #[test]
+    fn run_player_wins_when_computer_reaches_zero() {
+        let mut reader = "\n4\n1\n".as_bytes();
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut reader,
+            &mut output,
+            NumRange { min: 2, max: 2 },
+            Difficulty::Normal,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert!(won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid input. Please enter a number between 1 and 3."));
+        assert!(output.ends_with("Computer removed: 1\n0 left.\nYou won!\n"));
+    }
+
+    #[test]
+    fn step_rejects_removing_more_than_three() {
+        let mut game = SubtractionGame::new(
+            NumRange { min: 9, max: 9 },
+            Difficulty::Normal,
+            StdRng::seed_from_u64(42),
+        );
+        game.setup();
+        game.step("").unwrap();
+
+        assert!(game.step("4").is_err());
+        assert_eq!(game.render(), "The current number is: 9");
+
+        let feedback = game.step("3").unwrap();
+        assert!(feedback.starts_with("Player removed: 3\n6 left.\nThe current number is: 6\n"));
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn parse_start_range_accepts_positive_range() {
+        assert_eq!(
+            parse_start_range("5..9").unwrap(),
+            NumRange { min: 5, max: 9 }
+        );
+    }
+
+    #[test]
+    fn parse_start_range_rejects_range_starting_at_zero() {
+        assert!(parse_start_range("0..9").is_err());
+    }
+
+    #[test]
+    fn make_move_ai_returns_number_in_range_for_larger_inputs() {
+        // Test several larger numbers to ensure the output is always in range
+        let mut rng = StdRng::seed_from_u64(42);
+        for i in 4..20 {
+            let result = make_move_ai(i, Difficulty::Normal, &mut rng);
+            assert!(
+                (1..=3).contains(&result),
+                "Expected move to be between 1 and 3, got {}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn make_move_ai_plays_the_winning_move_on_hard() {
+        assert_eq!(
+            make_move_ai(4, Difficulty::Hard, &mut StdRng::seed_from_u64(42)),
+            3
+        );
+        assert_eq!(
+            make_move_ai(7, Difficulty::Hard, &mut StdRng::seed_from_u64(42)),
+            2
+        );
+        assert_eq!(
+            make_move_ai(10, Difficulty::Hard, &mut StdRng::seed_from_u64(42)),
+            1
+        );
+    }
+
+    #[test]
+    fn make_move_ai_plays_at_random_on_easy() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for i in 1..20 {
+            assert!((1..=3).contains(&make_move_ai(i, Difficulty::Easy, &mut rng)));
+        }
+    }
+}
@@ -0,0 +1,469 @@
+//! # Higher or Lower Game
+//!
+//! This module implements a card guessing game where the player predicts if the next
+//! card dealt will be higher or lower than the current one.
+//!
+//! ## Game Rules
+//!
+//! - Cards are dealt from a shuffled deck, Aces low, and a new deck is shuffled
+//!   whenever it runs out
+//! - Players must guess if the next card will be higher or lower than the current card
+//! - Players need to guess correctly 10 times in a row to win
+//! - Players have 2 lives (attempts) to achieve the winning streak
+//!
+//! ## Features
+//!
+//! - Interactive command-line interface
+//! - Shuffled playing cards for unpredictable gameplay
+//! - Input validation to ensure valid guesses
+//! - Multiple lives system for replayability
+//! - Streak-based win condition to test player prediction skills
+//! - Lifetime wins and losses, and the longest run of games won, remembered
+//!   between runs
+use challenge_cards::{Card, Deck, Rank};
+use challenge_common::cli::{CommonArgs, NumRange};
+use challenge_common::difficulty::DifficultyArgs;
+use challenge_common::game::{self, Game};
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use challenge_common::sound::{self, Event, SoundArgs};
+use challenge_storage::Store;
+use clap::Parser;
+use rand::Rng;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+#[derive(Debug, PartialEq)]
+enum Guess {
+    Higher,
+    Lower,
+}
+
+/// Parses the range of ranks to deal, from 1 for an Ace to 13 for a King.
+fn parse_rank_range(s: &str) -> Result<NumRange<u32>, String> {
+    let range: NumRange<u32> = s.parse()?;
+    if range.min < Rank::Ace.value() || range.max > Rank::King.value() {
+        return Err(format!(
+            "ranks go from {} (Ace) to {} (King)",
+            Rank::Ace.value(),
+            Rank::King.value()
+        ));
+    }
+    Ok(range)
+}
+
+/// Returns a deck of the cards with a rank in `range`, shuffled with `rng`.
+fn shuffled_deck<R: Rng + ?Sized>(range: &NumRange<u32>, rng: &mut R) -> Deck {
+    let mut deck = Deck::new();
+    deck.retain(|card| range.contains(&card.rank.value()));
+    deck.shuffle(rng);
+    deck
+}
+
+/// Deals the top card of `deck`, shuffling a new one for `range` with `rng`
+/// once it is empty.
+fn deal<R: Rng + ?Sized>(deck: &mut Deck, range: &NumRange<u32>, rng: &mut R) -> Card {
+    if deck.is_empty() {
+        *deck = shuffled_deck(range, rng);
+    }
+    deck.deal().expect("every range holds at least one rank")
+}
+
+fn parse_guess(answer: &str) -> Result<Guess, String> {
+    match answer {
+        "H" => Ok(Guess::Higher),
+        "L" => Ok(Guess::Lower),
+        _ => Err(l10n::text("c14.invalid").to_string()),
+    }
+}
+
+/// Adds a game to the lifetime record and returns (wins, losses, longest
+/// run of games won in a row).
+fn record_result(won: bool) -> challenge_storage::Result<(u64, u64, u64)> {
+    let mut store = Store::open("c14")?;
+    if won {
+        store.increment("wins")?;
+        let streak = store.increment("win_streak")?;
+        if streak > store.get_or_default("best_win_streak")? {
+            store.set("best_win_streak", &streak)?;
+        }
+    } else {
+        store.increment("losses")?;
+        store.set("win_streak", &0)?;
+    }
+    store.save()?;
+    Ok((
+        store.get_or_default("wins")?,
+        store.get_or_default("losses")?,
+        store.get_or_default("best_win_streak")?,
+    ))
+}
+
+/// Guess whether the next card will be higher or lower.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Ranks dealt, from 1 for an Ace to 13 for a King
+    #[arg(short, long, default_value = "1..13", value_parser = parse_rank_range)]
+    range: NumRange<u32>,
+
+    /// Number of lives [default: 3, 2 or 1 by difficulty]
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    lives: Option<u64>,
+
+    /// Number of correct guesses needed to win [default: 7, 10 or 12 by
+    /// difficulty]
+    #[arg(short, long)]
+    streak: Option<u64>,
+
+    #[command(flatten)]
+    difficulty: DifficultyArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    sound: SoundArgs,
+}
+
+/// The rules of a game, after applying the difficulty.
+struct Rules {
+    range: NumRange<u32>,
+    lives: u64,
+    streak: u64,
+}
+
+impl Args {
+    fn rules(&self) -> Rules {
+        let level = self.difficulty.level();
+        Rules {
+            range: self.range,
+            lives: self.lives.unwrap_or(level.pick(3, 2, 1)),
+            streak: self.streak.unwrap_or(level.pick(7, 10, 12)),
+        }
+    }
+}
+
+/// Guesses made with each life.
+const ROUNDS_PER_LIFE: u64 = 13;
+
+/// What the game is waiting for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stage {
+    /// The player to press Enter before a life starts.
+    Continue,
+    Guess,
+    Over {
+        won: bool,
+    },
+}
+
+/// A game of up to `rules.lives` lives of 13 guesses each, dealt from decks
+/// shuffled with `rng`.
+struct HigherOrLower<'a, R> {
+    rules: &'a Rules,
+    deck: Deck,
+    prev_card: Card,
+    stage: Stage,
+    lives_used: u64,
+    rounds: u64,
+    correct_guesses: u64,
+    rng: R,
+}
+
+impl<'a, R: Rng> HigherOrLower<'a, R> {
+    /// Creates a game for `rules`. The deck is shuffled by [`Game::setup`].
+    fn new(rules: &'a Rules, rng: R) -> Self {
+        let mut deck = Deck::new();
+        let prev_card = deck.deal().expect("a new deck is full");
+        HigherOrLower {
+            rules,
+            deck,
+            prev_card,
+            stage: Stage::Continue,
+            lives_used: 0,
+            rounds: 0,
+            correct_guesses: 0,
+            rng,
+        }
+    }
+
+    /// Ends the current life and describes what happens next.
+    fn end_life(&mut self) -> String {
+        self.lives_used += 1;
+        tracing::debug!(
+            lives_used = self.lives_used,
+            correct_guesses = self.correct_guesses,
+            "life over"
+        );
+        if self.correct_guesses >= self.rules.streak {
+            self.stage = Stage::Over { won: true };
+            sound::play(Event::Win);
+            l10n::text("c14.won").to_string()
+        } else if self.lives_used < self.rules.lives {
+            self.stage = Stage::Continue;
+            self.rounds = 0;
+            self.correct_guesses = 0;
+            l10n::format(
+                "c14.life_lost",
+                &[("lives", &(self.rules.lives - self.lives_used))],
+            )
+        } else {
+            self.stage = Stage::Over { won: false };
+            sound::play(Event::Lose);
+            l10n::text("c14.lost").to_string()
+        }
+    }
+}
+
+impl<R: Rng> Game for HigherOrLower<'_, R> {
+    /// `true` if the player won.
+    type Result = bool;
+
+    fn setup(&mut self) -> String {
+        self.stage = Stage::Continue;
+        self.lives_used = 0;
+        self.rounds = 0;
+        self.correct_guesses = 0;
+        self.deck = shuffled_deck(&self.rules.range, &mut self.rng);
+        self.prev_card = deal(&mut self.deck, &self.rules.range, &mut self.rng);
+        let rank_name = |value| Rank::from_value(value).map_or(String::new(), |r| r.to_string());
+        l10n::format(
+            "c14.intro",
+            &[
+                ("min", &rank_name(self.rules.range.min)),
+                ("max", &rank_name(self.rules.range.max)),
+                ("streak", &self.rules.streak),
+            ],
+        )
+    }
+
+    fn prompt(&self) -> String {
+        match self.stage {
+            Stage::Continue => l10n::text("prompt.continue"),
+            Stage::Guess | Stage::Over { .. } => l10n::text("c14.guess"),
+        }
+        .to_string()
+    }
+
+    fn step(&mut self, answer: &str) -> Result<String, String> {
+        if self.stage != Stage::Guess {
+            self.stage = Stage::Guess;
+            return Ok(String::new());
+        }
+
+        let guess = parse_guess(answer)?;
+        let card = deal(&mut self.deck, &self.rules.range, &mut self.rng);
+        let prev_card = self.prev_card;
+        tracing::debug!(%prev_card, %card, ?guess, "round dealt");
+        if (card.rank > prev_card.rank && guess == Guess::Higher)
+            || (card.rank < prev_card.rank && guess == Guess::Lower)
+        {
+            self.correct_guesses += 1;
+            sound::play(Event::Correct);
+        } else {
+            sound::play(Event::WrongGuess);
+        }
+        self.prev_card = card;
+
+        self.rounds += 1;
+        if self.rounds == ROUNDS_PER_LIFE {
+            Ok(self.end_life())
+        } else {
+            Ok(String::new())
+        }
+    }
+
+    fn render(&self) -> String {
+        match self.stage {
+            Stage::Guess => l10n::format("c14.card", &[("card", &self.prev_card)]),
+            _ => String::new(),
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        matches!(self.stage, Stage::Over { .. })
+    }
+
+    fn result(&self) -> bool {
+        self.stage == Stage::Over { won: true }
+    }
+}
+
+/// Plays one game, shuffling the decks with `rng`, and returns `true` if the
+/// player won.
+fn run<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    rules: &Rules,
+    rng: &mut G,
+) -> std::io::Result<bool> {
+    game::play(&mut HigherOrLower::new(rules, rng), reader, writer)
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    sound::init(&args.sound);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let won = match run(&mut input, &mut std::io::stdout(), &args.rules(), &mut rng) {
+        Ok(won) => won,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    sound::wait();
+    match record_result(won) {
+        Ok((wins, losses, best)) => {
+            println!(
+                "{}",
+                l10n::format("c14.lifetime", &[("wins", &wins), ("losses", &losses)])
+            );
+            println!("{}", l10n::format("c14.best", &[("best", &best)]));
+        }
+        Err(e) => eprintln!("Warning: unable to save your record: {}", e),
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn rules(range: &str, lives: u64, streak: u64) -> Rules {
+        Args::parse_from([
+            "c14",
+            "--range",
+            range,
+            "--lives",
+            &lives.to_string(),
+            "--streak",
+            &streak.to_string(),
+        ])
+        .rules()
+    }
+
+    #[test]
+    fn run_loses_when_streak_is_not_reached() {
+        // Every card of a single rank equals the last one, so no guess can be
+        // correct.
+        let script = format!("\n{}", "H\n".repeat(13));
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut script.as_bytes(),
+            &mut output,
+            &rules("5..5", 1, 1),
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert!(!won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("You will be dealt cards from Five to Five, Aces low.\n"));
+        assert_eq!(output.matches("Starting card: Five of ").count(), 13);
+        assert!(output.ends_with("Sorry, you lost. Better luck next time!\n"));
+    }
+
+    #[test]
+    fn run_uses_every_life_before_losing() {
+        let script = format!("\n{}\n{}", "L\n".repeat(13), "L\n".repeat(13));
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut script.as_bytes(),
+            &mut output,
+            &rules("5..5", 2, 1),
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert!(!won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Sorry, you lost. You have 1 lives remaining."));
+    }
+
+    #[test]
+    fn run_reprompts_on_invalid_guess() {
+        let script = format!("\nX\n{}", "H\n".repeat(13));
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut script.as_bytes(),
+            &mut output,
+            &rules("1..13", 1, 0),
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert!(won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid input. Please enter 'H' for higher or 'L' for lower."));
+    }
+
+    #[test]
+    fn step_counts_a_life_as_thirteen_guesses() {
+        let rules = rules("5..5", 2, 1);
+        let mut game = HigherOrLower::new(&rules, StdRng::seed_from_u64(42));
+        game.setup();
+        game.step("").unwrap();
+
+        for _ in 1..ROUNDS_PER_LIFE {
+            assert_eq!(game.step("H"), Ok(String::new()));
+        }
+        assert_eq!(
+            game.step("H").unwrap(),
+            "Sorry, you lost. You have 1 lives remaining."
+        );
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn rules_follow_the_difficulty_unless_overridden() {
+        let rules = Args::parse_from(["c14", "--difficulty", "easy", "--lives", "5"]).rules();
+        assert_eq!(rules.lives, 5);
+        assert_eq!(rules.streak, 7);
+        assert_eq!(rules.range, NumRange { min: 1, max: 13 });
+    }
+
+    #[test]
+    fn range_must_hold_card_ranks() {
+        assert!(Args::try_parse_from(["c14", "--range", "2..10"]).is_ok());
+        assert!(Args::try_parse_from(["c14", "--range", "0..10"]).is_err());
+        assert!(Args::try_parse_from(["c14", "--range", "1..14"]).is_err());
+    }
+
+    #[test]
+    fn deal_shuffles_a_new_deck_of_the_ranks_in_range() {
+        let range = NumRange::new(12, 13).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut deck = shuffled_deck(&range, &mut rng);
+        // Two decks' worth of Queens and Kings and then some.
+        for _ in 0..20 {
+            let card = deal(&mut deck, &range, &mut rng);
+            assert!(matches!(card.rank, Rank::Queen | Rank::King));
+        }
+    }
+}
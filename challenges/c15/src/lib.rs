@@ -0,0 +1,108 @@
+//! # Text Query Tool
+//!
+//! This module implements a simple interactive text processing utility that allows
+//! users to perform common text analysis operations on input sentences.
+//!
+//! ## Features
+//!
+//! - Interactive command-line interface
+//! - Multiple query modes:
+//!   - Word counting - counts the number of words in a sentence
+//!   - Text reversal - reverses the characters in a sentence
+//! - Input validation with clear error handling
+//! - Simple user interface with clear prompts and feedback
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use clap::{Parser, ValueEnum};
+use std::ffi::OsString;
+use std::io::BufRead;
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum QueryType {
+    Count,
+    Reverse,
+}
+
+fn prompt_for_query_type<R: BufRead>(reader: &mut R) -> std::io::Result<QueryType> {
+    Prompt::new(l10n::text("c15.query")).ask(reader, &mut std::io::stdout(), |answer| match answer
+        .to_uppercase()
+        .as_str()
+    {
+        "C" => Ok(QueryType::Count),
+        "R" => Ok(QueryType::Reverse),
+        _ => Err(l10n::text("c15.invalid")),
+    })
+}
+
+/// Count the words in a sentence or reverse it.
+///
+/// Any value not given on the command line is prompted for interactively.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Operation to perform on the sentence
+    #[arg(short, long, value_enum)]
+    mode: Option<QueryType>,
+
+    /// Sentence to process
+    sentence: Option<String>,
+
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn run<R: BufRead>(reader: &mut R, args: Args) -> std::io::Result<()> {
+    let query = match args.mode {
+        Some(mode) => mode,
+        None => prompt_for_query_type(reader)?,
+    };
+
+    let input = match args.sentence {
+        Some(sentence) => sentence,
+        None => Prompt::new(l10n::text("c15.sentence")).ask_text(reader, &mut std::io::stdout())?,
+    };
+
+    match query {
+        QueryType::Count => {
+            let count = input.split_whitespace().count();
+            println!("{}", l10n::format("c15.count", &[("count", &count)]));
+        }
+        QueryType::Reverse => {
+            let reversed: String = input.trim().chars().rev().collect();
+            println!(
+                "{}",
+                l10n::format("c15.reversed", &[("sentence", &reversed)])
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = run(&mut input, args) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,131 @@
+//! # Number Guessing Game
+//!
+//! This module implements a two-mode number guessing game where either the player
+//! or the computer tries to guess a secret number.
+//!
+//! ## Game Modes
+//!
+//! - **Player as Guesser**: The computer chooses a random number between 1 and 100,
+//!   and the player tries to guess it with feedback after each attempt.
+//!
+//! - **Computer as Guesser**: The player thinks of a number between 1 and 100,
+//!   and the computer uses a binary search algorithm to find it based on
+//!   the player's feedback.
+//!
+//! ## Features
+//!
+//! - Interactive command-line interface
+//! - Mode selection at the beginning of the game
+//! - Input validation for all user entries
+//! - Efficient binary search algorithm for computer guessing
+//! - Tracking of attempts until the correct number is guessed
+//! - Clear feedback after each guess attempt
+//! - Personal best (fewest attempts) remembered between runs
+use crate::run;
+use challenge_common::cli::{CommonArgs, NumRange};
+use challenge_common::config;
+use challenge_common::difficulty::DifficultyArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use challenge_common::sound::{self, SoundArgs};
+use challenge_storage::{daily, Store};
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+/// Records the number of attempts a win took and returns the previous best.
+fn record_attempts(range: &NumRange<u64>, attempts: u64) -> challenge_storage::Result<Option<u64>> {
+    let mut store = Store::open("c16")?;
+    let previous = store.record_min(&format!("best_attempts_{range}"), attempts)?;
+    store.save()?;
+    Ok(previous)
+}
+
+/// Guess a secret number, or let the computer guess yours.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Range the secret number is chosen from [default: 1..50, 1..100 or
+    /// 1..1000 by difficulty]
+    #[arg(short, long)]
+    range: Option<NumRange<u64>>,
+
+    /// Number of guesses allowed before the game is lost [default: unlimited,
+    /// or 10 on hard]
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    max_guesses: Option<u64>,
+
+    #[command(flatten)]
+    difficulty: DifficultyArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    sound: SoundArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    sound::init(&args.sound);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let range = args
+        .range
+        .or(config::get().c16.range)
+        .unwrap_or(args.difficulty.level().pick(
+            NumRange { min: 1, max: 50 },
+            NumRange { min: 1, max: 100 },
+            NumRange { min: 1, max: 1000 },
+        ));
+    let max_guesses = args
+        .max_guesses
+        .or(args.difficulty.level().pick(None, None, Some(10)));
+    let result = run(
+        &mut input,
+        &mut std::io::stdout(),
+        &range,
+        max_guesses,
+        &mut rng,
+    );
+    sound::wait();
+    // A daily game that ends any other way than with the player's number
+    // found, quitting included, counts as lost.
+    if let Err(e) = daily::record("c16", matches!(result, Ok(Some(_)))) {
+        eprintln!("Warning: unable to save your daily result: {}", e);
+    }
+    let attempts = match result {
+        Ok(attempts) => attempts,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(attempts) = attempts {
+        match record_attempts(&range, attempts) {
+            Ok(Some(best)) if attempts < best => println!("{}", l10n::text("c16.new_best")),
+            Ok(Some(best)) => println!("{}", l10n::format("c16.best", &[("attempts", &best)])),
+            Ok(None) => {}
+            Err(e) => eprintln!("Warning: unable to save your score: {}", e),
+        }
+    }
+    ExitCode::SUCCESS
+}
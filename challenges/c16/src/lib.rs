@@ -0,0 +1,343 @@
+//! # Number Guessing Game
+//!
+//! Both game modes, kept apart from the command line so that the browser
+//! build can play them too.
+mod cli;
+
+pub use cli::run_cli;
+
+use challenge_common::cli::NumRange;
+use challenge_common::game::{self, Game};
+use challenge_common::l10n;
+use challenge_common::prompt;
+use challenge_common::sound::{self, Event};
+use rand::Rng;
+use std::io::{BufRead, Write};
+
+#[derive(Clone, Copy)]
+enum GuessResult {
+    TooLow,
+    TooHigh,
+    Correct,
+}
+
+/// What the game is waiting for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stage {
+    /// The player to press Enter.
+    Start,
+    /// The player to say whether they are guessing.
+    Guesser,
+    /// A guess at `secret` from the player.
+    Human {
+        secret: u64,
+    },
+    /// The player's verdict on the computer's guess, the middle of the
+    /// numbers left between `left` and `right`.
+    Computer {
+        left: u64,
+        right: u64,
+    },
+    Over,
+}
+
+/// One game, guessed by the player or by the computer. The player's number
+/// is picked with `rng`.
+pub struct NumberGuessing<R> {
+    range: NumRange<u64>,
+    /// How many guesses the player gets, or `None` for as many as they need.
+    max_guesses: Option<u64>,
+    stage: Stage,
+    attempts: u64,
+    human: bool,
+    won: bool,
+    rng: R,
+}
+
+impl<R: Rng> NumberGuessing<R> {
+    pub fn new(range: NumRange<u64>, max_guesses: Option<u64>, rng: R) -> Self {
+        NumberGuessing {
+            range,
+            max_guesses,
+            stage: Stage::Start,
+            attempts: 0,
+            human: false,
+            won: false,
+            rng,
+        }
+    }
+
+    fn choose_guesser(&mut self, answer: &str) -> Result<String, String> {
+        self.human = prompt::yes_no(answer)?;
+        if self.human {
+            let secret = self.rng.random_range(self.range.min..=self.range.max);
+            tracing::debug!(num = secret, "secret number chosen");
+            self.stage = Stage::Human { secret };
+        } else {
+            self.stage = Stage::Computer {
+                left: self.range.min,
+                right: self.range.max,
+            };
+        }
+        Ok(String::new())
+    }
+
+    fn human_guess(&mut self, secret: u64, answer: &str) -> Result<String, String> {
+        let expected = l10n::format(
+            "prompt.number_between",
+            &[("min", &self.range.min), ("max", &self.range.max)],
+        );
+        let guess = prompt::parsed(&expected, |num| self.range.contains(num))(answer)?;
+        self.attempts += 1;
+        let hint = match guess.cmp(&secret) {
+            std::cmp::Ordering::Less => l10n::text("c16.too_low"),
+            std::cmp::Ordering::Greater => l10n::text("c16.too_high"),
+            std::cmp::Ordering::Equal => {
+                self.stage = Stage::Over;
+                self.won = true;
+                sound::play(Event::Win);
+                return Ok(l10n::format("c16.got_it", &[("attempts", &self.attempts)]));
+            }
+        };
+        if self.max_guesses.is_some_and(|max| self.attempts >= max) {
+            self.stage = Stage::Over;
+            sound::play(Event::Lose);
+            let out = l10n::format("c16.out_of_guesses", &[("number", &secret)]);
+            return Ok(format!("{}\n{}", hint, out));
+        }
+        sound::play(Event::WrongGuess);
+        Ok(hint.to_string())
+    }
+
+    fn computer_guess(&mut self, left: u64, right: u64, answer: &str) -> Result<String, String> {
+        let verdict = prompt::choice(&[
+            ("H", GuessResult::TooHigh),
+            ("L", GuessResult::TooLow),
+            ("C", GuessResult::Correct),
+        ])(answer)?;
+        let guess = (left + right) / 2;
+        self.attempts += 1;
+        match verdict {
+            GuessResult::TooLow => {
+                self.stage = Stage::Computer {
+                    left: guess + 1,
+                    right,
+                };
+                Ok(String::new())
+            }
+            GuessResult::TooHigh => {
+                self.stage = Stage::Computer {
+                    left,
+                    right: guess - 1,
+                };
+                Ok(String::new())
+            }
+            GuessResult::Correct => {
+                self.stage = Stage::Over;
+                Ok(l10n::format(
+                    "c16.computer_got_it",
+                    &[("attempts", &self.attempts)],
+                ))
+            }
+        }
+    }
+}
+
+impl<R: Rng> Game for NumberGuessing<R> {
+    /// The number of attempts the player took when they guessed the number,
+    /// or `None` when they ran out of guesses or the computer was guessing.
+    type Result = Option<u64>;
+
+    fn setup(&mut self) -> String {
+        self.stage = Stage::Start;
+        self.attempts = 0;
+        self.human = false;
+        self.won = false;
+        l10n::format(
+            "c16.intro",
+            &[("min", &self.range.min), ("max", &self.range.max)],
+        )
+    }
+
+    fn prompt(&self) -> String {
+        match self.stage {
+            Stage::Start => l10n::text("prompt.continue"),
+            Stage::Guesser => l10n::text("c16.guesser"),
+            Stage::Human { .. } | Stage::Over => l10n::text("c16.guess"),
+            Stage::Computer { .. } => l10n::text("c16.feedback"),
+        }
+        .to_string()
+    }
+
+    fn step(&mut self, answer: &str) -> Result<String, String> {
+        match self.stage {
+            Stage::Start => {
+                self.stage = Stage::Guesser;
+                Ok(String::new())
+            }
+            Stage::Guesser => self.choose_guesser(answer),
+            Stage::Human { secret } => self.human_guess(secret, answer),
+            Stage::Computer { left, right } => self.computer_guess(left, right, answer),
+            Stage::Over => Ok(String::new()),
+        }
+    }
+
+    fn render(&self) -> String {
+        match self.stage {
+            Stage::Computer { left, right } => {
+                let guess = (left + right) / 2;
+                tracing::debug!(left, right, guess, "computer guessing");
+                l10n::format("c16.computer_guess", &[("guess", &guess)])
+            }
+            Stage::Human { .. } => match self.max_guesses {
+                Some(max) => l10n::format("c16.left", &[("guesses", &(max - self.attempts))]),
+                None => String::new(),
+            },
+            _ => String::new(),
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        self.stage == Stage::Over
+    }
+
+    fn result(&self) -> Option<u64> {
+        (self.human && self.won).then_some(self.attempts)
+    }
+}
+
+/// Plays one game, giving the player `max_guesses` guesses if set. Returns
+/// the number of attempts the player took when they guessed the number, or
+/// `None` when they ran out of guesses or the computer was guessing.
+pub fn run<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    range: &NumRange<u64>,
+    max_guesses: Option<u64>,
+    rng: &mut G,
+) -> std::io::Result<Option<u64>> {
+    game::play(
+        &mut NumberGuessing::new(*range, max_guesses, rng),
+        reader,
+        writer,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn run_computer_finds_number_by_bisection() {
+        let mut reader = "\nn\nH\nL\nC\n".as_bytes();
+        let mut output = Vec::new();
+
+        let attempts = run(
+            &mut reader,
+            &mut output,
+            &NumRange { min: 1, max: 100 },
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert_eq!(attempts, None);
+        let output = String::from_utf8(output).unwrap();
+        let guesses: Vec<_> = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("The computer guesses: "))
+            .collect();
+        assert_eq!(guesses, ["50", "25", "37"]);
+    }
+
+    #[test]
+    fn run_player_guesses_number() {
+        // A single-value range leaves only one possible secret.
+        let mut reader = "\ny\n8\n7\n".as_bytes();
+        let mut output = Vec::new();
+
+        let attempts = run(
+            &mut reader,
+            &mut output,
+            &NumRange { min: 7, max: 7 },
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert_eq!(attempts, Some(1));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid input. Please enter a number between 7 and 7."));
+        assert!(output.ends_with("Got it!\nIt took you 1 attempts to guess the number.\n"));
+    }
+
+    #[test]
+    fn step_narrows_the_computer_guess() {
+        let mut game = NumberGuessing::new(
+            NumRange { min: 1, max: 100 },
+            None,
+            StdRng::seed_from_u64(42),
+        );
+        game.setup();
+        game.step("").unwrap();
+        game.step("n").unwrap();
+
+        assert_eq!(game.render(), "The computer guesses: 50");
+        assert!(game.step("X").is_err());
+        game.step("L").unwrap();
+        assert_eq!(game.render(), "The computer guesses: 75");
+        assert_eq!(
+            game.step("C").unwrap(),
+            "It took the computer 2 attempts to guess the number."
+        );
+        assert_eq!(game.result(), None);
+    }
+
+    #[test]
+    fn run_reprompts_for_guesser() {
+        let mut reader = "\nmaybe\nn\nC\n".as_bytes();
+        let mut output = Vec::new();
+
+        run(
+            &mut reader,
+            &mut output,
+            &NumRange { min: 1, max: 3 },
+            None,
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid input. Please enter 'y' or 'n'."));
+        assert!(output.ends_with("It took the computer 1 attempts to guess the number.\n"));
+    }
+
+    #[test]
+    fn player_loses_after_the_last_guess() {
+        let mut game = NumberGuessing::new(
+            NumRange { min: 1, max: 100 },
+            Some(2),
+            StdRng::seed_from_u64(42),
+        );
+        game.setup();
+        game.step("").unwrap();
+        game.step("y").unwrap();
+        let Stage::Human { secret } = game.stage else {
+            panic!("the player should be guessing");
+        };
+        let wrong = if secret == 1 { "2" } else { "1" };
+
+        assert_eq!(game.render(), "Guesses left: 2");
+        game.step(wrong).unwrap();
+        assert_eq!(game.render(), "Guesses left: 1");
+        let feedback = game.step(wrong).unwrap();
+        assert!(feedback.ends_with(&format!(
+            "You're out of guesses. The number was {}.",
+            secret
+        )));
+        assert!(game.is_over());
+        assert_eq!(game.result(), None);
+    }
+}
@@ -0,0 +1,212 @@
+//! # UMS Score Converter
+//!
+//! This module implements a simple interactive UMS (Uniform Mark Scale) score converter
+//! that translates numerical scores into letter grades for educational assessment.
+//!
+//! ## Features
+//!
+//! - **Score Conversion**: Converts UMS scores between 0-100 to letter grades A-F
+//! - **Multi-Module Support**: Handles scores for two separate modules
+//! - **Average Calculation**: Computes an overall AS Level grade based on module averages
+//! - **Input Validation**: Ensures all scores are within the valid UMS range (0-100)
+//! - **Error Handling**: Provides clear feedback for invalid inputs
+//! - **JSON Output**: `--format json` prints the scores and grades as JSON
+//! - **Scriptable**: The scores can be given as arguments, e.g. `c17 85 62`
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use challenge_error::ChallengeError;
+use clap::Parser;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+type UmsScore = u32;
+const MAX_SCORE: UmsScore = 100;
+
+fn ums_to_grade(ums: UmsScore) -> challenge_error::Result<char> {
+    let grade = match ums {
+        80..=100 => 'A',
+        70..=79 => 'B',
+        60..=69 => 'C',
+        50..=59 => 'D',
+        0..=49 => 'F',
+        _ => {
+            return Err(ChallengeError::OutOfRange {
+                min: 0,
+                max: MAX_SCORE.into(),
+            })
+        }
+    };
+
+    Ok(grade)
+}
+
+fn prompt_for_module_result<R: BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: &str,
+) -> std::io::Result<UmsScore> {
+    Prompt::new(prompt).ask_parsed(
+        reader,
+        writer,
+        &l10n::format("prompt.number_between", &[("min", &0), ("max", &MAX_SCORE)]),
+        |num| *num <= MAX_SCORE,
+    )
+}
+
+fn print_results(
+    module1: UmsScore,
+    module2: UmsScore,
+    format: Format,
+) -> challenge_error::Result<()> {
+    let grade1 = ums_to_grade(module1)?;
+    let grade2 = ums_to_grade(module2)?;
+    let overall_grade = ums_to_grade((module1 + module2) / 2)?;
+    match format {
+        Format::Text => {
+            println!("{}", l10n::text("c17.result"));
+            let module = |module, grade| {
+                l10n::format("c17.grade", &[("module", &module), ("grade", &grade)])
+            };
+            println!("{}", module(1, grade1));
+            println!("{}", module(2, grade2));
+            println!(
+                "{}",
+                l10n::format("c17.as_level", &[("grade", &overall_grade)])
+            );
+        }
+        Format::Json => output::write_json(
+            &mut std::io::stdout(),
+            "c17",
+            json!({ "module1": module1, "module2": module2 }),
+            json!({
+                "module1": grade1.to_string(),
+                "module2": grade2.to_string(),
+                "as_level": overall_grade.to_string(),
+            }),
+        )?,
+    }
+
+    Ok(())
+}
+
+/// Convert two module UMS scores into letter grades.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// UMS score for Module 1, prompted for if omitted
+    #[arg(value_parser = clap::value_parser!(UmsScore).range(..=MAX_SCORE as i64))]
+    module1: Option<UmsScore>,
+    /// UMS score for Module 2, prompted for if omitted
+    #[arg(value_parser = clap::value_parser!(UmsScore).range(..=MAX_SCORE as i64))]
+    module2: Option<UmsScore>,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn run<R: BufRead>(reader: &mut R, args: &Args) -> challenge_error::Result<()> {
+    let format = args.format.format();
+    let mut prompts = format.prompts();
+    let module1 = match args.module1 {
+        Some(score) => score,
+        None => prompt_for_module_result(
+            reader,
+            &mut prompts,
+            &l10n::format("c17.module", &[("module", &1)]),
+        )?,
+    };
+    let module2 = match args.module2 {
+        Some(score) => score,
+        None => prompt_for_module_result(
+            reader,
+            &mut prompts,
+            &l10n::format("c17.module", &[("module", &2)]),
+        )?,
+    };
+    print_results(module1, module2, format)
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+
+    let result = match Input::from_args(&args.common) {
+        Ok(mut input) => run(&mut input, &args),
+        Err(e) => Err(e.into()),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ums_to_grade_returns_a_for_scores_between_80_and_100() {
+        assert_eq!(ums_to_grade(80).unwrap(), 'A');
+        assert_eq!(ums_to_grade(90).unwrap(), 'A');
+        assert_eq!(ums_to_grade(100).unwrap(), 'A');
+    }
+
+    #[test]
+    fn ums_to_grade_returns_b_for_scores_between_70_and_79() {
+        assert_eq!(ums_to_grade(70).unwrap(), 'B');
+        assert_eq!(ums_to_grade(75).unwrap(), 'B');
+        assert_eq!(ums_to_grade(79).unwrap(), 'B');
+    }
+
+    #[test]
+    fn ums_to_grade_returns_c_for_scores_between_60_and_69() {
+        assert_eq!(ums_to_grade(60).unwrap(), 'C');
+        assert_eq!(ums_to_grade(65).unwrap(), 'C');
+        assert_eq!(ums_to_grade(69).unwrap(), 'C');
+    }
+
+    #[test]
+    fn ums_to_grade_returns_d_for_scores_between_50_and_59() {
+        assert_eq!(ums_to_grade(50).unwrap(), 'D');
+        assert_eq!(ums_to_grade(55).unwrap(), 'D');
+        assert_eq!(ums_to_grade(59).unwrap(), 'D');
+    }
+
+    #[test]
+    fn ums_to_grade_returns_f_for_scores_between_0_and_49() {
+        assert_eq!(ums_to_grade(0).unwrap(), 'F');
+        assert_eq!(ums_to_grade(25).unwrap(), 'F');
+        assert_eq!(ums_to_grade(49).unwrap(), 'F');
+    }
+
+    #[test]
+    fn ums_to_grade_returns_error_for_scores_above_100() {
+        assert!(ums_to_grade(101).is_err());
+        assert!(matches!(
+            ums_to_grade(150),
+            Err(ChallengeError::OutOfRange { min: 0, max: 100 })
+        ));
+    }
+
+    #[test]
+    fn args_accept_scores_up_to_the_maximum() {
+        let args = Args::try_parse_from(["c17", "85", "100"]).unwrap();
+        assert_eq!((args.module1, args.module2), (Some(85), Some(100)));
+        assert!(Args::try_parse_from(["c17", "85", "101"]).is_err());
+        let args = Args::try_parse_from(["c17"]).unwrap();
+        assert_eq!((args.module1, args.module2), (None, None));
+    }
+}
@@ -0,0 +1,179 @@
+//! # Pyramid Generator
+//!
+//! This module implements a simple interactive pyramid generator
+//! that creates ASCII pyramids based on user input.
+//!
+//! ## Features
+//!
+//! - **ASCII Art**: Generates pyramids of stars with proper spacing
+//! - **Input Validation**: Ensures the base is an odd number
+//! - **Error Handling**: Provides clear feedback for invalid inputs
+//! - **String Formatting**: Handles proper alignment of pyramid elements
+//! - **Accessible Mode**: Describes the pyramid row by row instead of drawing it
+//! - **Colors**: Draws the stars in the active theme's colors, one per row
+use challenge_common::accessible::{self, AccessibleArgs};
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use challenge_common::theme::{self, ThemeArgs};
+use clap::Parser;
+use colored::Colorize;
+use std::ffi::OsString;
+use std::io::BufRead;
+use std::process::ExitCode;
+
+fn draw_stars(num_spaces: u32, num_stars: u32) -> String {
+    let spaces = " ".repeat(num_spaces as usize);
+    let stars = "*".repeat(num_stars as usize);
+    let line = format!("{}{}", spaces, stars);
+    line
+}
+
+fn draw_pyramid(base: u32) {
+    let palette = theme::current().palette();
+    (0..base).for_each(|i| {
+        let num_spaces = base - i - 1;
+        let num_stars = 2 * i + 1;
+        let color = palette[i as usize % palette.len()];
+        println!("{}", draw_stars(num_spaces, num_stars).color(color));
+    })
+}
+
+/// Describes the pyramid `draw_pyramid` would draw, one sentence per row.
+fn describe_pyramid(base: u32) -> Vec<String> {
+    let rows = accessible::count(
+        u64::from(base),
+        l10n::text("c18.row"),
+        l10n::text("c18.rows"),
+    );
+    let mut lines = vec![l10n::format("c18.pyramid", &[("rows", &rows)])];
+    lines.extend((0..base).map(|i| {
+        let stars = accessible::count(
+            u64::from(2 * i + 1),
+            l10n::text("c18.star"),
+            l10n::text("c18.stars"),
+        );
+        l10n::format("c18.centered", &[("row", &(i + 1)), ("stars", &stars)])
+    }));
+    lines
+}
+
+fn prompt_for_base<R: BufRead>(reader: &mut R) -> std::io::Result<u32> {
+    Prompt::new(l10n::text("c18.base")).ask_parsed(
+        reader,
+        &mut std::io::stdout(),
+        l10n::text("c18.odd"),
+        |num: &u32| !num.is_multiple_of(2),
+    )
+}
+
+fn parse_base(s: &str) -> Result<u32, String> {
+    let base: u32 = s.parse().map_err(|e| format!("{e}"))?;
+    if base.is_multiple_of(2) {
+        return Err("the base must be an odd number".to_string());
+    }
+    Ok(base)
+}
+
+/// Draw a pyramid of stars.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Base of the pyramid (must be odd), prompted for if omitted
+    #[arg(short, long, value_parser = parse_base)]
+    base: Option<u32>,
+
+    #[command(flatten)]
+    accessible: AccessibleArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    theme::init(&args.theme);
+
+    let base = match args.base {
+        Some(base) => Ok(base),
+        None => Input::from_args(&args.common).and_then(|mut input| prompt_for_base(&mut input)),
+    };
+    match base {
+        Ok(base) if args.accessible.enabled() => {
+            describe_pyramid(base)
+                .iter()
+                .for_each(|line| println!("{}", line));
+        }
+        Ok(base) => draw_pyramid(base),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_stars_returns_correct_string_with_zero_spaces() {
+        assert_eq!(draw_stars(0, 5), "*****");
+    }
+
+    #[test]
+    fn draw_stars_returns_correct_string_with_zero_stars() {
+        assert_eq!(draw_stars(3, 0), "   ");
+    }
+
+    #[test]
+    fn draw_stars_returns_correct_string_with_spaces_and_stars() {
+        assert_eq!(draw_stars(3, 5), "   *****");
+    }
+
+    #[test]
+    fn draw_stars_returns_empty_string_with_zero_spaces_and_stars() {
+        assert_eq!(draw_stars(0, 0), "");
+    }
+
+    #[test]
+    fn draw_stars_handles_large_numbers_correctly() {
+        assert_eq!(draw_stars(10, 10), "          **********");
+    }
+
+    #[test]
+    fn describe_pyramid_gives_every_row_in_words() {
+        assert_eq!(
+            describe_pyramid(3),
+            [
+                "A pyramid of stars three rows tall:",
+                "Row 1: one star centered",
+                "Row 2: three stars centered",
+                "Row 3: five stars centered",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_base_accepts_odd_numbers() {
+        assert_eq!(parse_base("7"), Ok(7));
+    }
+
+    #[test]
+    fn parse_base_rejects_even_and_non_numeric_input() {
+        assert!(parse_base("8").is_err());
+        assert!(parse_base("abc").is_err());
+    }
+}
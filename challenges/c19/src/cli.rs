@@ -0,0 +1,139 @@
+//! # ASCII Caesar Cipher
+//!
+//! This module implements a simple interactive ASCII Caesar cipher
+//! that encrypts and decrypts text by shifting characters.
+//!
+//! ## Features
+//!
+//! - **Bidirectional Operation**: Supports both encryption and decryption
+//! - **ASCII Support**: Works with the full ASCII character set (0-127)
+//! - **Character Shifting**: Shifts characters by a user-specified value
+//! - **Wraparound Handling**: Properly handles shifts that exceed ASCII bounds
+//! - **Non-ASCII Preservation**: Leaves non-ASCII characters unchanged
+//! - **Input Validation**: Provides clear feedback for invalid inputs
+//! - **Scriptable**: The mode, shift and text can be given as arguments, e.g.
+//!   `c19 encrypt --shift 5 "hello"`; anything left out is prompted for
+use crate::apply_cipher;
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use clap::{Parser, Subcommand};
+use std::ffi::OsString;
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+use std::process::ExitCode;
+
+enum CipherMode {
+    Encrypt,
+    Decrypt,
+}
+
+impl Display for CipherMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CipherMode::Encrypt => "encrypt",
+                CipherMode::Decrypt => "decrypt",
+            }
+        )
+    }
+}
+
+fn prompt_for_cipher_mode<R: BufRead>(reader: &mut R) -> std::io::Result<CipherMode> {
+    Prompt::new(l10n::text("c19.mode")).ask(reader, &mut std::io::stdout(), |answer| match answer {
+        "e" => Ok(CipherMode::Encrypt),
+        "d" => Ok(CipherMode::Decrypt),
+        _ => Err(l10n::text("c19.mode_invalid")),
+    })
+}
+
+fn prompt_for_shift_value<R: BufRead>(reader: &mut R) -> std::io::Result<i32> {
+    Prompt::new(l10n::text("c19.shift")).ask(reader, &mut std::io::stdout(), |answer| {
+        answer.parse().map_err(|_| l10n::text("c19.shift_invalid"))
+    })
+}
+
+fn prompt_for_text<R: BufRead>(reader: &mut R) -> std::io::Result<String> {
+    Prompt::new(l10n::text("c19.text")).ask_text(reader, &mut std::io::stdout())
+}
+
+/// The shift and text given on the command line.
+#[derive(clap::Args, Default)]
+struct CipherArgs {
+    /// Number of places to shift each character, prompted for if omitted
+    #[arg(short, long, allow_negative_numbers = true)]
+    shift: Option<i32>,
+    /// Text to encrypt or decrypt, prompted for if omitted
+    text: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum CipherCommand {
+    /// Encrypt text
+    Encrypt(CipherArgs),
+    /// Decrypt text
+    Decrypt(CipherArgs),
+}
+
+/// Encrypt or decrypt text with an ASCII Caesar cipher.
+///
+/// Without a mode, the mode, text and shift are prompted for interactively.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    mode: Option<CipherCommand>,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn run<R: BufRead>(reader: &mut R, command: Option<CipherCommand>) -> std::io::Result<()> {
+    let (mode, given) = match command {
+        Some(CipherCommand::Encrypt(given)) => (CipherMode::Encrypt, given),
+        Some(CipherCommand::Decrypt(given)) => (CipherMode::Decrypt, given),
+        None => (prompt_for_cipher_mode(reader)?, CipherArgs::default()),
+    };
+    let text = match given.text {
+        Some(text) => text,
+        None => prompt_for_text(reader)?,
+    };
+    let shift = match given.shift {
+        Some(shift) => shift,
+        None => prompt_for_shift_value(reader)?,
+    };
+    let (key, result) = match mode {
+        CipherMode::Encrypt => ("c19.encrypted", apply_cipher(&text, shift)),
+        CipherMode::Decrypt => ("c19.decrypted", apply_cipher(&text, shift.wrapping_neg())),
+    };
+    println!("{}", l10n::format(key, &[("result", &result)]));
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = run(&mut input, args.mode) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
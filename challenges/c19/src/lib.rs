@@ -0,0 +1,112 @@
+//! # ASCII Caesar Cipher
+//!
+//! The cipher itself, kept apart from the interactive prompts so that it can
+//! be tested on its own.
+mod cli;
+
+pub use cli::run_cli;
+
+/// Shifts every ASCII character of `text` by `shift` positions, wrapping
+/// around the 128-character ASCII range. Non-ASCII characters are unchanged.
+pub fn apply_cipher(text: &str, shift: i32) -> String {
+    text.chars().map(|c| shift_char(c, shift)).collect()
+}
+
+/// Shifts a single ASCII character by `shift` positions, wrapping around the
+/// ASCII range. Non-ASCII characters are returned unchanged.
+pub fn shift_char(c: char, shift: i32) -> char {
+    if !c.is_ascii() {
+        return c;
+    }
+
+    const ASCII_ALPHABET_LEN: i32 = 128;
+    let pos = c as i32;
+    let shifted = (pos + shift.rem_euclid(ASCII_ALPHABET_LEN)).rem_euclid(ASCII_ALPHABET_LEN);
+
+    char::from_u32(shifted as u32).unwrap_or(c)
+}
+
+/// Same as [`apply_cipher`], but shifts the bytes of `text` in place instead
+/// of decoding its characters. Shifting an ASCII byte gives another ASCII
+/// byte, and the bytes of a non-ASCII character are never ASCII, so the
+/// result is still valid UTF-8.
+pub fn apply_cipher_bytes(text: &str, shift: i32) -> String {
+    const ASCII_ALPHABET_LEN: i32 = 128;
+    let shift = shift.rem_euclid(ASCII_ALPHABET_LEN) as u8;
+    let mut bytes = text.as_bytes().to_vec();
+    for byte in bytes.iter_mut().filter(|byte| byte.is_ascii()) {
+        *byte = (*byte + shift) % ASCII_ALPHABET_LEN as u8;
+    }
+    String::from_utf8(bytes).expect("ASCII bytes stay ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_char_correctly_shifts_ascii_characters() {
+        assert_eq!(shift_char('a', 1), 'b');
+        assert_eq!(shift_char('z', 1), '{');
+        assert_eq!(shift_char('A', 1), 'B');
+    }
+
+    #[test]
+    fn shift_char_wraps_around_when_exceeding_ascii_range() {
+        assert_eq!(shift_char('~', 1), '\u{7f}');
+        assert_eq!(shift_char('\u{7f}', 1), '\u{00}');
+    }
+
+    #[test]
+    fn shift_char_handles_negative_shifts() {
+        assert_eq!(shift_char('b', -1), 'a');
+        assert_eq!(shift_char('a', -1), '`');
+    }
+
+    #[test]
+    fn shift_char_preserves_non_ascii_characters() {
+        assert_eq!(shift_char('é', 5), 'é');
+        assert_eq!(shift_char('ñ', -10), 'ñ');
+        assert_eq!(shift_char('日', 20), '日');
+    }
+
+    #[test]
+    fn shift_char_wraps_correctly_with_large_shifts() {
+        assert_eq!(shift_char('a', 128), 'a'); // Full cycle
+        assert_eq!(shift_char('a', 129), 'b'); // Full cycle plus one
+        assert_eq!(shift_char('a', -128), 'a'); // Negative full cycle
+    }
+
+    #[test]
+    fn apply_cipher_correctly_shifts_all_characters_in_string() {
+        assert_eq!(apply_cipher("abc", 1), "bcd");
+        assert_eq!(apply_cipher("xyz", 1), "yz{");
+    }
+
+    #[test]
+    fn apply_cipher_handles_empty_strings() {
+        assert_eq!(apply_cipher("", 5), "");
+    }
+
+    #[test]
+    fn apply_cipher_preserves_non_ascii_characters_in_string() {
+        assert_eq!(apply_cipher("café", 1), "dbgé");
+    }
+
+    #[test]
+    fn apply_cipher_properly_handles_negative_shifts() {
+        assert_eq!(apply_cipher("bcd", -1), "abc");
+    }
+
+    #[test]
+    fn apply_cipher_bytes_matches_apply_cipher() {
+        for (text, shift) in [("Hello, World!", 1), ("café", -3), ("\u{7f}日", 200)] {
+            assert_eq!(apply_cipher_bytes(text, shift), apply_cipher(text, shift));
+        }
+    }
+
+    #[test]
+    fn apply_cipher_correctly_processes_strings_with_spaces_and_symbols() {
+        assert_eq!(apply_cipher("Hello, World!", 1), "Ifmmp-!Xpsme\"");
+    }
+}
@@ -0,0 +1,35 @@
+use c19::{apply_cipher, apply_cipher_bytes, shift_char};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn decrypting_undoes_encrypting(text in any::<String>(), shift in any::<i32>()) {
+        let encrypted = apply_cipher(&text, shift);
+        prop_assert_eq!(apply_cipher(&encrypted, shift.wrapping_neg()), text);
+    }
+
+    #[test]
+    fn cipher_preserves_length(text in any::<String>(), shift in any::<i32>()) {
+        prop_assert_eq!(apply_cipher(&text, shift).chars().count(), text.chars().count());
+    }
+
+    #[test]
+    fn byte_cipher_matches_char_cipher(text in any::<String>(), shift in any::<i32>()) {
+        prop_assert_eq!(apply_cipher_bytes(&text, shift), apply_cipher(&text, shift));
+    }
+
+    #[test]
+    fn shifts_a_full_cycle_apart_are_equivalent(c in any::<char>(), shift in -1000..1000i32) {
+        prop_assert_eq!(shift_char(c, shift), shift_char(c, shift + 128));
+    }
+
+    #[test]
+    fn ascii_stays_ascii_and_non_ascii_is_unchanged(c in any::<char>(), shift in any::<i32>()) {
+        let shifted = shift_char(c, shift);
+        if c.is_ascii() {
+            prop_assert!(shifted.is_ascii());
+        } else {
+            prop_assert_eq!(shifted, c);
+        }
+    }
+}
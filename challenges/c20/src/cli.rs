@@ -0,0 +1,91 @@
+//! # Fibonacci Calculator
+//!
+//! This module implements a simple interactive Fibonacci number calculator
+//! that computes Fibonacci sequence values at specified indices.
+//!
+//! ## Features
+//!
+//! - **Efficient Computation**: Calculates Fibonacci numbers using an iterative approach
+//! - **Large Number Support**: Handles large Fibonacci numbers up to the 50th value using u128
+//! - **Memory Optimization**: Uses constant space regardless of input size
+//! - **Scriptable**: The index can be given as an argument, e.g. `c20 50`
+//! - **JSON Output**: `--format json` prints the index and the number as JSON,
+//!   with the number as a string since it can exceed what JSON readers handle
+use crate::{fib, FibIndex};
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use clap::Parser;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+fn prompt_for_index<R: BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> std::io::Result<FibIndex> {
+    Prompt::new(l10n::text("c20.index")).ask(reader, writer, |answer| {
+        answer.parse().map_err(|_| l10n::text("c20.invalid"))
+    })
+}
+
+/// Calculate the Fibonacci number at a given index.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Index of the Fibonacci number, prompted for if omitted
+    index: Option<FibIndex>,
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn run<R: BufRead>(reader: &mut R, index: Option<FibIndex>, format: Format) -> std::io::Result<()> {
+    let index = match index {
+        Some(index) => index,
+        None => prompt_for_index(reader, &mut format.prompts())?,
+    };
+    let number = fib(index);
+    match format {
+        Format::Text => println!(
+            "{}",
+            l10n::format("c20.result", &[("index", &index), ("number", &number)])
+        ),
+        Format::Json => output::write_json(
+            &mut std::io::stdout(),
+            "c20",
+            json!({ "index": index }),
+            json!({ "fibonacci": number.to_string() }),
+        )?,
+    }
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = run(&mut input, args.index, args.format.format()) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
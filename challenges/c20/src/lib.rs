@@ -0,0 +1,98 @@
+//! # Fibonacci Calculator
+//!
+//! Two ways of computing Fibonacci numbers: the straightforward iteration the
+//! game uses, and fast doubling for comparison.
+mod cli;
+
+pub use cli::run_cli;
+
+/// The index of a Fibonacci number.
+pub type FibIndex = u8;
+
+/// Returns the `n`th Fibonacci number, walking the sequence from the start.
+pub fn fib(n: FibIndex) -> u128 {
+    if n == 0 {
+        return 0;
+    } else if n == 1 {
+        return 1;
+    }
+
+    let mut a = 0;
+    let mut b = 1;
+    let mut c = 0;
+    (2..=n).for_each(|_| {
+        c = a + b;
+        a = b;
+        b = c;
+    });
+    c
+}
+
+/// Returns the `n`th Fibonacci number by fast doubling, which takes
+/// O(log n) steps instead of the O(n) of [`fib`]:
+///
+/// - F(2k) = F(k) * (2 * F(k + 1) - F(k))
+/// - F(2k + 1) = F(k)² + F(k + 1)²
+pub fn fib_fast_doubling(n: FibIndex) -> u128 {
+    // (F(k), F(k + 1)), starting at k = 0. F(k + 1) may wrap one step past
+    // the largest value a u128 holds, but arithmetic modulo 2^128 still
+    // gives the right F(n) whenever F(n) itself fits.
+    let mut pair: (u128, u128) = (0, 1);
+    for bit in (0..FibIndex::BITS - n.leading_zeros()).rev() {
+        let (a, b) = pair;
+        let even = a.wrapping_mul(b.wrapping_mul(2).wrapping_sub(a));
+        let odd = a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b));
+        pair = if n >> bit & 1 == 1 {
+            (odd, even.wrapping_add(odd))
+        } else {
+            (even, odd)
+        };
+    }
+    pair.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fib_returns_zero_for_index_zero() {
+        assert_eq!(fib(0), 0);
+    }
+
+    #[test]
+    fn fib_returns_one_for_index_one() {
+        assert_eq!(fib(1), 1);
+    }
+
+    #[test]
+    fn fib_calculates_small_fibonacci_numbers_correctly() {
+        assert_eq!(fib(2), 1);
+        assert_eq!(fib(3), 2);
+        assert_eq!(fib(4), 3);
+        assert_eq!(fib(5), 5);
+        assert_eq!(fib(6), 8);
+    }
+
+    #[test]
+    fn fib_calculates_medium_fibonacci_numbers_correctly() {
+        assert_eq!(fib(10), 55);
+        assert_eq!(fib(15), 610);
+        assert_eq!(fib(20), 6765);
+    }
+
+    #[test]
+    fn fib_calculates_large_fibonacci_numbers_correctly() {
+        assert_eq!(fib(30), 832040);
+        assert_eq!(fib(40), 102334155);
+        assert_eq!(fib(50), 12586269025);
+    }
+
+    #[test]
+    fn fib_fast_doubling_matches_iteration() {
+        // F(186) is the largest Fibonacci number a u128 holds
+        for n in 0..=186 {
+            assert_eq!(fib_fast_doubling(n), fib(n), "index {n}");
+        }
+    }
+}
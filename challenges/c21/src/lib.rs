@@ -0,0 +1,171 @@
+//! # Name Duplicate Counter
+//!
+//! This module implements a simple interactive name duplicate counter
+//! that tracks and reports repeated names entered by the user.
+//!
+//! ## Features
+//!
+//! - **Data Collection**: Gathers names interactively until user decides to exit
+//! - **Duplicate Detection**: Identifies and counts repeated name entries
+//! - **Hash-based Storage**: Uses efficient HashMap for name frequency tracking
+//! - **Error Handling**: Provides clear feedback for input errors
+//! - **Filtered Reporting**: Only displays names that appear multiple times
+//! - **Interactive Interface**: Allows continuous input with a clear exit command
+//! - **JSON Output**: `--format json` prints every name's count and the
+//!   duplicates as JSON
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::output::{self, json, Format, FormatArgs};
+use challenge_common::prompt::{self, Prompt};
+use clap::Parser;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+fn prompt_for_names<R: BufRead, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> std::io::Result<HashMap<String, u32>> {
+    const EXIT_MARKER: &str = "exit";
+    let mut names = HashMap::new();
+    loop {
+        let name = Prompt::new(l10n::text("c21.name")).ask_text(reader, writer)?;
+
+        if name == EXIT_MARKER {
+            break;
+        }
+
+        let count = names.entry(name).or_insert(0);
+        *count += 1;
+    }
+    Ok(names)
+}
+
+/// Enter names and report the ones that were entered more than once.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    #[command(flatten)]
+    format: FormatArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Reads names, writing the prompts to `prompts`, and reports the duplicates
+/// to `writer` in `format`.
+fn run<R: BufRead, P: Write + ?Sized, W: Write>(
+    reader: &mut R,
+    prompts: &mut P,
+    writer: &mut W,
+    format: Format,
+) -> std::io::Result<()> {
+    let names = prompt_for_names(reader, prompts)?;
+    let mut duplicates: Vec<_> = names.iter().filter(|(_, count)| **count >= 2).collect();
+    duplicates.sort();
+    match format {
+        Format::Text => {
+            for (name, count) in duplicates {
+                let line = l10n::format("c21.duplicates", &[("name", &name), ("count", &count)]);
+                writeln!(writer, "{}", line)?;
+            }
+        }
+        Format::Json => {
+            let duplicates: Vec<_> = duplicates
+                .into_iter()
+                .map(|(name, count)| json!({ "name": name, "count": count }))
+                .collect();
+            output::write_json(
+                writer,
+                "c21",
+                json!({ "names": names }),
+                json!({ "duplicates": duplicates }),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let format = args.format.format();
+    if let Err(e) = run(
+        &mut input,
+        &mut format.prompts(),
+        &mut std::io::stdout(),
+        format,
+    ) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_for_names_counts_each_name() {
+        let mut reader = "ann\nbob\nann\nexit\n".as_bytes();
+        let names = prompt_for_names(&mut reader, &mut std::io::sink()).unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names["ann"], 2);
+        assert_eq!(names["bob"], 1);
+    }
+
+    #[test]
+    fn run_reports_duplicates_sorted_by_name() {
+        let mut reader = "cy\nbob\nann\nbob\nann\nann\nexit\n".as_bytes();
+        let mut output = Vec::new();
+
+        run(&mut reader, &mut std::io::sink(), &mut output, Format::Text).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("ann has 3 duplicates.\nbob has 2 duplicates.\n"));
+    }
+
+    #[test]
+    fn run_reports_counts_and_duplicates_as_json() {
+        let mut reader = "bob\nann\nbob\nexit\n".as_bytes();
+        let mut output = Vec::new();
+
+        run(&mut reader, &mut std::io::sink(), &mut output, Format::Json).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"challenge\":\"c21\",\"input\":{\"names\":{\"ann\":1,\"bob\":2}},\
+             \"result\":{\"duplicates\":[{\"count\":2,\"name\":\"bob\"}]}}\n"
+        );
+    }
+
+    #[test]
+    fn run_fails_without_exit_marker() {
+        let mut reader = "ann\n".as_bytes();
+        assert!(run(
+            &mut reader,
+            &mut std::io::sink(),
+            &mut std::io::sink(),
+            Format::Text
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,149 @@
+//! # Random Array Generator and Visualizer
+//!
+//! This module implements a random 2D array generator and visualizer
+//! that creates and displays numerical arrays with colorful representations.
+//!
+//! ## Features
+//!
+//! - **Random Generation**: Creates 2D arrays with random values in specified ranges
+//! - **Numerical Display**: Outputs formatted numerical representation of arrays
+//! - **Color Visualization**: Renders arrays using terminal background colors
+//!   from the active theme, or palette indices when colors are disabled
+//! - **Modular Design**: Separates generation and visualization concerns
+//! - **Customizable Dimensions**: Supports arbitrary square array sizes
+//! - **Accessible Mode**: Lists the values and color names row by row
+use crate::create_rand_2d_array;
+use challenge_common::accessible::AccessibleArgs;
+use challenge_common::cli::NumRange;
+use challenge_common::l10n::{self, LangArgs};
+use challenge_common::log::{self, LogArgs};
+use challenge_common::rng::RngArgs;
+use challenge_common::theme::{self, ThemeArgs};
+use clap::Parser;
+use colored::{Color, Colorize};
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+fn print_2d_array(arr: &[Vec<u32>]) {
+    arr.iter().for_each(|row| {
+        row.iter().for_each(|elem| {
+            print!("{:4}", elem);
+        });
+        println!();
+    });
+}
+
+fn print_2d_array_colored(arr: &[Vec<u32>]) {
+    let palette = theme::current().palette();
+    let colored = theme::is_enabled();
+    arr.iter().for_each(|row| {
+        row.iter().for_each(|elem| {
+            let index = *elem as usize % palette.len();
+            if colored {
+                print!("{}", " ".on_color(palette[index]));
+            } else {
+                print!("{}", index);
+            }
+        });
+        println!();
+    });
+}
+
+/// Describes `arr` row by row, naming each value's color from `palette` when
+/// `colors` is set.
+fn describe_2d_array(arr: &[Vec<u32>], palette: &[Color], colors: bool) -> Vec<String> {
+    let mut lines = vec![l10n::text(if colors { "c22.colors" } else { "c22.values" }).to_string()];
+    lines.extend(arr.iter().enumerate().map(|(i, row)| {
+        let cells: Vec<_> = row
+            .iter()
+            .map(|elem| {
+                if colors {
+                    theme::color_name(palette[*elem as usize % palette.len()]).to_string()
+                } else {
+                    elem.to_string()
+                }
+            })
+            .collect();
+        l10n::format(
+            "c22.row",
+            &[("row", &(i + 1)), ("cells", &cells.join(", "))],
+        )
+    }));
+    lines
+}
+
+/// Generate a random square array and print it as numbers and as colors.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number of rows and columns in the array
+    #[arg(short, long, default_value_t = 10)]
+    size: usize,
+
+    /// Range the array values are drawn from
+    #[arg(long, default_value = "0..15")]
+    values: NumRange<u32>,
+
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+    #[command(flatten)]
+    accessible: AccessibleArgs,
+    #[command(flatten)]
+    lang: LangArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.log);
+    l10n::init(&args.lang);
+    let mut rng = args.rng.rng(None);
+    theme::init(&args.theme);
+
+    let num_rng = (args.values.min, args.values.max);
+    let arr = create_rand_2d_array(args.size, &num_rng, &mut rng);
+    tracing::debug!(size = args.size, values = %args.values, "array generated");
+    if args.accessible.enabled() {
+        let palette = theme::current().palette();
+        for colors in [false, true] {
+            describe_2d_array(&arr, palette, colors)
+                .iter()
+                .for_each(|line| println!("{}", line));
+        }
+    } else {
+        print_2d_array(&arr);
+        print_2d_array_colored(&arr);
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_2d_array_lists_values_and_color_names() {
+        let arr = vec![vec![0, 7], vec![2, 13]];
+        let palette = [Color::Red, Color::Green, Color::Blue];
+        assert_eq!(
+            describe_2d_array(&arr, &palette, false),
+            ["Values, row by row:", "Row 1: 0, 7", "Row 2: 2, 13"]
+        );
+        assert_eq!(
+            describe_2d_array(&arr, &palette, true),
+            [
+                "Colors, row by row:",
+                "Row 1: red, green",
+                "Row 2: blue, green"
+            ]
+        );
+    }
+}
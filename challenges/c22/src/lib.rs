@@ -0,0 +1,40 @@
+//! # Random Array Generator
+//!
+//! Array generation, kept apart from the printing so that it can be tested
+//! and benchmarked on its own.
+mod cli;
+
+pub use cli::run_cli;
+
+use rand::Rng;
+
+/// Returns an `n` by `n` array of values drawn uniformly from the inclusive
+/// range `num_rng`, drawn from `rng`.
+pub fn create_rand_2d_array<R: Rng + ?Sized>(
+    n: usize,
+    num_rng: &(u32, u32),
+    rng: &mut R,
+) -> Vec<Vec<u32>> {
+    let mut arr = vec![vec![0; n]; n];
+    arr.iter_mut().for_each(|row| {
+        row.iter_mut().for_each(|elem| {
+            *elem = rng.random_range(num_rng.0..=num_rng.1);
+        });
+    });
+    arr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn create_rand_2d_array_is_square_and_in_range() {
+        let arr = create_rand_2d_array(5, &(3, 7), &mut StdRng::seed_from_u64(42));
+        assert_eq!(arr.len(), 5);
+        assert!(arr.iter().all(|row| row.len() == 5));
+        assert!(arr.iter().flatten().all(|elem| (3..=7).contains(elem)));
+    }
+}
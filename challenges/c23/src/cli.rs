@@ -0,0 +1,197 @@
+//! # Treasure Hunt Game
+//!
+//! This module implements an interactive treasure hunt game
+//! where players search for hidden treasures on a 2D grid.
+//!
+//! ## Features
+//!
+//! - **Random Generation**: Creates random treasure locations on a grid
+//! - **Proximity Hints**: Provides "hot/warm/cold" feedback based on distance
+//! - **Distance Calculation**: Uses Euclidean distance to measure proximity
+//! - **Input Validation**: Ensures coordinates are within the grid boundaries
+//! - **Error Handling**: Provides clear feedback for invalid inputs
+//! - **Interactive Gameplay**: Continues until the treasure is found
+//! - **Best Score**: Remembers the fewest guesses needed for each grid size
+//! - **Accessible Mode**: Keeps to line-by-line play, where every hint is text
+//! - **Network Play**: Race another player on the LAN to the same treasure
+use crate::net;
+#[cfg(feature = "tui")]
+use crate::tui;
+use crate::{generate_random_coord, run};
+use challenge_common::accessible::AccessibleArgs;
+use challenge_common::cli::CommonArgs;
+use challenge_common::clock::{self, GameClock};
+use challenge_common::config;
+use challenge_common::difficulty::DifficultyArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use challenge_common::sound::{self, SoundArgs};
+use challenge_common::theme::{self, ThemeArgs};
+use challenge_netplay::{Lobby, Mode, NetArgs, Side};
+use challenge_storage::{daily, Store};
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Records the number of guesses a win took and the time it took, and
+/// returns the previous best.
+fn record_guesses(
+    map_size: u32,
+    guesses: u32,
+    elapsed: Duration,
+) -> challenge_storage::Result<Option<u32>> {
+    let mut store = Store::open("c23")?;
+    store.add("seconds_played", elapsed.as_secs())?;
+    let previous = store.record_min(&format!("best_guesses_{map_size}x{map_size}"), guesses)?;
+    store.save()?;
+    Ok(previous)
+}
+
+/// Hunt for treasure hidden on a square grid.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Width and height of the grid [default: 5, 10 or 20 by difficulty]
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    grid_size: Option<u32>,
+
+    /// Play in a full-screen terminal UI
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui: bool,
+
+    #[command(flatten)]
+    net: NetArgs,
+    #[command(flatten)]
+    accessible: AccessibleArgs,
+    #[command(flatten)]
+    difficulty: DifficultyArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+    #[command(flatten)]
+    sound: SoundArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    theme::init(&args.theme);
+    sound::init(&args.sound);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let grid_size = args
+        .grid_size
+        .or(config::get().c23.grid_size)
+        .unwrap_or(args.difficulty.level().pick(5, 10, 20));
+    let mut clock = GameClock::start();
+    let mode = match args.net.mode() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(mode) = mode {
+        let mut stdout = std::io::stdout();
+        let result = match mode {
+            Mode::Host(addr) => {
+                Lobby::bind(&addr, "c23", &args.net.name(Side::Host)).and_then(|lobby| {
+                    let treasure = generate_random_coord(grid_size, &mut rng);
+                    net::host(
+                        &mut input,
+                        &mut stdout,
+                        &lobby,
+                        grid_size,
+                        treasure,
+                        &mut clock,
+                    )
+                })
+            }
+            Mode::Join(addr) => net::join(
+                &mut input,
+                &mut stdout,
+                &addr,
+                &args.net.name(Side::Guest),
+                &mut clock,
+            ),
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        sound::wait();
+        return ExitCode::SUCCESS;
+    }
+    #[cfg(feature = "tui")]
+    if args.tui && args.accessible.enabled() {
+        println!("{}", l10n::text("c23.no_map"));
+    }
+    #[cfg(feature = "tui")]
+    let guesses = if args.tui && !args.accessible.enabled() {
+        tui::run(grid_size, &mut rng)
+    } else {
+        run(
+            &mut input,
+            &mut std::io::stdout(),
+            grid_size,
+            &mut clock,
+            &mut rng,
+        )
+        .map(Some)
+    };
+    #[cfg(not(feature = "tui"))]
+    let guesses = run(
+        &mut input,
+        &mut std::io::stdout(),
+        grid_size,
+        &mut clock,
+        &mut rng,
+    )
+    .map(Some);
+    sound::wait();
+    // A daily game that ends any other way than with the treasure found,
+    // quitting included, counts as lost.
+    if let Err(e) = daily::record("c23", matches!(guesses, Ok(Some(_)))) {
+        eprintln!("Warning: unable to save your daily result: {}", e);
+    }
+    let guesses = match guesses {
+        Ok(guesses) => guesses,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(guesses) = guesses {
+        let time = clock::format(clock.elapsed());
+        println!("{}", l10n::format("clock.time_played", &[("time", &time)]));
+        match record_guesses(grid_size, guesses, clock.elapsed()) {
+            Ok(Some(best)) if guesses < best => println!("{}", l10n::text("c23.new_best")),
+            Ok(Some(best)) => println!("{}", l10n::format("c23.best", &[("guesses", &best)])),
+            Ok(None) => {}
+            Err(e) => eprintln!("Warning: unable to save your score: {}", e),
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,309 @@
+//! # Treasure Hunt Game
+//!
+//! The grid, the hints and the line-by-line hunt, kept apart from the command
+//! line so that the browser build can play them too.
+mod cli;
+mod net;
+#[cfg(feature = "tui")]
+mod tui;
+
+pub use cli::run_cli;
+
+use challenge_common::clock::GameClock;
+use challenge_common::game::{self, Game};
+use challenge_common::l10n;
+use challenge_common::prompt::Prompt;
+use challenge_common::sound::{self, Event};
+use challenge_common::theme;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+
+/// A square on the grid as (x, y), counted from 0.
+pub type Point2D = (u32, u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Proximity {
+    Hot,
+    Warm,
+    Cold,
+}
+
+impl Proximity {
+    /// The word for how close a guess is, e.g. `hot`.
+    pub fn word(self) -> &'static str {
+        l10n::text(match self {
+            Proximity::Hot => "c23.hot",
+            Proximity::Warm => "c23.warm",
+            Proximity::Cold => "c23.cold",
+        })
+    }
+
+    /// The hint telling the player how close their guess is, e.g.
+    /// `You're hot!`.
+    pub fn hint(self) -> String {
+        l10n::format("c23.you_are", &[("hint", &self.word())])
+    }
+}
+
+/// Picks a random square on a `size` by `size` grid with `rng`.
+pub fn generate_random_coord<R: Rng + ?Sized>(size: u32, rng: &mut R) -> (u32, u32) {
+    (rng.random_range(0..size), rng.random_range(0..size))
+}
+
+fn calculate_2d_distance(p1: Point2D, p2: Point2D) -> f64 {
+    let x_diff = f64::from(p1.0) - f64::from(p2.0);
+    let y_diff = f64::from(p1.1) - f64::from(p2.1);
+    (x_diff.powi(2) + y_diff.powi(2)).sqrt()
+}
+
+pub fn prompt_for_location<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    size: u32,
+    clock: &mut GameClock,
+) -> std::io::Result<Point2D> {
+    Prompt::new(l10n::text("c23.location")).ask_with(
+        writer,
+        |writer| clock.read_line(reader, writer),
+        |answer| parse_location(size, answer),
+    )
+}
+
+/// Parses an `x,y` answer naming a square on a `size` by `size` grid.
+fn parse_location(size: u32, answer: &str) -> Result<Point2D, &'static str> {
+    let invalid = l10n::text("c23.invalid");
+    let (x, y) = answer.split_once(',').ok_or(invalid)?;
+    match (x.parse(), y.parse()) {
+        (Ok(x), Ok(y)) if x >= size || y >= size => Err(l10n::text("c23.out_of_bounds")),
+        (Ok(x), Ok(y)) => Ok((x, y)),
+        _ => Err(invalid),
+    }
+}
+
+/// Returns how close `p1` is to `p2`, relative to the size of the grid.
+pub fn get_proximity(size: u32, p1: Point2D, p2: Point2D) -> Proximity {
+    let distance = calculate_2d_distance(p1, p2);
+    let hot_radius = f64::from(size) * 0.25;
+    let warm_radius = f64::from(size) * 0.5;
+    if distance <= hot_radius {
+        Proximity::Hot
+    } else if distance <= warm_radius {
+        Proximity::Warm
+    } else {
+        Proximity::Cold
+    }
+}
+
+/// A hunt for treasure hidden on a square grid.
+pub struct TreasureHunt<R> {
+    size: u32,
+    treasure: Point2D,
+    guesses: u32,
+    found: bool,
+    rng: R,
+}
+
+impl<R: Rng> TreasureHunt<R> {
+    /// Creates a hunt on a `size` by `size` grid. The treasure is hidden with
+    /// `rng` by [`Game::setup`].
+    pub fn new(size: u32, rng: R) -> Self {
+        TreasureHunt {
+            size,
+            treasure: (0, 0),
+            guesses: 0,
+            found: false,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng> Game for TreasureHunt<R> {
+    /// The number of guesses taken.
+    type Result = u32;
+
+    fn setup(&mut self) -> String {
+        self.guesses = 0;
+        self.found = false;
+        self.treasure = generate_random_coord(self.size, &mut self.rng);
+        tracing::debug!(treasure = ?self.treasure, "treasure hidden");
+        l10n::format("c23.intro", &[("size", &self.size)])
+    }
+
+    fn prompt(&self) -> String {
+        l10n::text("c23.location").to_string()
+    }
+
+    fn step(&mut self, answer: &str) -> Result<String, String> {
+        let guess = parse_location(self.size, answer)?;
+        self.guesses += 1;
+        tracing::debug!(?guess, num_guesses = self.guesses, "location guessed");
+        if guess == self.treasure {
+            self.found = true;
+            sound::play(Event::Win);
+            let message = l10n::format("c23.found_in", &[("guesses", &self.guesses)]);
+            return Ok(theme::win(&message).to_string());
+        }
+
+        let hint = get_proximity(self.size, guess, self.treasure).hint();
+        sound::play(Event::WrongGuess);
+        Ok(theme::hint(&hint).to_string())
+    }
+
+    fn render(&self) -> String {
+        String::new()
+    }
+
+    fn is_over(&self) -> bool {
+        self.found
+    }
+
+    fn result(&self) -> u32 {
+        self.guesses
+    }
+}
+
+/// Plays until the treasure is found and returns the number of guesses taken.
+/// The player can pause `clock` at any prompt.
+pub fn run<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    map_size: u32,
+    clock: &mut GameClock,
+    rng: &mut G,
+) -> std::io::Result<u32> {
+    game::play_timed(&mut TreasureHunt::new(map_size, rng), reader, writer, clock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn calculate_2d_distance_returns_zero_for_same_points() {
+        assert_eq!(calculate_2d_distance((5, 5), (5, 5)), 0.0);
+    }
+
+    #[test]
+    fn calculate_2d_distance_calculates_horizontal_distance_correctly() {
+        assert_eq!(calculate_2d_distance((0, 0), (3, 0)), 3.0);
+        assert_eq!(calculate_2d_distance((5, 7), (10, 7)), 5.0);
+    }
+
+    #[test]
+    fn calculate_2d_distance_calculates_vertical_distance_correctly() {
+        assert_eq!(calculate_2d_distance((0, 0), (0, 4)), 4.0);
+        assert_eq!(calculate_2d_distance((8, 2), (8, 7)), 5.0);
+    }
+
+    #[test]
+    fn calculate_2d_distance_calculates_diagonal_distance_correctly() {
+        assert_eq!(calculate_2d_distance((0, 0), (3, 4)), 5.0);
+        assert_eq!(calculate_2d_distance((1, 1), (4, 5)), 5.0);
+    }
+
+    #[test]
+    fn calculate_2d_distance_handles_large_coordinates() {
+        let result = calculate_2d_distance((100, 100), (104, 103));
+        assert!((result - 5.0).abs() < 0.00001);
+    }
+
+    #[test]
+    fn calculate_2d_distance_is_commutative() {
+        let point1 = (3, 7);
+        let point2 = (8, 2);
+        let distance1 = calculate_2d_distance(point1, point2);
+        let distance2 = calculate_2d_distance(point2, point1);
+        assert_eq!(distance1, distance2);
+    }
+
+    #[test]
+    fn get_proximity_returns_hot_for_close_points() {
+        // Within 25% of the size
+        let size = 10;
+        let hot_threshold = (size as f64 * 0.25) as u32;
+
+        // Test at exact threshold
+        assert!(matches!(
+            get_proximity(size, (5, 5), (5, 5 + hot_threshold)),
+            Proximity::Hot
+        ));
+
+        // Test well within threshold
+        assert!(matches!(
+            get_proximity(size, (5, 5), (6, 6)),
+            Proximity::Hot
+        ));
+    }
+
+    #[test]
+    fn get_proximity_returns_warm_for_medium_distance_points() {
+        // Between 25% and 50% of the size
+        let size = 10;
+        let hot_threshold = (size as f64 * 0.25) as u32;
+        let warm_threshold = (size as f64 * 0.5) as u32;
+
+        // Test just outside hot threshold
+        assert!(matches!(
+            get_proximity(size, (5, 5), (5, 5 + hot_threshold + 1)),
+            Proximity::Warm
+        ));
+
+        // Test at warm threshold
+        assert!(matches!(
+            get_proximity(size, (5, 5), (5, 5 + warm_threshold)),
+            Proximity::Warm
+        ));
+    }
+
+    #[test]
+    fn get_proximity_returns_cold_for_distant_points() {
+        // Beyond 50% of the size
+        let size = 10;
+        let warm_threshold = (size as f64 * 0.5) as u32;
+
+        // Test just outside warm threshold
+        assert!(matches!(
+            get_proximity(size, (5, 5), (5, 5 + warm_threshold + 1)),
+            Proximity::Cold
+        ));
+
+        // Test at maximum distance
+        assert!(matches!(
+            get_proximity(size, (0, 0), (size - 1, size - 1)),
+            Proximity::Cold
+        ));
+    }
+
+    #[test]
+    fn generate_random_coord_is_the_same_for_the_same_seed() {
+        let coord = |seed| generate_random_coord(20, &mut StdRng::seed_from_u64(seed));
+        assert_eq!(coord(3), coord(3));
+        let (x, y) = coord(3);
+        assert!(x < 20 && y < 20);
+    }
+
+    #[test]
+    fn step_rejects_squares_off_the_grid() {
+        // A one-square grid leaves only one place for the treasure.
+        let mut hunt = TreasureHunt::new(1, StdRng::seed_from_u64(42));
+        hunt.setup();
+
+        assert_eq!(
+            hunt.step("1,0"),
+            Err(l10n::text("c23.out_of_bounds").to_string())
+        );
+        assert_eq!(
+            hunt.step("zero"),
+            Err(l10n::text("c23.invalid").to_string())
+        );
+        assert!(!hunt.is_over());
+
+        hunt.step("0,0").unwrap();
+        assert!(hunt.is_over());
+        assert_eq!(hunt.result(), 1);
+    }
+}
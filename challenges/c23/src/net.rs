@@ -0,0 +1,233 @@
+//! Two-player treasure hunt over the network.
+//!
+//! The host hides the treasure and checks every guess. The players take
+//! turns, the host first, and whoever finds the treasure first wins. Both
+//! players see each other's guesses and hints.
+use crate::{get_proximity, prompt_for_location, Point2D, Proximity};
+use challenge_common::clock::GameClock;
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_netplay::{Guest, Lobby, Side};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+
+/// Rules the host sends to the guest when it joins.
+#[derive(Debug, Serialize, Deserialize)]
+struct Settings {
+    grid_size: u32,
+}
+
+/// A location guessed by the guest.
+#[derive(Debug, Serialize, Deserialize)]
+struct Turn {
+    x: u32,
+    y: u32,
+}
+
+/// The host's account of a guess, sent after every turn.
+#[derive(Debug, Serialize, Deserialize)]
+struct Report {
+    side: Side,
+    x: u32,
+    y: u32,
+    /// How close the guess was, or `None` if it found the treasure.
+    hint: Option<Proximity>,
+}
+
+fn check(side: Side, guess: Point2D, grid_size: u32, treasure: Point2D) -> Report {
+    let hint = (guess != treasure).then(|| get_proximity(grid_size, guess, treasure));
+    tracing::debug!(?side, ?guess, ?hint, "location checked");
+    Report {
+        side,
+        x: guess.0,
+        y: guess.1,
+        hint,
+    }
+}
+
+/// Tells the player on `me` about `report`. Returns `true` if the treasure
+/// was found.
+fn show<W: Write>(
+    writer: &mut W,
+    report: &Report,
+    me: Side,
+    opponent: &str,
+) -> std::io::Result<bool> {
+    let location = format!("{},{}", report.x, report.y);
+    let hint = match report.hint {
+        Some(hint) => hint,
+        None if report.side == me => {
+            sound::play(Event::Win);
+            writeln!(writer, "{}", l10n::text("c23.found"))?;
+            return Ok(true);
+        }
+        None => {
+            sound::play(Event::Lose);
+            let line = l10n::format(
+                "c23.found_first",
+                &[("name", &opponent), ("location", &location)],
+            );
+            writeln!(writer, "{}", line)?;
+            return Ok(true);
+        }
+    };
+    if report.side == me {
+        sound::play(Event::WrongGuess);
+        writeln!(writer, "{}", hint.hint())?;
+    } else {
+        let line = l10n::format(
+            "c23.guessed",
+            &[
+                ("name", &opponent),
+                ("location", &location),
+                ("hint", &hint.word()),
+            ],
+        );
+        writeln!(writer, "{}", line)?;
+    }
+    Ok(false)
+}
+
+/// Hosts a hunt for `treasure` on a `grid_size` grid and plays it once a
+/// guest joins `lobby`.
+pub fn host<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    lobby: &Lobby,
+    grid_size: u32,
+    treasure: Point2D,
+    clock: &mut GameClock,
+) -> std::io::Result<()> {
+    let addr = lobby.local_addr()?;
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("net.waiting_to_join", &[("addr", &addr)])
+    )?;
+    let mut host = lobby.accept(&Settings { grid_size })?;
+    let guest = host.guest().to_string();
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("c23.joined", &[("name", &guest), ("size", &grid_size)])
+    )?;
+
+    loop {
+        let guess = prompt_for_location(reader, writer, grid_size, clock)?;
+        let report = check(Side::Host, guess, grid_size, treasure);
+        host.send_state(&report)?;
+        if show(writer, &report, Side::Host, &guest)? {
+            return Ok(());
+        }
+
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &guest)])
+        )?;
+        let Turn { x, y } = host.recv_turn()?;
+        if x >= grid_size || y >= grid_size {
+            host.connection().bye("guess off the grid")?;
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} guessed a location off the grid", guest),
+            ));
+        }
+        let report = check(Side::Guest, (x, y), grid_size, treasure);
+        host.send_state(&report)?;
+        if show(writer, &report, Side::Host, &guest)? {
+            return Ok(());
+        }
+    }
+}
+
+/// Joins the hunt hosted at `addr` as `name` and plays it.
+pub fn join<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    addr: &str,
+    name: &str,
+    clock: &mut GameClock,
+) -> std::io::Result<()> {
+    let mut guest = Guest::join(addr, "c23", name)?;
+    let Settings { grid_size } = guest.settings()?;
+    let host = guest.host().to_string();
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("c23.joined_hunt", &[("name", &host), ("size", &grid_size)])
+    )?;
+
+    loop {
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &host)])
+        )?;
+        let report: Report = guest.recv_state()?;
+        if show(writer, &report, Side::Guest, &host)? {
+            return Ok(());
+        }
+
+        let (x, y) = prompt_for_location(reader, writer, grid_size, clock)?;
+        guest.send_turn(&Turn { x, y })?;
+        let report: Report = guest.recv_state()?;
+        if show(writer, &report, Side::Guest, &host)? {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn players_take_turns_until_one_finds_the_treasure() {
+        let lobby = Lobby::bind("127.0.0.1:0", "c23", "Bob").unwrap();
+        let addr = lobby.local_addr().unwrap().to_string();
+        let host_thread = std::thread::spawn(move || {
+            let mut output = Vec::new();
+            host(
+                &mut "0,0\n".as_bytes(),
+                &mut output,
+                &lobby,
+                10,
+                (5, 5),
+                &mut GameClock::start(),
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        });
+
+        let mut output = Vec::new();
+        join(
+            &mut "10,3\n5,5\n".as_bytes(),
+            &mut output,
+            &addr,
+            "Ada",
+            &mut GameClock::start(),
+        )
+        .unwrap();
+        let host = host_thread.join().unwrap();
+        let guest = String::from_utf8(output).unwrap();
+
+        assert!(host.ends_with(
+            "Ada joined. Find the treasure on the 10x10 grid before they do, you go first.\n\
+             Enter the x,y location of the treasure: \n\
+             You're cold!\n\
+             Waiting for Ada...\n\
+             Ada found the treasure at 5,5 first.\n"
+        ));
+        assert_eq!(
+            guest,
+            "Joined Bob's hunt. Find the treasure on the 10x10 grid before they do, Bob goes first.\n\
+             Waiting for Bob...\n\
+             Bob guessed 0,0 and is cold.\n\
+             Enter the x,y location of the treasure: \n\
+             Coordinates out of bounds. Please enter values within the grid size.\n\
+             Enter the x,y location of the treasure: \n\
+             Congratulations! You found the treasure!\n"
+        );
+    }
+}
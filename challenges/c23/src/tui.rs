@@ -0,0 +1,187 @@
+//! Full-screen version of the game, enabled with `--tui`.
+use crate::{generate_random_coord, get_proximity, Point2D, Proximity};
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_tui::crossterm::event::KeyCode;
+use challenge_tui::ratatui::layout::{Constraint, Layout};
+use challenge_tui::ratatui::style::Color;
+use challenge_tui::ratatui::text::Line;
+use challenge_tui::ratatui::Frame;
+use challenge_tui::widgets::{Grid, StatusBar};
+use rand::Rng;
+
+/// The keys shown in the status bar, with what they do.
+fn keys() -> [(&'static str, &'static str); 3] {
+    [
+        ("←↑↓→", l10n::text("tui.move")),
+        ("enter", l10n::text("c23.dig")),
+        ("q", l10n::text("tui.quit")),
+    ]
+}
+
+/// State of one hunt as shown on screen.
+struct Hunt {
+    size: u32,
+    treasure: Point2D,
+    cursor: Point2D,
+    /// Every location dug so far, with the hint it gave.
+    dug: Vec<(Point2D, Proximity)>,
+    found: bool,
+}
+
+impl Hunt {
+    /// A hunt with the treasure hidden with `rng`.
+    fn new<R: Rng + ?Sized>(size: u32, rng: &mut R) -> Hunt {
+        let treasure = generate_random_coord(size, rng);
+        tracing::debug!(?treasure, "treasure hidden");
+        Hunt {
+            size,
+            treasure,
+            cursor: (size / 2, size / 2),
+            dug: Vec::new(),
+            found: false,
+        }
+    }
+
+    fn move_cursor(&mut self, dx: i64, dy: i64) {
+        let clamp = |v: u32, d: i64| (i64::from(v) + d).clamp(0, i64::from(self.size) - 1) as u32;
+        self.cursor = (clamp(self.cursor.0, dx), clamp(self.cursor.1, dy));
+    }
+
+    fn dig(&mut self) {
+        if self.cursor == self.treasure {
+            self.found = true;
+            sound::play(Event::Win);
+            return;
+        }
+        sound::play(Event::WrongGuess);
+        if !self.dug.iter().any(|(point, _)| *point == self.cursor) {
+            let proximity = get_proximity(self.size, self.cursor, self.treasure);
+            self.dug.push((self.cursor, proximity));
+        }
+    }
+
+    fn guesses(&self) -> u32 {
+        self.dug.len() as u32 + u32::from(self.found)
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let mut grid = Grid::new(self.size, self.size);
+        for ((x, y), proximity) in &self.dug {
+            // The shade tells the hints apart without relying on color.
+            let (symbol, color) = match proximity {
+                Proximity::Hot => ('█', Color::Red),
+                Proximity::Warm => ('▒', Color::Yellow),
+                Proximity::Cold => ('░', Color::Blue),
+            };
+            grid = grid.cell(*x, *y, symbol, color);
+        }
+        grid = if self.found {
+            grid.cell(self.treasure.0, self.treasure.1, '$', Color::LightYellow)
+        } else {
+            grid.cursor(self.cursor.0, self.cursor.1)
+        };
+
+        let [title, map, _, status] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(grid.size().1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        frame.render_widget(
+            Line::raw(l10n::format(
+                "c23.legend",
+                &[
+                    ("size", &self.size),
+                    ("hot", &Proximity::Hot.word()),
+                    ("warm", &Proximity::Warm.word()),
+                    ("cold", &Proximity::Cold.word()),
+                ],
+            )),
+            title,
+        );
+        frame.render_widget(grid, map);
+
+        let message = if self.found {
+            l10n::format("c23.found_tui", &[("guesses", &self.guesses())])
+        } else {
+            let hint = match self.dug.last() {
+                Some((_, proximity)) => proximity.hint(),
+                None => l10n::text("c23.pick").to_string(),
+            };
+            l10n::format(
+                "c23.status",
+                &[
+                    ("location", &format!("{},{}", self.cursor.0, self.cursor.1)),
+                    ("hint", &hint),
+                    ("guesses", &self.guesses()),
+                ],
+            )
+        };
+        let keys = keys();
+        let keys: &[_] = if self.found { &[] } else { &keys };
+        frame.render_widget(
+            StatusBar::new(l10n::text("c23.title"))
+                .message(message)
+                .keys(keys),
+            status,
+        );
+    }
+}
+
+/// Plays full-screen until the treasure is found. Returns the number of
+/// guesses taken, or `None` if the player quit.
+pub fn run<R: Rng + ?Sized>(size: u32, rng: &mut R) -> std::io::Result<Option<u32>> {
+    challenge_tui::run(|terminal| {
+        let mut hunt = Hunt::new(size, rng);
+        loop {
+            terminal.draw(|frame| hunt.draw(frame))?;
+            let key = challenge_tui::next_key()?;
+            if hunt.found {
+                return Ok(Some(hunt.guesses()));
+            }
+            if challenge_tui::is_quit(&key) {
+                return Ok(None);
+            }
+
+            match key.code {
+                KeyCode::Left => hunt.move_cursor(-1, 0),
+                KeyCode::Right => hunt.move_cursor(1, 0),
+                KeyCode::Up => hunt.move_cursor(0, -1),
+                KeyCode::Down => hunt.move_cursor(0, 1),
+                KeyCode::Enter | KeyCode::Char(' ') => hunt.dig(),
+                _ => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn move_cursor_stays_on_the_grid() {
+        let mut hunt = Hunt::new(3, &mut StdRng::seed_from_u64(42));
+        hunt.move_cursor(-5, 5);
+        assert_eq!(hunt.cursor, (0, 2));
+    }
+
+    #[test]
+    fn dig_counts_each_location_once() {
+        let mut hunt = Hunt::new(10, &mut StdRng::seed_from_u64(42));
+        hunt.treasure = (9, 9);
+        hunt.cursor = (0, 0);
+        hunt.dig();
+        hunt.dig();
+        assert_eq!(hunt.guesses(), 1);
+
+        hunt.cursor = (9, 9);
+        hunt.dig();
+        assert!(hunt.found);
+        assert_eq!(hunt.guesses(), 2);
+    }
+}
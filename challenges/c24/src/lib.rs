@@ -0,0 +1,201 @@
+//! # Sports Results Tracker
+//!
+//! This module implements an interactive sports results tracker
+//! that allows users to add and search for match results.
+//!
+//! ## Features
+//!
+//! - **Data Collection**: Allows users to add match results with team names and scores
+//! - **Search Functionality**: Enables searching for results by team name
+//! - **Menu-driven Interface**: Provides a simple menu for operation selection
+//! - **Error Handling**: Handles invalid inputs with clear error messages
+//! - **Data Persistence**: Maintains results in memory during program execution
+//! - **Pretty Formatting**: Displays match results in a readable format
+use challenge_common::cli::CommonArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt::{self, Prompt};
+use clap::Parser;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+enum MenuOption {
+    Add,
+    Search,
+}
+
+#[derive(Debug, Clone)]
+struct Results {
+    home_team: String,
+    home_score: u32,
+    away_team: String,
+    away_score: u32,
+}
+
+impl Display for Results {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} - {} {}",
+            self.home_team, self.home_score, self.away_team, self.away_score
+        )
+    }
+}
+
+fn prompt_for_menu_opt<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> std::io::Result<MenuOption> {
+    Prompt::new(l10n::text("c24.menu")).ask(reader, writer, |answer| match answer {
+        "1" => Ok(MenuOption::Add),
+        "2" => Ok(MenuOption::Search),
+        _ => Err(l10n::text("c24.menu_invalid")),
+    })
+}
+
+fn prompt_for_result<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> std::io::Result<Results> {
+    let home_team = Prompt::new(l10n::text("c24.home_team")).ask_text(reader, writer)?;
+    let home_score =
+        Prompt::new(l10n::text("c24.home_score")).ask(reader, writer, challenge_error::parse)?;
+    let away_team = Prompt::new(l10n::text("c24.away_team")).ask_text(reader, writer)?;
+    let away_score =
+        Prompt::new(l10n::text("c24.away_score")).ask(reader, writer, challenge_error::parse)?;
+
+    Ok(Results {
+        home_team,
+        home_score,
+        away_team,
+        away_score,
+    })
+}
+
+fn prompt_for_query<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> std::io::Result<String> {
+    Prompt::new(l10n::text("c24.team")).ask_text(reader, writer)
+}
+
+/// Record match results and search them by team name.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number of menu actions before the program exits
+    #[arg(short, long, default_value_t = 20)]
+    max_actions: u32,
+
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Runs the menu for `max_actions` actions and returns the recorded results.
+fn run<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    max_actions: u32,
+) -> std::io::Result<Vec<Results>> {
+    let mut results: Vec<Results> = Vec::new();
+
+    for _ in 0..max_actions {
+        let query_type = prompt_for_menu_opt(reader, writer)?;
+
+        match query_type {
+            MenuOption::Add => results.push(prompt_for_result(reader, writer)?),
+            MenuOption::Search => {
+                let query = prompt_for_query(reader, writer)?;
+
+                let heading = l10n::format("c24.results", &[("query", &query)]);
+                writeln!(writer, "{}", heading)?;
+                let search_results: Vec<Results> = results
+                    .iter()
+                    .filter(|r| r.home_team == query || r.away_team == query)
+                    .cloned()
+                    .collect();
+                if search_results.is_empty() {
+                    writeln!(writer, "{}", l10n::text("c24.no_results"))?;
+                } else {
+                    for result in search_results {
+                        writeln!(writer, "{}", result)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = run(&mut input, &mut std::io::stdout(), args.max_actions) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_for_result_reads_both_teams() {
+        let mut reader = "Leeds\n2\nYork\n1\n".as_bytes();
+        let result = prompt_for_result(&mut reader, &mut std::io::sink()).unwrap();
+        assert_eq!(result.to_string(), "Leeds 2 - York 1");
+    }
+
+    #[test]
+    fn prompt_for_result_asks_again_for_non_numeric_score() {
+        let mut reader = "Leeds\ntwo\n2\nYork\n1\n".as_bytes();
+        let mut output = Vec::new();
+        let result = prompt_for_result(&mut reader, &mut output).unwrap();
+        assert_eq!(result.to_string(), "Leeds 2 - York 1");
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(&format!(
+            "Enter the home team's score: \n{}\nEnter the home team's score: \n",
+            challenge_error::parse::<u32>("two").unwrap_err()
+        )));
+    }
+
+    #[test]
+    fn run_finds_results_for_either_team() {
+        let mut reader = "1\nLeeds\n2\nYork\n1\n2\nYork\n2\nHull\n".as_bytes();
+        let mut output = Vec::new();
+
+        let results = run(&mut reader, &mut output, 3).unwrap();
+
+        assert_eq!(results.len(), 1);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Search results for \"York\":\nLeeds 2 - York 1\n"));
+        assert!(output.ends_with("Search results for \"Hull\":\nNo results found.\n"));
+    }
+
+    #[test]
+    fn run_stops_after_max_actions() {
+        let mut reader = "2\nLeeds\n".as_bytes();
+        let results = run(&mut reader, &mut std::io::sink(), 1).unwrap();
+        assert!(results.is_empty());
+    }
+}
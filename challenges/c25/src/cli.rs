@@ -0,0 +1,156 @@
+//! # Blackjack Card Game
+//!
+//! This module implements a simple interactive Blackjack card game
+//! with standard rules and gameplay mechanics.
+//!
+//! ## Features
+//!
+//! - **Card Representation**: Models playing cards with suits and ranks
+//! - **Deck Management**: Implements a full 52-card deck with shuffling and dealing
+//! - **Hand Evaluation**: Calculates hand values with special Ace handling (1 or 11)
+//! - **Game Logic**: Follows standard Blackjack rules for player and dealer actions
+//! - **Interactive Play**: Offers players choices to hit or stand during gameplay
+//! - **Bust Detection**: Identifies when a hand exceeds 21 points
+//! - **Game Outcome**: Determines winners based on final hand values
+//! - **Lifetime Record**: Keeps a win/loss/tie tally between runs
+#[cfg(feature = "tui")]
+use crate::tui;
+use crate::{run, Outcome, BLACKJACK};
+use challenge_common::cli::CommonArgs;
+use challenge_common::clock::{self, GameClock};
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use challenge_common::sound::{self, SoundArgs};
+use challenge_common::theme::{self, ThemeArgs};
+use challenge_storage::{achievements, Store};
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Adds `outcome` and the time the hand took to the lifetime record and
+/// returns (wins, losses, ties, longest run of hands won in a row). A tie
+/// does not end a run.
+fn record_outcome(
+    outcome: Outcome,
+    elapsed: Duration,
+) -> challenge_storage::Result<(u64, u64, u64, u64)> {
+    let mut store = Store::open("c25")?;
+    store.add("seconds_played", elapsed.as_secs())?;
+    match outcome {
+        Outcome::Win => {
+            store.increment("wins")?;
+            let streak = store.increment("win_streak")?;
+            if streak > store.get_or_default("best_win_streak")? {
+                store.set("best_win_streak", &streak)?;
+            }
+        }
+        Outcome::Lose => {
+            store.increment("losses")?;
+            store.set("win_streak", &0)?;
+        }
+        Outcome::Tie => {
+            store.increment("ties")?;
+        }
+    }
+    store.save()?;
+    Ok((
+        store.get_or_default("wins")?,
+        store.get_or_default("losses")?,
+        store.get_or_default("ties")?,
+        store.get_or_default("best_win_streak")?,
+    ))
+}
+
+/// Play a hand of Blackjack against the dealer.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Play in a full-screen terminal UI
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui: bool,
+
+    #[command(flatten)]
+    sound: SoundArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+    #[command(flatten)]
+    theme: ThemeArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    sound::init(&args.sound);
+    theme::init(&args.theme);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let mut clock = GameClock::start();
+    #[cfg(feature = "tui")]
+    let outcome = if args.tui {
+        tui::run(&mut rng)
+    } else {
+        run(&mut input, &mut std::io::stdout(), &mut clock, &mut rng)
+    };
+    #[cfg(not(feature = "tui"))]
+    let outcome = run(&mut input, &mut std::io::stdout(), &mut clock, &mut rng);
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    sound::wait();
+    if let Some((outcome, value)) = outcome {
+        let time = clock::format(clock.elapsed());
+        println!("{}", l10n::format("clock.time_played", &[("time", &time)]));
+        match record_outcome(outcome, clock.elapsed()) {
+            Ok((wins, losses, ties, best)) => {
+                println!(
+                    "{}",
+                    l10n::format(
+                        "c25.lifetime",
+                        &[("wins", &wins), ("losses", &losses), ("ties", &ties)],
+                    )
+                );
+                println!("{}", l10n::format("c25.best", &[("best", &best)]));
+            }
+            Err(e) => eprintln!("Warning: unable to save your record: {}", e),
+        }
+        if outcome == Outcome::Win && value == BLACKJACK {
+            match achievements::unlock("first_blackjack") {
+                Ok(Some(achievement)) => {
+                    let name = l10n::text(&format!("achievement.{}", achievement.id));
+                    println!(
+                        "{}",
+                        l10n::format("achievement.unlocked", &[("name", &name)])
+                    );
+                }
+                Ok(None) => {}
+                Err(e) => eprintln!("Warning: unable to save your achievement: {}", e),
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
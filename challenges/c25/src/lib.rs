@@ -0,0 +1,402 @@
+//! # Blackjack Card Game
+//!
+//! Hand evaluation, kept apart from the interactive game so that it can be
+//! tested on its own. The cards come from the shared `challenge-cards` crate.
+mod cli;
+#[cfg(feature = "tui")]
+mod tui;
+
+pub use cli::run_cli;
+
+use challenge_cards::{Card, Deck, Rank};
+use challenge_common::clock::GameClock;
+use challenge_common::game::{self, Game};
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_common::theme;
+use rand::Rng;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+
+/// How a hand ended for the player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Tie,
+}
+
+impl Outcome {
+    /// Tells the player how the hand ended, e.g. `You win!`.
+    pub fn message(self) -> &'static str {
+        l10n::text(match self {
+            Outcome::Win => "c25.win",
+            Outcome::Lose => "c25.lose",
+            Outcome::Tie => "c25.tie",
+        })
+    }
+}
+
+/// Plays the sound effect for `outcome`. A tie has none.
+pub fn play_outcome_sound(outcome: Outcome) {
+    match outcome {
+        Outcome::Win => sound::play(Event::Win),
+        Outcome::Lose => sound::play(Event::Lose),
+        Outcome::Tie => {}
+    }
+}
+
+/// The best possible hand value.
+pub const BLACKJACK: u32 = 21;
+
+/// The cards held by the player or the dealer.
+#[derive(Default)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+}
+
+impl Hand {
+    pub fn new() -> Hand {
+        Hand { cards: Vec::new() }
+    }
+
+    pub fn add_card(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+
+    /// Returns the value of the hand, counting each Ace as 11 unless that
+    /// would bust the hand.
+    pub fn evaluate(&self) -> u32 {
+        let mut sum = 0;
+        let mut ace_count = 0;
+
+        // First pass: Count all non-Ace cards and track number of Aces
+        for card in &self.cards {
+            match card.rank {
+                Rank::Ace => ace_count += 1,
+                // Face cards count as ten like the Ten itself.
+                rank => sum += rank.value().min(10),
+            }
+        }
+
+        // Second pass: Count every Ace as 1, then one of them as 11 if that
+        // does not bust the hand. Two Aces as 11 would always bust.
+        sum += ace_count;
+        if ace_count > 0 && sum + 10 <= 21 {
+            sum += 10;
+        }
+
+        sum
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for card in &self.cards {
+            writeln!(f, "\t{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decides a hand the player stood on by comparing it with the dealer's.
+pub fn compare_hands(player: &Hand, dealer: &Hand) -> Outcome {
+    match player.evaluate().cmp(&dealer.evaluate()) {
+        std::cmp::Ordering::Less => Outcome::Lose,
+        std::cmp::Ordering::Equal => Outcome::Tie,
+        std::cmp::Ordering::Greater => Outcome::Win,
+    }
+}
+
+enum Move {
+    Hit,
+    Stand,
+}
+
+fn parse_move(answer: &str) -> Result<Move, &'static str> {
+    match answer {
+        "H" => Ok(Move::Hit),
+        "S" => Ok(Move::Stand),
+        _ => Err(l10n::text("c25.invalid")),
+    }
+}
+
+/// One hand against the dealer, dealt from a deck shuffled with `rng`.
+pub struct Blackjack<R> {
+    deck: Deck,
+    player_hand: Hand,
+    outcome: Option<Outcome>,
+    over: bool,
+    rng: R,
+}
+
+impl<R: Rng> Blackjack<R> {
+    /// Creates a hand. The cards are shuffled and dealt by [`Game::setup`].
+    pub fn new(rng: R) -> Self {
+        Blackjack {
+            deck: Deck::new(),
+            player_hand: Hand::new(),
+            outcome: None,
+            over: false,
+            rng,
+        }
+    }
+
+    /// Ends the hand with `outcome`.
+    fn finish(&mut self, outcome: Outcome) {
+        play_outcome_sound(outcome);
+        self.outcome = Some(outcome);
+        self.over = true;
+    }
+
+    fn stand(&mut self) -> String {
+        let mut dealer_hand = Hand::new();
+        dealer_hand.add_card(self.deck.deal().unwrap());
+        dealer_hand.add_card(self.deck.deal().unwrap());
+        sound::play(Event::CardDealt);
+        sound::play(Event::CardDealt);
+
+        let outcome = compare_hands(&self.player_hand, &dealer_hand);
+        tracing::debug!(
+            player = self.player_hand.evaluate(),
+            dealer = dealer_hand.evaluate(),
+            ?outcome,
+            "player stood"
+        );
+        let message = match outcome {
+            Outcome::Lose => theme::lose(outcome.message()),
+            Outcome::Tie => theme::hint(outcome.message()),
+            Outcome::Win => theme::win(outcome.message()),
+        };
+        self.finish(outcome);
+        let dealer_hand = l10n::format("c25.dealer_hand", &[("hand", &dealer_hand)]);
+        format!("{}\n{}", dealer_hand, message)
+    }
+
+    fn hit(&mut self) -> String {
+        let Some(card) = self.deck.deal() else {
+            self.over = true;
+            return l10n::text("c25.deck_empty").to_string();
+        };
+        self.player_hand.add_card(card);
+        sound::play(Event::CardDealt);
+        tracing::debug!(value = self.player_hand.evaluate(), "player hit");
+        let drawn = l10n::format("c25.drew", &[("card", &card)]);
+        if self.player_hand.evaluate() > BLACKJACK {
+            self.finish(Outcome::Lose);
+            format!("{}\n{}", drawn, theme::lose(l10n::text("c25.bust")))
+        } else {
+            drawn
+        }
+    }
+}
+
+impl<R: Rng> Game for Blackjack<R> {
+    /// The outcome with the final value of the player's hand, or `None` if
+    /// the deck ran out.
+    type Result = Option<(Outcome, u32)>;
+
+    fn setup(&mut self) -> String {
+        self.deck = Deck::new();
+        self.deck.shuffle(&mut self.rng);
+        self.player_hand = Hand::new();
+        self.outcome = None;
+        self.over = false;
+        self.player_hand.add_card(self.deck.deal().unwrap());
+        self.player_hand.add_card(self.deck.deal().unwrap());
+        sound::play(Event::CardDealt);
+        sound::play(Event::CardDealt);
+        String::new()
+    }
+
+    fn prompt(&self) -> String {
+        l10n::text("c25.move").to_string()
+    }
+
+    fn step(&mut self, answer: &str) -> Result<String, String> {
+        Ok(match parse_move(answer)? {
+            Move::Stand => self.stand(),
+            Move::Hit => self.hit(),
+        })
+    }
+
+    fn render(&self) -> String {
+        l10n::format("c25.your_hand", &[("hand", &self.player_hand)])
+    }
+
+    fn is_over(&self) -> bool {
+        self.over
+    }
+
+    fn result(&self) -> Option<(Outcome, u32)> {
+        self.outcome
+            .map(|outcome| (outcome, self.player_hand.evaluate()))
+    }
+}
+
+/// Plays one hand and returns its outcome with the final value of the
+/// player's hand, or `None` if the deck ran out. The deck is shuffled with
+/// `rng`, and the player can pause `clock` at any prompt.
+pub fn run<R: BufRead, W: Write, G: Rng + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    clock: &mut GameClock,
+    rng: &mut G,
+) -> std::io::Result<Option<(Outcome, u32)>> {
+    game::play_timed(&mut Blackjack::new(rng), reader, writer, clock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_cards::Suit;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn evaluate_returns_correct_value_for_empty_hand() {
+        let hand = Hand::new();
+        assert_eq!(hand.evaluate(), 0);
+    }
+
+    #[test]
+    fn evaluate_calculates_numbered_cards_correctly() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Two, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Three, Suit::Diamonds));
+        hand.add_card(Card::new(Rank::Four, Suit::Clubs));
+        assert_eq!(hand.evaluate(), 9);
+    }
+
+    #[test]
+    fn evaluate_calculates_face_cards_correctly() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Jack, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Queen, Suit::Diamonds));
+        hand.add_card(Card::new(Rank::King, Suit::Clubs));
+        assert_eq!(hand.evaluate(), 30);
+    }
+
+    #[test]
+    fn evaluate_calculates_mixed_cards_correctly() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Two, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Queen, Suit::Diamonds));
+        hand.add_card(Card::new(Rank::Seven, Suit::Clubs));
+        assert_eq!(hand.evaluate(), 19);
+    }
+
+    #[test]
+    fn evaluate_handles_single_ace_as_eleven_when_possible() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Five, Suit::Diamonds));
+        assert_eq!(hand.evaluate(), 16); // Ace should be 11
+    }
+
+    #[test]
+    fn evaluate_handles_single_ace_as_one_when_necessary() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Ten, Suit::Diamonds));
+        hand.add_card(Card::new(Rank::Queen, Suit::Clubs));
+        assert_eq!(hand.evaluate(), 21); // Ace must be 1 to avoid bust
+    }
+
+    #[test]
+    fn evaluate_handles_multiple_aces_correctly() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Ace, Suit::Diamonds));
+        hand.add_card(Card::new(Rank::Nine, Suit::Clubs));
+
+        // First Ace as 11, second Ace as 1: 11 + 1 + 9 = 21
+        assert_eq!(hand.evaluate(), 21);
+    }
+
+    #[test]
+    fn evaluate_handles_all_aces_as_one_when_necessary() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Hearts));
+        hand.add_card(Card::new(Rank::Ace, Suit::Diamonds));
+        hand.add_card(Card::new(Rank::Ace, Suit::Clubs));
+        hand.add_card(Card::new(Rank::King, Suit::Spades));
+
+        // All Aces must be 1 to avoid bust: 1 + 1 + 1 + 10 = 13
+        assert_eq!(hand.evaluate(), 13);
+    }
+
+    #[test]
+    fn evaluate_does_not_discount_an_ace_already_counted_as_one() {
+        let mut hand = Hand::new();
+        for value in [Rank::Ace, Rank::Four, Rank::Ten, Rank::Seven] {
+            hand.add_card(Card::new(value, Suit::Hearts));
+        }
+
+        // 1 + 4 + 10 + 7 = 22: the hand is bust even with the Ace as 1
+        assert_eq!(hand.evaluate(), 22);
+    }
+
+    #[test]
+    fn run_standing_shows_dealer_hand() {
+        let mut reader = "S\n".as_bytes();
+        let mut output = Vec::new();
+
+        let outcome = run(
+            &mut reader,
+            &mut output,
+            &mut GameClock::start(),
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        assert!(outcome.is_some());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("Your hand: \n"));
+        assert!(output.contains("Dealer hand: \n"));
+    }
+
+    #[test]
+    fn run_reprompts_on_invalid_move() {
+        let mut reader = "X\nS\n".as_bytes();
+        let mut output = Vec::new();
+
+        run(
+            &mut reader,
+            &mut output,
+            &mut GameClock::start(),
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid input. Please enter 'H' or 'S'."));
+    }
+
+    #[test]
+    fn step_rejects_unknown_moves_without_dealing() {
+        let mut game = Blackjack::new(StdRng::seed_from_u64(42));
+        game.setup();
+
+        assert_eq!(game.step("X"), Err(l10n::text("c25.invalid").to_string()));
+        assert_eq!(game.player_hand.cards.len(), 2);
+
+        assert!(game.step("H").unwrap().starts_with("You drew: "));
+        assert_eq!(game.player_hand.cards.len(), 3);
+    }
+
+    #[test]
+    fn run_hitting_until_bust_loses() {
+        // Hitting 12 times always goes over 21, so the hand ends in a bust
+        // before the script runs out.
+        let script = "H\n".repeat(12);
+        let outcome = run(
+            &mut script.as_bytes(),
+            &mut std::io::sink(),
+            &mut GameClock::start(),
+            &mut StdRng::seed_from_u64(42),
+        )
+        .unwrap();
+        assert_eq!(outcome.map(|(outcome, _)| outcome), Some(Outcome::Lose));
+    }
+}
@@ -0,0 +1,207 @@
+//! Full-screen version of the game, enabled with `--tui`.
+use crate::{compare_hands, play_outcome_sound, Hand, Outcome, BLACKJACK};
+use challenge_cards::Deck;
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_tui::crossterm::event::KeyCode;
+use challenge_tui::ratatui::layout::{Constraint, Layout};
+use challenge_tui::ratatui::text::Line;
+use challenge_tui::ratatui::Frame;
+use challenge_tui::widgets::{Cards, Menu, StatusBar, CARD_HEIGHT};
+use rand::Rng;
+
+/// The moves in the menu.
+fn moves() -> [&'static str; 2] {
+    [l10n::text("c25.hit"), l10n::text("c25.stand")]
+}
+
+/// The keys shown in the status bar, with what they do.
+fn keys() -> [(&'static str, &'static str); 4] {
+    [
+        ("↑↓", l10n::text("c25.select")),
+        ("enter", l10n::text("c25.play")),
+        ("h/s", l10n::text("c25.hit_stand")),
+        ("q", l10n::text("tui.quit")),
+    ]
+}
+
+/// State of one hand as shown on screen.
+struct Table {
+    deck: Deck,
+    player: Hand,
+    dealer: Option<Hand>,
+    selected: usize,
+    /// Set once the hand is over, holding its outcome (`None` if the deck ran
+    /// out) and the message to show.
+    result: Option<(Option<Outcome>, &'static str)>,
+}
+
+impl Table {
+    /// A hand dealt from a deck shuffled with `rng`.
+    fn new<R: Rng + ?Sized>(rng: &mut R) -> Table {
+        let mut deck = Deck::new();
+        deck.shuffle(rng);
+        let mut player = Hand::new();
+        player.add_card(deck.deal().unwrap());
+        player.add_card(deck.deal().unwrap());
+        sound::play(Event::CardDealt);
+        sound::play(Event::CardDealt);
+        Table {
+            deck,
+            player,
+            dealer: None,
+            selected: 0,
+            result: None,
+        }
+    }
+
+    fn hit(&mut self) {
+        match self.deck.deal() {
+            Some(card) => {
+                self.player.add_card(card);
+                sound::play(Event::CardDealt);
+                tracing::debug!(value = self.player.evaluate(), "player hit");
+                if self.player.evaluate() > BLACKJACK {
+                    play_outcome_sound(Outcome::Lose);
+                    self.result = Some((Some(Outcome::Lose), l10n::text("c25.bust")));
+                }
+            }
+            None => self.result = Some((None, l10n::text("c25.deck_empty"))),
+        }
+    }
+
+    fn stand(&mut self) {
+        let mut dealer = Hand::new();
+        dealer.add_card(self.deck.deal().unwrap());
+        dealer.add_card(self.deck.deal().unwrap());
+        sound::play(Event::CardDealt);
+        sound::play(Event::CardDealt);
+        let outcome = compare_hands(&self.player, &dealer);
+        tracing::debug!(
+            player = self.player.evaluate(),
+            dealer = dealer.evaluate(),
+            ?outcome,
+            "player stood"
+        );
+        play_outcome_sound(outcome);
+        self.dealer = Some(dealer);
+        self.result = Some((Some(outcome), outcome.message()));
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [dealer_label, dealer_cards, player_label, player_cards, menu, _, status] =
+            Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Length(CARD_HEIGHT),
+                Constraint::Length(1),
+                Constraint::Length(CARD_HEIGHT),
+                Constraint::Length(moves().len() as u16 + 2),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .areas(frame.area());
+
+        let dealer_hand = match &self.dealer {
+            Some(dealer) => {
+                frame.render_widget(
+                    Line::raw(l10n::format(
+                        "c25.dealer_value",
+                        &[("value", &dealer.evaluate())],
+                    )),
+                    dealer_label,
+                );
+                Cards::new(&dealer.cards)
+            }
+            None => {
+                frame.render_widget(Line::raw(l10n::text("c25.dealer")), dealer_label);
+                Cards::new(&[]).face_down(2)
+            }
+        };
+        frame.render_widget(dealer_hand, dealer_cards);
+
+        frame.render_widget(
+            Line::raw(l10n::format(
+                "c25.you_value",
+                &[("value", &self.player.evaluate())],
+            )),
+            player_label,
+        );
+        frame.render_widget(Cards::new(&self.player.cards), player_cards);
+
+        let exit_keys = [(l10n::text("tui.any_key"), l10n::text("tui.exit"))];
+        let keys = keys();
+        let status_bar = StatusBar::new(l10n::text("c25.title"));
+        let status_bar = match self.result {
+            Some((_, message)) => status_bar.message(message).keys(&exit_keys),
+            None => {
+                let menu_area = menu.intersection(frame.area());
+                frame.render_widget(
+                    Menu::new(l10n::text("c25.your_move"), &moves()).selected(self.selected),
+                    menu_area,
+                );
+                status_bar
+                    .message(l10n::text("c25.hit_or_stand"))
+                    .keys(&keys)
+            }
+        };
+        frame.render_widget(status_bar, status);
+    }
+}
+
+/// Plays one hand full-screen. Returns the outcome with the final value of
+/// the player's hand, or `None` if the player quit or the deck ran out.
+pub fn run<R: Rng + ?Sized>(rng: &mut R) -> std::io::Result<Option<(Outcome, u32)>> {
+    challenge_tui::run(|terminal| {
+        let mut table = Table::new(rng);
+        loop {
+            terminal.draw(|frame| table.draw(frame))?;
+            let key = challenge_tui::next_key()?;
+            if let Some((outcome, _)) = table.result {
+                return Ok(outcome.map(|outcome| (outcome, table.player.evaluate())));
+            }
+            if challenge_tui::is_quit(&key) {
+                return Ok(None);
+            }
+
+            match key.code {
+                KeyCode::Up | KeyCode::Down => table.selected = 1 - table.selected,
+                KeyCode::Char('h') | KeyCode::Char('H') => table.hit(),
+                KeyCode::Char('s') | KeyCode::Char('S') => table.stand(),
+                KeyCode::Enter if table.selected == 0 => table.hit(),
+                KeyCode::Enter => table.stand(),
+                _ => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_tui::ratatui::backend::TestBackend;
+    use challenge_tui::ratatui::Terminal;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn stand_reveals_dealer_and_ends_hand() {
+        let mut table = Table::new(&mut StdRng::seed_from_u64(42));
+        table.stand();
+        assert_eq!(table.dealer.as_ref().unwrap().cards.len(), 2);
+        assert!(matches!(table.result, Some((Some(_), _))));
+    }
+
+    #[test]
+    fn draw_shows_hands_and_status() {
+        let table = Table::new(&mut StdRng::seed_from_u64(42));
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|frame| table.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Dealer"));
+        assert!(text.contains(&format!("You ({})", table.player.evaluate())));
+        assert!(text.contains("> Hit"));
+        assert!(text.contains("Hit or stand?"));
+    }
+}
@@ -0,0 +1,80 @@
+use c25::{compare_hands, Hand, Outcome, BLACKJACK};
+use challenge_cards::{Card, Rank, Suit};
+use proptest::prelude::*;
+use proptest::sample::select;
+
+fn card() -> impl Strategy<Value = Card> {
+    (select(Rank::ALL.to_vec()), select(Suit::ALL.to_vec()))
+        .prop_map(|(rank, suit)| Card::new(rank, suit))
+}
+
+fn cards() -> impl Strategy<Value = Vec<Card>> {
+    prop::collection::vec(card(), 0..=11)
+}
+
+fn hand(cards: &[Card]) -> Hand {
+    let mut hand = Hand::new();
+    for card in cards {
+        hand.add_card(*card);
+    }
+    hand
+}
+
+/// The value of the cards with every Ace counted as 1.
+fn hard_total(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|card| match card.rank {
+            Rank::Ace => 1,
+            Rank::Two => 2,
+            Rank::Three => 3,
+            Rank::Four => 4,
+            Rank::Five => 5,
+            Rank::Six => 6,
+            Rank::Seven => 7,
+            Rank::Eight => 8,
+            Rank::Nine => 9,
+            Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => 10,
+        })
+        .sum()
+}
+
+proptest! {
+    #[test]
+    fn value_counts_at_most_one_ace_as_eleven(cards in cards()) {
+        let value = hand(&cards).evaluate();
+        let hard = hard_total(&cards);
+        let has_ace = cards.iter().any(|card| card.rank == Rank::Ace);
+        prop_assert!(value == hard || (has_ace && value == hard + 10));
+    }
+
+    #[test]
+    fn value_is_the_best_total_that_does_not_bust(cards in cards()) {
+        let value = hand(&cards).evaluate();
+        let hard = hard_total(&cards);
+        let has_ace = cards.iter().any(|card| card.rank == Rank::Ace);
+        if has_ace && hard + 10 <= BLACKJACK {
+            prop_assert_eq!(value, hard + 10);
+        } else {
+            prop_assert_eq!(value, hard);
+        }
+    }
+
+    #[test]
+    fn value_does_not_depend_on_card_order(cards in cards()) {
+        let mut reversed = cards.clone();
+        reversed.reverse();
+        prop_assert_eq!(hand(&cards).evaluate(), hand(&reversed).evaluate());
+    }
+
+    #[test]
+    fn comparing_hands_is_antisymmetric(player in cards(), dealer in cards()) {
+        let (player, dealer) = (hand(&player), hand(&dealer));
+        let expected = match compare_hands(&player, &dealer) {
+            Outcome::Win => Outcome::Lose,
+            Outcome::Lose => Outcome::Win,
+            Outcome::Tie => Outcome::Tie,
+        };
+        prop_assert_eq!(compare_hands(&dealer, &player), expected);
+    }
+}
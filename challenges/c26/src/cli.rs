@@ -0,0 +1,231 @@
+//! # Mastermind Guessing Game
+//!
+//! This module implements an interactive Mastermind-style code-breaking game
+//! where players guess a randomly generated numeric code.
+//!
+//! ## Features
+//!
+//! - **Random Code Generation**: Creates random numeric codes of configurable length
+//! - **Feedback System**: Provides feedback on correct digits and positions after each guess
+//! - **Input Validation**: Ensures guesses are valid numeric sequences of the correct length
+//! - **Error Handling**: Provides clear feedback for invalid inputs
+//! - **Game Logic**: Tracks game progress and determines win conditions
+//! - **Limited Attempts**: Enforces a maximum number of guesses before game over
+//! - **Personal Best**: Remembers wins, losses, and the fewest guesses needed
+//! - **Network Play**: Race another player on the LAN to crack the same code,
+//!   or set a code with `--set-code` for them to crack
+use crate::net;
+#[cfg(feature = "tui")]
+use crate::tui;
+use crate::{generate_code, run};
+use challenge_common::cli::CommonArgs;
+use challenge_common::clock::{self, GameClock};
+use challenge_common::difficulty::DifficultyArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::rng::RngArgs;
+use challenge_common::sound::{self, SoundArgs};
+use challenge_netplay::{Lobby, Mode, NetArgs, Side};
+use challenge_storage::{achievements, daily, Store};
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Records the result of a game. `guesses` is `Some` for a win and holds the
+/// number of guesses it took. Returns the previous best for this code length.
+fn record_game(
+    code_length: u32,
+    guesses: Option<u32>,
+    elapsed: Duration,
+) -> challenge_storage::Result<Option<u32>> {
+    let mut store = Store::open("c26")?;
+    store.add("seconds_played", elapsed.as_secs())?;
+    let best_key = format!("best_guesses_{code_length}");
+    let previous = match guesses {
+        Some(guesses) => {
+            store.increment("wins")?;
+            store.record_min(&best_key, guesses)?
+        }
+        None => {
+            store.increment("losses")?;
+            store.get(&best_key)?
+        }
+    };
+    store.save()?;
+    Ok(previous)
+}
+
+/// Crack a secret numeric code using feedback on each guess.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number of digits in the secret code [default: 3, 4 or 5 by difficulty]
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    code_length: Option<u32>,
+
+    /// Number of guesses allowed [default: 15, 12 or 10 by difficulty]
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    max_guesses: Option<u32>,
+
+    /// When hosting, type the secret code yourself and let the other player
+    /// crack it, instead of racing them to crack a random one
+    #[arg(long, requires = "host")]
+    set_code: bool,
+
+    /// Play in a full-screen terminal UI
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui: bool,
+
+    #[command(flatten)]
+    net: NetArgs,
+    #[command(flatten)]
+    difficulty: DifficultyArgs,
+    #[command(flatten)]
+    sound: SoundArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(flatten)]
+    rng: RngArgs,
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    sound::init(&args.sound);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng = args.rng.rng(input.default_seed());
+
+    let level = args.difficulty.level();
+    let code_length = args.code_length.unwrap_or(level.pick(3, 4, 5));
+    let max_guesses = args.max_guesses.unwrap_or(level.pick(15, 12, 10));
+    let target = generate_code(code_length, &mut rng);
+    tracing::debug!(code = %target, "secret code generated");
+    let mut clock = GameClock::start();
+    let mode = match args.net.mode() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(mode) = mode {
+        let hide_code = !input.is_scripted();
+        let mut stdout = std::io::stdout();
+        let result = match mode {
+            Mode::Host(addr) if args.set_code => {
+                Lobby::bind(&addr, "c26", &args.net.name(Side::Host)).and_then(|lobby| {
+                    net::host_set_code(
+                        &mut input,
+                        &mut stdout,
+                        &lobby,
+                        code_length,
+                        max_guesses,
+                        hide_code,
+                    )
+                })
+            }
+            Mode::Host(addr) => {
+                Lobby::bind(&addr, "c26", &args.net.name(Side::Host)).and_then(|lobby| {
+                    net::host(
+                        &mut input,
+                        &mut stdout,
+                        &lobby,
+                        &target,
+                        max_guesses,
+                        &mut clock,
+                    )
+                })
+            }
+            Mode::Join(addr) => net::join(
+                &mut input,
+                &mut stdout,
+                &addr,
+                &args.net.name(Side::Guest),
+                &mut clock,
+            ),
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        sound::wait();
+        return ExitCode::SUCCESS;
+    }
+    #[cfg(feature = "tui")]
+    let result = if args.tui {
+        tui::run(&target, max_guesses)
+    } else {
+        run(
+            &mut input,
+            &mut std::io::stdout(),
+            &target,
+            max_guesses,
+            &mut clock,
+        )
+        .map(Some)
+    };
+    #[cfg(not(feature = "tui"))]
+    let result = run(
+        &mut input,
+        &mut std::io::stdout(),
+        &target,
+        max_guesses,
+        &mut clock,
+    )
+    .map(Some);
+    sound::wait();
+    // A daily game that ends any other way than with the code cracked,
+    // quitting included, counts as lost.
+    if let Err(e) = daily::record("c26", matches!(result, Ok(Some(Some(_))))) {
+        eprintln!("Warning: unable to save your daily result: {}", e);
+    }
+    let guesses_taken = match result {
+        Ok(Some(guesses_taken)) => guesses_taken,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let time = clock::format(clock.elapsed());
+    println!("{}", l10n::format("clock.time_played", &[("time", &time)]));
+    match record_game(code_length, guesses_taken, clock.elapsed()) {
+        Ok(Some(best)) if guesses_taken.is_some_and(|taken| taken < best) => {
+            println!("{}", l10n::text("c26.new_best"))
+        }
+        Ok(Some(best)) => println!("{}", l10n::format("c26.best", &[("guesses", &best)])),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: unable to save your score: {}", e),
+    }
+    if guesses_taken.is_some_and(|taken| taken <= 6) {
+        match achievements::unlock("mastermind_in_6") {
+            Ok(Some(achievement)) => {
+                let name = l10n::text(&format!("achievement.{}", achievement.id));
+                println!(
+                    "{}",
+                    l10n::format("achievement.unlocked", &[("name", &name)])
+                );
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Warning: unable to save your achievement: {}", e),
+        }
+    }
+    ExitCode::SUCCESS
+}
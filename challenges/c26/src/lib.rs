@@ -0,0 +1,277 @@
+//! # Mastermind Guessing Game
+//!
+//! Guess scoring, kept apart from the interactive game so that it can be
+//! tested on its own.
+mod cli;
+mod net;
+#[cfg(feature = "tui")]
+mod tui;
+
+pub use cli::run_cli;
+
+use challenge_common::clock::GameClock;
+use challenge_common::input;
+use challenge_common::l10n;
+use challenge_common::prompt::{self, Prompt};
+use challenge_common::sound::{self, Event};
+use rand::Rng;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+/// Feedback on a guess: how many of its digits appear in the code, and how
+/// many of those are in the right position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuessStats {
+    pub correct_digits: u32,
+    pub correct_positions: u32,
+}
+
+impl GuessStats {
+    /// The feedback shown to the player, e.g.
+    /// `Correct digits: 2, correct positions: 1`.
+    pub fn feedback(&self) -> String {
+        l10n::format(
+            "c26.feedback",
+            &[
+                ("digits", &self.correct_digits),
+                ("positions", &self.correct_positions),
+            ],
+        )
+    }
+}
+
+/// Scores `guess` against `target`. Each digit of the target is matched at
+/// most once.
+pub fn evaluate_guess(guess: &str, target: &str) -> GuessStats {
+    // Pass 1: Count correct positions
+    let correct_positions =
+        guess
+            .chars()
+            .zip(target.chars())
+            .fold(0, |acc, (g, t)| if g == t { acc + 1 } else { acc });
+
+    // Pass 2: Count the number of correct digits regardless of position
+    let guess_counts = guess.chars().fold(HashMap::new(), |mut counts, c| {
+        *counts.entry(c).or_insert(0) += 1;
+        counts
+    });
+    let target_counts = target.chars().fold(HashMap::new(), |mut counts, c| {
+        *counts.entry(c).or_insert(0) += 1;
+        counts
+    });
+    let mut correct_digits = 0;
+    for (c, gcount) in guess_counts {
+        if target_counts.contains_key(&c) {
+            let tcount = target_counts[&c];
+            correct_digits += match gcount.cmp(&tcount) {
+                std::cmp::Ordering::Less => gcount,
+                _ => tcount,
+            };
+        }
+    }
+
+    GuessStats {
+        correct_digits,
+        correct_positions,
+    }
+}
+
+/// Returns a random code of `num_digits` decimal digits, drawn from `rng`.
+pub fn generate_code<R: Rng + ?Sized>(num_digits: u32, rng: &mut R) -> String {
+    (0..num_digits)
+        .map(|_| rng.random_range(0..10).to_string())
+        .collect()
+}
+
+/// Returns `true` if `code` is made of exactly `num_digits` decimal digits,
+/// as every code and guess must be.
+pub fn is_valid_code(code: &str, num_digits: u32) -> bool {
+    code.len() == num_digits as usize && code.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Asks the player setting the code for a code of `num_digits` digits until
+/// a valid one is entered. It is hidden as it is typed if `hidden` is `true`.
+pub fn prompt_for_code<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_digits: u32,
+    hidden: bool,
+) -> std::io::Result<String> {
+    let expected = l10n::format("c26.expected", &[("digits", &num_digits)]);
+    Prompt::new(&l10n::format("c26.code", &[("digits", &num_digits)])).ask_with(
+        writer,
+        |_| match hidden {
+            #[cfg(feature = "masked")]
+            true => rpassword::read_password(),
+            _ => input::read_line(reader),
+        },
+        prompt::parsed(&expected, |code: &String| is_valid_code(code, num_digits)),
+    )
+}
+
+/// Asks for a guess of `num_digits` digits until a valid one is entered. The
+/// player can pause `clock` at the prompt.
+pub fn prompt_user_for_guess<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_digits: u32,
+    clock: &mut GameClock,
+) -> std::io::Result<String> {
+    let expected = l10n::format("c26.expected", &[("digits", &num_digits)]);
+    Prompt::new(&l10n::format("c26.guess", &[("digits", &num_digits)])).ask_with(
+        writer,
+        |writer| clock.read_line(reader, writer),
+        prompt::parsed(&expected, |guess: &String| is_valid_code(guess, num_digits)),
+    )
+}
+
+/// Plays one game against the secret code `target`. Returns the number of
+/// guesses it took to crack the code, or `None` if the player ran out. The
+/// player can pause `clock` at any prompt.
+pub fn run<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    target: &str,
+    max_guesses: u32,
+    clock: &mut GameClock,
+) -> std::io::Result<Option<u32>> {
+    let code_length = target.len() as u32;
+    let mut guesses_taken = None;
+    for num_guesses in 1..=max_guesses {
+        let guess = prompt_user_for_guess(reader, writer, code_length, clock)?;
+        let stats = evaluate_guess(&guess, target);
+        tracing::debug!(
+            %guess,
+            correct_digits = stats.correct_digits,
+            correct_positions = stats.correct_positions,
+            "guess evaluated"
+        );
+        if stats.correct_positions == code_length {
+            writeln!(writer, "{}", l10n::text("c26.cracked"))?;
+            sound::play(Event::Win);
+            guesses_taken = Some(num_guesses);
+            break;
+        } else {
+            writeln!(writer, "{}", stats.feedback())?;
+            sound::play(Event::WrongGuess);
+        }
+    }
+
+    if guesses_taken.is_none() {
+        let line = l10n::format("c26.out_of_guesses", &[("code", &target)]);
+        writeln!(writer, "{}", line)?;
+        sound::play(Event::Lose);
+    }
+    Ok(guesses_taken)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluate_guess_returns_zero_when_no_matching_digits() {
+        let stats = evaluate_guess("1234", "5678");
+        assert_eq!(stats.correct_digits, 0);
+        assert_eq!(stats.correct_positions, 0);
+    }
+
+    #[test]
+    fn evaluate_guess_counts_correct_digits_in_wrong_positions() {
+        let stats = evaluate_guess("1234", "4321");
+        assert_eq!(stats.correct_digits, 4);
+        assert_eq!(stats.correct_positions, 0);
+    }
+
+    #[test]
+    fn evaluate_guess_counts_correct_digits_in_correct_positions() {
+        let stats = evaluate_guess("1234", "1256");
+        assert_eq!(stats.correct_digits, 2);
+        assert_eq!(stats.correct_positions, 2);
+    }
+
+    #[test]
+    fn evaluate_guess_handles_mixed_correct_and_incorrect_positions() {
+        let stats = evaluate_guess("1234", "1432");
+        assert_eq!(stats.correct_digits, 4);
+        assert_eq!(stats.correct_positions, 2);
+    }
+
+    #[test]
+    fn evaluate_guess_handles_duplicate_digits_in_guess() {
+        let stats = evaluate_guess("1122", "1234");
+        assert_eq!(stats.correct_digits, 2);
+        assert_eq!(stats.correct_positions, 1);
+    }
+
+    #[test]
+    fn evaluate_guess_handles_duplicate_digits_in_target() {
+        let stats = evaluate_guess("1234", "1122");
+        assert_eq!(stats.correct_digits, 2);
+        assert_eq!(stats.correct_positions, 1);
+    }
+
+    #[test]
+    fn evaluate_guess_identifies_perfect_match() {
+        let stats = evaluate_guess("1234", "1234");
+        assert_eq!(stats.correct_digits, 4);
+        assert_eq!(stats.correct_positions, 4);
+    }
+
+    #[test]
+    fn evaluate_guess_handles_empty_strings() {
+        let stats = evaluate_guess("", "");
+        assert_eq!(stats.correct_digits, 0);
+        assert_eq!(stats.correct_positions, 0);
+    }
+
+    #[test]
+    fn run_returns_guesses_taken_when_code_is_cracked() {
+        let mut reader = "1111\n12\n1243\n1234\n".as_bytes();
+        let mut output = Vec::new();
+
+        let guesses = run(
+            &mut reader,
+            &mut output,
+            "1234",
+            12,
+            &mut GameClock::start(),
+        )
+        .unwrap();
+
+        assert_eq!(guesses, Some(3));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Correct digits: 1, correct positions: 1\n"));
+        assert!(output.contains("Invalid input. Please enter a 4-digit number.\n"));
+        assert!(output.contains("Correct digits: 4, correct positions: 2\n"));
+        assert!(output.ends_with("Congratulations! You've guessed the code.\n"));
+    }
+
+    #[test]
+    fn run_reveals_code_when_out_of_guesses() {
+        let mut reader = "0000\n9999\n".as_bytes();
+        let mut output = Vec::new();
+
+        let guesses = run(&mut reader, &mut output, "1234", 2, &mut GameClock::start()).unwrap();
+
+        assert_eq!(guesses, None);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("Out of guesses! The code was 1234.\n"));
+    }
+
+    #[test]
+    fn prompt_for_code_reprompts_until_the_code_is_valid() {
+        let mut output = Vec::new();
+
+        let code = prompt_for_code(&mut "12a\n123\n0042\n".as_bytes(), &mut output, 4, false);
+
+        assert_eq!(code.unwrap(), "0042");
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output
+                .matches("Invalid input. Please enter a 4-digit number.\n")
+                .count(),
+            2
+        );
+    }
+}
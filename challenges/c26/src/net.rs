@@ -0,0 +1,459 @@
+//! Two-player Mastermind over the network.
+//!
+//! The host makes up the code and scores every guess. In a race the code is
+//! random and the players take turns, the host first, each with the same
+//! number of guesses; whoever cracks the code first wins. With `--set-code`
+//! the host types the code instead and the guest alone tries to crack it,
+//! while the host watches the guesses come in.
+use crate::{evaluate_guess, is_valid_code, prompt_for_code, prompt_user_for_guess, GuessStats};
+use challenge_common::clock::GameClock;
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_netplay::{Guest, Host, Lobby, Side};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+
+/// Rules the host sends to the guest when it joins.
+#[derive(Debug, Serialize, Deserialize)]
+struct Settings {
+    code_length: u32,
+    max_guesses: u32,
+    /// `true` if the host set the code and only the guest guesses.
+    set_by_host: bool,
+}
+
+/// A guess submitted by the guest.
+#[derive(Debug, Serialize, Deserialize)]
+struct Turn {
+    guess: String,
+}
+
+/// The host's account of a guess, sent after every turn.
+#[derive(Debug, Serialize, Deserialize)]
+struct Report {
+    side: Side,
+    guess: String,
+    correct_digits: u32,
+    correct_positions: u32,
+    /// Set once the game is over.
+    ending: Option<Ending>,
+}
+
+impl Report {
+    fn stats(&self) -> GuessStats {
+        GuessStats {
+            correct_digits: self.correct_digits,
+            correct_positions: self.correct_positions,
+        }
+    }
+
+    /// What `name` guessed and the feedback on it.
+    fn guessed_by(&self, name: &str) -> String {
+        l10n::format(
+            "c26.guessed",
+            &[
+                ("name", &name),
+                ("guess", &self.guess),
+                ("feedback", &self.stats().feedback()),
+            ],
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Ending {
+    /// `None` if both players ran out of guesses.
+    winner: Option<Side>,
+    code: String,
+}
+
+/// Scores `guess` by the player on `side`. `last` is `true` for the final
+/// guess of the game.
+fn score(side: Side, guess: String, target: &str, last: bool) -> Report {
+    let stats = evaluate_guess(&guess, target);
+    let cracked = stats.correct_positions == target.len() as u32;
+    tracing::debug!(?side, %guess, cracked, "guess scored");
+    Report {
+        side,
+        guess,
+        correct_digits: stats.correct_digits,
+        correct_positions: stats.correct_positions,
+        ending: (cracked || last).then(|| Ending {
+            winner: cracked.then_some(side),
+            code: target.to_string(),
+        }),
+    }
+}
+
+/// Receives the guest's next guess, hanging up on a guest who sends one that
+/// is not a code of `code_length` digits.
+fn recv_guess(host: &mut Host, code_length: u32) -> std::io::Result<String> {
+    let Turn { guess } = host.recv_turn()?;
+    if !is_valid_code(&guess, code_length) {
+        host.connection().bye("invalid guess")?;
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{} sent an invalid guess", host.guest()),
+        ));
+    }
+    Ok(guess)
+}
+
+/// Tells the player on `me` about `report`. Returns `true` if the game is
+/// over.
+fn show<W: Write>(
+    writer: &mut W,
+    report: &Report,
+    me: Side,
+    opponent: &str,
+) -> std::io::Result<bool> {
+    if report.side != me {
+        writeln!(writer, "{}", report.guessed_by(opponent))?;
+    }
+    let Some(ending) = &report.ending else {
+        if report.side == me {
+            writeln!(writer, "{}", report.stats().feedback())?;
+            sound::play(Event::WrongGuess);
+        }
+        return Ok(false);
+    };
+    match ending.winner {
+        Some(winner) if winner == me => {
+            writeln!(writer, "{}", l10n::text("c26.cracked"))?;
+            sound::play(Event::Win);
+        }
+        Some(_) => {
+            let line = l10n::format("c26.cracked_first", &[("name", &opponent)]);
+            writeln!(writer, "{}", line)?;
+            sound::play(Event::Lose);
+        }
+        None => {
+            let line = l10n::format("c26.out_of_guesses", &[("code", &ending.code)]);
+            writeln!(writer, "{}", line)?;
+            sound::play(Event::Lose);
+        }
+    }
+    Ok(true)
+}
+
+/// Hosts a game against the code `target` and plays it once a guest joins
+/// `lobby`.
+pub fn host<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    lobby: &Lobby,
+    target: &str,
+    max_guesses: u32,
+    clock: &mut GameClock,
+) -> std::io::Result<()> {
+    let code_length = target.len() as u32;
+    let addr = lobby.local_addr()?;
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("net.waiting_to_join", &[("addr", &addr)])
+    )?;
+    let mut host = lobby.accept(&Settings {
+        code_length,
+        max_guesses,
+        set_by_host: false,
+    })?;
+    let guest = host.guest().to_string();
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("c26.joined", &[("name", &guest), ("digits", &code_length)])
+    )?;
+
+    for round in 1..=max_guesses {
+        let guess = prompt_user_for_guess(reader, writer, code_length, clock)?;
+        let report = score(Side::Host, guess, target, false);
+        host.send_state(&report)?;
+        if show(writer, &report, Side::Host, &guest)? {
+            break;
+        }
+
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &guest)])
+        )?;
+        let guess = recv_guess(&mut host, code_length)?;
+        let report = score(Side::Guest, guess, target, round == max_guesses);
+        host.send_state(&report)?;
+        if show(writer, &report, Side::Host, &guest)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Asks for a code of `code_length` digits, hidden as it is typed if
+/// `hide_code`, and hosts a game of cracking it in `max_guesses` guesses once
+/// a guest joins `lobby`.
+pub fn host_set_code<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    lobby: &Lobby,
+    code_length: u32,
+    max_guesses: u32,
+    hide_code: bool,
+) -> std::io::Result<()> {
+    let target = prompt_for_code(reader, writer, code_length, hide_code)?;
+    let addr = lobby.local_addr()?;
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("net.waiting_to_join", &[("addr", &addr)])
+    )?;
+    let mut host = lobby.accept(&Settings {
+        code_length,
+        max_guesses,
+        set_by_host: true,
+    })?;
+    let guest = host.guest().to_string();
+    writeln!(
+        writer,
+        "{}",
+        l10n::format(
+            "c26.joined_set_code",
+            &[("name", &guest), ("guesses", &max_guesses)]
+        )
+    )?;
+
+    for round in 1..=max_guesses {
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &guest)])
+        )?;
+        let guess = recv_guess(&mut host, code_length)?;
+        let report = score(Side::Guest, guess, &target, round == max_guesses);
+        host.send_state(&report)?;
+        writeln!(writer, "{}", report.guessed_by(&guest))?;
+        match report.ending.map(|ending| ending.winner) {
+            Some(Some(_)) => {
+                let line = l10n::format("c26.cracked_yours", &[("name", &guest)]);
+                writeln!(writer, "{}", line)?;
+                sound::play(Event::Lose);
+                break;
+            }
+            Some(None) => {
+                let line = l10n::format("c26.ran_out", &[("name", &guest)]);
+                writeln!(writer, "{}", line)?;
+                sound::play(Event::Win);
+                break;
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+/// Joins the game hosted at `addr` as `name` and plays it.
+pub fn join<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    addr: &str,
+    name: &str,
+    clock: &mut GameClock,
+) -> std::io::Result<()> {
+    let mut guest = Guest::join(addr, "c26", name)?;
+    let settings: Settings = guest.settings()?;
+    let host = guest.host().to_string();
+    let key = if settings.set_by_host {
+        "c26.joined_their_code"
+    } else {
+        "c26.joined_game"
+    };
+    let line = l10n::format(
+        key,
+        &[
+            ("name", &host),
+            ("digits", &settings.code_length),
+            ("guesses", &settings.max_guesses),
+        ],
+    );
+    writeln!(writer, "{}", line)?;
+
+    loop {
+        // In a race the host guesses first; a host who set the code does
+        // not guess at all.
+        if !settings.set_by_host {
+            writeln!(
+                writer,
+                "{}",
+                l10n::format("net.waiting_for", &[("name", &host)])
+            )?;
+            let report: Report = guest.recv_state()?;
+            if show(writer, &report, Side::Guest, &host)? {
+                return Ok(());
+            }
+        }
+
+        let guess = prompt_user_for_guess(reader, writer, settings.code_length, clock)?;
+        guest.send_turn(&Turn { guess })?;
+        let report: Report = guest.recv_state()?;
+        if show(writer, &report, Side::Guest, &host)? {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plays a whole game on localhost with scripted input for both players,
+    /// the host playing it with `play_host`, and returns what each of them
+    /// saw.
+    fn play_with<F>(host_input: &str, guest_input: &str, play_host: F) -> (String, String)
+    where
+        F: FnOnce(&mut &[u8], &mut Vec<u8>, &Lobby) -> std::io::Result<()> + Send + 'static,
+    {
+        let lobby = Lobby::bind("127.0.0.1:0", "c26", "Bob").unwrap();
+        let addr = lobby.local_addr().unwrap().to_string();
+        let host_input = host_input.to_string();
+        let host_thread = std::thread::spawn(move || {
+            let mut output = Vec::new();
+            play_host(&mut host_input.as_bytes(), &mut output, &lobby).unwrap();
+            String::from_utf8(output).unwrap()
+        });
+
+        let mut output = Vec::new();
+        join(
+            &mut guest_input.as_bytes(),
+            &mut output,
+            &addr,
+            "Ada",
+            &mut GameClock::start(),
+        )
+        .unwrap();
+        (
+            host_thread.join().unwrap(),
+            String::from_utf8(output).unwrap(),
+        )
+    }
+
+    /// Plays a race to crack `target`.
+    fn play(
+        target: &str,
+        max_guesses: u32,
+        host_input: &str,
+        guest_input: &str,
+    ) -> (String, String) {
+        let target = target.to_string();
+        play_with(host_input, guest_input, move |reader, writer, lobby| {
+            host(
+                reader,
+                writer,
+                lobby,
+                &target,
+                max_guesses,
+                &mut GameClock::start(),
+            )
+        })
+    }
+
+    /// Plays a game of cracking the code the host types first.
+    fn play_set_code(
+        code_length: u32,
+        max_guesses: u32,
+        host_input: &str,
+        guest_input: &str,
+    ) -> (String, String) {
+        play_with(host_input, guest_input, move |reader, writer, lobby| {
+            host_set_code(reader, writer, lobby, code_length, max_guesses, false)
+        })
+    }
+
+    #[test]
+    fn guest_can_crack_the_code_first() {
+        let (host, guest) = play("1234", 5, "5678\n9999\n", "1243\n1234\n");
+
+        assert!(host.ends_with(
+            "Ada guessed 1234. Correct digits: 4, correct positions: 4\n\
+             Ada cracked the code first.\n"
+        ));
+        assert!(guest.ends_with(
+            "Correct digits: 4, correct positions: 2\n\
+             Waiting for Bob...\n\
+             Bob guessed 9999. Correct digits: 0, correct positions: 0\n\
+             Enter a 4-digit guess: \n\
+             Congratulations! You've guessed the code.\n"
+        ));
+    }
+
+    #[test]
+    fn host_wins_by_cracking_the_code() {
+        let (host, guest) = play("1234", 5, "1111\n1234\n", "5678\n");
+
+        assert!(host.starts_with("Waiting for a player to join on 127.0.0.1:"));
+        assert!(host.contains(
+            "Ada joined. Crack the 4-digit code before they do, you go first.\n\
+             Enter a 4-digit guess: \n\
+             Correct digits: 1, correct positions: 1\n\
+             Waiting for Ada...\n\
+             Ada guessed 5678. Correct digits: 0, correct positions: 0\n\
+             Enter a 4-digit guess: \n\
+             Congratulations! You've guessed the code.\n"
+        ));
+        assert_eq!(
+            guest,
+            "Joined Bob's game. Crack the 4-digit code in 5 guesses before they do, Bob goes first.\n\
+             Waiting for Bob...\n\
+             Bob guessed 1111. Correct digits: 1, correct positions: 1\n\
+             Enter a 4-digit guess: \n\
+             Correct digits: 0, correct positions: 0\n\
+             Waiting for Bob...\n\
+             Bob guessed 1234. Correct digits: 4, correct positions: 4\n\
+             Bob cracked the code first.\n"
+        );
+    }
+
+    #[test]
+    fn nobody_wins_when_both_run_out_of_guesses() {
+        let (host, guest) = play("12", 1, "34\n", "56\n");
+
+        assert!(host.ends_with("Out of guesses! The code was 12.\n"));
+        assert!(guest.ends_with("Out of guesses! The code was 12.\n"));
+    }
+
+    #[test]
+    fn guest_cracks_the_code_the_host_set() {
+        let (host, guest) = play_set_code(4, 5, "12a\n4321\n", "1234\n4321\n");
+
+        assert!(host.starts_with(
+            "Enter the secret 4-digit code: \n\
+             Invalid input. Please enter a 4-digit number.\n\
+             Enter the secret 4-digit code: \n\
+             Waiting for a player to join on 127.0.0.1:"
+        ));
+        assert!(host.ends_with(
+            "Ada joined and is cracking your code with 5 guesses.\n\
+             Waiting for Ada...\n\
+             Ada guessed 1234. Correct digits: 4, correct positions: 0\n\
+             Waiting for Ada...\n\
+             Ada guessed 4321. Correct digits: 4, correct positions: 4\n\
+             Ada cracked your code.\n"
+        ));
+        assert_eq!(
+            guest,
+            "Joined Bob's game. Crack their 4-digit code in 5 guesses.\n\
+             Enter a 4-digit guess: \n\
+             Correct digits: 4, correct positions: 0\n\
+             Enter a 4-digit guess: \n\
+             Congratulations! You've guessed the code.\n"
+        );
+    }
+
+    #[test]
+    fn guest_runs_out_of_guesses_on_the_code_the_host_set() {
+        let (host, guest) = play_set_code(2, 2, "12\n", "34\n56\n");
+
+        assert!(host.ends_with(
+            "Ada guessed 56. Correct digits: 0, correct positions: 0\nAda ran out of guesses.\n"
+        ));
+        assert!(guest.ends_with("Out of guesses! The code was 12.\n"));
+    }
+}
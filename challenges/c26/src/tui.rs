@@ -0,0 +1,212 @@
+//! Full-screen version of the game, enabled with `--tui`.
+use crate::{evaluate_guess, GuessStats};
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_tui::crossterm::event::KeyCode;
+use challenge_tui::ratatui::layout::{Constraint, Layout};
+use challenge_tui::ratatui::style::{Color, Style};
+use challenge_tui::ratatui::text::{Line, Span};
+use challenge_tui::ratatui::widgets::{Block, List};
+use challenge_tui::ratatui::Frame;
+use challenge_tui::widgets::StatusBar;
+
+/// The keys shown in the status bar, with what they do.
+fn keys() -> [(&'static str, &'static str); 3] {
+    [
+        ("0-9", l10n::text("c26.type")),
+        ("enter", l10n::text("c26.guess_key")),
+        ("esc", l10n::text("tui.quit")),
+    ]
+}
+
+/// State of one game as shown on screen.
+struct Board<'a> {
+    target: &'a str,
+    max_guesses: u32,
+    guesses: Vec<(String, GuessStats)>,
+    entry: String,
+}
+
+impl<'a> Board<'a> {
+    fn new(target: &'a str, max_guesses: u32) -> Board<'a> {
+        Board {
+            target,
+            max_guesses,
+            guesses: Vec::new(),
+            entry: String::new(),
+        }
+    }
+
+    fn code_length(&self) -> usize {
+        self.target.len()
+    }
+
+    fn is_won(&self) -> bool {
+        self.guesses
+            .last()
+            .is_some_and(|(guess, _)| guess == self.target)
+    }
+
+    fn is_over(&self) -> bool {
+        self.is_won() || self.guesses.len() as u32 >= self.max_guesses
+    }
+
+    fn type_digit(&mut self, digit: char) {
+        if self.entry.len() < self.code_length() {
+            self.entry.push(digit);
+        }
+    }
+
+    fn submit(&mut self) {
+        if self.entry.len() == self.code_length() {
+            let stats = evaluate_guess(&self.entry, self.target);
+            self.guesses.push((std::mem::take(&mut self.entry), stats));
+            sound::play(if self.is_won() {
+                Event::Win
+            } else if self.is_over() {
+                Event::Lose
+            } else {
+                Event::WrongGuess
+            });
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [history, entry, status] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let rows = self.guesses.iter().enumerate().map(|(i, (guess, stats))| {
+            Line::from(vec![
+                Span::raw(format!("{:>3}. ", i + 1)),
+                Span::styled(guess.as_str(), Style::new().fg(Color::Cyan)),
+                Span::raw(l10n::format(
+                    "c26.row",
+                    &[
+                        ("digits", &stats.correct_digits),
+                        ("positions", &stats.correct_positions),
+                    ],
+                )),
+            ])
+        });
+        frame.render_widget(
+            List::new(rows).block(Block::bordered().title(l10n::text("c26.guesses"))),
+            history,
+        );
+
+        let placeholder = "_".repeat(self.code_length() - self.entry.len());
+        let entry_block = Block::bordered().title(l10n::text("c26.your_guess"));
+        let entry_line = Line::raw(format!(" {}{}", self.entry, placeholder))
+            .style(Style::new().fg(Color::Yellow));
+        frame.render_widget(entry_line, entry_block.inner(entry));
+        frame.render_widget(entry_block, entry);
+
+        let keys = keys();
+        let status_bar = StatusBar::new(l10n::text("c26.title"));
+        let status_bar = if self.is_won() {
+            status_bar.message(l10n::format(
+                "c26.cracked_tui",
+                &[("guesses", &self.guesses.len())],
+            ))
+        } else if self.is_over() {
+            status_bar.message(l10n::format(
+                "c26.out_of_guesses_tui",
+                &[("code", &self.target)],
+            ))
+        } else {
+            let remaining = self.max_guesses - self.guesses.len() as u32;
+            status_bar
+                .message(l10n::format("c26.left", &[("guesses", &remaining)]))
+                .keys(&keys)
+        };
+        frame.render_widget(status_bar, status);
+    }
+}
+
+/// Plays one game full-screen against the secret code `target`. Returns
+/// `None` if the player quit, and otherwise the result [`crate::run`] would
+/// give.
+pub fn run(target: &str, max_guesses: u32) -> std::io::Result<Option<Option<u32>>> {
+    challenge_tui::run(|terminal| {
+        let mut board = Board::new(target, max_guesses);
+        loop {
+            terminal.draw(|frame| board.draw(frame))?;
+            let key = challenge_tui::next_key()?;
+            if board.is_over() {
+                let guesses = board.is_won().then_some(board.guesses.len() as u32);
+                return Ok(Some(guesses));
+            }
+            // Digits are typed, so only Esc and Ctrl-C leave the game.
+            if key.code != KeyCode::Char('q') && challenge_tui::is_quit(&key) {
+                return Ok(None);
+            }
+
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => board.type_digit(c),
+                KeyCode::Backspace => {
+                    board.entry.pop();
+                }
+                KeyCode::Enter => board.submit(),
+                _ => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_tui::ratatui::backend::TestBackend;
+    use challenge_tui::ratatui::Terminal;
+
+    #[test]
+    fn submit_ignores_incomplete_guesses() {
+        let mut board = Board::new("1234", 3);
+        board.type_digit('1');
+        board.submit();
+        assert!(board.guesses.is_empty());
+        assert_eq!(board.entry, "1");
+    }
+
+    #[test]
+    fn type_digit_stops_at_code_length() {
+        let mut board = Board::new("12", 3);
+        for c in "987".chars() {
+            board.type_digit(c);
+        }
+        assert_eq!(board.entry, "98");
+    }
+
+    #[test]
+    fn game_ends_on_win_or_after_max_guesses() {
+        let mut board = Board::new("12", 2);
+        board.entry = "21".to_string();
+        board.submit();
+        assert!(!board.is_over());
+        board.entry = "12".to_string();
+        board.submit();
+        assert!(board.is_won());
+
+        let mut board = Board::new("12", 1);
+        board.entry = "00".to_string();
+        board.submit();
+        assert!(board.is_over() && !board.is_won());
+    }
+
+    #[test]
+    fn draw_shows_guess_feedback() {
+        let mut board = Board::new("1234", 12);
+        board.entry = "1243".to_string();
+        board.submit();
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        terminal.draw(|frame| board.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("1. 1243   digits: 4  positions: 2"));
+        assert!(text.contains("11 guesses left"));
+    }
+}
@@ -0,0 +1,41 @@
+use c26::evaluate_guess;
+use proptest::prelude::*;
+use proptest::string::string_regex;
+
+/// A guess and a code of the same length, as the game always compares.
+fn guess_and_code() -> impl Strategy<Value = (String, String)> {
+    (0..=8usize).prop_flat_map(|len| {
+        let code = format!("[0-9]{{{len}}}");
+        (string_regex(&code).unwrap(), string_regex(&code).unwrap())
+    })
+}
+
+proptest! {
+    #[test]
+    fn scoring_is_symmetric((guess, code) in guess_and_code()) {
+        prop_assert_eq!(evaluate_guess(&guess, &code), evaluate_guess(&code, &guess));
+    }
+
+    #[test]
+    fn positions_are_bounded_by_digits_and_length((guess, code) in guess_and_code()) {
+        let stats = evaluate_guess(&guess, &code);
+        prop_assert!(stats.correct_positions <= stats.correct_digits);
+        prop_assert!(stats.correct_digits as usize <= code.len());
+    }
+
+    #[test]
+    fn a_code_matches_itself_completely(code in "[0-9]{0,8}") {
+        let stats = evaluate_guess(&code, &code);
+        prop_assert_eq!(stats.correct_digits as usize, code.len());
+        prop_assert_eq!(stats.correct_positions as usize, code.len());
+    }
+
+    #[test]
+    fn digits_do_not_depend_on_order((guess, code) in guess_and_code()) {
+        let reversed: String = guess.chars().rev().collect();
+        prop_assert_eq!(
+            evaluate_guess(&guess, &code).correct_digits,
+            evaluate_guess(&reversed, &code).correct_digits
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! # Hangman Word Guessing Game
+//!
+//! This module implements a simple interactive Hangman-style word guessing game
+//! where players attempt to guess a hidden word one letter at a time.
+//!
+//! ## Features
+//!
+//! - **Secure Word Input**: Hides the target word during input using password masking
+//! - **Letter-by-Letter Guessing**: Allows players to guess one letter at a time
+//! - **Visual Feedback**: Shows partially completed word with placeholders for unguessed letters
+//! - **Life System**: Implements a limited number of incorrect guesses before game over
+//! - **Input Validation**: Ensures only valid alphabetic characters are accepted as guesses
+//! - **Case Handling**: Converts all input to uppercase for consistent comparison
+//! - **Win/Loss Detection**: Identifies when the player has won or lost the game
+//! - **Lifetime Record**: Keeps a win/loss tally and the time played between runs
+//! - **Accessible Mode**: Reads the word out letter by letter, with "blank" for
+//!   each letter still hidden
+//! - **Network Play**: One player hosts with `--host` and chooses the word, the
+//!   other joins with `--join` and guesses it from their own keyboard
+use crate::net;
+use crate::run;
+use challenge_common::accessible::AccessibleArgs;
+use challenge_common::cli::CommonArgs;
+use challenge_common::clock::{self, GameClock};
+use challenge_common::config;
+use challenge_common::difficulty::DifficultyArgs;
+use challenge_common::input::Input;
+use challenge_common::l10n;
+use challenge_common::log;
+use challenge_common::prompt;
+use challenge_common::sound::{self, SoundArgs};
+use challenge_netplay::{Lobby, Mode, NetArgs, Side};
+use challenge_storage::Store;
+use clap::Parser;
+use std::ffi::OsString;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Play Hangman: one player picks a word, the other guesses it letter by letter.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Number of wrong guesses allowed [default: 8, 5 or 3 by difficulty]
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    lives: Option<u32>,
+
+    #[command(flatten)]
+    net: NetArgs,
+    #[command(flatten)]
+    accessible: AccessibleArgs,
+    #[command(flatten)]
+    difficulty: DifficultyArgs,
+    #[command(flatten)]
+    sound: SoundArgs,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Adds a game and the time it took to the lifetime record and returns
+/// (wins, losses).
+fn record_game(won: bool, elapsed: Duration) -> challenge_storage::Result<(u64, u64)> {
+    let mut store = Store::open("c27")?;
+    store.increment(if won { "wins" } else { "losses" })?;
+    store.add("seconds_played", elapsed.as_secs())?;
+    store.save()?;
+    Ok((
+        store.get_or_default("wins")?,
+        store.get_or_default("losses")?,
+    ))
+}
+
+/// Runs the program with the command line `args`, the program name first,
+/// and returns its exit status. The binary and the launcher both start here.
+pub fn run_cli(args: Vec<OsString>) -> ExitCode {
+    let args: Args = match challenge_common::cli::parse_args(args) {
+        Ok(args) => args,
+        Err(status) => return status,
+    };
+    log::init(&args.common.log);
+    l10n::init(&args.common.lang);
+    prompt::init(&args.common.prompt);
+    sound::init(&args.sound);
+    let mut input = match Input::from_args(&args.common) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let hide_word = !input.is_scripted();
+    let lives = args
+        .lives
+        .or(config::get().c27.lives)
+        .unwrap_or(args.difficulty.level().pick(8, 5, 3));
+    let mut clock = GameClock::start();
+    let mode = match args.net.mode() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(mode) = mode {
+        let mut stdout = std::io::stdout();
+        let result = match mode {
+            Mode::Host(addr) => Lobby::bind(&addr, "c27", &args.net.name(Side::Host))
+                .and_then(|lobby| net::host(&mut input, &mut stdout, &lobby, lives, hide_word)),
+            Mode::Join(addr) => net::join(
+                &mut input,
+                &mut stdout,
+                &addr,
+                &args.net.name(Side::Guest),
+                args.accessible.enabled(),
+                &mut clock,
+            ),
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        sound::wait();
+        return ExitCode::SUCCESS;
+    }
+    let won = match run(
+        &mut input,
+        &mut std::io::stdout(),
+        lives,
+        hide_word,
+        args.accessible.enabled(),
+        &mut clock,
+    ) {
+        Ok(won) => won,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    sound::wait();
+    let time = clock::format(clock.elapsed());
+    println!("{}", l10n::format("clock.time_played", &[("time", &time)]));
+    match record_game(won, clock.elapsed()) {
+        Ok((wins, losses)) => println!(
+            "{}",
+            l10n::format("c27.lifetime", &[("wins", &wins), ("losses", &losses)])
+        ),
+        Err(e) => eprintln!("Warning: unable to save your record: {}", e),
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,235 @@
+//! # Hangman Word Guessing Game
+//!
+//! A game of Hangman from the secret word to the last guess, kept apart from
+//! the command line so that the browser build can play it too.
+mod cli;
+mod net;
+
+pub use cli::run_cli;
+
+use challenge_common::clock::GameClock;
+use challenge_common::input;
+use challenge_common::l10n;
+use challenge_common::prompt::Prompt;
+use challenge_common::sound::{self, Event};
+use std::io::{BufRead, Write};
+
+/// Reads the word to guess. The word is hidden as it is typed unless `hidden`
+/// is `false`, in which case it is read from `reader` like any other input.
+/// Without the `masked` feature it is never hidden.
+pub fn prompt_for_word<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    hidden: bool,
+) -> std::io::Result<String> {
+    Prompt::new(l10n::text("c27.word")).ask_with(
+        writer,
+        |_| match hidden {
+            #[cfg(feature = "masked")]
+            true => rpassword::read_password(),
+            _ => input::read_line(reader),
+        },
+        |answer| {
+            if answer.is_empty() || !answer.chars().all(char::is_alphabetic) {
+                return Err(l10n::text("c27.word_invalid"));
+            }
+            Ok(answer.to_uppercase())
+        },
+    )
+}
+
+/// Asks the guesser for a letter, upper-cased.
+pub fn prompt_for_letter<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_lives: u32,
+    clock: &mut GameClock,
+) -> std::io::Result<char> {
+    Prompt::new(&l10n::format("c27.letter", &[("lives", &num_lives)])).ask_with(
+        writer,
+        |writer| clock.read_line(reader, writer),
+        |answer| match answer.chars().next() {
+            Some(letter) if letter.is_alphabetic() => Ok(letter.to_uppercase().next().unwrap()),
+            _ => Err(l10n::text("c27.letter_invalid")),
+        },
+    )
+}
+
+/// Reveals every `guess_letter` of `target_word` in `player_word`.
+pub fn update_player_word(target_word: &str, guess_letter: char, player_word: &mut String) {
+    for (i, target_char) in target_word.chars().enumerate() {
+        if target_char == guess_letter {
+            player_word.replace_range(i..i + 1, &guess_letter.to_string());
+        }
+    }
+}
+
+/// Returns the word as the guesser sees it. In accessible mode the letters
+/// are separated by commas and each hidden letter is spelled out as "blank",
+/// which a screen reader reads far better than a run of asterisks.
+pub fn show_word(player_word: &str, accessible: bool) -> String {
+    if !accessible {
+        return player_word.to_string();
+    }
+    let letters: Vec<_> = player_word
+        .chars()
+        .map(|c| match c {
+            '*' => l10n::text("c27.blank").to_string(),
+            _ => c.to_string(),
+        })
+        .collect();
+    letters.join(", ")
+}
+
+/// Plays one game and returns `true` if the word was guessed. The guesser can
+/// pause `clock` at any prompt; it starts once the word has been entered.
+pub fn run<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    lives: u32,
+    hide_word: bool,
+    accessible: bool,
+    clock: &mut GameClock,
+) -> std::io::Result<bool> {
+    let target_word = prompt_for_word(reader, writer, hide_word)?;
+    *clock = GameClock::start();
+    let mut player_word = "*".repeat(target_word.len());
+    let word = show_word(&player_word, accessible);
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("c27.word_to_guess", &[("word", &word)])
+    )?;
+
+    let mut lives = lives;
+    while lives > 0 {
+        let letter = prompt_for_letter(reader, writer, lives, clock)?;
+        tracing::debug!(%letter, lives, "letter guessed");
+        if target_word.find(letter).is_none() {
+            lives -= 1;
+            sound::play(Event::WrongGuess);
+        } else {
+            update_player_word(&target_word, letter, &mut player_word);
+        }
+
+        if player_word.find('*').is_none() {
+            let line = l10n::format("c27.guessed", &[("word", &target_word)]);
+            writeln!(writer, "{}", line)?;
+            sound::play(Event::Win);
+            return Ok(true);
+        } else if lives == 0 {
+            let line = l10n::format("c27.out_of_lives", &[("word", &target_word)]);
+            writeln!(writer, "{}", line)?;
+            sound::play(Event::Lose);
+        } else {
+            let word = show_word(&player_word, accessible);
+            writeln!(
+                writer,
+                "{}",
+                l10n::format("c27.word_to_guess", &[("word", &word)])
+            )?;
+        }
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_player_word_replaces_single_matching_character() {
+        let target = "HELLO";
+        let mut player_word = "*****".to_string();
+        update_player_word(target, 'L', &mut player_word);
+        assert_eq!(player_word, "**LL*");
+    }
+
+    #[test]
+    fn update_player_word_replaces_multiple_instances_of_matching_character() {
+        let target = "BANANA";
+        let mut player_word = "******".to_string();
+        update_player_word(target, 'A', &mut player_word);
+        assert_eq!(player_word, "*A*A*A");
+    }
+
+    #[test]
+    fn update_player_word_makes_no_changes_for_non_matching_character() {
+        let target = "HELLO";
+        let mut player_word = "*****".to_string();
+        update_player_word(target, 'Z', &mut player_word);
+        assert_eq!(player_word, "*****");
+    }
+
+    #[test]
+    fn update_player_word_preserves_previously_guessed_characters() {
+        let target = "HELLO";
+        let mut player_word = "*E***".to_string();
+        update_player_word(target, 'L', &mut player_word);
+        assert_eq!(player_word, "*ELL*");
+    }
+
+    #[test]
+    fn update_player_word_handles_empty_strings() {
+        let target = "";
+        let mut player_word = "".to_string();
+        update_player_word(target, 'A', &mut player_word);
+        assert_eq!(player_word, "");
+    }
+
+    #[test]
+    fn update_player_word_is_case_sensitive() {
+        let target = "Hello";
+        let mut player_word = "*****".to_string();
+        update_player_word(target, 'h', &mut player_word);
+        assert_eq!(player_word, "*****"); // 'h' doesn't match 'H'
+    }
+
+    #[test]
+    fn show_word_spells_out_blanks_in_accessible_mode() {
+        assert_eq!(show_word("R**T", false), "R**T");
+        assert_eq!(show_word("R**T", true), "R, blank, blank, T");
+    }
+
+    #[test]
+    fn run_wins_when_every_letter_is_found() {
+        let mut reader = "noon\nn\n7\no\n".as_bytes();
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut reader,
+            &mut output,
+            5,
+            false,
+            false,
+            &mut GameClock::start(),
+        )
+        .unwrap();
+
+        assert!(won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Word to guess: N**N\n"));
+        assert!(output.contains("Invalid input. Please enter a single letter.\n"));
+        assert!(output.ends_with("Congratulations! You've guessed the word: NOON\n"));
+    }
+
+    #[test]
+    fn run_loses_when_lives_run_out() {
+        let mut reader = "cat\nz\nq\n".as_bytes();
+        let mut output = Vec::new();
+
+        let won = run(
+            &mut reader,
+            &mut output,
+            2,
+            false,
+            false,
+            &mut GameClock::start(),
+        )
+        .unwrap();
+
+        assert!(!won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("You've run out of lives. The word was: CAT\n"));
+    }
+}
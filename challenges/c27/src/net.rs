@@ -0,0 +1,245 @@
+//! Two-player Hangman over the network.
+//!
+//! The host chooses the word and the guest guesses it. The host keeps the
+//! word to itself: after every guess it sends only the letters found so far
+//! and the lives left, and reveals the word once the game is over.
+use crate::{prompt_for_letter, prompt_for_word, show_word, update_player_word};
+use challenge_common::clock::GameClock;
+use challenge_common::l10n;
+use challenge_common::sound::{self, Event};
+use challenge_netplay::{Guest, Lobby};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+
+/// Rules the host sends to the guest when it joins.
+#[derive(Debug, Serialize, Deserialize)]
+struct Settings {
+    lives: u32,
+    length: usize,
+}
+
+/// A letter guessed by the guest.
+#[derive(Debug, Serialize, Deserialize)]
+struct Turn {
+    letter: char,
+}
+
+/// The host's account of a guess, sent after every turn.
+#[derive(Debug, Serialize, Deserialize)]
+struct Report {
+    letter: char,
+    /// The word with the letters not found yet as `*`.
+    word: String,
+    lives: u32,
+    /// The word itself, sent once the game is over.
+    answer: Option<String>,
+}
+
+/// Asks for a word and hosts a game of guessing it once a guest joins
+/// `lobby`. Returns `true` if the guest guessed the word.
+pub fn host<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    lobby: &Lobby,
+    lives: u32,
+    hide_word: bool,
+) -> std::io::Result<bool> {
+    let target_word = prompt_for_word(reader, writer, hide_word)?;
+    let addr = lobby.local_addr()?;
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("net.waiting_to_join", &[("addr", &addr)])
+    )?;
+    let mut host = lobby.accept(&Settings {
+        lives,
+        length: target_word.chars().count(),
+    })?;
+    let guest = host.guest().to_string();
+    writeln!(
+        writer,
+        "{}",
+        l10n::format("c27.joined", &[("name", &guest), ("lives", &lives)])
+    )?;
+
+    let mut player_word = "*".repeat(target_word.chars().count());
+    let mut lives = lives;
+    loop {
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("net.waiting_for", &[("name", &guest)])
+        )?;
+        let Turn { letter } = host.recv_turn()?;
+        if !letter.is_alphabetic() {
+            host.connection().bye("invalid letter")?;
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} sent an invalid letter", guest),
+            ));
+        }
+        let letter = letter.to_uppercase().next().unwrap_or(letter);
+        if target_word.contains(letter) {
+            update_player_word(&target_word, letter, &mut player_word);
+        } else {
+            lives -= 1;
+        }
+        tracing::debug!(%letter, lives, %player_word, "letter guessed");
+
+        let won = !player_word.contains('*');
+        let over = won || lives == 0;
+        host.send_state(&Report {
+            letter,
+            word: player_word.clone(),
+            lives,
+            answer: over.then(|| target_word.clone()),
+        })?;
+        let line = l10n::format(
+            "c27.guessed_letter",
+            &[
+                ("name", &guest),
+                ("letter", &letter),
+                ("word", &player_word),
+            ],
+        );
+        writeln!(writer, "{}", line)?;
+        if won {
+            let line = l10n::format("c27.guessed_yours", &[("name", &guest)]);
+            writeln!(writer, "{}", line)?;
+            return Ok(true);
+        } else if over {
+            let line = l10n::format("c27.ran_out", &[("name", &guest)]);
+            writeln!(writer, "{}", line)?;
+            return Ok(false);
+        }
+    }
+}
+
+/// Joins the game hosted at `addr` as `name` and guesses the host's word.
+/// Returns `true` if the word was guessed.
+pub fn join<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    addr: &str,
+    name: &str,
+    accessible: bool,
+    clock: &mut GameClock,
+) -> std::io::Result<bool> {
+    let mut guest = Guest::join(addr, "c27", name)?;
+    let settings: Settings = guest.settings()?;
+    let host = guest.host().to_string();
+    let line = l10n::format(
+        "c27.joined_game",
+        &[
+            ("name", &host),
+            ("length", &settings.length),
+            ("lives", &settings.lives),
+        ],
+    );
+    writeln!(writer, "{}", line)?;
+
+    let mut report = Report {
+        letter: '*',
+        word: "*".repeat(settings.length),
+        lives: settings.lives,
+        answer: None,
+    };
+    loop {
+        let word = show_word(&report.word, accessible);
+        writeln!(
+            writer,
+            "{}",
+            l10n::format("c27.word_to_guess", &[("word", &word)])
+        )?;
+        let letter = prompt_for_letter(reader, writer, report.lives, clock)?;
+        guest.send_turn(&Turn { letter })?;
+        let lives = report.lives;
+        report = guest.recv_state()?;
+        if report.lives < lives {
+            sound::play(Event::WrongGuess);
+        }
+
+        let Some(answer) = &report.answer else {
+            continue;
+        };
+        if report.word.contains('*') {
+            let line = l10n::format("c27.out_of_lives", &[("word", &answer)]);
+            writeln!(writer, "{}", line)?;
+            sound::play(Event::Lose);
+            return Ok(false);
+        }
+        let line = l10n::format("c27.guessed", &[("word", &answer)]);
+        writeln!(writer, "{}", line)?;
+        sound::play(Event::Win);
+        return Ok(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plays a whole game on localhost with scripted input for both players
+    /// and returns what each of them saw.
+    fn play(lives: u32, host_input: &str, guest_input: &str) -> (String, String) {
+        let lobby = Lobby::bind("127.0.0.1:0", "c27", "Bob").unwrap();
+        let addr = lobby.local_addr().unwrap().to_string();
+        let host_input = host_input.to_string();
+        let host_thread = std::thread::spawn(move || {
+            let mut output = Vec::new();
+            host(
+                &mut host_input.as_bytes(),
+                &mut output,
+                &lobby,
+                lives,
+                false,
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        });
+
+        let mut output = Vec::new();
+        join(
+            &mut guest_input.as_bytes(),
+            &mut output,
+            &addr,
+            "Ada",
+            false,
+            &mut GameClock::start(),
+        )
+        .unwrap();
+        (
+            host_thread.join().unwrap(),
+            String::from_utf8(output).unwrap(),
+        )
+    }
+
+    #[test]
+    fn guest_guesses_the_hosts_word() {
+        let (host, guest) = play(3, "noon\n", "n\nz\no\n");
+
+        assert!(host.ends_with(
+            "Ada guessed O: NOON\n\
+             Ada guessed your word.\n"
+        ));
+        assert_eq!(
+            guest,
+            "Joined Bob's game. Guess their 4-letter word with 3 lives.\n\
+             Word to guess: ****\n\
+             You have 3 lives left - Letter? \n\
+             Word to guess: N**N\n\
+             You have 3 lives left - Letter? \n\
+             Word to guess: N**N\n\
+             You have 2 lives left - Letter? \n\
+             Congratulations! You've guessed the word: NOON\n"
+        );
+    }
+
+    #[test]
+    fn guest_loses_when_lives_run_out() {
+        let (host, guest) = play(1, "cat\n", "z\n");
+
+        assert!(host.ends_with("Ada guessed Z: ***\nAda ran out of lives.\n"));
+        assert!(guest.ends_with("You've run out of lives. The word was: CAT\n"));
+    }
+}
@@ -0,0 +1,41 @@
+use c12::{factors, FactorSieve};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Factors a single number, where the sieve has to be built first.
+fn factor_one(c: &mut Criterion) {
+    let mut group = c.benchmark_group("c12/factor_one");
+    for n in [1_000u64, 100_000, 1_000_000] {
+        group.bench_with_input(BenchmarkId::new("trial_division", n), &n, |b, &n| {
+            b.iter(|| factors(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("sieve", n), &n, |b, &n| {
+            b.iter(|| FactorSieve::new(black_box(n)).factors(n))
+        });
+    }
+    group.finish();
+}
+
+/// Factors every number up to a limit, where one sieve serves them all.
+fn factor_range(c: &mut Criterion) {
+    let mut group = c.benchmark_group("c12/factor_range");
+    for limit in [1_000u64, 10_000, 100_000] {
+        group.bench_with_input(
+            BenchmarkId::new("trial_division", limit),
+            &limit,
+            |b, &limit| b.iter(|| (1..=limit).map(factors).map(|f| f.len()).sum::<usize>()),
+        );
+        group.bench_with_input(BenchmarkId::new("sieve", limit), &limit, |b, &limit| {
+            b.iter(|| {
+                let sieve = FactorSieve::new(limit);
+                (1..=limit)
+                    .filter_map(|n| sieve.factors(n))
+                    .map(|f| f.len())
+                    .sum::<usize>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, factor_one, factor_range);
+criterion_main!(benches);
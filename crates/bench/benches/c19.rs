@@ -0,0 +1,22 @@
+use c19::{apply_cipher, apply_cipher_bytes};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn cipher(c: &mut Criterion) {
+    let mut group = c.benchmark_group("c19/cipher");
+    // Mostly ASCII with an occasional accented letter, like real text
+    let line = "The quick brown fox jumps over the lazy dog, café au lait. ";
+    for size in [1_000usize, 100_000, 1_000_000] {
+        let text: String = line.chars().cycle().take(size).collect();
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("chars", size), &text, |b, text| {
+            b.iter(|| apply_cipher(black_box(text), 5))
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", size), &text, |b, text| {
+            b.iter(|| apply_cipher_bytes(black_box(text), 5))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, cipher);
+criterion_main!(benches);
@@ -0,0 +1,19 @@
+use c20::{fib, fib_fast_doubling};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn fibonacci(c: &mut Criterion) {
+    let mut group = c.benchmark_group("c20/fib");
+    // F(186) is the largest Fibonacci number a u128 holds
+    for n in [10u8, 50, 100, 186] {
+        group.bench_with_input(BenchmarkId::new("iteration", n), &n, |b, &n| {
+            b.iter(|| fib(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("fast_doubling", n), &n, |b, &n| {
+            b.iter(|| fib_fast_doubling(black_box(n)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, fibonacci);
+criterion_main!(benches);
@@ -0,0 +1,19 @@
+use c22::create_rand_2d_array;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn array_generation(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut group = c.benchmark_group("c22/create_rand_2d_array");
+    for size in [10usize, 100, 1_000] {
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| create_rand_2d_array(black_box(size), &(0, 15), &mut rng))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, array_generation);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+//! # Challenge Benchmarks
+//!
+//! Criterion benchmarks for the challenges that do real computation. Each
+//! bench in `benches/` compares the algorithm a challenge uses with an
+//! alternative from the same challenge's library:
+//!
+//! - `c12`: factoring by trial division against a smallest-prime-factor sieve
+//! - `c19`: Caesar cipher throughput, char by char against byte by byte
+//! - `c20`: Fibonacci numbers by iteration against fast doubling
+//! - `c22`: random array generation at several sizes
+//!
+//! Run them all with `cargo bench -p challenge-bench`, or one of them with
+//! `cargo bench -p challenge-bench --bench c20`. Reports are written to
+//! `target/criterion`.
@@ -0,0 +1,316 @@
+//! # Challenge Cards
+//!
+//! Standard playing cards for the card games, so that they all deal and
+//! display cards the same way. A [`Card`] is a [`Rank`] and a [`Suit`], and
+//! is written out in full in the player's language, e.g. "Queen of Hearts".
+//! A [`Deck`] holds one or
+//! more 52-card decks, is shuffled with the generator the game passes in so
+//! that seeded sessions deal the same cards, and is dealt from the top.
+use challenge_common::l10n;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt::{self, Display, Formatter};
+
+/// The suit of a playing card.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Suit {
+    Hearts,
+    Diamonds,
+    Clubs,
+    Spades,
+}
+
+impl Suit {
+    /// Every suit, in the order a new deck is sorted.
+    pub const ALL: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+
+    /// Returns the symbol printed on the card, e.g. '♥'.
+    pub fn symbol(self) -> char {
+        match self {
+            Suit::Hearts => '♥',
+            Suit::Diamonds => '♦',
+            Suit::Clubs => '♣',
+            Suit::Spades => '♠',
+        }
+    }
+
+    /// Returns `true` for the red suits, Hearts and Diamonds.
+    pub fn is_red(self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds)
+    }
+}
+
+impl Display for Suit {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(l10n::text(match self {
+            Suit::Hearts => "cards.hearts",
+            Suit::Diamonds => "cards.diamonds",
+            Suit::Clubs => "cards.clubs",
+            Suit::Spades => "cards.spades",
+        }))
+    }
+}
+
+/// The rank of a playing card, ordered with Aces low.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rank {
+    Ace,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+}
+
+impl Rank {
+    /// Every rank from Ace to King.
+    pub const ALL: [Rank; 13] = [
+        Rank::Ace,
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+    ];
+
+    /// Returns the rank's number, from 1 for an Ace to 13 for a King.
+    pub fn value(self) -> u32 {
+        self as u32 + 1
+    }
+
+    /// Returns the rank whose number is `value`, see [`Rank::value`].
+    pub fn from_value(value: u32) -> Option<Rank> {
+        Rank::ALL.get(value.checked_sub(1)? as usize).copied()
+    }
+
+    /// Returns the index printed in a card's corner, e.g. "A" or "10".
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Rank::Ace => "A",
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+        }
+    }
+}
+
+impl Display for Rank {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(l10n::text(match self {
+            Rank::Ace => "cards.ace",
+            Rank::Two => "cards.two",
+            Rank::Three => "cards.three",
+            Rank::Four => "cards.four",
+            Rank::Five => "cards.five",
+            Rank::Six => "cards.six",
+            Rank::Seven => "cards.seven",
+            Rank::Eight => "cards.eight",
+            Rank::Nine => "cards.nine",
+            Rank::Ten => "cards.ten",
+            Rank::Jack => "cards.jack",
+            Rank::Queen => "cards.queen",
+            Rank::King => "cards.king",
+        }))
+    }
+}
+
+/// A playing card.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        Card { rank, suit }
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let card = l10n::format("cards.card", &[("rank", &self.rank), ("suit", &self.suit)]);
+        f.write_str(&card)
+    }
+}
+
+/// A pile of cards dealt from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// Creates an unshuffled standard 52-card deck.
+    pub fn new() -> Deck {
+        Deck::with_decks(1)
+    }
+
+    /// Creates `count` unshuffled standard decks stacked into one, like the
+    /// shoe of a casino table.
+    pub fn with_decks(count: usize) -> Deck {
+        let cards = (0..count)
+            .flat_map(|_| Suit::ALL)
+            .flat_map(|suit| Rank::ALL.map(|rank| Card::new(rank, suit)))
+            .collect();
+        Deck { cards }
+    }
+
+    /// Shuffles the deck with `rng`.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+        tracing::debug!(cards = self.cards.len(), "deck shuffled");
+    }
+
+    /// Removes every card `keep` returns `false` for.
+    pub fn retain(&mut self, keep: impl FnMut(&Card) -> bool) {
+        self.cards.retain(keep);
+    }
+
+    /// Deals the top card, or `None` once the deck is empty.
+    pub fn deal(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Returns the number of cards left to deal.
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn deal_all(mut deck: Deck) -> Vec<Card> {
+        std::iter::from_fn(|| deck.deal()).collect()
+    }
+
+    #[test]
+    fn new_deck_holds_every_card_once() {
+        let cards = deal_all(Deck::new());
+        assert_eq!(cards.len(), 52);
+        let unique: HashSet<_> = cards.iter().collect();
+        for suit in Suit::ALL {
+            for rank in Rank::ALL {
+                assert!(unique.contains(&Card::new(rank, suit)));
+            }
+        }
+    }
+
+    #[test]
+    fn multiple_decks_hold_every_card_once_per_deck() {
+        let deck = Deck::with_decks(6);
+        assert_eq!(deck.remaining(), 312);
+        let cards = deal_all(deck);
+        let aces = cards
+            .iter()
+            .filter(|card| **card == Card::new(Rank::Ace, Suit::Spades))
+            .count();
+        assert_eq!(aces, 6);
+    }
+
+    #[test]
+    fn deal_counts_down_until_the_deck_is_empty() {
+        let mut deck = Deck::new();
+        deck.deal();
+        assert_eq!(deck.remaining(), 51);
+        while deck.deal().is_some() {}
+        assert!(deck.is_empty());
+        assert_eq!(deck.deal(), None);
+    }
+
+    #[test]
+    fn shuffle_keeps_the_same_cards() {
+        let mut deck = Deck::new();
+        deck.shuffle(&mut StdRng::seed_from_u64(42));
+        assert_ne!(deck, Deck::new());
+        let mut shuffled = deal_all(deck);
+        let mut sorted = deal_all(Deck::new());
+        shuffled.sort_by_key(|card| (card.suit as u8, card.rank));
+        sorted.sort_by_key(|card| (card.suit as u8, card.rank));
+        assert_eq!(shuffled, sorted);
+    }
+
+    #[test]
+    fn shuffle_is_the_same_for_the_same_seed() {
+        let shuffled = |seed| {
+            let mut deck = Deck::new();
+            deck.shuffle(&mut StdRng::seed_from_u64(seed));
+            deck
+        };
+        assert_eq!(shuffled(7), shuffled(7));
+        assert_ne!(shuffled(7), shuffled(8));
+    }
+
+    #[test]
+    fn retain_removes_cards() {
+        let mut deck = Deck::new();
+        deck.retain(|card| card.rank == Rank::Five);
+        assert_eq!(deck.remaining(), 4);
+    }
+
+    #[test]
+    fn rank_values_run_from_ace_to_king() {
+        assert_eq!(Rank::Ace.value(), 1);
+        assert_eq!(Rank::King.value(), 13);
+        for rank in Rank::ALL {
+            assert_eq!(Rank::from_value(rank.value()), Some(rank));
+        }
+        assert_eq!(Rank::from_value(0), None);
+        assert_eq!(Rank::from_value(14), None);
+    }
+
+    #[test]
+    fn cards_are_written_out_in_full() {
+        assert_eq!(
+            Card::new(Rank::Queen, Suit::Diamonds).to_string(),
+            "Queen of Diamonds"
+        );
+        assert_eq!(Rank::Ten.short_name(), "10");
+    }
+
+    #[test]
+    fn suits_have_symbols_and_colors() {
+        let symbols: String = Suit::ALL.iter().map(|suit| suit.symbol()).collect();
+        assert_eq!(symbols, "♥♦♣♠");
+        let red: Vec<_> = Suit::ALL.into_iter().filter(|suit| suit.is_red()).collect();
+        assert_eq!(red, [Suit::Hearts, Suit::Diamonds]);
+    }
+}
@@ -0,0 +1,106 @@
+//! Screen-reader-friendly output.
+//!
+//! Challenges that draw with characters, such as ASCII art, grids of colored
+//! blocks or masked words, describe what they draw in plain sentences when
+//! accessible mode is on, and full-screen views fall back to line-by-line
+//! play. The mode is turned on with `--accessible` or, to enable it once for
+//! every game, with the `LBPC_ACCESSIBLE` environment variable or
+//! `accessible = true` in the [`config`](crate::config) file.
+use crate::config;
+use clap::builder::FalseyValueParser;
+use clap::Args;
+
+/// Environment variable that turns accessible mode on when set to anything
+/// other than an empty string, `0`, `false`, `no` or `off`.
+pub const ACCESSIBLE_ENV_VAR: &str = "LBPC_ACCESSIBLE";
+
+/// Command-line arguments controlling accessible mode.
+#[derive(Args, Debug, Clone, Default)]
+pub struct AccessibleArgs {
+    /// Describe pictures, grids and colors in words, for screen readers
+    #[arg(long, env = ACCESSIBLE_ENV_VAR, value_parser = FalseyValueParser::new())]
+    pub accessible: bool,
+}
+
+impl AccessibleArgs {
+    /// Returns `true` if accessible mode was turned on on the command line or
+    /// in the config file.
+    pub fn enabled(&self) -> bool {
+        self.accessible || config::get().accessible
+    }
+}
+
+const NUMBER_WORDS: [&str; 21] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+];
+
+/// Spells out `n` in words up to twenty and in digits above that, the way
+/// numbers are usually written in running text.
+pub fn number(n: u64) -> String {
+    match NUMBER_WORDS.get(n as usize) {
+        Some(word) => word.to_string(),
+        None => n.to_string(),
+    }
+}
+
+/// Describes `n` things, e.g. `count(3, "star", "stars")` is "three stars".
+pub fn count(n: u64, singular: &str, plural: &str) -> String {
+    format!("{} {}", number(n), if n == 1 { singular } else { plural })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        accessible: AccessibleArgs,
+    }
+
+    #[test]
+    fn number_spells_out_small_numbers() {
+        assert_eq!(number(0), "zero");
+        assert_eq!(number(7), "seven");
+        assert_eq!(number(20), "twenty");
+        assert_eq!(number(21), "21");
+    }
+
+    #[test]
+    fn count_picks_singular_or_plural() {
+        assert_eq!(count(1, "star", "stars"), "one star");
+        assert_eq!(count(2, "star", "stars"), "two stars");
+        assert_eq!(count(0, "life", "lives"), "zero lives");
+    }
+
+    #[test]
+    fn accessible_args_parse_flag() {
+        assert!(
+            TestArgs::parse_from(["test", "--accessible"])
+                .accessible
+                .accessible
+        );
+        assert!(!TestArgs::parse_from(["test"]).accessible.accessible);
+    }
+}
@@ -0,0 +1,202 @@
+//! Command-line conventions shared by every challenge binary.
+//!
+//! Each challenge defines its own `clap` argument struct with
+//! `#[command(version)]` and a doc comment describing the program, so that
+//! `--help` and `--version` are always available. Interactive challenges
+//! also flatten [`CommonArgs`], which includes the logging, retry policy,
+//! transcript and language flags. The other
+//! types in this module cover the parameters that show up in more than one
+//! challenge, such as inclusive number ranges.
+//!
+//! Every challenge runs from a `run_cli` function in its library, which the
+//! binary's `main` and the launcher both call. It reads its arguments with
+//! [`parse_args`], which returns the exit status for `--help` or a usage
+//! error instead of ending the process.
+use crate::l10n::LangArgs;
+use crate::log::LogArgs;
+use crate::prompt::PromptArgs;
+use crate::transcript::TranscriptArgs;
+use clap::{Args, Parser};
+use serde::{Deserialize, Deserializer};
+use std::ffi::OsString;
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// Arguments accepted by every interactive challenge.
+#[derive(Args, Debug, Clone, Default)]
+pub struct CommonArgs {
+    /// Read input from FILE (one answer per line) instead of the terminal
+    #[arg(long, value_name = "FILE")]
+    pub script: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub log: LogArgs,
+
+    #[command(flatten)]
+    pub prompt: PromptArgs,
+
+    #[command(flatten)]
+    pub transcript: TranscriptArgs,
+
+    #[command(flatten)]
+    pub lang: LangArgs,
+}
+
+/// Parses the command line `args`, the program name first, like
+/// [`Parser::parse_from`]. For `--help`, `--version` or a usage error it
+/// prints what clap would and returns the exit status to end with.
+pub fn parse_args<A: Parser>(args: Vec<OsString>) -> Result<A, ExitCode> {
+    A::try_parse_from(args).map_err(|e| report(&e))
+}
+
+/// Prints `error` from parsing the command line as clap would before
+/// exiting, and returns the exit status to end with.
+pub fn report(error: &clap::Error) -> ExitCode {
+    let _ = error.print();
+    ExitCode::from(u8::try_from(error.exit_code()).unwrap_or(1))
+}
+
+/// An inclusive range of numbers given on the command line as `MIN..MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumRange<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: PartialOrd> NumRange<T> {
+    /// Creates a new range, returning `None` if `min` is greater than `max`.
+    pub fn new(min: T, max: T) -> Option<Self> {
+        if min > max {
+            None
+        } else {
+            Some(NumRange { min, max })
+        }
+    }
+
+    /// Returns `true` if `value` lies within the range (bounds included).
+    pub fn contains(&self, value: &T) -> bool {
+        *value >= self.min && *value <= self.max
+    }
+}
+
+impl<T> FromStr for NumRange<T>
+where
+    T: FromStr + PartialOrd + Display,
+    T::Err: Display,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min, max) = s
+            .split_once("..")
+            .ok_or_else(|| format!("expected a range in the form MIN..MAX, got '{s}'"))?;
+        let min: T = min
+            .trim()
+            .parse()
+            .map_err(|e| format!("invalid range start '{min}': {e}"))?;
+        let max: T = max
+            .trim()
+            .parse()
+            .map_err(|e| format!("invalid range end '{max}': {e}"))?;
+
+        if min > max {
+            return Err(format!("range start {min} is greater than range end {max}"));
+        }
+        Ok(NumRange { min, max })
+    }
+}
+
+/// Reads a range written as `"MIN..MAX"`, as in a configuration file.
+impl<'de, T> Deserialize<'de> for NumRange<T>
+where
+    T: FromStr + PartialOrd + Display,
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl<T: Display> Display for NumRange<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}..{}", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[arg(long)]
+        rounds: u32,
+    }
+
+    #[test]
+    fn parse_args_returns_the_exit_status_instead_of_exiting() {
+        let args: TestArgs =
+            parse_args(vec!["test".into(), "--rounds".into(), "3".into()]).unwrap();
+        assert_eq!(args.rounds, 3);
+        let status = parse_args::<TestArgs>(vec!["test".into(), "--rounds".into()]).unwrap_err();
+        assert_eq!(status, ExitCode::from(2));
+    }
+
+    #[test]
+    fn num_range_parses_min_and_max() {
+        let range: NumRange<u64> = "1..100".parse().unwrap();
+        assert_eq!(range, NumRange { min: 1, max: 100 });
+    }
+
+    #[test]
+    fn num_range_allows_surrounding_whitespace() {
+        let range: NumRange<u32> = " 0 .. 15 ".parse().unwrap();
+        assert_eq!(range, NumRange { min: 0, max: 15 });
+    }
+
+    #[test]
+    fn num_range_accepts_single_value_range() {
+        let range: NumRange<u32> = "7..7".parse().unwrap();
+        assert!(range.contains(&7));
+    }
+
+    #[test]
+    fn num_range_rejects_missing_separator() {
+        assert!("1-100".parse::<NumRange<u64>>().is_err());
+    }
+
+    #[test]
+    fn num_range_rejects_reversed_bounds() {
+        let err = "30..20".parse::<NumRange<u64>>().unwrap_err();
+        assert_eq!(err, "range start 30 is greater than range end 20");
+    }
+
+    #[test]
+    fn num_range_rejects_non_numeric_bounds() {
+        assert!("a..10".parse::<NumRange<u64>>().is_err());
+        assert!("1..b".parse::<NumRange<u64>>().is_err());
+    }
+
+    #[test]
+    fn num_range_contains_checks_inclusive_bounds() {
+        let range = NumRange::new(1, 3).unwrap();
+        assert!(range.contains(&1));
+        assert!(range.contains(&3));
+        assert!(!range.contains(&0));
+        assert!(!range.contains(&4));
+    }
+
+    #[test]
+    fn num_range_new_rejects_reversed_bounds() {
+        assert!(NumRange::new(5, 1).is_none());
+    }
+
+    #[test]
+    fn num_range_displays_as_min_dot_dot_max() {
+        assert_eq!(NumRange { min: 20, max: 30 }.to_string(), "20..30");
+    }
+}
@@ -0,0 +1,153 @@
+//! A session clock for the longer games.
+//!
+//! A [`GameClock`] measures how long a game has been played, leaving out the
+//! time it spent paused. Games read their input through
+//! [`GameClock::read_line`], which lets the player type `pause` at any prompt
+//! to stop the clock until they press Enter.
+//!
+//! In the browser, where `std::time::Instant` panics, the clock reads the
+//! browser's own timer instead.
+use crate::{input, l10n};
+use std::io::{BufRead, Write};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// What the player types at a prompt to pause the game.
+pub const PAUSE_COMMAND: &str = "pause";
+
+/// Measures the time spent playing a game, excluding pauses.
+#[derive(Debug, Clone)]
+pub struct GameClock {
+    started: Instant,
+    paused_since: Option<Instant>,
+    paused: Duration,
+}
+
+impl GameClock {
+    /// Starts a new clock.
+    pub fn start() -> Self {
+        GameClock {
+            started: Instant::now(),
+            paused_since: None,
+            paused: Duration::ZERO,
+        }
+    }
+
+    /// Stops the clock. Does nothing if it is already paused.
+    pub fn pause(&mut self) {
+        self.paused_since.get_or_insert_with(Instant::now);
+    }
+
+    /// Restarts a paused clock.
+    pub fn resume(&mut self) {
+        if let Some(since) = self.paused_since.take() {
+            self.paused += since.elapsed();
+        }
+    }
+
+    /// Returns `true` while the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused_since.is_some()
+    }
+
+    /// Returns the time played so far, not counting pauses.
+    pub fn elapsed(&self) -> Duration {
+        let end = self.paused_since.unwrap_or_else(Instant::now);
+        end.duration_since(self.started).saturating_sub(self.paused)
+    }
+
+    /// Reads one line like [`input::read_line`], handling the pause command
+    /// on the way: the clock stops until the player presses Enter, and the
+    /// next line is read instead.
+    pub fn read_line<R, W>(&mut self, reader: &mut R, writer: &mut W) -> std::io::Result<String>
+    where
+        R: BufRead + ?Sized,
+        W: Write + ?Sized,
+    {
+        loop {
+            let line = input::read_line(reader)?;
+            if !line.trim().eq_ignore_ascii_case(PAUSE_COMMAND) {
+                return Ok(line);
+            }
+            self.pause();
+            tracing::debug!(elapsed = ?self.elapsed(), "game paused");
+            writeln!(writer, "{}", l10n::text("clock.paused"))?;
+            let resumed = input::read_line(reader);
+            self.resume();
+            resumed?;
+            writeln!(writer, "{}", l10n::text("clock.resumed"))?;
+        }
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        GameClock::start()
+    }
+}
+
+/// Formats a duration as `M:SS`, or `H:MM:SS` from an hour up.
+pub fn format(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paused_clock_does_not_advance() {
+        let mut clock = GameClock::start();
+        clock.pause();
+        let elapsed = clock.elapsed();
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(clock.is_paused());
+        assert_eq!(clock.elapsed(), elapsed);
+
+        clock.resume();
+        assert!(!clock.is_paused());
+        assert!(clock.elapsed() < Duration::from_millis(10));
+    }
+
+    #[test]
+    fn read_line_handles_pause_command() {
+        let mut clock = GameClock::start();
+        let mut reader = "pause\n\nH\n".as_bytes();
+        let mut output = Vec::new();
+
+        let line = clock.read_line(&mut reader, &mut output).unwrap();
+
+        assert_eq!(line, "H\n");
+        assert!(!clock.is_paused());
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Game paused. Press Enter to resume.\nGame resumed.\n"
+        );
+    }
+
+    #[test]
+    fn read_line_fails_when_input_ends_while_paused() {
+        let mut clock = GameClock::start();
+        let err = clock
+            .read_line(&mut "PAUSE\n".as_bytes(), &mut std::io::sink())
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert!(!clock.is_paused());
+    }
+
+    #[test]
+    fn format_shows_minutes_and_seconds() {
+        assert_eq!(format(Duration::from_secs(0)), "0:00");
+        assert_eq!(format(Duration::from_millis(65_900)), "1:05");
+        assert_eq!(format(Duration::from_secs(3_723)), "1:02:03");
+    }
+}
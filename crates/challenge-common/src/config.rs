@@ -0,0 +1,253 @@
+//! Defaults the player sets once for every challenge.
+//!
+//! The configuration file is TOML, read from `config.toml` in the platform
+//! config directory (for example `~/.config/lbpc/config.toml` on Linux) or
+//! from the file named by `LBPC_CONFIG`. Every setting is optional, and a
+//! flag given on the command line always wins over the file:
+//!
+//! ```toml
+//! theme = "light"
+//! no_color = false
+//! difficulty = "hard"
+//! accessible = true
+//!
+//! [c02.templates]
+//! yo = "Yo {name}, it's {weekday}!"
+//!
+//! [c16]
+//! range = "1..500"
+//!
+//! [c23]
+//! grid_size = 15
+//!
+//! [c27]
+//! lives = 10
+//! ```
+//!
+//! A missing file is the same as an empty one. A file that cannot be read or
+//! parsed is reported as a warning and ignored, so a typo never stops a game
+//! from starting.
+use crate::cli::NumRange;
+use crate::difficulty::Difficulty;
+use crate::theme::Theme;
+use directories::ProjectDirs;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Placeholders a greeting template can use.
+pub const GREETING_PLACEHOLDERS: &[&str] = &["name", "time", "date", "weekday"];
+
+/// Environment variable naming the configuration file to read instead of the
+/// default one.
+pub const CONFIG_ENV_VAR: &str = "LBPC_CONFIG";
+
+/// Every setting the configuration file can hold.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Color theme, like `--theme`.
+    pub theme: Option<Theme>,
+    /// Turns colored output off, like `--no-color`.
+    pub no_color: bool,
+    /// Difficulty of the games with tunable rules, like `--difficulty`.
+    pub difficulty: Option<Difficulty>,
+    /// Turns accessible mode on, like `--accessible`.
+    pub accessible: bool,
+    /// Name Greeting.
+    pub c02: GreetingConfig,
+    /// Number Guessing.
+    pub c16: GuessingConfig,
+    /// Treasure Hunt.
+    pub c23: TreasureHuntConfig,
+    /// Hangman.
+    pub c27: HangmanConfig,
+}
+
+/// Settings for Name Greeting (c02).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GreetingConfig {
+    /// Greeting templates by name, with [`GREETING_PLACEHOLDERS`] in braces.
+    /// One is picked at random, or by name with `--template`.
+    pub templates: BTreeMap<String, String>,
+}
+
+/// Settings for Number Guessing (c16).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GuessingConfig {
+    /// Range the secret number is chosen from, like `--range`.
+    pub range: Option<NumRange<u64>>,
+}
+
+/// Settings for Treasure Hunt (c23).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TreasureHuntConfig {
+    /// Width and height of the grid, like `--grid-size`.
+    pub grid_size: Option<u32>,
+}
+
+/// Settings for Hangman (c27).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HangmanConfig {
+    /// Number of wrong guesses allowed, like `--lives`.
+    pub lives: Option<u32>,
+}
+
+impl Config {
+    /// Parses the text of a configuration file.
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let config: Config = toml::from_str(text).map_err(|e| e.message().to_string())?;
+        // Zero would make the game unplayable, as it would on the command line.
+        for (name, template) in &config.c02.templates {
+            check_template(template).map_err(|e| format!("c02.templates.{name} {e}"))?;
+        }
+        if config.c23.grid_size == Some(0) {
+            return Err("c23.grid_size must be at least 1".to_string());
+        }
+        if config.c27.lives == Some(0) {
+            return Err("c27.lives must be at least 1".to_string());
+        }
+        Ok(config)
+    }
+
+    /// Reads the configuration file at `path`. A missing file gives the
+    /// default configuration.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+}
+
+/// Checks that every `{placeholder}` in `template` is one of
+/// [`GREETING_PLACEHOLDERS`].
+fn check_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("is empty".to_string());
+    }
+    for rest in template.split('{').skip(1) {
+        let Some((name, _)) = rest.split_once('}') else {
+            return Err("has an unclosed '{'".to_string());
+        };
+        if !GREETING_PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "has an unknown placeholder {{{name}}} (use {})",
+                GREETING_PLACEHOLDERS
+                    .iter()
+                    .map(|p| format!("{{{p}}}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the path of the configuration file.
+pub fn path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_ENV_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    ProjectDirs::from("", "", "lbpc").map(|dirs| dirs.config_dir().join("config.toml"))
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Returns the player's configuration, reading it on the first call.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        let Some(path) = path() else {
+            return Config::default();
+        };
+        tracing::debug!(path = %path.display(), "loading config");
+        Config::load(&path).unwrap_or_else(|e| {
+            eprintln!("Warning: ignoring config file {}", e);
+            Config::default()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_every_setting() {
+        let config = Config::parse(
+            "theme = \"high-contrast\"\n\
+             no_color = true\n\
+             difficulty = \"easy\"\n\
+             accessible = true\n\
+             [c02.templates]\n\
+             yo = \"Yo {name}, it's {weekday}!\"\n\
+             [c16]\n\
+             range = \"1..500\"\n\
+             [c23]\n\
+             grid_size = 15\n\
+             [c27]\n\
+             lives = 10\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                theme: Some(Theme::HighContrast),
+                no_color: true,
+                difficulty: Some(Difficulty::Easy),
+                accessible: true,
+                c02: GreetingConfig {
+                    templates: BTreeMap::from([(
+                        "yo".to_string(),
+                        "Yo {name}, it's {weekday}!".to_string()
+                    )]),
+                },
+                c16: GuessingConfig {
+                    range: NumRange::new(1, 500),
+                },
+                c23: TreasureHuntConfig {
+                    grid_size: Some(15),
+                },
+                c27: HangmanConfig { lives: Some(10) },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_leaves_missing_settings_unset() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        let config = Config::parse("[c27]\nlives = 3\n").unwrap();
+        assert_eq!(config.c27.lives, Some(3));
+        assert_eq!(config.theme, None);
+    }
+
+    #[test]
+    fn parse_rejects_bad_settings() {
+        assert!(Config::parse("[c27]\nlifes = 3\n").is_err());
+        assert!(Config::parse("[c27]\nlives = 0\n").is_err());
+        assert!(Config::parse("[c16]\nrange = \"9..1\"\n").is_err());
+        assert!(Config::parse("theme = \"sepia\"\n").is_err());
+        assert!(Config::parse("difficulty = \"extreme\"\n").is_err());
+        assert_eq!(
+            Config::parse("[c02.templates]\nhi = \"Hi {nmae}\"\n").unwrap_err(),
+            "c02.templates.hi has an unknown placeholder {nmae} (use {name}, {time}, {date}, {weekday})"
+        );
+        assert!(Config::parse("[c02.templates]\nhi = \"Hi {name\"\n").is_err());
+    }
+
+    #[test]
+    fn load_treats_a_missing_file_as_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        assert_eq!(Config::load(&path).unwrap(), Config::default());
+
+        std::fs::write(&path, "[c23]\ngrid_size = 7\n").unwrap();
+        assert_eq!(Config::load(&path).unwrap().c23.grid_size, Some(7));
+    }
+}
@@ -0,0 +1,82 @@
+//! Difficulty levels shared by the games.
+//!
+//! Every game with tunable rules accepts `--difficulty easy|normal|hard` (or
+//! `LBPC_DIFFICULTY`, or `difficulty` in the [`config`](crate::config) file)
+//! and maps the level onto its own knobs with
+//! [`Difficulty::pick`], such as the number of lives or the size of the
+//! grid. Normal keeps each game's classic rules. Options that set a knob
+//! directly, like `--lives`, take precedence over the level, so a custom game
+//! is a difficulty plus whatever the player overrides.
+use crate::config;
+use clap::{Args, ValueEnum};
+use serde::Deserialize;
+
+/// Environment variable used to pick the difficulty when `--difficulty` is
+/// not given.
+pub const DIFFICULTY_ENV_VAR: &str = "LBPC_DIFFICULTY";
+
+/// How hard a game is.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    /// More lives, more guesses, and smaller numbers
+    Easy,
+    /// The game's classic rules
+    #[default]
+    Normal,
+    /// Fewer lives, fewer guesses, and bigger numbers
+    Hard,
+}
+
+impl Difficulty {
+    /// Returns the value of a knob at this difficulty.
+    pub fn pick<T>(self, easy: T, normal: T, hard: T) -> T {
+        match self {
+            Difficulty::Easy => easy,
+            Difficulty::Normal => normal,
+            Difficulty::Hard => hard,
+        }
+    }
+}
+
+/// Command-line arguments selecting the difficulty.
+#[derive(Args, Debug, Clone, Default)]
+pub struct DifficultyArgs {
+    /// How hard the game is; options that set a rule directly override it
+    /// [default: normal]
+    #[arg(long = "difficulty", value_enum, env = DIFFICULTY_ENV_VAR)]
+    level: Option<Difficulty>,
+}
+
+impl DifficultyArgs {
+    /// Returns the level given on the command line, or else the one in the
+    /// config file.
+    pub fn level(&self) -> Difficulty {
+        self.level.or(config::get().difficulty).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        difficulty: DifficultyArgs,
+    }
+
+    #[test]
+    fn pick_returns_the_value_for_the_level() {
+        assert_eq!(Difficulty::Easy.pick(3, 2, 1), 3);
+        assert_eq!(Difficulty::Normal.pick(3, 2, 1), 2);
+        assert_eq!(Difficulty::Hard.pick(3, 2, 1), 1);
+    }
+
+    #[test]
+    fn difficulty_flag_parses_levels() {
+        let args = TestArgs::parse_from(["test", "--difficulty", "hard"]);
+        assert_eq!(args.difficulty.level(), Difficulty::Hard);
+    }
+}
@@ -0,0 +1,115 @@
+//! Line editing for answers typed at a terminal.
+//!
+//! [`Editor`] reads each line with `rustyline`, so the player can move
+//! around the line with the arrow keys, edit it, and bring back earlier
+//! answers with Up and Down. It is only used when stdin and stdout are both
+//! terminals, since the editor draws on stdout; scripts and pipes are read
+//! as they are.
+//!
+//! An inline prompt is handed to the editor with [`set_prompt`] rather than
+//! written out, so that redrawing the line keeps it in front of the answer.
+//! Once [`prompts_elsewhere`] says the prompts go somewhere other than
+//! stdout, they are left to the caller.
+use rustyline::config::Config;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::io::{BufRead, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// Set once the terminal is read through an [`Editor`].
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Set once the prompts are written somewhere other than stdout.
+static ELSEWHERE: AtomicBool = AtomicBool::new(false);
+
+/// The prompt shown in front of the next line the editor reads.
+static PROMPT: Mutex<String> = Mutex::new(String::new());
+
+/// Has the editor show `prompt` in front of the next line it reads. Returns
+/// `false`, leaving the prompt to the caller, if the terminal is not read
+/// through an editor or the prompts go elsewhere.
+pub(crate) fn set_prompt(prompt: &str) -> bool {
+    if !ACTIVE.load(Ordering::Relaxed) || ELSEWHERE.load(Ordering::Relaxed) {
+        return false;
+    }
+    *PROMPT.lock().unwrap_or_else(PoisonError::into_inner) = prompt.to_string();
+    true
+}
+
+/// Forgets a prompt that was not shown because the answer was read some
+/// other way.
+pub(crate) fn clear_prompt() {
+    PROMPT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+/// Notes that the prompts are written somewhere other than stdout, where the
+/// editor would show them.
+pub(crate) fn prompts_elsewhere() {
+    ELSEWHERE.store(true, Ordering::Relaxed);
+}
+
+fn take_prompt() -> String {
+    std::mem::take(&mut *PROMPT.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// A terminal reader with line editing and a history of the session's
+/// answers.
+pub(crate) struct Editor {
+    editor: DefaultEditor,
+    line: Vec<u8>,
+    pos: usize,
+}
+
+impl Editor {
+    pub(crate) fn new() -> rustyline::Result<Editor> {
+        let config = Config::builder().auto_add_history(true).build();
+        let editor = DefaultEditor::with_config(config)?;
+        ACTIVE.store(true, Ordering::Relaxed);
+        Ok(Editor {
+            editor,
+            line: Vec::new(),
+            pos: 0,
+        })
+    }
+}
+
+impl Read for Editor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let read = available.len().min(buf.len());
+        buf[..read].copy_from_slice(&available[..read]);
+        self.consume(read);
+        Ok(read)
+    }
+}
+
+impl BufRead for Editor {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        if self.pos >= self.line.len() {
+            self.pos = 0;
+            self.line = match self.editor.readline(&take_prompt()) {
+                Ok(line) => format!("{line}\n").into_bytes(),
+                // An empty buffer is the end of input.
+                Err(ReadlineError::Eof) => Vec::new(),
+                Err(ReadlineError::Interrupted) => {
+                    // The terminal is in raw mode while a line is read, so
+                    // Ctrl-C arrives here instead of as a signal. Treat it
+                    // like the abort command, so the game can save and stop.
+                    tracing::debug!("interrupted at a prompt");
+                    return Err(crate::prompt::aborted());
+                }
+                Err(ReadlineError::Io(e)) => return Err(e),
+                Err(e) => return Err(std::io::Error::other(e)),
+            };
+        }
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
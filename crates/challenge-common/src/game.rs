@@ -0,0 +1,189 @@
+//! A shared game loop for the turn-based games.
+//!
+//! A game implements [`Game`] as a state machine that never touches stdin or
+//! stdout: it says what to ask next, takes each answer in [`Game::step`], and
+//! describes itself as text. [`play`] runs any such game on a reader and a
+//! writer, asking through [`Prompt`] so every game gets the same retry
+//! policy, `quit` command and `--script` echo, and [`play_timed`] adds the
+//! `pause` command of a [`GameClock`]. A game's tests can call `step`
+//! directly instead of scripting a whole session.
+use crate::clock::GameClock;
+use crate::input;
+use crate::prompt::Prompt;
+use std::io::{BufRead, Write};
+
+/// A game played one answer at a time.
+pub trait Game {
+    /// What the game reports once it is over, such as whether the player won.
+    type Result;
+
+    /// Starts the game and returns the introduction shown before the first
+    /// prompt.
+    fn setup(&mut self) -> String;
+
+    /// Returns the question the player answers next.
+    fn prompt(&self) -> String;
+
+    /// Plays the player's `answer`, without surrounding whitespace, and
+    /// returns what happened. An answer that is not valid is rejected with a
+    /// message saying why, and leaves the game as it was.
+    fn step(&mut self, answer: &str) -> Result<String, String>;
+
+    /// Describes the game as the player sees it before the next prompt, such
+    /// as the cards in their hand.
+    fn render(&self) -> String;
+
+    /// Returns `true` once the game has ended.
+    fn is_over(&self) -> bool;
+
+    /// Returns the outcome of the game. Only meaningful once it is over.
+    fn result(&self) -> Self::Result;
+}
+
+/// Plays `game` from start to finish, reading answers from `reader`.
+pub fn play<G, R, W>(game: &mut G, reader: &mut R, writer: &mut W) -> std::io::Result<G::Result>
+where
+    G: Game + ?Sized,
+    R: BufRead + ?Sized,
+    W: Write + ?Sized,
+{
+    play_with(game, writer, |_| input::read_line(reader))
+}
+
+/// Like [`play`], but the player can pause `clock` at any prompt.
+pub fn play_timed<G, R, W>(
+    game: &mut G,
+    reader: &mut R,
+    writer: &mut W,
+    clock: &mut GameClock,
+) -> std::io::Result<G::Result>
+where
+    G: Game + ?Sized,
+    R: BufRead + ?Sized,
+    W: Write + ?Sized,
+{
+    play_with(game, writer, |writer| clock.read_line(reader, writer))
+}
+
+fn play_with<G, W>(
+    game: &mut G,
+    writer: &mut W,
+    mut read_line: impl FnMut(&mut W) -> std::io::Result<String>,
+) -> std::io::Result<G::Result>
+where
+    G: Game + ?Sized,
+    W: Write + ?Sized,
+{
+    write_text(writer, &game.setup())?;
+    while !game.is_over() {
+        write_text(writer, &game.render())?;
+        let prompt = game.prompt();
+        let feedback =
+            Prompt::new(&prompt).ask_with(writer, &mut read_line, |answer| game.step(answer))?;
+        write_text(writer, &feedback)?;
+    }
+    Ok(game.result())
+}
+
+/// Writes `text` on lines of its own, or nothing if it is empty.
+fn write_text<W: Write + ?Sized>(writer: &mut W, text: &str) -> std::io::Result<()> {
+    if text.is_empty() {
+        return Ok(());
+    }
+    writeln!(writer, "{}", text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts down from a number, one step per answer of "next".
+    struct Countdown {
+        left: u32,
+    }
+
+    impl Game for Countdown {
+        type Result = &'static str;
+
+        fn setup(&mut self) -> String {
+            "Counting down.".to_string()
+        }
+
+        fn prompt(&self) -> String {
+            "Say next:".to_string()
+        }
+
+        fn step(&mut self, answer: &str) -> Result<String, String> {
+            if answer != "next" {
+                return Err("Say next.".to_string());
+            }
+            self.left -= 1;
+            Ok(if self.left == 0 {
+                "Done!".to_string()
+            } else {
+                String::new()
+            })
+        }
+
+        fn render(&self) -> String {
+            format!("{} left", self.left)
+        }
+
+        fn is_over(&self) -> bool {
+            self.left == 0
+        }
+
+        fn result(&self) -> &'static str {
+            "finished"
+        }
+    }
+
+    #[test]
+    fn play_writes_setup_state_prompts_and_feedback() {
+        let mut output = Vec::new();
+
+        let result = play(
+            &mut Countdown { left: 2 },
+            &mut "next\nback\nnext\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(result, "finished");
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Counting down.\n2 left\nSay next:\n1 left\nSay next:\nSay next.\nSay next:\nDone!\n"
+        );
+    }
+
+    #[test]
+    fn play_timed_handles_the_pause_command() {
+        let mut output = Vec::new();
+        let mut clock = GameClock::start();
+
+        play_timed(
+            &mut Countdown { left: 1 },
+            &mut "pause\n\nnext\n".as_bytes(),
+            &mut output,
+            &mut clock,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Game paused. Press Enter to resume.\nGame resumed.\n"));
+        assert!(output.ends_with("Done!\n"));
+    }
+
+    #[test]
+    fn play_fails_when_input_ends() {
+        let result = play(
+            &mut Countdown { left: 2 },
+            &mut "next\n".as_bytes(),
+            &mut std::io::sink(),
+        );
+        assert_eq!(
+            result.unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+    }
+}
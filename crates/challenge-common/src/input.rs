@@ -0,0 +1,222 @@
+//! Line-oriented input for the interactive challenges.
+//!
+//! Games read their input through an [`Input`] rather than straight from
+//! stdin. Input normally comes from the terminal, with line editing and a
+//! history of earlier answers, but can instead be taken from a script file
+//! (`--script <FILE>`) or from a pipe. Such scripted
+//! sessions are deterministic: unless a seed is given they use a fixed RNG
+//! seed, so replaying the same script always produces the same output.
+//!
+//! A `--script` session is meant to be read back afterwards, so every answer
+//! is echoed after its prompt, and since a script cannot correct itself the
+//! first invalid answer ends the program with an error (see
+//! [`prompt::policy`](crate::prompt::policy)).
+//!
+//! [`Input::from_args`] also sets up `--record` and `--replay`, see the
+//! [`transcript`](crate::transcript) module.
+use crate::cli::CommonArgs;
+use crate::transcript::{self, Recorded, Replay};
+use std::io::{BufRead, IsTerminal, Read};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Seed used by scripted sessions when no seed was given explicitly.
+pub const SCRIPTED_SEED: u64 = 0;
+
+static SCRIPT_FILE: AtomicBool = AtomicBool::new(false);
+
+/// Returns `true` if the session is driven by a `--script` file.
+pub fn is_script_file() -> bool {
+    SCRIPT_FILE.load(Ordering::Relaxed)
+}
+
+/// Source of a game's input: the terminal, a pipe, or a script file.
+pub struct Input {
+    reader: Box<dyn BufRead>,
+    scripted: bool,
+    /// Seed recorded in a replayed transcript.
+    seed: Option<u64>,
+}
+
+impl Input {
+    /// Opens the input selected by `args`: a replayed transcript, a script,
+    /// or stdin, recording it if asked to.
+    pub fn from_args(args: &CommonArgs) -> std::io::Result<Input> {
+        let mut input = match &args.transcript.replay {
+            Some(path) => {
+                let replay = Replay::load(path)?;
+                tracing::debug!(path = %path.display(), seed = ?replay.seed, "replaying transcript");
+                Input {
+                    seed: replay.seed,
+                    ..Input::scripted(std::io::Cursor::new(replay.input()))
+                }
+            }
+            None => Input::open(args.script.as_deref())?,
+        };
+        if args.script.is_some() {
+            SCRIPT_FILE.store(true, Ordering::Relaxed);
+        }
+        if let Some(path) = &args.transcript.record {
+            transcript::start(path)?;
+            input.reader = Box::new(Recorded::new(input.reader));
+        }
+        Ok(input)
+    }
+
+    /// Opens `script` if given, otherwise stdin.
+    ///
+    /// Stdin counts as scripted when it is not a terminal.
+    pub fn open(script: Option<&Path>) -> std::io::Result<Input> {
+        match script {
+            Some(path) => {
+                let file = std::fs::File::open(path).map_err(|e| {
+                    std::io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+                })?;
+                tracing::debug!(path = %path.display(), "reading input from script");
+                Ok(Input::scripted(std::io::BufReader::new(file)))
+            }
+            None => {
+                let stdin = std::io::stdin();
+                let scripted = !stdin.is_terminal();
+                tracing::debug!(scripted, "reading input from stdin");
+                Ok(Input {
+                    reader: if scripted {
+                        Box::new(stdin.lock())
+                    } else {
+                        terminal()
+                    },
+                    scripted,
+                    seed: None,
+                })
+            }
+        }
+    }
+
+    /// Wraps an arbitrary reader as scripted input.
+    pub fn scripted<R: BufRead + 'static>(reader: R) -> Input {
+        Input {
+            reader: Box::new(reader),
+            scripted: true,
+            seed: None,
+        }
+    }
+
+    /// Returns `true` if input is not coming from an interactive terminal.
+    pub fn is_scripted(&self) -> bool {
+        self.scripted
+    }
+
+    /// Returns the RNG seed to use when none was given on the command line:
+    /// the recorded one when replaying a transcript.
+    pub fn default_seed(&self) -> Option<u64> {
+        self.seed.or(self.scripted.then_some(SCRIPTED_SEED))
+    }
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl BufRead for Input {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.reader.consume(amt)
+    }
+}
+
+/// Returns a reader for the terminal, with line editing where it is
+/// available and stdout is the terminal too, as the editor draws on it.
+fn terminal() -> Box<dyn BufRead> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if std::io::stdout().is_terminal() {
+        match crate::editor::Editor::new() {
+            Ok(editor) => return Box::new(editor),
+            Err(e) => tracing::debug!(error = %e, "line editing unavailable"),
+        }
+    }
+    Box::new(std::io::stdin().lock())
+}
+
+/// Reads one line from `reader`, including its line terminator.
+///
+/// Unlike [`BufRead::read_line`], running out of input is reported as an
+/// [`std::io::ErrorKind::UnexpectedEof`] error so that prompt loops stop
+/// instead of retrying forever when a script ends early.
+pub fn read_line<R: BufRead + ?Sized>(reader: &mut R) -> std::io::Result<String> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "unexpected end of input",
+        ));
+    }
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_line_returns_lines_in_order() {
+        let mut reader = "first\nsecond\n".as_bytes();
+        assert_eq!(read_line(&mut reader).unwrap(), "first\n");
+        assert_eq!(read_line(&mut reader).unwrap(), "second\n");
+    }
+
+    #[test]
+    fn read_line_returns_last_line_without_newline() {
+        let mut reader = "last".as_bytes();
+        assert_eq!(read_line(&mut reader).unwrap(), "last");
+    }
+
+    #[test]
+    fn read_line_reports_end_of_input() {
+        let mut reader = "".as_bytes();
+        let err = read_line(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn scripted_input_uses_fixed_default_seed() {
+        let input = Input::scripted("".as_bytes());
+        assert!(input.is_scripted());
+        assert_eq!(input.default_seed(), Some(SCRIPTED_SEED));
+    }
+
+    #[test]
+    fn scripted_input_reads_through() {
+        let mut input = Input::scripted("rock\nq\n".as_bytes());
+        assert_eq!(read_line(&mut input).unwrap(), "rock\n");
+        assert_eq!(read_line(&mut input).unwrap(), "q\n");
+        assert!(read_line(&mut input).is_err());
+    }
+
+    #[test]
+    fn replayed_input_uses_recorded_seed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.txt");
+        std::fs::write(&path, "# lbpc transcript\nseed: 99\ninput: rock\n").unwrap();
+        let mut args = CommonArgs::default();
+        args.transcript.replay = Some(path);
+
+        let mut input = Input::from_args(&args).unwrap();
+        assert_eq!(input.default_seed(), Some(99));
+        assert_eq!(read_line(&mut input).unwrap(), "rock\n");
+        assert!(read_line(&mut input).is_err());
+    }
+
+    #[test]
+    fn open_reports_missing_script_file() {
+        let err = Input::open(Some(Path::new("/nonexistent/script.txt")))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("/nonexistent/script.txt"));
+    }
+}
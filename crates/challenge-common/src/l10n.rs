@@ -0,0 +1,2479 @@
+//! Translations of the messages the games show.
+//!
+//! Every question a game asks, every complaint about an answer, and every
+//! hint, result and score it reports is looked up by key in a message catalog
+//! rather than written out in the game. The
+//! language is picked with `--lang` (or the `LBPC_LANG` environment variable)
+//! and defaults to English.
+//!
+//! Messages can have `{name}` placeholders, filled in by [`format`]:
+//!
+//! ```
+//! use challenge_common::l10n;
+//!
+//! let message = l10n::format("c26.guess", &[("digits", &4)]);
+//! assert_eq!(message, "Enter a 4-digit guess: ");
+//! ```
+//!
+//! Answers the games compare against, such as `rock` or `H`, are the same in
+//! every language, and so are error messages and JSON or CSV output.
+use clap::{Args, ValueEnum};
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Environment variable used to pick the language when `--lang` is not given.
+pub const LANG_ENV_VAR: &str = "LBPC_LANG";
+
+/// A language the messages are translated into.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    /// English
+    #[default]
+    En,
+    /// Spanish
+    Es,
+}
+
+/// Command-line arguments selecting the language.
+#[derive(Args, Debug, Clone, Default)]
+pub struct LangArgs {
+    /// Language of the messages
+    #[arg(long, value_enum, env = LANG_ENV_VAR, default_value_t)]
+    pub lang: Lang,
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// Sets the language for the rest of the program. Only the first call has
+/// any effect.
+pub fn init(args: &LangArgs) {
+    let _ = LANG.set(args.lang);
+}
+
+/// Returns the active language.
+pub fn lang() -> Lang {
+    LANG.get().copied().unwrap_or_default()
+}
+
+/// Every message as (key, English, Spanish).
+const CATALOG: &[(&str, &str, &str)] = &[
+    // Shared by the prompts of every game.
+    (
+        "prompt.invalid",
+        "Invalid input. Please enter {expected}.",
+        "Entrada no válida. Introduce {expected}.",
+    ),
+    ("prompt.either", "{rest} or {last}", "{rest} o {last}"),
+    ("prompt.an_answer", "an answer", "una respuesta"),
+    ("prompt.yes_or_no", "'y' or 'n'", "'s' o 'n'"),
+    (
+        "prompt.number_between",
+        "a number between {min} and {max}",
+        "un número entre {min} y {max}",
+    ),
+    (
+        "prompt.start",
+        "Press Enter to start the game.",
+        "Pulsa Enter para empezar el juego.",
+    ),
+    (
+        "prompt.continue",
+        "Press Enter to continue.",
+        "Pulsa Enter para continuar.",
+    ),
+    (
+        "prompt.birth_date",
+        "Please enter your birth date (YYYY-MM-DD):",
+        "Introduce tu fecha de nacimiento (AAAA-MM-DD):",
+    ),
+    // Shared by the games that unlock achievements.
+    (
+        "achievement.unlocked",
+        "Achievement unlocked: {name}!",
+        "¡Logro desbloqueado: {name}!",
+    ),
+    ("achievement.alphabet_under_5s", "Speed Typist", "Mecanógrafo veloz"),
+    ("achievement.first_blackjack", "Twenty-One", "Veintiuno"),
+    ("achievement.mastermind_in_6", "Codebreaker", "Descifracódigos"),
+    // Shared by the card games.
+    ("cards.card", "{rank} of {suit}", "{rank} de {suit}"),
+    ("cards.ace", "Ace", "As"),
+    ("cards.two", "Two", "Dos"),
+    ("cards.three", "Three", "Tres"),
+    ("cards.four", "Four", "Cuatro"),
+    ("cards.five", "Five", "Cinco"),
+    ("cards.six", "Six", "Seis"),
+    ("cards.seven", "Seven", "Siete"),
+    ("cards.eight", "Eight", "Ocho"),
+    ("cards.nine", "Nine", "Nueve"),
+    ("cards.ten", "Ten", "Diez"),
+    ("cards.jack", "Jack", "Jota"),
+    ("cards.queen", "Queen", "Reina"),
+    ("cards.king", "King", "Rey"),
+    ("cards.hearts", "Hearts", "Corazones"),
+    ("cards.diamonds", "Diamonds", "Diamantes"),
+    ("cards.clubs", "Clubs", "Tréboles"),
+    ("cards.spades", "Spades", "Picas"),
+    // Shared by the games played over the network.
+    (
+        "net.waiting_to_join",
+        "Waiting for a player to join on {addr}...",
+        "Esperando a que alguien se una en {addr}...",
+    ),
+    ("net.waiting_for", "Waiting for {name}...", "Esperando a {name}..."),
+    // Shared by the games that describe colors in words.
+    ("color.black", "black", "negro"),
+    ("color.red", "red", "rojo"),
+    ("color.green", "green", "verde"),
+    ("color.yellow", "yellow", "amarillo"),
+    ("color.blue", "blue", "azul"),
+    ("color.magenta", "magenta", "magenta"),
+    ("color.cyan", "cyan", "cian"),
+    ("color.white", "white", "blanco"),
+    ("color.gray", "gray", "gris"),
+    ("color.bright_red", "bright red", "rojo claro"),
+    ("color.bright_green", "bright green", "verde claro"),
+    ("color.bright_yellow", "bright yellow", "amarillo claro"),
+    ("color.bright_blue", "bright blue", "azul claro"),
+    ("color.bright_magenta", "bright magenta", "magenta claro"),
+    ("color.bright_cyan", "bright cyan", "cian claro"),
+    ("color.bright_white", "bright white", "blanco brillante"),
+    ("color.custom", "custom color", "color personalizado"),
+    // Shared by the games that keep a session clock.
+    (
+        "clock.paused",
+        "Game paused. Press Enter to resume.",
+        "Juego en pausa. Pulsa Intro para continuar.",
+    ),
+    (
+        "clock.resumed",
+        "Game resumed.",
+        "Juego reanudado.",
+    ),
+    (
+        "clock.time_played",
+        "Time played: {time}.",
+        "Tiempo de juego: {time}.",
+    ),
+    // Shared by the full-screen games.
+    (
+        "tui.move",
+        "move",
+        "mover",
+    ),
+    (
+        "tui.quit",
+        "quit",
+        "salir",
+    ),
+    (
+        "tui.any_key",
+        "any key",
+        "cualquier tecla",
+    ),
+    (
+        "tui.exit",
+        "exit",
+        "salir",
+    ),
+    // c01
+    ("c01.reveal", "(press enter)", "(pulsa Enter)"),
+    (
+        "c01.category",
+        "Pick a category ({categories}) or press Enter for any: ",
+        "Elige una categoría ({categories}) o pulsa Enter para cualquiera: ",
+    ),
+    (
+        "c01.rate",
+        "Rate it from 1 to 5, f to make it a favorite, or press Enter to skip: ",
+        "Puntúalo del 1 al 5, f para marcarlo como favorito, o pulsa Enter para saltar: ",
+    ),
+    (
+        "c01.rating",
+        "a rating from 1 to 5, 'f' or nothing",
+        "una puntuación del 1 al 5, 'f' o nada",
+    ),
+    (
+        "c01.another",
+        "Another joke? (y/n) ",
+        "¿Otro chiste? (s/n) ",
+    ),
+    ("c01.new_question", "Question: ", "Pregunta: "),
+    ("c01.new_answer", "Answer: ", "Respuesta: "),
+    (
+        "c01.new_category",
+        "Category (e.g. animal, school) or press Enter for 'other': ",
+        "Categoría (p. ej. animal, school) o pulsa Enter para 'other': ",
+    ),
+    (
+        "c01.text_missing",
+        "Invalid input. Please enter some text.",
+        "Entrada no válida. Introduce algún texto.",
+    ),
+    (
+        "c01.known_question",
+        "There is already a joke with that question. Please enter another one.",
+        "Ya hay un chiste con esa pregunta. Introduce otra.",
+    ),
+    (
+        "c01.category_invalid",
+        "Invalid input. Please enter a category made of letters.",
+        "Entrada no válida. Introduce una categoría formada por letras.",
+    ),
+    (
+        "c01.added",
+        "Added your joke to {path}. It will be told from now on.",
+        "Se ha añadido tu chiste a {path}. A partir de ahora se contará.",
+    ),
+    (
+        "c01.offline",
+        "Unable to fetch a joke online ({error}), so here is one of ours.",
+        "No se ha podido obtener un chiste en línea ({error}), así que aquí va uno de los nuestros.",
+    ),
+    // c02
+    ("c02.name", "What is you name?", "¿Cómo te llamas?"),
+    (
+        "c02.name_invalid",
+        "Invalid input. Please enter a name made of letters.",
+        "Entrada no válida. Introduce un nombre formado por letras.",
+    ),
+    // c03
+    ("c03.shape", "Enter {choices}", "Introduce {choices}"),
+    (
+        "c03.choice",
+        "{number} for {shape}",
+        "{number} para {shape}",
+    ),
+    ("c03.rectangle", "Rectangle", "un rectángulo"),
+    ("c03.cuboid", "Cuboid", "un cuboide"),
+    ("c03.circle", "Circle", "un círculo"),
+    ("c03.triangle", "Triangle", "un triángulo"),
+    (
+        "c03.triangle-sides",
+        "Triangle from three sides",
+        "un triángulo a partir de sus tres lados",
+    ),
+    ("c03.sphere", "Sphere", "una esfera"),
+    ("c03.cylinder", "Cylinder", "un cilindro"),
+    ("c03.cone", "Cone", "un cono"),
+    ("c03.polygon", "Polygon", "un polígono"),
+    (
+        "c03.next",
+        "Enter 1 for a new shape, 2 to repeat the last shape with new dimensions, 3 to quit",
+        "Introduce 1 para una figura nueva, 2 para repetir la última con otras medidas, 3 para salir",
+    ),
+    ("c03.summary", "Summary:", "Resumen:"),
+    ("c03.first", "First shape:", "Primera figura:"),
+    ("c03.second", "Second shape:", "Segunda figura:"),
+    (
+        "c03.first_bigger",
+        "The first shape is bigger by {difference} {unit} ({percent}% more)",
+        "La primera figura es mayor por {difference} {unit} ({percent}% más)",
+    ),
+    (
+        "c03.second_bigger",
+        "The second shape is bigger by {difference} {unit} ({percent}% more)",
+        "La segunda figura es mayor por {difference} {unit} ({percent}% más)",
+    ),
+    (
+        "c03.same_size",
+        "The two shapes are the same size",
+        "Las dos figuras tienen el mismo tamaño",
+    ),
+    (
+        "c03.vertices",
+        "Enter the vertices in order as x,y pairs separated by spaces:",
+        "Introduce los vértices en orden como pares x,y separados por espacios:",
+    ),
+    (
+        "c03.dimension",
+        "Enter {dimension}:",
+        "Introduce {dimension}:",
+    ),
+    ("c03.width", "width", "la anchura"),
+    ("c03.height", "height", "la altura"),
+    ("c03.depth", "depth", "la profundidad"),
+    (
+        "c03.measure",
+        "Enter 1 for Volume, 2 for Surface area",
+        "Introduce 1 para el volumen, 2 para el área de la superficie",
+    ),
+    ("c03.radius", "radius", "el radio"),
+    ("c03.base", "base", "la base"),
+    ("c03.side a", "side a", "el lado a"),
+    ("c03.side b", "side b", "el lado b"),
+    ("c03.side c", "side c", "el lado c"),
+    (
+        "c03.area",
+        "Area",
+        "Área",
+    ),
+    (
+        "c03.surface_area",
+        "Surface area",
+        "Área de la superficie",
+    ),
+    (
+        "c03.volume",
+        "Volume",
+        "Volumen",
+    ),
+    (
+        "c03.units",
+        "units",
+        "unidades",
+    ),
+    (
+        "c03.column_shape",
+        "Shape",
+        "Figura",
+    ),
+    (
+        "c03.column_dimensions",
+        "Dimensions",
+        "Medidas",
+    ),
+    (
+        "c03.column_result",
+        "Result",
+        "Resultado",
+    ),
+    (
+        "c03.name.rectangle",
+        "rectangle",
+        "rectángulo",
+    ),
+    (
+        "c03.name.circle",
+        "circle",
+        "círculo",
+    ),
+    (
+        "c03.name.triangle",
+        "triangle",
+        "triángulo",
+    ),
+    (
+        "c03.name.triangle-sides",
+        "triangle-sides",
+        "triángulo por lados",
+    ),
+    (
+        "c03.name.cuboid",
+        "cuboid",
+        "cuboide",
+    ),
+    (
+        "c03.name.sphere",
+        "sphere",
+        "esfera",
+    ),
+    (
+        "c03.name.cylinder",
+        "cylinder",
+        "cilindro",
+    ),
+    (
+        "c03.name.cone",
+        "cone",
+        "cono",
+    ),
+    (
+        "c03.name.polygon",
+        "polygon",
+        "polígono",
+    ),
+    (
+        "c03.name.width",
+        "width",
+        "anchura",
+    ),
+    (
+        "c03.name.height",
+        "height",
+        "altura",
+    ),
+    (
+        "c03.name.depth",
+        "depth",
+        "profundidad",
+    ),
+    (
+        "c03.name.radius",
+        "radius",
+        "radio",
+    ),
+    (
+        "c03.name.base",
+        "base",
+        "base",
+    ),
+    (
+        "c03.name.side a",
+        "side a",
+        "lado a",
+    ),
+    (
+        "c03.name.side b",
+        "side b",
+        "lado b",
+    ),
+    (
+        "c03.name.side c",
+        "side c",
+        "lado c",
+    ),
+    // c04
+    (
+        "c04.query",
+        "Enter query type (1:distance, 2:speed, 3:time, 4:fuel, \
+         5:final speed, 6:distance while accelerating): ",
+        "Introduce el tipo de consulta (1:distancia, 2:velocidad, 3:tiempo, 4:combustible, \
+         5:velocidad final, 6:distancia acelerando): ",
+    ),
+    ("c04.param", "Enter {param}: ", "Introduce {param}: "),
+    ("c04.speed", "speed (mph)", "la velocidad (mph)"),
+    ("c04.distance", "distance (miles)", "la distancia (millas)"),
+    ("c04.time", "time (hours)", "el tiempo (horas)"),
+    (
+        "c04.initial_speed",
+        "initial speed (mph)",
+        "la velocidad inicial (mph)",
+    ),
+    (
+        "c04.acceleration",
+        "acceleration (mph/s)",
+        "la aceleración (mph/s)",
+    ),
+    (
+        "c04.economy",
+        "fuel economy (mpg)",
+        "el consumo (millas por galón)",
+    ),
+    (
+        "c04.price",
+        "fuel price (per gallon)",
+        "el precio del combustible (por galón)",
+    ),
+    (
+        "c04.result.distance",
+        "Distance",
+        "Distancia",
+    ),
+    (
+        "c04.result.speed",
+        "Speed",
+        "Velocidad",
+    ),
+    (
+        "c04.result.time",
+        "Time",
+        "Tiempo",
+    ),
+    (
+        "c04.result.fuel",
+        "Fuel",
+        "Combustible",
+    ),
+    (
+        "c04.result.final_speed",
+        "Final speed",
+        "Velocidad final",
+    ),
+    (
+        "c04.unit.miles",
+        "miles",
+        "millas",
+    ),
+    (
+        "c04.unit.mph",
+        "mph",
+        "mph",
+    ),
+    (
+        "c04.unit.mph/s",
+        "mph/s",
+        "mph/s",
+    ),
+    (
+        "c04.unit.mpg",
+        "mpg",
+        "mpg",
+    ),
+    (
+        "c04.unit.hours",
+        "hours",
+        "horas",
+    ),
+    (
+        "c04.unit.seconds",
+        "seconds",
+        "segundos",
+    ),
+    (
+        "c04.unit.gallons",
+        "gallons",
+        "galones",
+    ),
+    (
+        "c04.unit.per_gallon",
+        "per gallon",
+        "por galón",
+    ),
+    (
+        "c04.hour",
+        "{n} hour",
+        "{n} hora",
+    ),
+    (
+        "c04.hours",
+        "{n} hours",
+        "{n} horas",
+    ),
+    (
+        "c04.minute",
+        "{n} minute",
+        "{n} minuto",
+    ),
+    (
+        "c04.minutes",
+        "{n} minutes",
+        "{n} minutos",
+    ),
+    (
+        "c04.litres",
+        "{litres} litres",
+        "{litres} litros",
+    ),
+    (
+        "c04.costing",
+        ", costing {cost}",
+        ", con un coste de {cost}",
+    ),
+    (
+        "c04.column_line",
+        "Line",
+        "Línea",
+    ),
+    (
+        "c04.column_query",
+        "Query",
+        "Consulta",
+    ),
+    (
+        "c04.column_values",
+        "Values",
+        "Valores",
+    ),
+    (
+        "c04.column_result",
+        "Result",
+        "Resultado",
+    ),
+    // c05
+    (
+        "c05.date",
+        "Please enter a date (YYYY-MM-DD):",
+        "Introduce una fecha (AAAA-MM-DD):",
+    ),
+    (
+        "c05.days_difference",
+        "Days difference: {days}",
+        "Diferencia en días: {days}",
+    ),
+    (
+        "c05.seconds_difference",
+        "Seconds difference: {seconds}",
+        "Diferencia en segundos: {seconds}",
+    ),
+    (
+        "c05.breakdown",
+        "Breakdown: {breakdown}",
+        "Desglose: {breakdown}",
+    ),
+    (
+        "c05.working_days_difference",
+        "Working days difference: {days}",
+        "Diferencia en días laborables: {days}",
+    ),
+    (
+        "c05.result_date",
+        "Result date: {date} ({weekday})",
+        "Fecha resultante: {date} ({weekday})",
+    ),
+    (
+        "c05.next_dates",
+        "Next {count} dates every {every} from {date}:",
+        "Próximas {count} fechas cada {every} desde {date}:",
+    ),
+    (
+        "c05.year",
+        "{count} year",
+        "{count} año",
+    ),
+    (
+        "c05.years",
+        "{count} years",
+        "{count} años",
+    ),
+    (
+        "c05.month",
+        "{count} month",
+        "{count} mes",
+    ),
+    (
+        "c05.months",
+        "{count} months",
+        "{count} meses",
+    ),
+    (
+        "c05.week",
+        "{count} week",
+        "{count} semana",
+    ),
+    (
+        "c05.weeks",
+        "{count} weeks",
+        "{count} semanas",
+    ),
+    (
+        "c05.day",
+        "{count} day",
+        "{count} día",
+    ),
+    (
+        "c05.days",
+        "{count} days",
+        "{count} días",
+    ),
+    (
+        "c05.born",
+        "Born on a {weekday}",
+        "Naciste un {weekday}",
+    ),
+    (
+        "c05.next_birthday",
+        "Next birthday: {date}, {when} (turning {age})",
+        "Próximo cumpleaños: {date}, {when} (cumplirás {age})",
+    ),
+    (
+        "c05.milestones",
+        "Milestones:",
+        "Hitos:",
+    ),
+    (
+        "c05.days_old",
+        "{age} days old",
+        "{age} días de vida",
+    ),
+    (
+        "c05.weeks_old",
+        "{age} weeks old",
+        "{age} semanas de vida",
+    ),
+    (
+        "c05.seconds_old",
+        "{age} seconds old",
+        "{age} segundos de vida",
+    ),
+    (
+        "c05.today",
+        "today",
+        "hoy",
+    ),
+    (
+        "c05.in_one_day",
+        "in 1 day",
+        "dentro de 1 día",
+    ),
+    (
+        "c05.in_days",
+        "in {days} days",
+        "dentro de {days} días",
+    ),
+    (
+        "c05.monday",
+        "Monday",
+        "lunes",
+    ),
+    (
+        "c05.tuesday",
+        "Tuesday",
+        "martes",
+    ),
+    (
+        "c05.wednesday",
+        "Wednesday",
+        "miércoles",
+    ),
+    (
+        "c05.thursday",
+        "Thursday",
+        "jueves",
+    ),
+    (
+        "c05.friday",
+        "Friday",
+        "viernes",
+    ),
+    (
+        "c05.saturday",
+        "Saturday",
+        "sábado",
+    ),
+    (
+        "c05.sunday",
+        "Sunday",
+        "domingo",
+    ),
+    (
+        "c05.iso_week",
+        "ISO week",
+        "Semana ISO",
+    ),
+    (
+        "c05.day_of_year",
+        "Day of year",
+        "Día del año",
+    ),
+    (
+        "c05.quarter",
+        "Quarter",
+        "Trimestre",
+    ),
+    (
+        "c05.quarter_of",
+        "Q{quarter}",
+        "T{quarter}",
+    ),
+    // c06
+    (
+        "c06.stop",
+        "Press Enter again when you think exactly {seconds} seconds has elapsed.",
+        "Vuelve a pulsar Enter cuando creas que han pasado exactamente {seconds} segundos.",
+    ),
+    (
+        "c06.intro",
+        "This is a game that tests how good you are at guessing if {seconds} seconds has elapsed.",
+        "Este juego pone a prueba lo bien que adivinas cuándo han pasado {seconds} segundos.",
+    ),
+    (
+        "c06.intro_random",
+        "This is a game that tests how good you are at guessing when a number of seconds has elapsed. Each round has a new target from {min} to {max} seconds.",
+        "Este juego pone a prueba lo bien que adivinas cuándo ha pasado un número de segundos. Cada ronda tiene un objetivo nuevo de {min} a {max} segundos.",
+    ),
+    (
+        "c06.round_target",
+        "Round {round} of {rounds}: your target is {seconds} seconds",
+        "Ronda {round} de {rounds}: tu objetivo es {seconds} segundos",
+    ),
+    (
+        "c06.round",
+        "Round {round} of {rounds}",
+        "Ronda {round} de {rounds}",
+    ),
+    (
+        "c06.start",
+        "Start!",
+        "¡Ya!",
+    ),
+    (
+        "c06.stopped",
+        "Stop!",
+        "¡Alto!",
+    ),
+    (
+        "c06.too_long",
+        "Too long! You waited {waited} seconds, {off} seconds over.",
+        "¡Demasiado! Esperaste {waited} segundos, {off} segundos de más.",
+    ),
+    (
+        "c06.too_short",
+        "Not long enough! You waited {waited} seconds, {off} seconds short.",
+        "¡Demasiado poco! Esperaste {waited} segundos, {off} segundos de menos.",
+    ),
+    (
+        "c06.spot_on",
+        "Spot on! You waited exactly {waited} seconds.",
+        "¡Exacto! Esperaste justo {waited} segundos.",
+    ),
+    (
+        "c06.accuracy",
+        "Accuracy: {accuracy}%",
+        "Precisión: {accuracy}%",
+    ),
+    (
+        "c06.accuracy_so_far",
+        "Accuracy so far: {accuracy}%",
+        "Precisión hasta ahora: {accuracy}%",
+    ),
+    (
+        "c06.new_best",
+        "New personal best! You were {off} seconds off.",
+        "¡Nuevo récord personal! Te desviaste {off} segundos.",
+    ),
+    (
+        "c06.best",
+        "Your best is {off} seconds off.",
+        "Tu mejor marca es una desviación de {off} segundos.",
+    ),
+    (
+        "c06.off",
+        "You were {off} seconds off.",
+        "Te desviaste {off} segundos.",
+    ),
+    (
+        "c06.summary",
+        "Summary of {value} rounds:",
+        "Resumen de {value} rondas:",
+    ),
+    (
+        "c06.summary_best",
+        "Best: {value} seconds",
+        "Mejor: {value} segundos",
+    ),
+    (
+        "c06.summary_worst",
+        "Worst: {value} seconds",
+        "Peor: {value} segundos",
+    ),
+    (
+        "c06.summary_average",
+        "Average error: {value} seconds",
+        "Error medio: {value} segundos",
+    ),
+    (
+        "c06.summary_std_dev",
+        "Standard deviation: {value} seconds",
+        "Desviación típica: {value} segundos",
+    ),
+    (
+        "c06.summary_accuracy",
+        "Overall accuracy: {value}%",
+        "Precisión global: {value}%",
+    ),
+    (
+        "c06.oven",
+        "Did you leave the oven on?",
+        "¿Te has dejado el horno encendido?",
+    ),
+    (
+        "c06.sum",
+        "Quick, what is 17 times 23?",
+        "Rápido, ¿cuánto es 17 por 23?",
+    ),
+    (
+        "c06.bird",
+        "Look, a bird!",
+        "¡Mira, un pájaro!",
+    ),
+    (
+        "c06.blink",
+        "Don't forget to blink.",
+        "No te olvides de parpadear.",
+    ),
+    (
+        "c06.capital",
+        "What was the capital of Australia again?",
+        "¿Cuál era la capital de Australia?",
+    ),
+    (
+        "c06.warm",
+        "Is it me, or is it getting warm in here?",
+        "¿Soy yo o aquí hace cada vez más calor?",
+    ),
+    (
+        "c06.elephants",
+        "Try not to think about elephants.",
+        "Intenta no pensar en elefantes.",
+    ),
+    (
+        "c06.almost",
+        "Almost there... or are you?",
+        "Ya casi... ¿o no?",
+    ),
+    // c07
+    (
+        "c07.type",
+        "Start typing, press enter to submit!",
+        "¡Empieza a escribir y pulsa Enter para terminar!",
+    ),
+    (
+        "c07.type_live",
+        "Start typing, the timer starts with your first key!",
+        "¡Empieza a escribir, el tiempo corre desde la primera tecla!",
+    ),
+    (
+        "c07.text",
+        "Type this: {text}",
+        "Escribe esto: {text}",
+    ),
+    (
+        "c07.again",
+        "Press Enter to play again or 'q' to quit.",
+        "Pulsa Enter para volver a jugar o 'q' para salir.",
+    ),
+    (
+        "c07.intro_alphabet",
+        "This is a game to see how fast you can type the alphabet.",
+        "Este juego mide lo rápido que escribes el abecedario.",
+    ),
+    (
+        "c07.intro_sentence",
+        "This is a game to see how fast you can type a sentence.",
+        "Este juego mide lo rápido que escribes una frase.",
+    ),
+    (
+        "c07.intro_drill",
+        "This is a game to see how fast you can type a drill.",
+        "Este juego mide lo rápido que escribes un ejercicio.",
+    ),
+    (
+        "c07.round",
+        "Round {round} of {rounds}",
+        "Ronda {round} de {rounds}",
+    ),
+    (
+        "c07.typed_alphabet",
+        "You typed the alphabet in {seconds} seconds!",
+        "¡Escribiste el abecedario en {seconds} segundos!",
+    ),
+    (
+        "c07.typed",
+        "You typed it in {seconds} seconds!",
+        "¡Lo escribiste en {seconds} segundos!",
+    ),
+    (
+        "c07.wrong_alphabet",
+        "You didn't type the alphabet correctly. Try again!",
+        "No escribiste bien el abecedario. ¡Inténtalo de nuevo!",
+    ),
+    (
+        "c07.wrong",
+        "You didn't type it correctly. Try again!",
+        "No lo escribiste bien. ¡Inténtalo de nuevo!",
+    ),
+    (
+        "c07.mistake",
+        "1 mistake:",
+        "1 error:",
+    ),
+    (
+        "c07.mistakes",
+        "{mistakes} mistakes:",
+        "{mistakes} errores:",
+    ),
+    (
+        "c07.expected",
+        "Expected:",
+        "Esperado:",
+    ),
+    (
+        "c07.you_typed",
+        "You typed:",
+        "Escribiste:",
+    ),
+    (
+        "c07.typed_clean",
+        "You typed it in {seconds} seconds without a wrong key!",
+        "¡Lo escribiste en {seconds} segundos sin fallar ninguna tecla!",
+    ),
+    (
+        "c07.typed_wrong_key",
+        "You typed it in {seconds} seconds, with 1 wrong key!",
+        "¡Lo escribiste en {seconds} segundos, con 1 tecla fallada!",
+    ),
+    (
+        "c07.typed_wrong_keys",
+        "You typed it in {seconds} seconds, with {mistakes} wrong keys!",
+        "¡Lo escribiste en {seconds} segundos, con {mistakes} teclas falladas!",
+    ),
+    (
+        "c07.histogram",
+        "Time before each key, slowest first:",
+        "Tiempo antes de cada tecla, de más lenta a más rápida:",
+    ),
+    (
+        "c07.ghost",
+        "ghost",
+        "fantasma",
+    ),
+    (
+        "c07.no_ghost",
+        "There is no ghost to race until you have typed this live.",
+        "No hay fantasma contra el que competir hasta que escribas esto en vivo.",
+    ),
+    (
+        "c07.speed",
+        "Speed: {wpm} WPM",
+        "Velocidad: {wpm} PPM",
+    ),
+    (
+        "c07.first_time",
+        "That's your first time typing this text.",
+        "Es la primera vez que escribes este texto.",
+    ),
+    (
+        "c07.faster",
+        "{seconds} s faster than your best ({wpm} WPM)!",
+        "¡{seconds} s más rápido que tu mejor marca ({wpm} PPM)!",
+    ),
+    (
+        "c07.slower",
+        "{seconds} s slower than your best of {best} s ({wpm} WPM).",
+        "{seconds} s más lento que tu mejor marca de {best} s ({wpm} PPM).",
+    ),
+    (
+        "c07.report",
+        "Session report:",
+        "Informe de la sesión:",
+    ),
+    (
+        "c07.report_attempts",
+        "Attempts: {attempts}",
+        "Intentos: {attempts}",
+    ),
+    (
+        "c07.report_correct",
+        "Correct: {correct} ({percent}%)",
+        "Correctos: {correct} ({percent}%)",
+    ),
+    (
+        "c07.report_best",
+        "Best time: {seconds} seconds",
+        "Mejor tiempo: {seconds} segundos",
+    ),
+    (
+        "c07.report_average",
+        "Average time: {seconds} seconds",
+        "Tiempo medio: {seconds} segundos",
+    ),
+    (
+        "c07.report_steady",
+        "Trend: steady",
+        "Tendencia: estable",
+    ),
+    (
+        "c07.report_faster",
+        "Trend: {seconds} seconds faster each round",
+        "Tendencia: {seconds} segundos más rápido cada ronda",
+    ),
+    (
+        "c07.report_slower",
+        "Trend: {seconds} seconds slower each round",
+        "Tendencia: {seconds} segundos más lento cada ronda",
+    ),
+    (
+        "c07.best_time",
+        "Your best time was {seconds} seconds!",
+        "¡Tu mejor tiempo fue de {seconds} segundos!",
+    ),
+    (
+        "c07.new_all_time_best",
+        "That's a new all-time best!",
+        "¡Es tu nueva mejor marca de siempre!",
+    ),
+    (
+        "c07.all_time_best",
+        "Your all-time best is {seconds} seconds.",
+        "Tu mejor marca de siempre es de {seconds} segundos.",
+    ),
+    // c08
+    (
+        "c08.eligible",
+        "You are eligible to vote!",
+        "¡Puedes votar!",
+    ),
+    (
+        "c08.not_eligible",
+        "You are not eligible to vote.",
+        "Todavía no puedes votar.",
+    ),
+    (
+        "c08.eligible_in",
+        "You are eligible to vote in {country}!",
+        "¡Puedes votar en {country}!",
+    ),
+    (
+        "c08.not_eligible_in",
+        "You are not eligible to vote in {country}, where the voting age is {age}.",
+        "Todavía no puedes votar en {country}, donde la edad para votar es {age}.",
+    ),
+    (
+        "c08.eligible_on",
+        "You will be eligible on {date}, {when}.",
+        "Podrás votar el {date}, {when}.",
+    ),
+    (
+        "c08.in_one_day",
+        "in 1 day",
+        "dentro de 1 día",
+    ),
+    (
+        "c08.in_days",
+        "in {days} days",
+        "dentro de {days} días",
+    ),
+    (
+        "c08.column_item",
+        "Item",
+        "Trámite",
+    ),
+    (
+        "c08.column_from_age",
+        "From age",
+        "Desde",
+    ),
+    (
+        "c08.column_eligible",
+        "Eligible",
+        "Permitido",
+    ),
+    (
+        "c08.vote",
+        "Vote",
+        "Votar",
+    ),
+    (
+        "c08.drive",
+        "Drive",
+        "Conducir",
+    ),
+    (
+        "c08.buy_alcohol",
+        "Buy alcohol",
+        "Comprar alcohol",
+    ),
+    (
+        "c08.retire",
+        "Retire",
+        "Jubilarse",
+    ),
+    (
+        "c08.yes",
+        "yes",
+        "sí",
+    ),
+    (
+        "c08.no",
+        "no",
+        "no",
+    ),
+    (
+        "c08.summary",
+        "{eligible} eligible, {not_eligible} not eligible",
+        "{eligible} pueden votar, {not_eligible} no pueden votar",
+    ),
+    (
+        "c08.summary_errors",
+        ", {errors} with errors",
+        ", {errors} con errores",
+    ),
+    // c09
+    (
+        "c09.another",
+        "Do you want another card? (yes/no, or a number of cards for a hand)",
+        "¿Quieres otra carta? (yes/no, o un número de cartas para una mano)",
+    ),
+    (
+        "c09.invalid",
+        "Invalid input. Please enter 'yes', 'no' or a number of cards from 1 to 52.",
+        "Entrada no válida. Introduce 'yes', 'no' o un número de cartas del 1 al 52.",
+    ),
+    (
+        "c09.intro",
+        "This program generates a random card from a deck of cards.",
+        "Este programa saca una carta al azar de una baraja.",
+    ),
+    (
+        "c09.card",
+        "Your card is:",
+        "Tu carta es:",
+    ),
+    (
+        "c09.hand",
+        "Your hand is:",
+        "Tu mano es:",
+    ),
+    (
+        "c09.left",
+        "({cards} left in the deck)",
+        "(quedan {cards} en la baraja)",
+    ),
+    (
+        "c09.one_card",
+        "1 card",
+        "1 carta",
+    ),
+    (
+        "c09.cards",
+        "{count} cards",
+        "{count} cartas",
+    ),
+    (
+        "c09.reshuffled_hand",
+        "Too few cards are left for the hand, so a new deck is shuffled.",
+        "Quedan muy pocas cartas para la mano, así que se baraja una baraja nueva.",
+    ),
+    (
+        "c09.reshuffled",
+        "The deck has run out, so a new one is shuffled.",
+        "La baraja se ha acabado, así que se baraja una nueva.",
+    ),
+    (
+        "c09.joker",
+        "Joker",
+        "Comodín",
+    ),
+    (
+        "c09.holds_nothing",
+        "It holds nothing of note.",
+        "No tiene nada destacable.",
+    ),
+    (
+        "c09.holds",
+        "It holds {notes}.",
+        "Tiene {notes}.",
+    ),
+    (
+        "c09.and",
+        "{rest} and {last}",
+        "{rest} y {last}",
+    ),
+    (
+        "c09.pair",
+        "a pair of {rank}",
+        "una pareja de {rank}",
+    ),
+    (
+        "c09.three",
+        "three {rank}",
+        "un trío de {rank}",
+    ),
+    (
+        "c09.four",
+        "four {rank}",
+        "un póquer de {rank}",
+    ),
+    (
+        "c09.flush",
+        "a flush in {suit}",
+        "un color de {suit}",
+    ),
+    (
+        "c09.plural_ace",
+        "Aces",
+        "Ases",
+    ),
+    (
+        "c09.plural_two",
+        "Twos",
+        "Doses",
+    ),
+    (
+        "c09.plural_three",
+        "Threes",
+        "Treses",
+    ),
+    (
+        "c09.plural_four",
+        "Fours",
+        "Cuatros",
+    ),
+    (
+        "c09.plural_five",
+        "Fives",
+        "Cincos",
+    ),
+    (
+        "c09.plural_six",
+        "Sixes",
+        "Seises",
+    ),
+    (
+        "c09.plural_seven",
+        "Sevens",
+        "Sietes",
+    ),
+    (
+        "c09.plural_eight",
+        "Eights",
+        "Ochos",
+    ),
+    (
+        "c09.plural_nine",
+        "Nines",
+        "Nueves",
+    ),
+    (
+        "c09.plural_ten",
+        "Tens",
+        "Dieces",
+    ),
+    (
+        "c09.plural_jack",
+        "Jacks",
+        "Jotas",
+    ),
+    (
+        "c09.plural_queen",
+        "Queens",
+        "Reinas",
+    ),
+    (
+        "c09.plural_king",
+        "Kings",
+        "Reyes",
+    ),
+    (
+        "c09.odds_empty",
+        "No cards are left, so the next comes from a new deck.",
+        "No quedan cartas, así que la siguiente sale de una baraja nueva.",
+    ),
+    (
+        "c09.odds",
+        "Odds of the next card:",
+        "Probabilidades de la siguiente carta:",
+    ),
+    (
+        "c09.chance",
+        "{count} in {total} ({percent}%)",
+        "{count} de {total} ({percent}%)",
+    ),
+    (
+        "c09.odds_red",
+        "Red: {chance}",
+        "Roja: {chance}",
+    ),
+    (
+        "c09.odds_face",
+        "Face card: {chance}",
+        "Figura: {chance}",
+    ),
+    (
+        "c09.odds_joker",
+        "Joker: {chance}",
+        "Comodín: {chance}",
+    ),
+    (
+        "c09.odds_rank",
+        "By rank: {ranks}",
+        "Por valor: {ranks}",
+    ),
+    // c10
+    (
+        "c10.intro",
+        "Play a game of Rock, Paper, Scissors. Press ENTER to begin, or enter an odd number such as 5 to play a best-of match.",
+        "Juega a piedra, papel o tijera. Pulsa Enter para empezar o escribe un número impar, como 5, para jugar una partida al mejor de esas rondas.",
+    ),
+    (
+        "c10.match",
+        "Play a best-of-{rounds} match of Rock, Paper, Scissors: the first to win {needed} rounds wins. Press ENTER to begin.",
+        "Juega una partida al mejor de {rounds} de piedra, papel o tijera: gana quien gane antes {needed} rondas. Pulsa Enter para empezar.",
+    ),
+    (
+        "c10.best_of",
+        "Invalid input. Please press ENTER or enter an odd number of rounds from 1 to 99.",
+        "Entrada no válida. Pulsa Enter o escribe un número impar de rondas del 1 al 99.",
+    ),
+    (
+        "c10.move",
+        "Enter your move (rock, paper, or scissors): ",
+        "Introduce tu jugada (rock, paper o scissors): ",
+    ),
+    (
+        "c10.invalid",
+        "Invalid move. Please try again.",
+        "Jugada no válida. Inténtalo de nuevo.",
+    ),
+    (
+        "c10.again",
+        "Press ENTER to play again or type 'q' to quit.",
+        "Pulsa Enter para volver a jugar o escribe 'q' para salir.",
+    ),
+    (
+        "c10.rock",
+        "Rock",
+        "Piedra",
+    ),
+    (
+        "c10.paper",
+        "Paper",
+        "Papel",
+    ),
+    (
+        "c10.scissors",
+        "Scissors",
+        "Tijera",
+    ),
+    (
+        "c10.win",
+        "You win! You chose {mine} and the computer chose {theirs}.",
+        "¡Ganas! Elegiste {mine} y el ordenador eligió {theirs}.",
+    ),
+    (
+        "c10.tie",
+        "It's a tie! You both chose {mine}.",
+        "¡Empate! Los dos elegisteis {mine}.",
+    ),
+    (
+        "c10.lose",
+        "You lose! You chose {mine} and the computer chose {theirs}.",
+        "¡Pierdes! Elegiste {mine} y el ordenador eligió {theirs}.",
+    ),
+    (
+        "c10.score",
+        "Score: you {wins}, computer {losses}, {ties}.",
+        "Marcador: tú {wins}, el ordenador {losses}, {ties}.",
+    ),
+    (
+        "c10.one_tie",
+        "1 tie",
+        "1 empate",
+    ),
+    (
+        "c10.ties",
+        "{ties} ties",
+        "{ties} empates",
+    ),
+    (
+        "c10.rules",
+        "Best of {rounds}: the first to win {needed} rounds wins the match.",
+        "Al mejor de {rounds}: gana la partida quien gane antes {needed} rondas.",
+    ),
+    (
+        "c10.match_won",
+        "You win the match!",
+        "¡Ganas la partida!",
+    ),
+    (
+        "c10.match_lost",
+        "The computer wins the match.",
+        "El ordenador gana la partida.",
+    ),
+    (
+        "c10.round",
+        "Round {round} of {rounds}.",
+        "Ronda {round} de {rounds}.",
+    ),
+    (
+        "c10.joined",
+        "{name} joined. The match is {rounds} rounds.",
+        "{name} se ha unido. La partida es a {rounds} rondas.",
+    ),
+    (
+        "c10.joined_game",
+        "Joined {name}'s game. The match is {rounds} rounds.",
+        "Te has unido a la partida de {name}. La partida es a {rounds} rondas.",
+    ),
+    (
+        "c10.round_won",
+        "You win the round!",
+        "¡Ganas la ronda!",
+    ),
+    (
+        "c10.round_tied",
+        "It's a tie!",
+        "¡Empate!",
+    ),
+    (
+        "c10.round_lost",
+        "{name} wins the round.",
+        "{name} gana la ronda.",
+    ),
+    (
+        "c10.chose",
+        "You chose {mine} and {name} chose {theirs}. {verdict}",
+        "Elegiste {mine} y {name} eligió {theirs}. {verdict}",
+    ),
+    (
+        "c10.final_score",
+        "Final score: you {wins}, {name} {losses}, {ties} ties.",
+        "Resultado final: tú {wins}, {name} {losses}, {ties} empates.",
+    ),
+    (
+        "c10.opponent_won",
+        "{name} wins the match.",
+        "{name} gana la partida.",
+    ),
+    (
+        "c10.match_drawn",
+        "The match is a draw.",
+        "La partida termina en empate.",
+    ),
+    (
+        "c10.lifetime",
+        "Lifetime record: {wins} wins, {losses} losses, {ties} ties.",
+        "Historial: {wins} victorias, {losses} derrotas, {ties} empates.",
+    ),
+    (
+        "c10.no_rounds",
+        "No rounds played yet.",
+        "Todavía no has jugado ninguna ronda.",
+    ),
+    (
+        "c10.report",
+        "Rounds played: {rounds}\nWins: {wins}, losses: {losses}, ties: {ties}\nWin rate: {rate}%",
+        "Rondas jugadas: {rounds}\nVictorias: {wins}, derrotas: {losses}, empates: {ties}\nPorcentaje de victorias: {rate}%",
+    ),
+    (
+        "c10.no_favorite",
+        "Favorite move: none recorded yet",
+        "Jugada favorita: ninguna todavía",
+    ),
+    (
+        "c10.once",
+        "once",
+        "una vez",
+    ),
+    (
+        "c10.times",
+        "{count} times",
+        "{count} veces",
+    ),
+    (
+        "c10.favorite",
+        "Favorite move: {move}, chosen {times} ({percent}%)",
+        "Jugada favorita: {move}, elegida {times} ({percent}%)",
+    ),
+    (
+        "c10.moves",
+        "Moves: {moves}",
+        "Jugadas: {moves}",
+    ),
+    (
+        "c10.best",
+        "Your best is {best} wins in a row.",
+        "Tu mejor racha es de {best} victorias seguidas.",
+    ),
+    // c11
+    (
+        "c11.gate",
+        "Enter the type of gate you want to create (and, or, xor, nand, nor): ",
+        "Introduce el tipo de puerta que quieres crear (and, or, xor, nand, nor): ",
+    ),
+    (
+        "c11.gate_invalid",
+        "Invalid gate type. Please enter and, or, xor, nand, or nor.",
+        "Tipo de puerta no válido. Introduce and, or, xor, nand o nor.",
+    ),
+    (
+        "c11.input_a",
+        "Enter the value for input A (1 or 0): ",
+        "Introduce el valor de la entrada A (1 o 0): ",
+    ),
+    (
+        "c11.input_b",
+        "Enter the value for input B (1 or 0): ",
+        "Introduce el valor de la entrada B (1 o 0): ",
+    ),
+    (
+        "c11.input_invalid",
+        "Invalid input. Please enter 1 or 0.",
+        "Entrada no válida. Introduce 1 o 0.",
+    ),
+    (
+        "c11.result",
+        "Result: {output}",
+        "Resultado: {output}",
+    ),
+    // c12
+    ("c12.number", "Enter a number: ", "Introduce un número: "),
+    (
+        "c12.invalid",
+        "Invalid input. Please enter a whole number.",
+        "Entrada no válida. Introduce un número entero.",
+    ),
+    (
+        "c12.prime",
+        "{number} is a prime number, its factors are 1 and {number}.",
+        "{number} es un número primo, sus factores son 1 y {number}.",
+    ),
+    (
+        "c12.factors",
+        "Factors of {number} are: {factors}",
+        "Los factores de {number} son: {factors}",
+    ),
+    // c13
+    (
+        "c13.remove",
+        "How many do you want to remove? ",
+        "¿Cuántos quieres quitar? ",
+    ),
+    (
+        "c13.intro",
+        "In this game, you are presented with a random starting number.\nEach round, you must chose a number in the range 1-3 to subtract from the starting number.\nThe player who reaches 0 is the loser.",
+        "En este juego empiezas con un número al azar.\nEn cada ronda eliges un número del 1 al 3 para restarlo del número.\nQuien llegue a 0 pierde.",
+    ),
+    (
+        "c13.player_removed",
+        "Player removed: {number}",
+        "El jugador ha quitado: {number}",
+    ),
+    (
+        "c13.computer_removed",
+        "Computer removed: {number}",
+        "El ordenador ha quitado: {number}",
+    ),
+    (
+        "c13.left",
+        "{number} left.",
+        "Quedan {number}.",
+    ),
+    (
+        "c13.current",
+        "The current number is: {number}",
+        "El número actual es: {number}",
+    ),
+    (
+        "c13.lost",
+        "You lost!",
+        "¡Has perdido!",
+    ),
+    (
+        "c13.won",
+        "You won!",
+        "¡Has ganado!",
+    ),
+    // c14
+    (
+        "c14.guess",
+        "Higher(H) or Lower(L)?",
+        "¿Mayor (H) o menor (L)?",
+    ),
+    (
+        "c14.invalid",
+        "Invalid input. Please enter 'H' for higher or 'L' for lower.",
+        "Entrada no válida. Introduce 'H' para mayor o 'L' para menor.",
+    ),
+    (
+        "c14.intro",
+        "You will be dealt cards from {min} to {max}, Aces low.\nYou must guess if the next card will be higher or lower.\nYou must guess correctly {streak} times in a row to win.",
+        "Recibirás cartas del {min} al {max}, con el As como la más baja.\nTienes que adivinar si la siguiente carta será más alta o más baja.\nPara ganar tienes que acertar {streak} veces seguidas.",
+    ),
+    (
+        "c14.card",
+        "Starting card: {card}",
+        "Carta inicial: {card}",
+    ),
+    (
+        "c14.won",
+        "Congratulations! You won!",
+        "¡Enhorabuena! ¡Has ganado!",
+    ),
+    (
+        "c14.life_lost",
+        "Sorry, you lost. You have {lives} lives remaining.",
+        "Lo siento, has perdido. Te quedan {lives} vidas.",
+    ),
+    (
+        "c14.lost",
+        "Sorry, you lost. Better luck next time!",
+        "Lo siento, has perdido. ¡Más suerte la próxima vez!",
+    ),
+    (
+        "c14.lifetime",
+        "Lifetime record: {wins} wins, {losses} losses.",
+        "Historial: {wins} victorias, {losses} derrotas.",
+    ),
+    (
+        "c14.best",
+        "Your best is {best} wins in a row.",
+        "Tu mejor racha es de {best} victorias seguidas.",
+    ),
+    // c15
+    (
+        "c15.query",
+        "Would you like to count words (C) or reverse your sentence (R)?",
+        "¿Quieres contar las palabras (C) o invertir la frase (R)?",
+    ),
+    (
+        "c15.invalid",
+        "Invalid input. Please enter 'C' or 'R'.",
+        "Entrada no válida. Introduce 'C' o 'R'.",
+    ),
+    (
+        "c15.sentence",
+        "Enter your sentence: ",
+        "Introduce tu frase: ",
+    ),
+    (
+        "c15.count",
+        "Word count: {count}",
+        "Número de palabras: {count}",
+    ),
+    (
+        "c15.reversed",
+        "Reversed sentence: {sentence}",
+        "Frase al revés: {sentence}",
+    ),
+    // c16
+    (
+        "c16.guesser",
+        "Do you want to be the guesser? (y/n)",
+        "¿Quieres adivinar tú? (s/n)",
+    ),
+    ("c16.guess", "Enter your guess: ", "Introduce tu número: "),
+    (
+        "c16.feedback",
+        "Was the guess too high(H), too low(L), or correct(C)?",
+        "¿El número es demasiado alto (H), demasiado bajo (L) o correcto (C)?",
+    ),
+    (
+        "c16.intro",
+        "This is a guessing gaming. A number is chosen between {min} and {max}.\nThe player must guess the number to win.",
+        "Este es un juego de adivinar. Se elige un número entre {min} y {max}.\nPara ganar hay que adivinar el número.",
+    ),
+    (
+        "c16.too_low",
+        "Too low!",
+        "¡Demasiado bajo!",
+    ),
+    (
+        "c16.too_high",
+        "Too high!",
+        "¡Demasiado alto!",
+    ),
+    (
+        "c16.got_it",
+        "Got it!\nIt took you {attempts} attempts to guess the number.",
+        "¡Acertaste!\nHas necesitado {attempts} intentos para adivinar el número.",
+    ),
+    (
+        "c16.out_of_guesses",
+        "You're out of guesses. The number was {number}.",
+        "Te has quedado sin intentos. El número era {number}.",
+    ),
+    (
+        "c16.computer_got_it",
+        "It took the computer {attempts} attempts to guess the number.",
+        "El ordenador ha necesitado {attempts} intentos para adivinar el número.",
+    ),
+    (
+        "c16.computer_guess",
+        "The computer guesses: {guess}",
+        "El ordenador dice: {guess}",
+    ),
+    (
+        "c16.left",
+        "Guesses left: {guesses}",
+        "Intentos restantes: {guesses}",
+    ),
+    (
+        "c16.new_best",
+        "That's a new personal best!",
+        "¡Es tu nuevo récord personal!",
+    ),
+    (
+        "c16.best",
+        "Your best is {attempts} attempts.",
+        "Tu récord es de {attempts} intentos.",
+    ),
+    // c17
+    (
+        "c17.module",
+        "Enter UMS score for Module {module}: ",
+        "Introduce la nota UMS del módulo {module}: ",
+    ),
+    (
+        "c17.result",
+        "Result: ",
+        "Resultado: ",
+    ),
+    (
+        "c17.grade",
+        "Module {module}: {grade}",
+        "Módulo {module}: {grade}",
+    ),
+    (
+        "c17.as_level",
+        "AS Level: {grade}",
+        "Nivel AS: {grade}",
+    ),
+    // c18
+    (
+        "c18.base",
+        "Enter the base of the pyramid: ",
+        "Introduce la base de la pirámide: ",
+    ),
+    ("c18.odd", "an odd number", "un número impar"),
+    (
+        "c18.pyramid",
+        "A pyramid of stars {rows} tall:",
+        "Una pirámide de estrellas de {rows} de alto:",
+    ),
+    (
+        "c18.centered",
+        "Row {row}: {stars} centered",
+        "Fila {row}: {stars} en el centro",
+    ),
+    (
+        "c18.row",
+        "row",
+        "fila",
+    ),
+    (
+        "c18.rows",
+        "rows",
+        "filas",
+    ),
+    (
+        "c18.star",
+        "star",
+        "estrella",
+    ),
+    (
+        "c18.stars",
+        "stars",
+        "estrellas",
+    ),
+    // c19
+    (
+        "c19.mode",
+        "Enter 'e' to encrypt or 'd' to decrypt: ",
+        "Introduce 'e' para cifrar o 'd' para descifrar: ",
+    ),
+    (
+        "c19.mode_invalid",
+        "Invalid input. Please enter 'e' or 'd'.",
+        "Entrada no válida. Introduce 'e' o 'd'.",
+    ),
+    (
+        "c19.shift",
+        "Enter the shift value: ",
+        "Introduce el desplazamiento: ",
+    ),
+    (
+        "c19.shift_invalid",
+        "Invalid input. Please enter a valid number in the range 0 to 255.",
+        "Entrada no válida. Introduce un número válido entre 0 y 255.",
+    ),
+    ("c19.text", "Enter the text: ", "Introduce el texto: "),
+    (
+        "c19.encrypted",
+        "encryption result: {result}",
+        "resultado del cifrado: {result}",
+    ),
+    (
+        "c19.decrypted",
+        "decryption result: {result}",
+        "resultado del descifrado: {result}",
+    ),
+    // c20
+    (
+        "c20.index",
+        "Enter the index of the Fibonacci number: ",
+        "Introduce el índice del número de Fibonacci: ",
+    ),
+    (
+        "c20.invalid",
+        "Invalid input. Please enter a valid number.",
+        "Entrada no válida. Introduce un número válido.",
+    ),
+    (
+        "c20.result",
+        "Fibonacci number at index {index}: {number}",
+        "Número de Fibonacci en la posición {index}: {number}",
+    ),
+    // c21
+    (
+        "c21.name",
+        "Enter a name (or 'exit' to finish): ",
+        "Introduce un nombre (o 'exit' para terminar): ",
+    ),
+    (
+        "c21.duplicates",
+        "{name} has {count} duplicates.",
+        "{name} tiene {count} duplicados.",
+    ),
+    // c22
+    (
+        "c22.values",
+        "Values, row by row:",
+        "Valores, fila por fila:",
+    ),
+    (
+        "c22.colors",
+        "Colors, row by row:",
+        "Colores, fila por fila:",
+    ),
+    (
+        "c22.row",
+        "Row {row}: {cells}",
+        "Fila {row}: {cells}",
+    ),
+    // c23
+    (
+        "c23.location",
+        "Enter the x,y location of the treasure: ",
+        "Introduce la posición x,y del tesoro: ",
+    ),
+    (
+        "c23.invalid",
+        "Invalid input. Please enter two numbers separated by a comma.",
+        "Entrada no válida. Introduce dos números separados por una coma.",
+    ),
+    (
+        "c23.out_of_bounds",
+        "Coordinates out of bounds. Please enter values within the grid size.",
+        "Coordenadas fuera del tablero. Introduce valores dentro de la cuadrícula.",
+    ),
+    (
+        "c23.intro",
+        "This is a game where you guess the x,y location of treasure on a {size}x{size} grid.\n\
+         Make your guesses and follow the hints to find the treasure!",
+        "Es un juego en el que adivinas la posición x,y de un tesoro en una cuadrícula de {size}x{size}.\n\
+         ¡Haz tus intentos y sigue las pistas para encontrar el tesoro!",
+    ),
+    (
+        "c23.found_in",
+        "Congratulations! You found the treasure in {guesses} guesses!",
+        "¡Enhorabuena! ¡Encontraste el tesoro en {guesses} intentos!",
+    ),
+    (
+        "c23.found",
+        "Congratulations! You found the treasure!",
+        "¡Enhorabuena! ¡Encontraste el tesoro!",
+    ),
+    (
+        "c23.found_first",
+        "{name} found the treasure at {location} first.",
+        "{name} encontró primero el tesoro en {location}.",
+    ),
+    (
+        "c23.hot",
+        "hot",
+        "caliente",
+    ),
+    (
+        "c23.warm",
+        "warm",
+        "templado",
+    ),
+    (
+        "c23.cold",
+        "cold",
+        "frío",
+    ),
+    (
+        "c23.you_are",
+        "You're {hint}!",
+        "¡Estás {hint}!",
+    ),
+    (
+        "c23.guessed",
+        "{name} guessed {location} and is {hint}.",
+        "{name} probó {location} y está {hint}.",
+    ),
+    (
+        "c23.joined",
+        "{name} joined. Find the treasure on the {size}x{size} grid before they do, you go first.",
+        "{name} se ha unido. Encuentra el tesoro en la cuadrícula de {size}x{size} antes que tu rival, empiezas tú.",
+    ),
+    (
+        "c23.joined_hunt",
+        "Joined {name}'s hunt. Find the treasure on the {size}x{size} grid before they do, {name} goes first.",
+        "Te has unido a la búsqueda de {name}. Encuentra el tesoro en la cuadrícula de {size}x{size} antes que tu rival, empieza {name}.",
+    ),
+    (
+        "c23.no_map",
+        "The full-screen map is not available in accessible mode, so the hunt is played line by line.",
+        "El mapa a pantalla completa no está disponible en el modo accesible, así que la búsqueda se juega línea a línea.",
+    ),
+    (
+        "c23.new_best",
+        "That's a new personal best!",
+        "¡Es un nuevo récord personal!",
+    ),
+    (
+        "c23.best",
+        "Your best is {guesses} guesses.",
+        "Tu récord es de {guesses} intentos.",
+    ),
+    (
+        "c23.title",
+        "Treasure Hunt",
+        "Búsqueda del tesoro",
+    ),
+    (
+        "c23.dig",
+        "dig",
+        "cavar",
+    ),
+    (
+        "c23.legend",
+        "Find the treasure on the {size}x{size} grid. █ {hot} ▒ {warm} ░ {cold}",
+        "Encuentra el tesoro en la cuadrícula de {size}x{size}. █ {hot} ▒ {warm} ░ {cold}",
+    ),
+    (
+        "c23.found_tui",
+        "You found the treasure in {guesses} guesses! Press any key.",
+        "¡Encontraste el tesoro en {guesses} intentos! Pulsa cualquier tecla.",
+    ),
+    (
+        "c23.pick",
+        "Pick a spot to dig.",
+        "Elige un sitio para cavar.",
+    ),
+    (
+        "c23.status",
+        "({location}) {hint} Guesses: {guesses}",
+        "({location}) {hint} Intentos: {guesses}",
+    ),
+    // c24
+    (
+        "c24.menu",
+        "Enter 1 to add a result or 2 to search for a result: ",
+        "Introduce 1 para añadir un resultado o 2 para buscar uno: ",
+    ),
+    (
+        "c24.menu_invalid",
+        "Invalid input. Please enter 1 or 2.",
+        "Entrada no válida. Introduce 1 o 2.",
+    ),
+    (
+        "c24.home_team",
+        "Enter the home team: ",
+        "Introduce el equipo local: ",
+    ),
+    (
+        "c24.home_score",
+        "Enter the home team's score: ",
+        "Introduce los goles del equipo local: ",
+    ),
+    (
+        "c24.away_team",
+        "Enter the away team: ",
+        "Introduce el equipo visitante: ",
+    ),
+    (
+        "c24.away_score",
+        "Enter the away team's score: ",
+        "Introduce los goles del equipo visitante: ",
+    ),
+    (
+        "c24.team",
+        "Enter the team name: ",
+        "Introduce el nombre del equipo: ",
+    ),
+    (
+        "c24.results",
+        "Search results for \"{query}\":",
+        "Resultados de la búsqueda de \"{query}\":",
+    ),
+    (
+        "c24.no_results",
+        "No results found.",
+        "No se encontraron resultados.",
+    ),
+    // c25
+    (
+        "c25.move",
+        "Do you want to hit(H) or stand(S)?",
+        "¿Quieres pedir carta (H) o plantarte (S)?",
+    ),
+    (
+        "c25.invalid",
+        "Invalid input. Please enter 'H' or 'S'.",
+        "Entrada no válida. Introduce 'H' o 'S'.",
+    ),
+    (
+        "c25.win",
+        "You win!",
+        "¡Ganas!",
+    ),
+    (
+        "c25.lose",
+        "You lose!",
+        "¡Pierdes!",
+    ),
+    (
+        "c25.tie",
+        "It's a tie!",
+        "¡Empate!",
+    ),
+    (
+        "c25.dealer_hand",
+        "Dealer hand: \n{hand}",
+        "Mano de la banca: \n{hand}",
+    ),
+    (
+        "c25.your_hand",
+        "Your hand: \n{hand}",
+        "Tu mano: \n{hand}",
+    ),
+    (
+        "c25.deck_empty",
+        "No more cards in the deck.",
+        "No quedan cartas en la baraja.",
+    ),
+    (
+        "c25.drew",
+        "You drew: {card}",
+        "Has robado: {card}",
+    ),
+    (
+        "c25.bust",
+        "Bust! Your hand is over 21.",
+        "¡Te pasas! Tu mano supera 21.",
+    ),
+    (
+        "c25.lifetime",
+        "Lifetime record: {wins} wins, {losses} losses, {ties} ties.",
+        "Historial: {wins} victorias, {losses} derrotas, {ties} empates.",
+    ),
+    (
+        "c25.title",
+        "Blackjack",
+        "Blackjack",
+    ),
+    (
+        "c25.hit",
+        "Hit",
+        "Pedir",
+    ),
+    (
+        "c25.stand",
+        "Stand",
+        "Plantarse",
+    ),
+    (
+        "c25.select",
+        "select",
+        "elegir",
+    ),
+    (
+        "c25.play",
+        "play",
+        "jugar",
+    ),
+    (
+        "c25.hit_stand",
+        "hit/stand",
+        "pedir/plantarse",
+    ),
+    (
+        "c25.dealer",
+        "Dealer",
+        "Banca",
+    ),
+    (
+        "c25.dealer_value",
+        "Dealer ({value})",
+        "Banca ({value})",
+    ),
+    (
+        "c25.you_value",
+        "You ({value})",
+        "Tú ({value})",
+    ),
+    (
+        "c25.your_move",
+        "Your move",
+        "Tu jugada",
+    ),
+    (
+        "c25.hit_or_stand",
+        "Hit or stand?",
+        "¿Pedir o plantarse?",
+    ),
+    (
+        "c25.best",
+        "Your best is {best} wins in a row.",
+        "Tu mejor racha es de {best} victorias seguidas.",
+    ),
+    // c26
+    (
+        "c26.guess",
+        "Enter a {digits}-digit guess: ",
+        "Introduce un intento de {digits} cifras: ",
+    ),
+    (
+        "c26.expected",
+        "a {digits}-digit number",
+        "un número de {digits} cifras",
+    ),
+    (
+        "c26.code",
+        "Enter the secret {digits}-digit code: ",
+        "Introduce el código secreto de {digits} cifras: ",
+    ),
+    (
+        "c26.cracked",
+        "Congratulations! You've guessed the code.",
+        "¡Enhorabuena! Has adivinado el código.",
+    ),
+    (
+        "c26.feedback",
+        "Correct digits: {digits}, correct positions: {positions}",
+        "Cifras correctas: {digits}, posiciones correctas: {positions}",
+    ),
+    (
+        "c26.out_of_guesses",
+        "Out of guesses! The code was {code}.",
+        "¡Sin intentos! El código era {code}.",
+    ),
+    (
+        "c26.guessed",
+        "{name} guessed {guess}. {feedback}",
+        "{name} probó {guess}. {feedback}",
+    ),
+    (
+        "c26.cracked_first",
+        "{name} cracked the code first.",
+        "{name} descifró primero el código.",
+    ),
+    (
+        "c26.joined",
+        "{name} joined. Crack the {digits}-digit code before they do, you go first.",
+        "{name} se ha unido. Descifra el código de {digits} cifras antes que tu rival, empiezas tú.",
+    ),
+    (
+        "c26.joined_set_code",
+        "{name} joined and is cracking your code with {guesses} guesses.",
+        "{name} se ha unido y está descifrando tu código con {guesses} intentos.",
+    ),
+    (
+        "c26.cracked_yours",
+        "{name} cracked your code.",
+        "{name} descifró tu código.",
+    ),
+    (
+        "c26.ran_out",
+        "{name} ran out of guesses.",
+        "{name} se quedó sin intentos.",
+    ),
+    (
+        "c26.joined_their_code",
+        "Joined {name}'s game. Crack their {digits}-digit code in {guesses} guesses.",
+        "Te has unido a la partida de {name}. Descifra su código de {digits} cifras en {guesses} intentos.",
+    ),
+    (
+        "c26.joined_game",
+        "Joined {name}'s game. Crack the {digits}-digit code in {guesses} guesses before they do, {name} goes first.",
+        "Te has unido a la partida de {name}. Descifra el código de {digits} cifras en {guesses} intentos antes que tu rival, empieza {name}.",
+    ),
+    (
+        "c26.new_best",
+        "That's a new personal best!",
+        "¡Es un nuevo récord personal!",
+    ),
+    (
+        "c26.best",
+        "Your best is {guesses} guesses.",
+        "Tu récord es de {guesses} intentos.",
+    ),
+    (
+        "c26.title",
+        "Mastermind",
+        "Mastermind",
+    ),
+    (
+        "c26.type",
+        "type",
+        "escribir",
+    ),
+    (
+        "c26.guess_key",
+        "guess",
+        "probar",
+    ),
+    (
+        "c26.row",
+        "   digits: {digits}  positions: {positions}",
+        "   cifras: {digits}  posiciones: {positions}",
+    ),
+    (
+        "c26.guesses",
+        "Guesses",
+        "Intentos",
+    ),
+    (
+        "c26.your_guess",
+        "Your guess",
+        "Tu intento",
+    ),
+    (
+        "c26.cracked_tui",
+        "You cracked the code in {guesses} guesses! Press any key.",
+        "¡Descifraste el código en {guesses} intentos! Pulsa cualquier tecla.",
+    ),
+    (
+        "c26.out_of_guesses_tui",
+        "Out of guesses! The code was {code}. Press any key.",
+        "¡Sin intentos! El código era {code}. Pulsa cualquier tecla.",
+    ),
+    (
+        "c26.left",
+        "{guesses} guesses left",
+        "Quedan {guesses} intentos",
+    ),
+    // c27
+    (
+        "c27.word",
+        "Player 1, enter a word: ",
+        "Jugador 1, introduce una palabra: ",
+    ),
+    (
+        "c27.word_invalid",
+        "Invalid input. Please enter a word made of letters.",
+        "Entrada no válida. Introduce una palabra formada por letras.",
+    ),
+    (
+        "c27.letter",
+        "You have {lives} lives left - Letter? ",
+        "Te quedan {lives} vidas - ¿Letra? ",
+    ),
+    (
+        "c27.letter_invalid",
+        "Invalid input. Please enter a single letter.",
+        "Entrada no válida. Introduce una sola letra.",
+    ),
+    (
+        "c27.blank",
+        "blank",
+        "hueco",
+    ),
+    (
+        "c27.word_to_guess",
+        "Word to guess: {word}",
+        "Palabra a adivinar: {word}",
+    ),
+    (
+        "c27.guessed",
+        "Congratulations! You've guessed the word: {word}",
+        "¡Enhorabuena! Has adivinado la palabra: {word}",
+    ),
+    (
+        "c27.out_of_lives",
+        "You've run out of lives. The word was: {word}",
+        "Te has quedado sin vidas. La palabra era: {word}",
+    ),
+    (
+        "c27.joined",
+        "{name} joined and is guessing your word with {lives} lives.",
+        "{name} se ha unido y está adivinando tu palabra con {lives} vidas.",
+    ),
+    (
+        "c27.guessed_letter",
+        "{name} guessed {letter}: {word}",
+        "{name} probó {letter}: {word}",
+    ),
+    (
+        "c27.guessed_yours",
+        "{name} guessed your word.",
+        "{name} adivinó tu palabra.",
+    ),
+    (
+        "c27.ran_out",
+        "{name} ran out of lives.",
+        "{name} se quedó sin vidas.",
+    ),
+    (
+        "c27.joined_game",
+        "Joined {name}'s game. Guess their {length}-letter word with {lives} lives.",
+        "Te has unido a la partida de {name}. Adivina su palabra de {length} letras con {lives} vidas.",
+    ),
+    (
+        "c27.lifetime",
+        "Lifetime record: {wins} wins, {losses} losses.",
+        "Historial: {wins} victorias, {losses} derrotas.",
+    ),
+];
+
+/// Returns the message for `key` in the active language, or `key` itself if
+/// the catalog has no such message.
+pub fn text(key: &str) -> &'static str {
+    text_in(lang(), key)
+}
+
+fn text_in(lang: Lang, key: &str) -> &'static str {
+    match CATALOG.iter().find(|(k, _, _)| *k == key) {
+        Some((_, en, es)) => match lang {
+            Lang::En => en,
+            Lang::Es => es,
+        },
+        None => {
+            tracing::debug!(key, "no message for key");
+            // Leaking is fine: a missing key is a bug, and at most a handful
+            // of them are ever looked up.
+            Box::leak(key.to_string().into_boxed_str())
+        }
+    }
+}
+
+/// Returns the message for `key` in the active language with each `{name}`
+/// placeholder replaced by the matching value in `args`.
+pub fn format(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    fill(text(key), args)
+}
+
+fn fill(message: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(message.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{name}}}"), &value.to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::collections::HashSet;
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        lang: LangArgs,
+    }
+
+    /// Returns the `{name}` placeholders in `message`.
+    fn placeholders(message: &str) -> HashSet<&str> {
+        message
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn lang_defaults_to_english() {
+        let args = TestArgs::try_parse_from(["test"]).unwrap();
+        assert_eq!(args.lang.lang, Lang::En);
+        let args = TestArgs::try_parse_from(["test", "--lang", "es"]).unwrap();
+        assert_eq!(args.lang.lang, Lang::Es);
+        assert!(TestArgs::try_parse_from(["test", "--lang", "xx"]).is_err());
+    }
+
+    #[test]
+    fn catalog_keys_are_unique() {
+        let mut keys = HashSet::new();
+        for (key, _, _) in CATALOG {
+            assert!(keys.insert(key), "duplicate key {key}");
+        }
+    }
+
+    #[test]
+    fn translations_keep_the_placeholders() {
+        for (key, en, es) in CATALOG {
+            assert!(!es.is_empty(), "{key} is not translated");
+            assert_eq!(placeholders(en), placeholders(es), "{key}");
+        }
+    }
+
+    #[test]
+    fn text_picks_the_language() {
+        assert_eq!(text_in(Lang::En, "c12.number"), "Enter a number: ");
+        assert_eq!(text_in(Lang::Es, "c12.number"), "Introduce un número: ");
+        assert_eq!(text_in(Lang::Es, "no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn format_fills_in_placeholders() {
+        let message = fill(
+            text_in(Lang::Es, "prompt.number_between"),
+            &[("min", &1), ("max", &10)],
+        );
+        assert_eq!(message, "un número entre 1 y 10");
+    }
+}
@@ -0,0 +1,39 @@
+//! # Challenge Common
+//!
+//! Shared building blocks used by the challenge binaries so that they look and
+//! behave consistently.
+//!
+//! ## Modules
+//!
+//! - [`accessible`]: `--accessible` descriptions in place of drawings and colors
+//! - [`cli`]: Command-line conventions (argument types and value parsers)
+//! - [`clock`]: Session timing and the `pause` command for the longer games
+//! - [`config`]: Player defaults read from `~/.config/lbpc/config.toml`
+//! - [`difficulty`]: Easy, normal and hard levels behind `--difficulty`
+//! - [`game`]: The `Game` trait and the loop that plays the turn-based games
+//! - [`input`]: Terminal or scripted line input for the interactive games
+//! - [`l10n`]: Translated prompts and the `--lang` flag
+//! - [`log`]: `--verbose` and `LBPC_LOG` diagnostic logging
+//! - [`output`]: `--format json` results for the calculator-style utilities
+//! - [`prompt`]: Validated prompts with a shared retry policy and `quit` command
+//! - [`rng`]: Seedable random number generation for reproducible sessions
+//! - [`sound`]: Sound effects for game events, muted with `--mute`
+//! - [`theme`]: Color themes and `--no-color` support for colored output
+//! - [`transcript`]: `--record` and `--replay` of whole game sessions
+pub mod accessible;
+pub mod cli;
+pub mod clock;
+pub mod config;
+pub mod difficulty;
+#[cfg(not(target_arch = "wasm32"))]
+mod editor;
+pub mod game;
+pub mod input;
+pub mod l10n;
+pub mod log;
+pub mod output;
+pub mod prompt;
+pub mod rng;
+pub mod sound;
+pub mod theme;
+pub mod transcript;
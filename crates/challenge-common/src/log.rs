@@ -0,0 +1,75 @@
+//! Diagnostic logging for the challenges, built on `tracing`.
+//!
+//! Only warnings are logged by default. `-v` turns on debug logs (random
+//! seeds, game state changes, and file I/O) and `-vv` adds trace logs. The
+//! `LBPC_LOG` environment variable takes a full `tracing` filter, such as
+//! `debug` or `c25=trace,challenge_storage=debug`, and overrides `-v`. Logs
+//! go to stderr so that they never mix with a challenge's output.
+use clap::{ArgAction, Args};
+use std::io::IsTerminal;
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable holding a log filter that overrides `--verbose`.
+pub const LOG_ENV_VAR: &str = "LBPC_LOG";
+
+/// Command-line arguments controlling diagnostic logging.
+#[derive(Args, Debug, Clone, Default)]
+pub struct LogArgs {
+    /// Print diagnostic logs to stderr (-v for debug, -vv for trace)
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+fn default_filter(verbose: u8) -> &'static str {
+    match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    }
+}
+
+fn filter(spec: Option<&str>, verbose: u8) -> EnvFilter {
+    let default = default_filter(verbose);
+    match spec.map(str::trim).filter(|spec| !spec.is_empty()) {
+        Some(spec) => EnvFilter::try_new(spec).unwrap_or_else(|e| {
+            eprintln!("Warning: ignoring invalid {}: {}", LOG_ENV_VAR, e);
+            EnvFilter::new(default)
+        }),
+        None => EnvFilter::new(default),
+    }
+}
+
+/// Starts logging to stderr. Should be called once, first thing in `main`.
+pub fn init(args: &LogArgs) {
+    let spec = std::env::var(LOG_ENV_VAR).ok();
+    // Only fails if logging was already started, which is harmless.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter(spec.as_deref(), args.verbose))
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none())
+        .without_time()
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_raises_the_default_level() {
+        assert_eq!(default_filter(0), "warn");
+        assert_eq!(default_filter(1), "debug");
+        assert_eq!(default_filter(3), "trace");
+    }
+
+    #[test]
+    fn env_filter_overrides_verbosity() {
+        assert_eq!(filter(Some("c25=trace"), 0).to_string(), "c25=trace");
+    }
+
+    #[test]
+    fn blank_or_invalid_env_filter_falls_back_to_verbosity() {
+        assert_eq!(filter(Some("  "), 1).to_string(), "debug");
+        assert_eq!(filter(Some("c25=loud"), 0).to_string(), "warn");
+    }
+}
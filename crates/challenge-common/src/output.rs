@@ -0,0 +1,162 @@
+//! Text or JSON results for the calculator-style utilities.
+//!
+//! The utilities accept `--format text|json`, and `--json` as a shorthand for
+//! `--format json`. With `--format json` a utility
+//! prints exactly one JSON document to stdout once it is done, in the same
+//! shape for every utility:
+//!
+//! ```json
+//! {"challenge": "c12", "input": {"number": 12}, "result": {"prime": false, "factors": [1, 2, 3, 4, 6, 12]}}
+//! ```
+//!
+//! `input` holds the values that were entered and `result` what was worked
+//! out from them; the fields inside both are specific to each utility. The
+//! prompts go to stderr instead of stdout, so the output can be piped
+//! straight into `jq`. Errors are still reported on stderr as text, with a
+//! non-zero exit status where the utility has one.
+use clap::{Args, ValueEnum};
+use std::io::Write;
+
+pub use serde_json::{json, Value};
+
+/// How a utility reports its results.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Sentences for people to read
+    #[default]
+    Text,
+    /// One JSON document with the inputs and results, for other programs
+    Json,
+}
+
+impl Format {
+    /// Returns where prompts should be written: stdout for text, and stderr
+    /// for JSON so that stdout carries nothing but the document.
+    pub fn prompts(self) -> Box<dyn Write> {
+        match self {
+            Format::Text => Box::new(std::io::stdout()),
+            Format::Json => {
+                #[cfg(not(target_arch = "wasm32"))]
+                crate::editor::prompts_elsewhere();
+                Box::new(std::io::stderr())
+            }
+        }
+    }
+}
+
+/// Command-line arguments selecting the output format.
+///
+/// Both options are global, so a utility with subcommands takes them after
+/// the subcommand as well as before it.
+#[derive(Args, Debug, Clone, Default)]
+pub struct FormatArgs {
+    /// Output format for the results
+    #[arg(long, value_enum, global = true, default_value_t)]
+    format: Format,
+
+    /// Same as `--format json`
+    #[arg(long, global = true, conflicts_with = "format")]
+    json: bool,
+}
+
+impl FormatArgs {
+    /// The format chosen with `--format` or `--json`.
+    ///
+    /// `--json` is resolved here rather than as a default for `--format`,
+    /// because clap lets the default of a global argument in a subcommand
+    /// override one worked out before the subcommand.
+    pub fn format(&self) -> Format {
+        if self.json {
+            Format::Json
+        } else {
+            self.format
+        }
+    }
+}
+
+/// Writes the JSON document for a run of `challenge` on one line.
+pub fn write_json<W: Write + ?Sized>(
+    writer: &mut W,
+    challenge: &str,
+    input: Value,
+    result: Value,
+) -> std::io::Result<()> {
+    let document = json!({
+        "challenge": challenge,
+        "input": input,
+        "result": result,
+    });
+    writeln!(writer, "{}", document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        format: FormatArgs,
+        #[command(subcommand)]
+        command: Option<TestCommand>,
+    }
+
+    #[derive(clap::Subcommand)]
+    enum TestCommand {
+        Run,
+    }
+
+    #[test]
+    fn format_defaults_to_text() {
+        assert_eq!(TestArgs::parse_from(["test"]).format.format(), Format::Text);
+        assert_eq!(
+            TestArgs::parse_from(["test", "--format", "json"])
+                .format
+                .format(),
+            Format::Json
+        );
+    }
+
+    #[test]
+    fn json_flag_is_short_for_format_json() {
+        let args = TestArgs::parse_from(["test", "--json"]);
+        assert_eq!(args.format.format(), Format::Json);
+        assert!(TestArgs::try_parse_from(["test", "--json", "--format", "text"]).is_err());
+    }
+
+    #[test]
+    fn format_can_follow_a_subcommand() {
+        for argv in [["test", "--json", "run"], ["test", "run", "--json"]] {
+            let args = TestArgs::parse_from(argv);
+            assert!(args.command.is_some());
+            assert_eq!(args.format.format(), Format::Json, "for {argv:?}");
+        }
+        let args = TestArgs::parse_from(["test", "run", "--format", "json"]);
+        assert_eq!(args.format.format(), Format::Json);
+    }
+
+    #[test]
+    fn write_json_wraps_input_and_result() {
+        let mut output = Vec::new();
+        write_json(
+            &mut output,
+            "c20",
+            json!({ "index": 10 }),
+            json!({ "fibonacci": "55" }),
+        )
+        .unwrap();
+
+        let document: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            document,
+            json!({
+                "challenge": "c20",
+                "input": { "index": 10 },
+                "result": { "fibonacci": "55" },
+            })
+        );
+        assert!(output.ends_with(b"}\n"));
+        assert_eq!(output.iter().filter(|&&b| b == b'\n').count(), 1);
+    }
+}
@@ -0,0 +1,507 @@
+//! Validated prompts that behave the same way in every challenge.
+//!
+//! A challenge asks for a value with a [`Prompt`] and a parser that either
+//! accepts the answer or explains what is wrong with it. What happens next is
+//! decided by the [`RetryPolicy`] rather than by each challenge:
+//!
+//! - An invalid answer prints the parser's explanation and asks again, until
+//!   `--max-attempts` (or `LBPC_MAX_ATTEMPTS`) invalid answers have been
+//!   given. Without a limit the prompt keeps asking.
+//! - An empty answer picks the prompt's default, if it has one.
+//! - Typing the abort command, [`ABORT_COMMAND`], or pressing Ctrl-C at any
+//!   prompt returns an error that [`is_abort`] recognizes, so the game can
+//!   save its progress before it stops.
+//!
+//! In a `--script` session each answer is echoed after its prompt, and the
+//! first invalid answer gives up unless `--max-attempts` says otherwise.
+//!
+//! Running out of input always ends the prompt, so scripted sessions never
+//! hang.
+//!
+//! The common kinds of question have ready-made parsers, [`parsed`],
+//! [`choice`] and [`yes_no`], with matching shortcuts on [`Prompt`]. They
+//! reject an answer with "Invalid input. Please enter ..." so every challenge
+//! words its complaints the same way, in the language picked with `--lang`.
+use crate::l10n::{self, Lang};
+use crate::{input, transcript};
+use clap::Args;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::editor;
+
+/// Stand-ins for the terminal's line editor, which wasm builds do not have.
+#[cfg(target_arch = "wasm32")]
+mod editor {
+    pub(crate) fn set_prompt(_prompt: &str) -> bool {
+        false
+    }
+
+    pub(crate) fn clear_prompt() {}
+}
+
+/// Answer that aborts the program from any prompt.
+pub const ABORT_COMMAND: &str = "quit";
+
+/// Environment variable used to limit attempts when `--max-attempts` is not
+/// given.
+pub const MAX_ATTEMPTS_ENV_VAR: &str = "LBPC_MAX_ATTEMPTS";
+
+/// Command-line arguments controlling the retry policy.
+#[derive(Args, Debug, Clone, Default)]
+pub struct PromptArgs {
+    /// Give up after N invalid answers to a prompt [default: keep asking]
+    #[arg(
+        long,
+        value_name = "N",
+        env = MAX_ATTEMPTS_ENV_VAR,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub max_attempts: Option<u32>,
+}
+
+/// What a prompt does when an answer is not valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Invalid answers allowed before giving up, or `None` to keep asking.
+    pub max_attempts: Option<u32>,
+    /// Answer that aborts instead of being validated.
+    pub abort_command: &'static str,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: None,
+            abort_command: ABORT_COMMAND,
+        }
+    }
+}
+
+static POLICY: OnceLock<RetryPolicy> = OnceLock::new();
+
+/// Sets the retry policy for the rest of the program. Only the first call has
+/// any effect.
+pub fn init(args: &PromptArgs) {
+    let _ = POLICY.set(RetryPolicy {
+        max_attempts: args.max_attempts,
+        ..RetryPolicy::default()
+    });
+}
+
+/// Returns the active retry policy.
+pub fn policy() -> RetryPolicy {
+    let policy = POLICY.get().copied().unwrap_or_default();
+    if input::is_script_file() && policy.max_attempts.is_none() {
+        // Asking a script again would only read its next, unrelated answer.
+        return RetryPolicy {
+            max_attempts: Some(1),
+            ..policy
+        };
+    }
+    policy
+}
+
+/// Marks the error returned when the player aborts at a prompt.
+#[derive(Debug)]
+struct Abort;
+
+impl Display for Abort {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "aborted by the player")
+    }
+}
+
+impl std::error::Error for Abort {}
+
+/// Returns the error for a player aborting at a prompt, see [`is_abort`].
+pub(crate) fn aborted() -> std::io::Error {
+    std::io::Error::other(Abort)
+}
+
+/// Returns `true` if `error` means the player aborted at a prompt.
+pub fn is_abort(error: &std::io::Error) -> bool {
+    error.get_ref().is_some_and(|e| e.is::<Abort>())
+}
+
+fn invalid(expected: &str) -> String {
+    l10n::format("prompt.invalid", &[("expected", &expected)])
+}
+
+/// Returns a parser for answers of type `T`, such as numbers, that `accept`
+/// allows. `expected` describes a valid answer, e.g. "a number between 1 and
+/// 10", for the message shown when one is rejected.
+pub fn parsed<'a, T: FromStr>(
+    expected: &'a str,
+    mut accept: impl FnMut(&T) -> bool + 'a,
+) -> impl FnMut(&str) -> Result<T, String> + 'a {
+    move |answer| {
+        answer
+            .parse()
+            .ok()
+            .filter(|value| accept(value))
+            .ok_or_else(|| invalid(expected))
+    }
+}
+
+/// Returns a parser that picks the value paired with the answer in
+/// `choices`, ignoring case.
+pub fn choice<'a, T: Clone>(
+    choices: &'a [(&'a str, T)],
+) -> impl FnMut(&str) -> Result<T, String> + 'a {
+    move |answer| {
+        choices
+            .iter()
+            .find(|(choice, _)| choice.eq_ignore_ascii_case(answer))
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| {
+                let names: Vec<_> = choices.iter().map(|(c, _)| format!("'{}'", c)).collect();
+                match names.split_last() {
+                    Some((last, [])) => invalid(last),
+                    Some((last, rest)) => invalid(&l10n::format(
+                        "prompt.either",
+                        &[("rest", &rest.join(", ")), ("last", last)],
+                    )),
+                    None => invalid(l10n::text("prompt.an_answer")),
+                }
+            })
+    }
+}
+
+/// Parses a yes-or-no answer: `y`, `yes`, `n` or `no` in any case, and also
+/// `s`, `si` or `sí` in Spanish.
+pub fn yes_no(answer: &str) -> Result<bool, String> {
+    match answer.to_lowercase().as_str() {
+        "y" | "yes" => Ok(true),
+        "s" | "si" | "sí" if l10n::lang() == Lang::Es => Ok(true),
+        "n" | "no" => Ok(false),
+        _ => Err(invalid(l10n::text("prompt.yes_or_no"))),
+    }
+}
+
+/// A question asked until it gets a valid answer.
+#[derive(Debug, Clone)]
+pub struct Prompt<'a> {
+    message: &'a str,
+    inline: bool,
+    default: Option<&'a str>,
+    policy: RetryPolicy,
+    echo: bool,
+}
+
+impl<'a> Prompt<'a> {
+    /// Creates a prompt that writes `message` on a line of its own and
+    /// follows the active [`policy`].
+    pub fn new(message: &'a str) -> Prompt<'a> {
+        Prompt {
+            message,
+            inline: false,
+            default: None,
+            policy: policy(),
+            echo: input::is_script_file(),
+        }
+    }
+
+    /// Leaves the cursor right after the message instead of ending the line.
+    pub fn inline(mut self) -> Self {
+        self.inline = true;
+        self
+    }
+
+    /// Uses `answer` when the player just presses Enter. The message should
+    /// mention it.
+    pub fn with_default(mut self, answer: &'a str) -> Self {
+        self.default = Some(answer);
+        self
+    }
+
+    /// Writes each answer after the prompt, as if it had been typed. On by
+    /// default in `--script` sessions.
+    pub fn echo(mut self, echo: bool) -> Self {
+        self.echo = echo;
+        self
+    }
+
+    /// Follows `policy` instead of the active one.
+    pub fn policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Asks until `parse` accepts an answer read from `reader`. `parse` gets
+    /// the answer without surrounding whitespace and explains a rejection in
+    /// its error, which is shown before asking again.
+    pub fn ask<R, W, T, E>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        parse: impl FnMut(&str) -> Result<T, E>,
+    ) -> std::io::Result<T>
+    where
+        R: BufRead + ?Sized,
+        W: Write + ?Sized,
+        E: Display,
+    {
+        self.ask_with(writer, |_| input::read_line(reader), parse)
+    }
+
+    /// Asks for free text, accepting any answer other than the abort command.
+    pub fn ask_text<R, W>(&self, reader: &mut R, writer: &mut W) -> std::io::Result<String>
+    where
+        R: BufRead + ?Sized,
+        W: Write + ?Sized,
+    {
+        self.ask(reader, writer, |answer| {
+            Ok::<_, std::convert::Infallible>(answer.to_string())
+        })
+    }
+
+    /// Asks for a `T` that `accept` allows, see [`parsed`].
+    pub fn ask_parsed<R, W, T>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        expected: &str,
+        accept: impl FnMut(&T) -> bool,
+    ) -> std::io::Result<T>
+    where
+        R: BufRead + ?Sized,
+        W: Write + ?Sized,
+        T: FromStr,
+    {
+        self.ask(reader, writer, parsed(expected, accept))
+    }
+
+    /// Asks for one of `choices`, see [`choice`].
+    pub fn ask_choice<R, W, T>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        choices: &[(&str, T)],
+    ) -> std::io::Result<T>
+    where
+        R: BufRead + ?Sized,
+        W: Write + ?Sized,
+        T: Clone,
+    {
+        self.ask(reader, writer, choice(choices))
+    }
+
+    /// Asks a yes-or-no question, see [`yes_no`].
+    pub fn ask_yes_no<R, W>(&self, reader: &mut R, writer: &mut W) -> std::io::Result<bool>
+    where
+        R: BufRead + ?Sized,
+        W: Write + ?Sized,
+    {
+        self.ask(reader, writer, yes_no)
+    }
+
+    /// Like [`Prompt::ask`], but reads each answer with `read_line`, for
+    /// games that intercept some answers themselves (such as `pause`).
+    pub fn ask_with<W, T, E>(
+        &self,
+        writer: &mut W,
+        mut read_line: impl FnMut(&mut W) -> std::io::Result<String>,
+        mut parse: impl FnMut(&str) -> Result<T, E>,
+    ) -> std::io::Result<T>
+    where
+        W: Write + ?Sized,
+        E: Display,
+    {
+        let mut attempts = 0;
+        loop {
+            transcript::prompt(self.message);
+            if self.inline {
+                // The line editor shows an inline prompt itself.
+                if !editor::set_prompt(self.message) {
+                    write!(writer, "{}", self.message)?;
+                    writer.flush()?;
+                }
+            } else {
+                writeln!(writer, "{}", self.message)?;
+            }
+
+            let line = read_line(writer);
+            editor::clear_prompt();
+            let line = line?;
+            if self.echo {
+                writeln!(writer, "{}", line.trim_end())?;
+            }
+            let answer = match line.trim() {
+                "" => self.default.unwrap_or(""),
+                answer => answer,
+            };
+            if answer.eq_ignore_ascii_case(self.policy.abort_command) {
+                tracing::debug!(prompt = self.message, "aborted");
+                return Err(aborted());
+            }
+
+            match parse(answer) {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    attempts += 1;
+                    tracing::debug!(prompt = self.message, answer, attempts, "invalid answer");
+                    writeln!(writer, "{}", e)?;
+                    if self.policy.max_attempts.is_some_and(|max| attempts >= max) {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            format!(
+                                "no valid answer after {} attempt{}",
+                                attempts,
+                                if attempts == 1 { "" } else { "s" }
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_digit(answer: &str) -> Result<u32, String> {
+        answer
+            .parse()
+            .ok()
+            .filter(|n| *n < 10)
+            .ok_or_else(|| "Invalid input. Please enter a digit.".to_string())
+    }
+
+    fn ask(prompt: &Prompt, input: &str) -> (std::io::Result<u32>, String) {
+        let mut output = Vec::new();
+        let result = prompt.ask(&mut input.as_bytes(), &mut output, parse_digit);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn ask_repeats_the_prompt_until_the_answer_is_valid() {
+        let (result, output) = ask(&Prompt::new("Digit?"), "x\n42\n7\n");
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(
+            output,
+            "Digit?\nInvalid input. Please enter a digit.\n\
+             Digit?\nInvalid input. Please enter a digit.\nDigit?\n"
+        );
+    }
+
+    #[test]
+    fn ask_gives_up_after_max_attempts() {
+        let policy = RetryPolicy {
+            max_attempts: Some(2),
+            ..RetryPolicy::default()
+        };
+        let (result, _) = ask(&Prompt::new("Digit?").policy(policy), "x\ny\n7\n");
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "no valid answer after 2 attempts");
+    }
+
+    #[test]
+    fn ask_echoes_answers_when_asked_to() {
+        let (result, output) = ask(&Prompt::new("Digit?").inline().echo(true), "x\n7\n");
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(
+            output,
+            "Digit?x\nInvalid input. Please enter a digit.\nDigit?7\n"
+        );
+    }
+
+    #[test]
+    fn ask_uses_the_default_for_an_empty_answer() {
+        let (result, _) = ask(&Prompt::new("Digit? [5]").with_default("5"), "  \n");
+        assert_eq!(result.unwrap(), 5);
+    }
+
+    #[test]
+    fn ask_rejects_an_empty_answer_without_a_default() {
+        let (result, output) = ask(&Prompt::new("Digit?"), "\n3\n");
+        assert_eq!(result.unwrap(), 3);
+        assert!(output.contains("Invalid input."));
+    }
+
+    #[test]
+    fn ask_aborts_on_the_abort_command() {
+        let (result, _) = ask(&Prompt::new("Digit?"), "QUIT\n");
+        let error = result.unwrap_err();
+        assert!(is_abort(&error));
+        assert_eq!(error.to_string(), "aborted by the player");
+        let interrupted = std::io::Error::from(std::io::ErrorKind::Interrupted);
+        assert!(!is_abort(&interrupted));
+    }
+
+    #[test]
+    fn ask_reports_end_of_input() {
+        let (result, _) = ask(&Prompt::new("Digit?"), "x\n");
+        assert_eq!(
+            result.unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn ask_text_accepts_any_answer() {
+        let mut output = Vec::new();
+        let text = Prompt::new("Sentence?")
+            .ask_text(&mut "  hello world \n".as_bytes(), &mut output)
+            .unwrap();
+        assert_eq!(text, "hello world");
+    }
+
+    #[test]
+    fn parsed_rejects_answers_that_do_not_parse_or_are_not_accepted() {
+        let mut parse = parsed("an odd number", |n: &u32| n % 2 == 1);
+        assert_eq!(parse("7"), Ok(7));
+        let rejected = Err("Invalid input. Please enter an odd number.".to_string());
+        assert_eq!(parse("8"), rejected);
+        assert_eq!(parse("seven"), rejected);
+    }
+
+    #[test]
+    fn choice_ignores_case_and_lists_the_choices() {
+        let choices = [("H", 1), ("L", 2), ("C", 3)];
+        let mut parse = choice(&choices);
+        assert_eq!(parse("l"), Ok(2));
+        assert_eq!(
+            parse("x"),
+            Err("Invalid input. Please enter 'H', 'L' or 'C'.".to_string())
+        );
+    }
+
+    #[test]
+    fn yes_no_accepts_short_and_long_answers() {
+        assert_eq!(yes_no("Y"), Ok(true));
+        assert_eq!(yes_no("yes"), Ok(true));
+        assert_eq!(yes_no("No"), Ok(false));
+        assert!(yes_no("maybe").is_err());
+    }
+
+    #[test]
+    fn ask_parsed_repeats_the_prompt_until_the_answer_is_accepted() {
+        let mut output = Vec::new();
+        let n = Prompt::new("Odd?")
+            .ask_parsed(
+                &mut "4\n5\n".as_bytes(),
+                &mut output,
+                "an odd number",
+                |n: &u32| n % 2 == 1,
+            )
+            .unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Odd?\nInvalid input. Please enter an odd number.\nOdd?\n"
+        );
+    }
+
+    #[test]
+    fn inline_prompts_stay_on_the_same_line() {
+        let (_, output) = ask(&Prompt::new("Digit: ").inline(), "4\n");
+        assert_eq!(output, "Digit: ");
+    }
+}
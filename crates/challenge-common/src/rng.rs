@@ -0,0 +1,75 @@
+//! Seedable random number generation shared by the randomized challenges.
+//!
+//! Each randomized challenge builds one generator with [`RngArgs::rng`] and
+//! passes it to its game logic, which takes any `&mut impl Rng`. The
+//! generator is seeded from the `--seed` flag, falling back to the
+//! `LBPC_SEED` environment variable and finally to a random seed, so any game
+//! session can be reproduced by re-running it with the same seed. Tests pass
+//! in their own seeded generator instead.
+use clap::Args;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Environment variable used to seed the generator when `--seed` is not given.
+pub const SEED_ENV_VAR: &str = "LBPC_SEED";
+
+/// Command-line arguments controlling the random number generator.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RngArgs {
+    /// Seed for the random number generator, to reproduce a session
+    #[arg(long, env = SEED_ENV_VAR)]
+    pub seed: Option<u64>,
+}
+
+impl RngArgs {
+    /// Builds the game's generator from `--seed`, or else `default`, such as
+    /// the seed of a transcript being replayed.
+    pub fn rng(&self, default: Option<u64>) -> StdRng {
+        seeded(self.seed.or(default))
+    }
+}
+
+fn seed_from_env() -> Option<u64> {
+    std::env::var(SEED_ENV_VAR).ok()?.trim().parse().ok()
+}
+
+/// Builds a generator from `seed`.
+///
+/// When `seed` is `None` the `LBPC_SEED` environment variable is used, and if
+/// that is unset too a random seed is chosen. The seed is logged and recorded
+/// in the session transcript, if there is one.
+pub fn seeded(seed: Option<u64>) -> StdRng {
+    let seed = seed
+        .or_else(seed_from_env)
+        .unwrap_or_else(|| rand::rng().random());
+    tracing::debug!(seed, "seeded random number generator");
+    crate::transcript::seed(seed);
+    StdRng::seed_from_u64(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_rng_is_reproducible() {
+        let mut first = seeded(Some(42));
+        let mut second = seeded(Some(42));
+        let a: Vec<u32> = (0..10).map(|_| first.random()).collect();
+        let b: Vec<u32> = (0..10).map(|_| second.random()).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn rng_prefers_the_seed_flag() {
+        let args = RngArgs { seed: Some(7) };
+        let a: u64 = args.rng(Some(8)).random();
+        let b: u64 = StdRng::seed_from_u64(7).random();
+        assert_eq!(a, b);
+
+        let args = RngArgs::default();
+        let a: u64 = args.rng(Some(8)).random();
+        let b: u64 = StdRng::seed_from_u64(8).random();
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,179 @@
+//! Sound effects for game events.
+//!
+//! Games call [`play`] when something worth hearing happens, such as a win, a
+//! right answer or a wrong guess, and [`wait`] before exiting so the last effect is not cut
+//! off. The effects are short tones played through the default audio device
+//! when the crate is built with the `sound` feature, which needs the system
+//! audio libraries (ALSA on Linux). Without the feature, or with `--mute` or
+//! `LBPC_MUTE`, both calls do nothing.
+use clap::builder::FalseyValueParser;
+use clap::Args;
+use std::sync::OnceLock;
+
+/// Environment variable that mutes every game when set to anything other
+/// than an empty string, `0`, `false`, `no` or `off`.
+pub const MUTE_ENV_VAR: &str = "LBPC_MUTE";
+
+/// Command-line arguments controlling sound effects.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SoundArgs {
+    /// Turn off sound effects
+    #[arg(long, env = MUTE_ENV_VAR, value_parser = FalseyValueParser::new())]
+    pub mute: bool,
+}
+
+/// Something that happened in a game and has a sound effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Win,
+    Lose,
+    CardDealt,
+    /// A right answer that does not end the game.
+    Correct,
+    WrongGuess,
+}
+
+impl Event {
+    /// The notes of the effect as (frequency in Hz, length in milliseconds).
+    #[cfg_attr(not(feature = "sound"), allow(dead_code))]
+    fn notes(self) -> &'static [(f32, u64)] {
+        match self {
+            Event::Win => &[(523.25, 120), (659.25, 120), (783.99, 240)],
+            Event::Lose => &[(392.0, 180), (311.13, 180), (261.63, 360)],
+            Event::CardDealt => &[(1200.0, 40)],
+            Event::Correct => &[(880.0, 60), (1318.51, 100)],
+            Event::WrongGuess => &[(220.0, 150)],
+        }
+    }
+}
+
+static MUTED: OnceLock<bool> = OnceLock::new();
+
+/// Applies `--mute` for the rest of the program. Only the first call has any
+/// effect.
+pub fn init(args: &SoundArgs) {
+    let _ = MUTED.set(args.mute);
+}
+
+/// Returns `true` if sound effects are on: the `sound` feature is enabled
+/// and the player has not muted them.
+pub fn enabled() -> bool {
+    cfg!(feature = "sound") && !MUTED.get().copied().unwrap_or(false)
+}
+
+/// Queues the effect for `event` and returns without waiting for it.
+pub fn play(event: Event) {
+    if !enabled() {
+        return;
+    }
+    tracing::trace!(?event, "sound effect");
+    #[cfg(feature = "sound")]
+    player::play(event.notes());
+}
+
+/// Blocks until every queued effect has played.
+pub fn wait() {
+    #[cfg(feature = "sound")]
+    if enabled() {
+        player::wait();
+    }
+}
+
+#[cfg(feature = "sound")]
+mod player {
+    use rodio::source::{SineWave, Source};
+    use rodio::{OutputStream, Sink};
+    use std::time::Duration;
+
+    /// Loudness of the effects relative to full scale.
+    const VOLUME: f32 = 0.2;
+
+    thread_local! {
+        /// The output stream and the queue of effects, opened on first use.
+        /// `None` if there is no audio device, in which case effects are
+        /// skipped.
+        static PLAYER: Option<(OutputStream, Sink)> = open();
+    }
+
+    fn open() -> Option<(OutputStream, Sink)> {
+        let opened = OutputStream::try_default()
+            .map_err(|e| e.to_string())
+            .and_then(|(stream, handle)| {
+                Sink::try_new(&handle)
+                    .map(|sink| (stream, sink))
+                    .map_err(|e| e.to_string())
+            });
+        match opened {
+            Ok(player) => Some(player),
+            Err(e) => {
+                tracing::debug!(error = %e, "no audio output, sound effects are off");
+                None
+            }
+        }
+    }
+
+    pub fn play(notes: &[(f32, u64)]) {
+        PLAYER.with(|player| {
+            if let Some((_, sink)) = player {
+                for &(frequency, millis) in notes {
+                    sink.append(
+                        SineWave::new(frequency)
+                            .take_duration(Duration::from_millis(millis))
+                            .amplify(VOLUME),
+                    );
+                }
+            }
+        });
+    }
+
+    pub fn wait() {
+        PLAYER.with(|player| {
+            if let Some((_, sink)) = player {
+                sink.sleep_until_end();
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        sound: SoundArgs,
+    }
+
+    #[test]
+    fn mute_flag_is_off_by_default() {
+        let args = TestArgs::try_parse_from(["test"]).unwrap();
+        assert!(!args.sound.mute);
+    }
+
+    #[test]
+    fn mute_flag_turns_sound_off() {
+        let args = TestArgs::try_parse_from(["test", "--mute"]).unwrap();
+        assert!(args.sound.mute);
+    }
+
+    #[test]
+    fn every_event_has_notes() {
+        for event in [
+            Event::Win,
+            Event::Lose,
+            Event::CardDealt,
+            Event::Correct,
+            Event::WrongGuess,
+        ] {
+            assert!(!event.notes().is_empty());
+        }
+    }
+
+    #[test]
+    fn play_and_wait_do_nothing_without_an_audio_device() {
+        play(Event::WrongGuess);
+        wait();
+    }
+}
@@ -0,0 +1,203 @@
+//! Colored output shared by the challenges that print in color.
+//!
+//! Challenges take their colors from the active [`Theme`] instead of keeping
+//! their own color lists, so every colored challenge can be switched to a
+//! palette that suits the terminal with `--theme` (or `LBPC_THEME`). Colors
+//! are turned off with `--no-color`, with the `monochrome` theme, when the
+//! `NO_COLOR` environment variable is set, or when stdout is not a terminal.
+//! Both can also be set in the [`config`](crate::config) file.
+//!
+//! Win, lose and hint messages go through [`win`], [`lose`] and [`hint`] so
+//! they look the same in every game.
+use crate::{config, l10n};
+use clap::{Args, ValueEnum};
+use colored::{Color, ColoredString, Colorize};
+use serde::Deserialize;
+use std::sync::OnceLock;
+
+/// Environment variable used to pick the theme when `--theme` is not given.
+pub const THEME_ENV_VAR: &str = "LBPC_THEME";
+
+/// A set of colors chosen to read well on a particular kind of terminal.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// Colors for terminals with a dark background
+    #[default]
+    Dark,
+    /// Colors for terminals with a light background
+    Light,
+    /// Bright, strongly contrasting colors
+    HighContrast,
+    /// No colors at all
+    Monochrome,
+}
+
+impl Theme {
+    /// Returns the theme's colors, for challenges that need several distinct
+    /// colors. Every palette has the same length.
+    pub fn palette(self) -> &'static [Color] {
+        use Color::*;
+        match self {
+            // Monochrome output is never colored, but the colors are still
+            // named when a challenge describes its output in words.
+            Theme::Dark | Theme::Monochrome => &[Red, Green, Blue, Yellow, Magenta, Cyan],
+            Theme::Light => &[Red, Green, Blue, Magenta, Cyan, Black],
+            Theme::HighContrast => &[
+                BrightRed,
+                BrightGreen,
+                BrightBlue,
+                BrightYellow,
+                BrightMagenta,
+                BrightCyan,
+            ],
+        }
+    }
+
+    /// Returns the colors used for win, lose and hint messages, in that order.
+    fn message_colors(self) -> [Color; 3] {
+        use Color::*;
+        match self {
+            Theme::Dark | Theme::Monochrome => [Green, Red, Yellow],
+            Theme::Light => [Green, Red, Blue],
+            Theme::HighContrast => [BrightGreen, BrightRed, BrightYellow],
+        }
+    }
+}
+
+/// Styles a message telling the player they won.
+pub fn win(text: &str) -> ColoredString {
+    text.color(current().message_colors()[0]).bold()
+}
+
+/// Styles a message telling the player they lost.
+pub fn lose(text: &str) -> ColoredString {
+    text.color(current().message_colors()[1]).bold()
+}
+
+/// Styles a hint or other feedback given while a game is still going.
+pub fn hint(text: &str) -> ColoredString {
+    text.color(current().message_colors()[2])
+}
+
+/// Returns the name of `color` in the player's language, for describing
+/// colored output in words.
+pub fn color_name(color: Color) -> &'static str {
+    use Color::*;
+    l10n::text(match color {
+        Black => "color.black",
+        Red => "color.red",
+        Green => "color.green",
+        Yellow => "color.yellow",
+        Blue => "color.blue",
+        Magenta => "color.magenta",
+        Cyan => "color.cyan",
+        White => "color.white",
+        BrightBlack => "color.gray",
+        BrightRed => "color.bright_red",
+        BrightGreen => "color.bright_green",
+        BrightYellow => "color.bright_yellow",
+        BrightBlue => "color.bright_blue",
+        BrightMagenta => "color.bright_magenta",
+        BrightCyan => "color.bright_cyan",
+        BrightWhite => "color.bright_white",
+        TrueColor { .. } => "color.custom",
+    })
+}
+
+/// Command-line arguments controlling colored output.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ThemeArgs {
+    /// Disable colored output (also honored via the NO_COLOR variable)
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Color theme to use [default: dark]
+    #[arg(long, value_enum, env = THEME_ENV_VAR)]
+    pub theme: Option<Theme>,
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Sets the theme for the rest of the program and turns colors off if
+/// `--no-color` was given or the theme is monochrome. Settings missing from
+/// `args` are taken from the config file. Only the first call has any effect.
+pub fn init(args: &ThemeArgs) {
+    let config = config::get();
+    let theme = args.theme.or(config.theme).unwrap_or_default();
+    let no_color = args.no_color || config.no_color || theme == Theme::Monochrome;
+    if THEME.set(theme).is_ok() && no_color {
+        colored::control::set_override(false);
+    }
+}
+
+/// Returns the active theme.
+pub fn current() -> Theme {
+    THEME.get().copied().unwrap_or_default()
+}
+
+/// Returns `true` if output should be colored.
+pub fn is_enabled() -> bool {
+    colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        theme: ThemeArgs,
+    }
+
+    #[test]
+    fn palettes_have_the_same_length() {
+        let len = Theme::Dark.palette().len();
+        assert_eq!(Theme::Light.palette().len(), len);
+        assert_eq!(Theme::HighContrast.palette().len(), len);
+        assert_eq!(Theme::Monochrome.palette().len(), len);
+    }
+
+    #[test]
+    fn light_palette_avoids_pale_colors() {
+        let palette = Theme::Light.palette();
+        assert!(!palette.contains(&Color::White));
+        assert!(!palette.contains(&Color::Yellow));
+    }
+
+    #[test]
+    fn palette_colors_have_distinct_names() {
+        for theme in Theme::value_variants() {
+            let palette = theme.palette();
+            for (i, color) in palette.iter().enumerate() {
+                assert!(palette[i + 1..]
+                    .iter()
+                    .all(|other| color_name(*other) != color_name(*color)));
+            }
+        }
+    }
+
+    #[test]
+    fn theme_args_parse_theme_and_no_color() {
+        let args = TestArgs::parse_from(["test", "--theme", "high-contrast", "--no-color"]);
+        assert_eq!(args.theme.theme, Some(Theme::HighContrast));
+        assert!(args.theme.no_color);
+    }
+
+    #[test]
+    fn message_colors_differ_within_each_theme() {
+        for theme in Theme::value_variants() {
+            let [win, lose, hint] = theme.message_colors();
+            assert!(win != lose && lose != hint && win != hint);
+        }
+    }
+
+    #[test]
+    fn message_helpers_keep_the_text() {
+        assert_eq!(win("You win!").input, "You win!");
+        assert_eq!(lose("You lose!").input, "You lose!");
+        assert_eq!(hint("You're warm!").input, "You're warm!");
+    }
+}
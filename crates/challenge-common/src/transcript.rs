@@ -0,0 +1,223 @@
+//! Recording game sessions and replaying them.
+//!
+//! With `--record FILE` a game writes a transcript of the session: the RNG
+//! seed, the question of every prompt, and every line the player types. With
+//! `--replay FILE` it reads a transcript back, answering the prompts with the
+//! recorded lines and seeding the RNG with the recorded seed, so every random
+//! outcome (cards dealt, moves, secret numbers) comes out the same and the
+//! session plays out exactly as it did.
+//!
+//! A transcript is a text file with one `kind: text` entry per line:
+//!
+//! ```text
+//! # lbpc transcript
+//! seed: 42
+//! prompt: Enter your move (rock, paper, or scissors):
+//! input: rock
+//! ```
+//!
+//! Only the `seed` and `input` entries matter for a replay, so a transcript
+//! can be edited by hand to try a different answer.
+use clap::Args;
+use std::fs::File;
+use std::io::{BufRead, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// First line of every transcript.
+const HEADER: &str = "# lbpc transcript";
+
+/// Command-line arguments for recording and replaying sessions.
+#[derive(Args, Debug, Clone, Default)]
+pub struct TranscriptArgs {
+    /// Record the session's seed, prompts and answers to FILE
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Replay a session recorded with `--record`
+    #[arg(long, value_name = "FILE", conflicts_with = "script")]
+    pub replay: Option<PathBuf>,
+}
+
+static RECORDER: OnceLock<Mutex<File>> = OnceLock::new();
+
+/// Starts recording the session to `path`, replacing any file there. Only
+/// the first call has any effect.
+pub fn start(path: &Path) -> std::io::Result<()> {
+    if RECORDER.get().is_some() {
+        return Ok(());
+    }
+    let mut file = File::create(path)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+    writeln!(file, "{}", HEADER)?;
+    tracing::debug!(path = %path.display(), "recording transcript");
+    let _ = RECORDER.set(Mutex::new(file));
+    Ok(())
+}
+
+fn record(kind: &str, text: &str) {
+    let Some(recorder) = RECORDER.get() else {
+        return;
+    };
+    let mut file = recorder.lock().unwrap_or_else(PoisonError::into_inner);
+    let written = if text.is_empty() {
+        writeln!(file, "{}:", kind)
+    } else {
+        writeln!(file, "{}: {}", kind, text)
+    };
+    if let Err(e) = written {
+        tracing::debug!(error = %e, "unable to write transcript");
+    }
+}
+
+/// Records the seed the RNG was initialized with.
+pub fn seed(seed: u64) {
+    record("seed", &seed.to_string());
+}
+
+/// Records a prompt shown to the player.
+pub fn prompt(message: &str) {
+    record("prompt", message.trim_end());
+}
+
+/// A session read back from a transcript.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Replay {
+    /// The recorded seed, if the game used randomness.
+    pub seed: Option<u64>,
+    /// The lines the player typed, in order.
+    pub inputs: Vec<String>,
+}
+
+impl Replay {
+    /// Reads the transcript at `path`.
+    pub fn load(path: &Path) -> std::io::Result<Replay> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        Replay::parse(&text).map_err(|e| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })
+    }
+
+    /// Parses the text of a transcript.
+    pub fn parse(text: &str) -> Result<Replay, String> {
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err("not a transcript recorded with --record".to_string());
+        }
+        let mut replay = Replay::default();
+        for line in lines {
+            let Some((kind, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match kind {
+                "seed" => {
+                    let seed = value
+                        .parse()
+                        .map_err(|_| format!("invalid seed '{value}'"))?;
+                    replay.seed = Some(seed);
+                }
+                "input" => replay.inputs.push(value.to_string()),
+                _ => {}
+            }
+        }
+        Ok(replay)
+    }
+
+    /// Returns the recorded input as the player typed it.
+    pub fn input(&self) -> String {
+        self.inputs.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
+
+/// A reader that records every line read through it as an `input` entry.
+pub(crate) struct Recorded<R> {
+    inner: R,
+    line: Vec<u8>,
+}
+
+impl<R: BufRead> Recorded<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Recorded {
+            inner,
+            line: Vec::new(),
+        }
+    }
+
+    fn record_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            if byte == b'\n' {
+                let line = String::from_utf8_lossy(&self.line);
+                record("input", line.trim_end_matches('\r'));
+                self.line.clear();
+            } else {
+                self.line.push(byte);
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Read for Recorded<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.record_bytes(&buf[..read]);
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for Recorded<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // The bytes being consumed are still at the front of the buffer.
+        let consumed = match self.inner.fill_buf() {
+            Ok(buf) => buf[..amt.min(buf.len())].to_vec(),
+            Err(_) => Vec::new(),
+        };
+        self.record_bytes(&consumed);
+        self.inner.consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_seed_and_inputs() {
+        let replay = Replay::parse(
+            "# lbpc transcript\n\
+             seed: 42\n\
+             prompt: Enter your move (rock, paper, or scissors):\n\
+             input: rock\n\
+             prompt: Press ENTER to play again or type 'q' to quit.\n\
+             input:\n\
+             input: q\n",
+        )
+        .unwrap();
+        assert_eq!(replay.seed, Some(42));
+        assert_eq!(replay.inputs, ["rock", "", "q"]);
+        assert_eq!(replay.input(), "rock\n\nq\n");
+    }
+
+    #[test]
+    fn parse_rejects_other_files() {
+        assert!(Replay::parse("rock\nq\n").is_err());
+        assert!(Replay::parse("# lbpc transcript\nseed: lots\n").is_err());
+    }
+
+    #[test]
+    fn recorded_reader_passes_lines_through() {
+        let mut reader = Recorded::new("rock\r\nq\n".as_bytes());
+        assert_eq!(crate::input::read_line(&mut reader).unwrap(), "rock\r\n");
+        assert_eq!(reader.line, b"");
+        assert_eq!(crate::input::read_line(&mut reader).unwrap(), "q\n");
+        assert!(crate::input::read_line(&mut reader).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! # Challenge E2E
+//!
+//! Harness for end-to-end tests of the challenge binaries. A [`Session`]
+//! spawns a challenge with its input piped from a script and captures the
+//! complete transcript of the run, so whole play sessions can be asserted
+//! on from the tests in this crate's `tests/` directory.
+//!
+//! Binaries are looked up in the target directory the tests were built into
+//! and are (re)built with `cargo build` the first time a session needs them.
+//! Every session gets its own empty data directory, so scores saved by one
+//! run never leak into another, and its own config file, empty unless the
+//! test writes one with [`Session::config`].
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::{Mutex, OnceLock};
+
+/// A scripted run of one challenge binary.
+pub struct Session {
+    bin: String,
+    args: Vec<String>,
+    input: String,
+    config: String,
+}
+
+/// Everything a finished session printed, and how it exited.
+#[derive(Debug)]
+pub struct Transcript {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: ExitStatus,
+}
+
+impl Session {
+    /// Starts describing a session of challenge `bin` (e.g. `"c10"`).
+    pub fn new(bin: &str) -> Session {
+        Session {
+            bin: bin.to_string(),
+            args: Vec::new(),
+            input: String::new(),
+            config: String::new(),
+        }
+    }
+
+    /// Passes `arg` on the command line.
+    pub fn arg(mut self, arg: &str) -> Session {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    /// Passes each of `args` on the command line.
+    pub fn args(mut self, args: &[&str]) -> Session {
+        self.args.extend(args.iter().map(|arg| arg.to_string()));
+        self
+    }
+
+    /// Appends `lines` to the session's input, one answer per line.
+    pub fn input(mut self, lines: &[&str]) -> Session {
+        for line in lines {
+            self.input.push_str(line);
+            self.input.push('\n');
+        }
+        self
+    }
+
+    /// Sets the contents of the config file the session reads.
+    pub fn config(mut self, text: &str) -> Session {
+        self.config = text.to_string();
+        self
+    }
+
+    /// Runs the binary to completion and returns its transcript.
+    ///
+    /// Panics if the binary cannot be built or spawned.
+    pub fn run(self) -> Transcript {
+        let path = binary(&self.bin);
+        let data_dir = tempfile::tempdir().expect("failed to create data dir");
+        let config = data_dir.path().join("config.toml");
+        std::fs::write(&config, &self.config).expect("failed to write config");
+        let mut child = Command::new(&path)
+            .args(&self.args)
+            .env("LBPC_DATA_DIR", data_dir.path())
+            .env("LBPC_CONFIG", &config)
+            .env_remove("LBPC_SEED")
+            .env_remove("LBPC_DIFFICULTY")
+            .env_remove("LBPC_ACCESSIBLE")
+            .env_remove("LBPC_MAX_ATTEMPTS")
+            .env_remove("LBPC_MUTE")
+            .env_remove("LBPC_LANG")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap_or_else(|e| panic!("failed to spawn {}: {}", path.display(), e));
+
+        // A game that exits early closes its end of the pipe, which is not an
+        // error as far as the harness is concerned.
+        let mut stdin = child.stdin.take().unwrap();
+        let _ = stdin.write_all(self.input.as_bytes());
+        drop(stdin);
+
+        let output = child
+            .wait_with_output()
+            .unwrap_or_else(|e| panic!("failed to run {}: {}", self.bin, e));
+        Transcript {
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            status: output.status,
+        }
+    }
+}
+
+impl Transcript {
+    /// Asserts that the session exited successfully and returns its stdout.
+    pub fn success(&self) -> &str {
+        assert!(
+            self.status.success(),
+            "session failed with {}\nstdout:\n{}\nstderr:\n{}",
+            self.status,
+            self.stdout,
+            self.stderr
+        );
+        &self.stdout
+    }
+
+    /// Asserts that the session exited with an error and returns its stderr.
+    pub fn failure(&self) -> &str {
+        assert!(
+            !self.status.success(),
+            "session unexpectedly succeeded\nstdout:\n{}",
+            self.stdout
+        );
+        &self.stderr
+    }
+}
+
+/// Replaces the durations in a transcript's "Time played: " lines with
+/// `M:SS`, so that transcripts of the timed games can be compared exactly.
+pub fn redact_time(output: &str) -> String {
+    const LABEL: &str = "Time played: ";
+    output
+        .split_inclusive('\n')
+        .map(|line| match line.strip_prefix(LABEL) {
+            Some(rest) => {
+                let time_len = rest
+                    .find(|c: char| !c.is_ascii_digit() && c != ':')
+                    .unwrap_or(rest.len());
+                format!("{LABEL}M:SS{}", &rest[time_len..])
+            }
+            None => line.to_string(),
+        })
+        .collect()
+}
+
+/// Returns the path to challenge `bin`, building it first if this process
+/// has not done so yet.
+fn binary(bin: &str) -> PathBuf {
+    static BUILT: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+    let dir = target_dir();
+    let mut built = BUILT.get_or_init(Default::default).lock().unwrap();
+    if built.insert(bin.to_string()) {
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut build = Command::new(cargo);
+        build.args(["build", "--quiet", "--package", bin, "--bin", bin]);
+        if dir.ends_with("release") {
+            build.arg("--release");
+        }
+        let status = build.status().expect("failed to run cargo build");
+        assert!(status.success(), "cargo build --bin {} failed", bin);
+    }
+    dir.join(format!("{bin}{}", std::env::consts::EXE_SUFFIX))
+}
+
+/// Returns the profile directory (e.g. `target/debug`) the running test
+/// executable was built into.
+fn target_dir() -> PathBuf {
+    let exe = std::env::current_exe().expect("failed to locate test executable");
+    // Test executables live in `<target>/<profile>/deps/`.
+    exe.parent()
+        .and_then(|deps| deps.parent())
+        .expect("test executable is not in a cargo target directory")
+        .to_path_buf()
+}
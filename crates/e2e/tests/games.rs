@@ -0,0 +1,432 @@
+//! Complete play sessions of the interactive games. Scripted sessions use a
+//! fixed seed, so the transcripts of the randomized games are stable.
+use challenge_e2e::{redact_time, Session};
+
+#[test]
+fn c10_plays_rounds_until_quit() {
+    let transcript = Session::new("c10")
+        .input(&["", "rock", "", "lizard", "paper", "q"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Play a game of Rock, Paper, Scissors. Press ENTER to begin, or enter an odd \
+         number such as 5 to play a best-of match.\n\
+         Enter your move (rock, paper, or scissors): \n\
+         You win! You chose Rock and the computer chose Scissors.\n\
+         Press ENTER to play again or type 'q' to quit.\n\
+         Enter your move (rock, paper, or scissors): \n\
+         Invalid move. Please try again.\n\
+         Enter your move (rock, paper, or scissors): \n\
+         You lose! You chose Paper and the computer chose Scissors.\n\
+         Press ENTER to play again or type 'q' to quit.\n\
+         Lifetime record: 1 wins, 1 losses, 0 ties.\n\
+         Your best is 1 wins in a row.\n"
+    );
+}
+
+#[test]
+fn c10_reads_script_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("session.txt");
+    std::fs::write(&script, "\nrock\nq\n").unwrap();
+
+    let transcript = Session::new("c10")
+        .args(&["--script", script.to_str().unwrap()])
+        .run();
+
+    assert!(transcript
+        .success()
+        .contains("You win! You chose Rock and the computer chose Scissors."));
+    assert!(transcript
+        .stdout
+        .contains("Enter your move (rock, paper, or scissors): \nrock\n"));
+}
+
+#[test]
+fn c10_script_fails_on_an_invalid_answer() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("session.txt");
+    std::fs::write(&script, "\nlizard\nrock\nq\n").unwrap();
+
+    let transcript = Session::new("c10")
+        .args(&["--script", script.to_str().unwrap()])
+        .run();
+
+    assert_eq!(
+        transcript.failure(),
+        "Error: no valid answer after 1 attempt\n"
+    );
+    assert!(transcript
+        .stdout
+        .ends_with("lizard\nInvalid move. Please try again.\n"));
+}
+
+#[test]
+fn c10_replays_a_recorded_session() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.txt");
+    let path = path.to_str().unwrap();
+
+    let recorded = Session::new("c10")
+        .args(&["--seed", "7", "--record", path])
+        .input(&["", "rock", "", "paper", "q"])
+        .run();
+    let replayed = Session::new("c10").args(&["--replay", path]).run();
+
+    assert_eq!(replayed.success(), recorded.success());
+    assert!(std::fs::read_to_string(path).unwrap().starts_with(
+        "# lbpc transcript\n\
+         seed: 7\n\
+         prompt: Play a game of Rock, Paper, Scissors. Press ENTER to begin, or enter an odd \
+         number such as 5 to play a best-of match.\n\
+         input:\n\
+         prompt: Enter your move (rock, paper, or scissors):\n\
+         input: rock\n"
+    ));
+}
+
+#[test]
+fn c10_plays_a_best_of_match() {
+    let moves = vec!["rock"; 20];
+    let transcript = Session::new("c10")
+        .args(&["--best-of", "3"])
+        .input(&[&[""], moves.as_slice()].concat())
+        .run();
+
+    let output = transcript.success();
+    assert!(output.starts_with(
+        "Play a best-of-3 match of Rock, Paper, Scissors: the first to win 2 rounds \
+         wins. Press ENTER to begin.\n"
+    ));
+    assert!(output.contains("\nScore: you "));
+    assert!(
+        output.contains("You win the match!\nLifetime record: ")
+            || output.contains("The computer wins the match.\nLifetime record: ")
+    );
+    assert!(!output.contains("Press ENTER to play again"));
+}
+
+#[test]
+fn c10_starts_a_match_from_the_first_prompt() {
+    let moves = vec!["rock"; 20];
+    let transcript = Session::new("c10")
+        .input(&[&["4", "3"], moves.as_slice()].concat())
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains(
+        "Invalid input. Please press ENTER or enter an odd number of rounds from 1 to 99.\n"
+    ));
+    assert!(output.contains("Best of 3: the first to win 2 rounds wins the match.\n"));
+    assert!(
+        output.contains("You win the match!") || output.contains("The computer wins the match.")
+    );
+}
+
+#[test]
+fn c10_fails_when_script_ends_early() {
+    let transcript = Session::new("c10").input(&["", "lizard"]).run();
+
+    assert_eq!(transcript.failure(), "Error: unexpected end of input\n");
+}
+
+#[test]
+fn c10_shows_no_stats_before_any_rounds() {
+    let transcript = Session::new("c10").arg("--stats").run();
+
+    assert_eq!(transcript.success(), "No rounds played yet.\n");
+}
+
+#[test]
+fn c13_plays_until_script_ends() {
+    let transcript = Session::new("c13")
+        .args(&["--start", "5..5"])
+        .input(&["", "4", "1", "3"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "In this game, you are presented with a random starting number.\n\
+         Each round, you must chose a number in the range 1-3 to subtract from the starting number.\n\
+         The player who reaches 0 is the loser.\n\
+         Press Enter to start the game.\n\
+         The current number is: 5\n\
+         How many do you want to remove? \n\
+         Invalid input. Please enter a number between 1 and 3.\n\
+         How many do you want to remove? \n\
+         Player removed: 1\n\
+         4 left.\n\
+         The current number is: 4\n\
+         Computer removed: 3\n\
+         1 left.\n\
+         The current number is: 1\n\
+         How many do you want to remove? \n\
+         Player removed: 3\n\
+         0 left.\n\
+         You lost!\n"
+    );
+}
+
+#[test]
+fn c14_records_a_lost_game() {
+    // Every card is a Five, so no guess can be right.
+    let mut input = vec![""];
+    input.extend(["H"; 13]);
+    let transcript = Session::new("c14")
+        .args(&["--range", "5..5", "--lives", "1", "--streak", "1"])
+        .input(&input)
+        .run();
+
+    assert!(transcript.success().ends_with(
+        "Sorry, you lost. Better luck next time!\n\
+         Lifetime record: 0 wins, 1 losses.\n\
+         Your best is 0 wins in a row.\n"
+    ));
+}
+
+#[test]
+fn c16_computer_finds_number_by_bisection() {
+    let transcript = Session::new("c16")
+        .input(&["", "n", "H", "L", "L", "H", "L", "L", "C"])
+        .run();
+
+    let stdout = transcript.success();
+    let guesses: Vec<_> = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("The computer guesses: "))
+        .collect();
+    assert_eq!(guesses, ["50", "25", "37", "43", "40", "41", "42"]);
+    assert!(stdout.ends_with("It took the computer 7 attempts to guess the number.\n"));
+}
+
+#[test]
+fn c16_player_guesses_secret_number() {
+    let transcript = Session::new("c16")
+        .args(&["--range", "1..20"])
+        .input(&["", "y", "10", "18", "15"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "This is a guessing gaming. A number is chosen between 1 and 20.\n\
+         The player must guess the number to win.\n\
+         Press Enter to continue.\n\
+         Do you want to be the guesser? (y/n)\n\
+         Enter your guess: \n\
+         Too low!\n\
+         Enter your guess: \n\
+         Too high!\n\
+         Enter your guess: \n\
+         Got it!\n\
+         It took you 3 attempts to guess the number.\n"
+    );
+}
+
+#[test]
+fn c25_hits_then_stands_and_wins() {
+    let transcript = Session::new("c25").input(&["H", "S"]).run();
+
+    assert_eq!(
+        redact_time(transcript.success()),
+        "Your hand: \n\
+         \tThree of Clubs\n\
+         \tFive of Clubs\n\
+         \n\
+         Do you want to hit(H) or stand(S)?\n\
+         You drew: Nine of Hearts\n\
+         Your hand: \n\
+         \tThree of Clubs\n\
+         \tFive of Clubs\n\
+         \tNine of Hearts\n\
+         \n\
+         Do you want to hit(H) or stand(S)?\n\
+         Dealer hand: \n\
+         \tEight of Spades\n\
+         \tSeven of Hearts\n\
+         \n\
+         You win!\n\
+         Time played: M:SS.\n\
+         Lifetime record: 1 wins, 0 losses, 0 ties.\n\
+         Your best is 1 wins in a row.\n"
+    );
+}
+
+#[test]
+fn c25_busts_after_hitting_twice() {
+    let transcript = Session::new("c25").input(&["H", "H"]).run();
+
+    let stdout = transcript.success();
+    assert!(stdout.contains("You drew: Eight of Spades\nBust! Your hand is over 21.\n"));
+    assert!(stdout
+        .ends_with("Lifetime record: 0 wins, 1 losses, 0 ties.\nYour best is 0 wins in a row.\n"));
+}
+
+#[test]
+fn c26_solves_code() {
+    let transcript = Session::new("c26")
+        .input(&["1234", "12", "5678", "8757"])
+        .run();
+
+    assert_eq!(
+        redact_time(transcript.success()),
+        "Enter a 4-digit guess: \n\
+         Correct digits: 0, correct positions: 0\n\
+         Enter a 4-digit guess: \n\
+         Invalid input. Please enter a 4-digit number.\n\
+         Enter a 4-digit guess: \n\
+         Correct digits: 3, correct positions: 0\n\
+         Enter a 4-digit guess: \n\
+         Congratulations! You've guessed the code.\n\
+         Time played: M:SS.\n\
+         Achievement unlocked: Codebreaker!\n"
+    );
+}
+
+#[test]
+fn c26_reveals_code_when_out_of_guesses() {
+    let transcript = Session::new("c26")
+        .args(&["--max-guesses", "1"])
+        .input(&["0000"])
+        .run();
+
+    assert!(redact_time(transcript.success())
+        .ends_with("Out of guesses! The code was 8757.\nTime played: M:SS.\n"));
+}
+
+#[test]
+fn c27_guesses_word() {
+    let transcript = Session::new("c27")
+        .input(&["rust", "r", "x", "u", "s", "t"])
+        .run();
+
+    assert_eq!(
+        redact_time(transcript.success()),
+        "Player 1, enter a word: \n\
+         Word to guess: ****\n\
+         You have 5 lives left - Letter? \n\
+         Word to guess: R***\n\
+         You have 5 lives left - Letter? \n\
+         Word to guess: R***\n\
+         You have 4 lives left - Letter? \n\
+         Word to guess: RU**\n\
+         You have 4 lives left - Letter? \n\
+         Word to guess: RUS*\n\
+         You have 4 lives left - Letter? \n\
+         Congratulations! You've guessed the word: RUST\n\
+         Time played: M:SS.\n\
+         Lifetime record: 1 wins, 0 losses.\n"
+    );
+}
+
+#[test]
+fn c27_takes_lives_from_the_config_file() {
+    let transcript = Session::new("c27")
+        .config("[c27]\nlives = 1\n")
+        .input(&["cat", "z"])
+        .run();
+
+    assert!(transcript
+        .success()
+        .contains("You have 1 lives left - Letter? \nYou've run out of lives."));
+
+    // The flag wins over the file
+    let transcript = Session::new("c27")
+        .config("[c27]\nlives = 1\n")
+        .args(&["--lives", "2"])
+        .input(&["cat", "z", "q"])
+        .run();
+    assert!(transcript.success().contains("You have 2 lives left"));
+}
+
+#[test]
+fn c27_takes_the_difficulty_from_the_config_file() {
+    let transcript = Session::new("c27")
+        .config("difficulty = \"hard\"\n")
+        .input(&["cat", "x", "y", "z"])
+        .run();
+    assert!(transcript.success().contains("You have 3 lives left"));
+
+    let transcript = Session::new("c27")
+        .config("difficulty = \"hard\"\n")
+        .args(&["--difficulty", "normal"])
+        .input(&["cat", "v", "w", "x", "y", "z"])
+        .run();
+    assert!(transcript.success().contains("You have 5 lives left"));
+}
+
+#[test]
+fn c27_warns_about_a_broken_config_file() {
+    let transcript = Session::new("c27")
+        .config("[c27]\nlifes = 1\n")
+        .input(&["a", "a"])
+        .run();
+
+    transcript.success();
+    assert!(transcript
+        .stderr
+        .starts_with("Warning: ignoring config file "));
+}
+
+#[test]
+fn c27_runs_out_of_lives() {
+    let transcript = Session::new("c27")
+        .args(&["--lives", "2"])
+        .input(&["cat", "z", "q"])
+        .run();
+
+    assert!(transcript
+        .success()
+        .contains("You've run out of lives. The word was: CAT\n"));
+}
+
+#[test]
+fn c27_difficulty_sets_lives() {
+    let transcript = Session::new("c27")
+        .args(&["--difficulty", "hard"])
+        .input(&["cat", "z", "y", "x"])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("You have 3 lives left - Letter? \n"));
+    assert!(output.contains("You've run out of lives. The word was: CAT\n"));
+}
+
+#[test]
+fn c27_lives_override_difficulty() {
+    let transcript = Session::new("c27")
+        .args(&["--difficulty", "easy", "--lives", "1"])
+        .input(&["cat", "z"])
+        .run();
+
+    assert!(transcript
+        .success()
+        .contains("You've run out of lives. The word was: CAT\n"));
+}
+
+#[test]
+fn c27_pause_holds_the_prompt() {
+    let transcript = Session::new("c27")
+        .args(&["--lives", "1"])
+        .input(&["cat", "pause", "", "z"])
+        .run();
+
+    assert!(transcript.success().contains(
+        "You have 1 lives left - Letter? \n\
+         Game paused. Press Enter to resume.\n\
+         Game resumed.\n\
+         You've run out of lives."
+    ));
+}
+
+#[test]
+fn c27_accessible_mode_spells_out_the_word() {
+    let transcript = Session::new("c27")
+        .args(&["--accessible", "--lives", "1"])
+        .input(&["cat", "a", "z"])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Word to guess: blank, blank, blank\n"));
+    assert!(output.contains("Word to guess: blank, A, blank\n"));
+}
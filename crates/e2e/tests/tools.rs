@@ -0,0 +1,978 @@
+//! Sessions of the single-shot challenges: calculators and text tools.
+use challenge_e2e::Session;
+
+#[test]
+fn c02_greets_user() {
+    let transcript = Session::new("c02")
+        .args(&["--hour", "9"])
+        .input(&["Ada"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "What is you name?\nGood morning, Ada\n"
+    );
+}
+
+#[test]
+fn c02_greets_user_in_spanish() {
+    let transcript = Session::new("c02")
+        .args(&["--hour", "20", "--lang", "es", "--name", "Ada"])
+        .run();
+
+    assert_eq!(transcript.success(), "Buenas noches, Ada\n");
+}
+
+#[test]
+fn c02_greets_user_in_german() {
+    let transcript = Session::new("c02")
+        .args(&["--hour", "9", "--greeting-lang", "de", "--name", "Ada"])
+        .run();
+
+    assert_eq!(transcript.success(), "Guten Morgen, Ada\n");
+}
+
+#[test]
+fn c02_greets_with_a_template_from_the_config_file() {
+    let transcript = Session::new("c02")
+        .config("[c02.templates]\nyo = \"Yo {name}!\"\nhi = \"Hi {name}.\"\n")
+        .args(&["--template", "yo", "--name", "ada", "--title-case"])
+        .run();
+
+    assert_eq!(transcript.success(), "Yo Ada!\n");
+}
+
+#[test]
+fn c03_computes_cuboid_volume() {
+    let transcript = Session::new("c03")
+        .input(&["2", "2", "3", "4", "1", "3"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Enter 1 for Rectangle, 2 for Cuboid, 3 for Circle, 4 for Triangle, \
+         5 for Triangle from three sides, 6 for Sphere, 7 for Cylinder, 8 for Cone, \
+         9 for Polygon\n\
+         Enter width:Enter height:Enter depth:\
+         Enter 1 for Volume, 2 for Surface area\n\
+         Volume: 24 units³\n\
+         Enter 1 for a new shape, 2 to repeat the last shape with new dimensions, 3 to quit\n\
+         Summary:\n\
+         #  Shape   Dimensions                  Result\n\
+         1  cuboid  width 2, height 3, depth 4  Volume: 24 units³\n"
+    );
+}
+
+#[test]
+fn c03_takes_the_shape_from_the_command_line() {
+    let transcript = Session::new("c03")
+        .args(&["rectangle", "--width", "2", "--height", "3"])
+        .run();
+
+    assert_eq!(transcript.success(), "Area: 6 units²\n");
+}
+
+#[test]
+fn c03_takes_the_measure_from_the_command_line() {
+    let transcript = Session::new("c03")
+        .args(&["cuboid", "--width", "2", "--height", "3", "--depth", "4"])
+        .args(&["--measure", "surface-area"])
+        .run();
+
+    assert_eq!(transcript.success(), "Surface area: 52 units²\n");
+}
+
+#[test]
+fn c03_converts_the_result_to_another_unit() {
+    let transcript = Session::new("c03")
+        .args(&["rectangle", "--width", "2", "--height", "3"])
+        .args(&["--unit", "m", "--to", "cm"])
+        .run();
+
+    assert_eq!(transcript.success(), "Area: 60000 cm²\n");
+}
+
+#[test]
+fn c03_prompts_for_dimensions_left_off_the_command_line() {
+    let transcript = Session::new("c03")
+        .args(&["cuboid", "--width", "2"])
+        .input(&["3", "4"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Enter height:Enter depth:Volume: 24 units³\n"
+    );
+}
+
+#[test]
+fn c04_computes_distance() {
+    let transcript = Session::new("c04").input(&["1", "60", "2"]).run();
+
+    assert!(transcript.success().ends_with("Distance: 120.00 miles\n"));
+}
+
+#[test]
+fn c04_takes_the_query_from_the_command_line() {
+    let transcript = Session::new("c04")
+        .args(&["distance", "--speed", "60", "--time", "2"])
+        .run();
+
+    assert_eq!(transcript.success(), "Distance: 120.00 miles\n");
+}
+
+#[test]
+fn c04_asks_again_after_invalid_query() {
+    let transcript = Session::new("c04").input(&["7", "2", "90", "1.5"]).run();
+    let menu = "Enter query type (1:distance, 2:speed, 3:time, 4:fuel, \
+                5:final speed, 6:distance while accelerating): ";
+
+    assert_eq!(
+        transcript.success(),
+        format!(
+            "{menu}Invalid input. Please enter '1', '2', '3', '4', '5' or '6'.\n\
+             {menu}Enter distance (miles): \
+             Enter time (hours): Speed: 60.00 mph\n"
+        )
+    );
+}
+
+#[test]
+fn c04_computes_time_in_hours_and_minutes() {
+    let transcript = Session::new("c04")
+        .args(&["time", "--distance", "150", "--speed", "60"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Time: 2.50 hours (2 hours 30 minutes)\n"
+    );
+}
+
+#[test]
+fn c04_converts_values_given_with_units() {
+    let transcript = Session::new("c04").input(&["1", "90 km/h", "1h20m"]).run();
+
+    assert!(transcript.success().ends_with("Distance: 74.56 miles\n"));
+}
+
+#[test]
+fn c04_estimates_fuel_and_cost_in_metric_units() {
+    let transcript = Session::new("c04")
+        .args(&["fuel", "--distance", "300 km", "--economy", "6.5 L/100km"])
+        .args(&["--price", "1.80/L"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Fuel: 5.15 gallons (19.50 litres), costing 35.10\n"
+    );
+}
+
+#[test]
+fn c04_stops_a_braking_vehicle_at_rest() {
+    let transcript = Session::new("c04")
+        .args(&["accelerated-distance", "--initial-speed", "60"])
+        .args(&["--acceleration", "-10", "--time", "10s"])
+        .run();
+
+    // Stopped after 6 seconds at an average of 30 mph.
+    assert_eq!(transcript.success(), "Distance: 0.05 miles\n");
+}
+
+#[test]
+fn c04_calculates_a_batch_of_queries() {
+    let dir = tempfile::tempdir().unwrap();
+    let queries = dir.path().join("queries.txt");
+    std::fs::write(&queries, "distance 60 2\ntime 150\n").unwrap();
+
+    let transcript = Session::new("c04")
+        .arg("--batch")
+        .arg(queries.to_str().unwrap())
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Line  Query     Values           Result\n\
+         1     distance  60 mph, 2 hours  120.00 miles\n\
+         2     time                       \
+         error: time needs 2 values (distance (miles), speed (mph)), not 1\n"
+    );
+    assert!(transcript
+        .stderr
+        .ends_with("line 2: time needs 2 values (distance (miles), speed (mph)), not 1\n"));
+}
+
+#[test]
+fn c04_gives_up_after_max_attempts() {
+    let transcript = Session::new("c04")
+        .args(&["--max-attempts", "2"])
+        .input(&["7", "x", "1"])
+        .run();
+
+    assert_eq!(
+        transcript.failure(),
+        "Error: no valid answer after 2 attempts\n"
+    );
+}
+
+#[test]
+fn c04_quits_from_any_prompt() {
+    let transcript = Session::new("c04").input(&["1", "quit"]).run();
+
+    assert_eq!(transcript.failure(), "Error: aborted by the player\n");
+}
+
+#[test]
+fn c05_adds_and_subtracts_spans() {
+    let added = Session::new("c05")
+        .args(&["2024-01-31", "--add", "1m"])
+        .run();
+    let subtracted = Session::new("c05")
+        .args(&["--sub", "6m", "--json"])
+        .input(&["2024-10-15"])
+        .run();
+
+    assert_eq!(added.success(), "Result date: 2024-02-29 (Thursday)\n");
+    assert!(subtracted.success().ends_with(
+        "{\"challenge\":\"c05\",\"input\":{\"date\":\"2024-10-15\",\"sub\":\"6m\"},\
+         \"result\":{\"date\":\"2024-04-15\"}}\n"
+    ));
+}
+
+#[test]
+fn c05_lists_a_recurring_schedule() {
+    // A start date still to come is the first date listed.
+    let transcript = Session::new("c05")
+        .args(&["2999-01-31", "--every", "1m", "--count", "3"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Next 3 dates every 1m from 2999-01-31:\n\
+         2999-01-31 (Thursday)\n\
+         2999-02-28 (Thursday)\n\
+         2999-03-31 (Sunday)\n"
+    );
+}
+
+#[test]
+fn c05_counts_working_days_without_holidays() {
+    let dir = tempfile::tempdir().unwrap();
+    let holidays = dir.path().join("holidays.txt");
+    std::fs::write(&holidays, "# New Year\n2024-01-01\n").unwrap();
+
+    let with = Session::new("c05")
+        .arg("2023-12-29")
+        .args(&["--working-days", "--holidays"])
+        .arg(holidays.to_str().unwrap())
+        .run();
+    let without = Session::new("c05")
+        .args(&["2023-12-29", "--working-days"])
+        .run();
+
+    let working = |output: &str| -> i64 {
+        let line = output.lines().last().unwrap();
+        line.strip_prefix("Working days difference: ")
+            .unwrap()
+            .parse()
+            .unwrap()
+    };
+    // New Year's Day 2024, a Monday, is the only difference.
+    assert_eq!(working(without.success()) - working(with.success()), 1);
+}
+
+#[test]
+fn c05_reports_a_bad_holiday_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let holidays = dir.path().join("holidays.txt");
+    std::fs::write(&holidays, "2024-12-25\nChristmas\n").unwrap();
+
+    let transcript = Session::new("c05")
+        .args(&["2000-01-01", "--working-days", "--holidays"])
+        .arg(holidays.to_str().unwrap())
+        .run();
+
+    assert!(transcript.failure().starts_with(&format!(
+        "Error: {}: line 2: invalid input 'Christmas'",
+        holidays.display()
+    )));
+}
+
+#[test]
+fn c06_sums_up_several_rounds() {
+    // Stopping straight away is about a second early every round.
+    let transcript = Session::new("c06")
+        .args(&["--target", "1", "--rounds", "2"])
+        .input(&["", "", "", ""])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Round 2 of 2\n"));
+    assert!(output.contains("Not long enough! You waited 0.0"));
+    assert!(output.contains("Accuracy: "));
+    let summary = &output[output.find("Summary of 2 rounds:\n").unwrap()..];
+    assert!(summary.contains("\n  Best: -"));
+    assert!(summary.contains("\n  Standard deviation: "));
+}
+
+#[test]
+fn c06_announces_each_random_target() {
+    let transcript = Session::new("c06")
+        .args(&["--random-targets=4..4", "--rounds", "2"])
+        .input(&["", "", "", ""])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Each round has a new target from 4 to 4 seconds.\n"));
+    assert!(output.contains("Round 1 of 2: your target is 4 seconds\n"));
+    assert!(output.contains("Round 2 of 2: your target is 4 seconds\n"));
+    assert!(output.contains("Accuracy so far: "));
+    assert!(output.contains("\n  Overall accuracy: "));
+}
+
+#[test]
+fn c06_stops_the_distraction_when_the_player_does() {
+    let transcript = Session::new("c06")
+        .args(&["--target", "1", "--distract", "jokes"])
+        .input(&["", ""])
+        .run();
+
+    assert!(transcript
+        .success()
+        .contains("Start!\nStop!\nNot long enough! "));
+}
+
+#[test]
+fn c07_types_a_text_from_a_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let texts = dir.path().join("texts.txt");
+    std::fs::write(&texts, "# Only one\nTalk is cheap.\n").unwrap();
+
+    let transcript = Session::new("c07")
+        .arg("--texts")
+        .arg(texts.to_str().unwrap())
+        .input(&["", "talk is cheap.", "", "Talk  is cheap.", "q"])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Type this: Talk is cheap.\n"));
+    assert!(output.contains(
+        "You didn't type it correctly. Try again!\n\
+         1 mistake:\n\
+         Expected:  Talk is cheap.\n\
+         You typed: talk is cheap.\n           \
+         ^\n"
+    ));
+    assert!(output.contains("You typed it in "));
+    assert!(output.contains(" WPM\nThat's your first time typing this text.\n"));
+    assert!(!output.contains("all-time best"));
+}
+
+#[test]
+fn c07_compares_each_attempt_with_the_best_for_its_text() {
+    let transcript = Session::new("c07")
+        .input(&[
+            "",
+            "abcdefghijklmnopqrstuvwxyz",
+            "",
+            "abcdefghijklmnopqrstuvwxyz",
+            "q",
+        ])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("That's your first time typing this text.\n"));
+    assert!(
+        output.contains(" s faster than your best (+")
+            || output.contains(" s slower than your best of ")
+    );
+}
+
+#[test]
+fn c07_reports_a_file_without_texts() {
+    let dir = tempfile::tempdir().unwrap();
+    let texts = dir.path().join("texts.txt");
+    std::fs::write(&texts, "# Nothing yet\n").unwrap();
+
+    let transcript = Session::new("c07")
+        .arg("--texts")
+        .arg(texts.to_str().unwrap())
+        .run();
+
+    assert_eq!(
+        transcript.failure(),
+        format!("Error: {}: the file has no texts\n", texts.display())
+    );
+}
+
+#[test]
+fn c07_reports_on_a_session_of_rounds() {
+    let transcript = Session::new("c07")
+        .args(&["--rounds", "3"])
+        .input(&[
+            "",
+            "abcdefghijklmnopqrstuvwxyz",
+            "abcdefghijklmnopqrstuvwxy",
+            "abcdefghijklmnopqrstuvwxyz",
+        ])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Round 3 of 3\n"));
+    assert!(!output.contains("Press Enter to play again"));
+    let report = &output[output.find("Session report:\n").unwrap()..];
+    assert!(report.starts_with("Session report:\n  Attempts: 3\n  Correct: 2 (67%)\n"));
+    assert!(report.contains("\n  Average time: "));
+    assert!(report.contains("\n  Trend: "));
+}
+
+#[test]
+fn c07_asks_for_the_exact_text_when_harder() {
+    let transcript = Session::new("c07")
+        .args(&["--difficulty", "medium"])
+        .input(&[
+            "",
+            "abcdefghijklmnopqrstuvwxyz",
+            "",
+            "aBcDeFgHiJkLmNoPqRsTuVwXyZ",
+            "q",
+        ])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Type this: aBcDeFgHiJkLmNoPqRsTuVwXyZ\n"));
+    assert!(output.contains("You didn't type the alphabet correctly. Try again!\n13 mistakes:\n"));
+    assert!(output.contains("You typed the alphabet in "));
+}
+
+#[test]
+fn c07_drills_the_characters_given() {
+    let transcript = Session::new("c07")
+        .args(&["--drill-chars", "x", "--length", "7"])
+        .input(&["", "xxxxxxx", "", "xxxxx xx", "q"])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains("Type this: xxxxx xx\n"));
+    assert!(output.contains("You didn't type it correctly. Try again!\n1 mistake:\n"));
+    assert!(output.contains("You typed it in "));
+    assert!(!output.contains("your best"));
+}
+
+#[test]
+fn c07_needs_a_terminal_to_type_live() {
+    let transcript = Session::new("c07").args(&["--live", "--histogram"]).run();
+
+    assert_eq!(
+        transcript.failure(),
+        "Error: --live needs a terminal to type in\n"
+    );
+}
+
+#[test]
+fn c11_prompts_for_gate_and_inputs() {
+    let transcript = Session::new("c11").input(&["xor", "1", "0"]).run();
+
+    assert_eq!(
+        transcript.success(),
+        "Enter the type of gate you want to create (and, or, xor, nand, nor): \n\
+         Enter the value for input A (1 or 0): \n\
+         Enter the value for input B (1 or 0): \n\
+         Result: true\n"
+    );
+}
+
+#[test]
+fn c19_encrypts_text() {
+    let transcript = Session::new("c19").input(&["e", "Hello", "1"]).run();
+
+    assert!(transcript.success().ends_with("encryption result: Ifmmp\n"));
+}
+
+#[test]
+fn c19_takes_the_mode_shift_and_text_from_the_command_line() {
+    let transcript = Session::new("c19")
+        .args(&["encrypt", "--shift", "5", "hello"])
+        .run();
+
+    assert_eq!(transcript.success(), "encryption result: mjqqt\n");
+}
+
+#[test]
+fn c19_decrypts_with_a_negative_shift() {
+    let transcript = Session::new("c19")
+        .args(&["decrypt", "-s", "-1", "Hello"])
+        .run();
+
+    assert_eq!(transcript.success(), "decryption result: Ifmmp\n");
+}
+
+#[test]
+fn c21_reports_duplicates_in_order() {
+    let transcript = Session::new("c21")
+        .input(&["cy", "bob", "ann", "bob", "ann", "ann", "exit"])
+        .run();
+
+    assert!(transcript
+        .success()
+        .ends_with("ann has 3 duplicates.\nbob has 2 duplicates.\n"));
+}
+
+#[test]
+fn c22_prints_palette_indices_without_color() {
+    let transcript = Session::new("c22")
+        .args(&["--size", "3", "--seed", "1", "--no-color"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "  13  15  12\n  11   3   6\n   3   2  12\n130\n530\n320\n"
+    );
+}
+
+#[test]
+fn c12_prints_json_with_prompts_on_stderr() {
+    let transcript = Session::new("c12")
+        .args(&["--format", "json"])
+        .input(&["12"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "{\"challenge\":\"c12\",\"input\":{\"number\":12},\
+         \"result\":{\"factors\":[1,2,3,4,6,12],\"prime\":false}}\n"
+    );
+    assert_eq!(transcript.stderr, "Enter a number: \n");
+}
+
+#[test]
+fn c12_prompts_in_spanish() {
+    let transcript = Session::new("c12")
+        .args(&["--lang", "es"])
+        .input(&["doce", "12"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "Introduce un número: \n\
+         Entrada no válida. Introduce un número entero.\n\
+         Introduce un número: \n\
+         Los factores de 12 son: [1, 2, 3, 4, 6, 12]\n"
+    );
+}
+
+#[test]
+fn c17_prints_json_grades() {
+    let transcript = Session::new("c17")
+        .args(&["--format", "json"])
+        .input(&["85", "62"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "{\"challenge\":\"c17\",\"input\":{\"module1\":85,\"module2\":62},\
+         \"result\":{\"as_level\":\"B\",\"module1\":\"A\",\"module2\":\"C\"}}\n"
+    );
+}
+
+#[test]
+fn c12_takes_the_number_from_the_command_line() {
+    let transcript = Session::new("c12").args(&["28"]).run();
+
+    assert_eq!(
+        transcript.success(),
+        "Factors of 28 are: [1, 2, 4, 7, 14, 28]\n"
+    );
+}
+
+#[test]
+fn c17_takes_the_scores_from_the_command_line() {
+    let transcript = Session::new("c17").args(&["85", "62"]).run();
+
+    assert_eq!(
+        transcript.success(),
+        "Result: \nModule 1: A\nModule 2: C\nAS Level: B\n"
+    );
+}
+
+#[test]
+fn c20_takes_the_index_from_the_command_line() {
+    let transcript = Session::new("c20").args(&["--format", "json", "50"]).run();
+
+    assert_eq!(
+        transcript.success(),
+        "{\"challenge\":\"c20\",\"input\":{\"index\":50},\
+         \"result\":{\"fibonacci\":\"12586269025\"}}\n"
+    );
+    assert_eq!(transcript.stderr, "");
+}
+
+#[test]
+fn c03_json_flag_prints_json() {
+    let transcript = Session::new("c03")
+        .args(&["--json", "rectangle", "--width", "2", "--height", "3"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "{\"challenge\":\"c03\",\"input\":{\"height\":3.0,\"shape\":\"rectangle\",\"width\":2.0},\
+         \"result\":{\"area\":6.0}}\n"
+    );
+}
+
+#[test]
+fn c03_json_flag_can_follow_the_shape() {
+    let transcript = Session::new("c03")
+        .args(&[
+            "cuboid", "--width", "1", "--height", "2", "--depth", "3", "--json",
+        ])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "{\"challenge\":\"c03\",\"input\":{\"depth\":3.0,\"height\":2.0,\"shape\":\"cuboid\",\
+         \"width\":1.0},\"result\":{\"volume\":6.0}}\n"
+    );
+}
+
+#[test]
+fn c03_compares_two_shapes() {
+    let transcript = Session::new("c03")
+        .arg("--compare")
+        .input(&["2", "2", "3", "4", "1", "2", "3", "3", "3"])
+        .run();
+
+    assert!(transcript.success().ends_with(
+        "First shape: Volume: 24 units³\n\
+         Second shape: Volume: 27 units³\n\
+         The second shape is bigger by 3 units³ (12.5% more)\n"
+    ));
+}
+
+#[test]
+fn c03_works_out_a_csv_file_of_shapes() {
+    let dir = tempfile::tempdir().unwrap();
+    let shapes = dir.path().join("shapes.csv");
+    let results = dir.path().join("results.csv");
+    std::fs::write(&shapes, "rectangle,2.5,4\ncuboid,1,2\ncuboid,1,2,3\n").unwrap();
+
+    let transcript = Session::new("c03")
+        .arg("--input")
+        .arg(shapes.to_str().unwrap())
+        .arg("--output")
+        .arg(results.to_str().unwrap())
+        .run();
+
+    assert_eq!(transcript.success(), "");
+    assert!(transcript
+        .stderr
+        .ends_with("line 2: cuboid needs 3 dimensions (width, height, depth), not 2\n"));
+    assert_eq!(
+        std::fs::read_to_string(&results).unwrap(),
+        "line,shape,measure,value,unit,error\n\
+         1,rectangle,area,10,units²,\n\
+         2,cuboid,,,,\"cuboid needs 3 dimensions (width, height, depth), not 2\"\n\
+         3,cuboid,volume,6,units³,\n"
+    );
+}
+
+#[test]
+fn c03_refuses_json_for_a_csv_file_of_shapes() {
+    let dir = tempfile::tempdir().unwrap();
+    let shapes = dir.path().join("shapes.csv");
+    std::fs::write(&shapes, "rectangle,2.5,4\n").unwrap();
+
+    let transcript = Session::new("c03")
+        .args(&["--format", "json", "--input", shapes.to_str().unwrap()])
+        .run();
+
+    assert_eq!(transcript.stdout, "");
+    assert_eq!(
+        transcript.stderr,
+        "Error: --input writes its results as CSV, not --format json\n"
+    );
+}
+
+#[test]
+fn c03_json_reports_the_units() {
+    let transcript = Session::new("c03")
+        .args(&[
+            "--json", "circle", "--radius", "1", "--unit", "cm", "--to", "mm",
+        ])
+        .run();
+
+    assert!(transcript
+        .success()
+        .contains("\"radius\":1.0,\"shape\":\"circle\",\"unit\":\"cm\""));
+    assert!(transcript.success().ends_with(",\"unit\":\"mm²\"}}\n"));
+}
+
+#[test]
+fn c08_takes_the_birth_date_from_the_command_line() {
+    let transcript = Session::new("c08").args(&["1970-01-01"]).run();
+
+    assert_eq!(transcript.success(), "You are eligible to vote!\n");
+}
+
+#[test]
+fn c08_uses_the_voting_age_of_the_country() {
+    let transcript = Session::new("c08")
+        .args(&["1970-01-01", "--country", "AT"])
+        .run();
+    assert_eq!(
+        transcript.success(),
+        "You are eligible to vote in Austria!\n"
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let rules = dir.path().join("rules.toml");
+    std::fs::write(
+        &rules,
+        "[[jurisdiction]]\ncode = \"at\"\nname = \"Austria\"\nvoting_age = 150\n",
+    )
+    .unwrap();
+    let transcript = Session::new("c08")
+        .args(&["1970-01-01", "--country", "at", "--rules"])
+        .arg(rules.to_str().unwrap())
+        .run();
+    assert!(transcript.success().starts_with(
+        "You are not eligible to vote in Austria, where the voting age is 150.\n\
+         You will be eligible on 2120-01-01, in "
+    ));
+}
+
+#[test]
+fn c08_shows_the_date_eligibility_begins() {
+    let transcript = Session::new("c08")
+        .args(&["2999-02-28", "--country", "at"])
+        .run();
+
+    let output = transcript.success();
+    assert!(
+        output.starts_with(
+            "You are not eligible to vote in Austria, where the voting age is 16.\n\
+             You will be eligible on 3015-02-28, in "
+        ),
+        "{output}"
+    );
+}
+
+#[test]
+fn c08_moves_a_leap_day_birthday_by_policy() {
+    let eligible_on = |policy: &str| {
+        let transcript = Session::new("c08")
+            .args(&["2996-02-29", "--leap-day", policy])
+            .run();
+        let output = transcript.success();
+        let line = output.lines().nth(1).unwrap();
+        line["You will be eligible on ".len()..][..10].to_string()
+    };
+
+    assert_eq!(eligible_on("feb-28"), "3014-02-28");
+    assert_eq!(eligible_on("mar-1"), "3014-03-01");
+}
+
+#[test]
+fn c08_reports_each_eligibility_in_a_table() {
+    let transcript = Session::new("c08")
+        .args(&["1970-01-01", "--country", "jp", "--report"])
+        .run();
+
+    assert_eq!(
+        transcript.success(),
+        "You are eligible to vote in Japan!\n\n\
+         Item         From age  Eligible\n\
+         Vote               18  yes\n\
+         Drive              18  yes\n\
+         Buy alcohol        20  yes\n\
+         Retire             65  no\n"
+    );
+}
+
+#[test]
+fn c08_checks_a_csv_file_of_people() {
+    let dir = tempfile::tempdir().unwrap();
+    let people = dir.path().join("people.csv");
+    let results = dir.path().join("results.csv");
+    std::fs::write(
+        &people,
+        "name,birth_date\nAda,1970-01-01\nBo,2999-01-01\nCy,x\n",
+    )
+    .unwrap();
+
+    let transcript = Session::new("c08")
+        .args(&["--country", "sg", "--input"])
+        .arg(people.to_str().unwrap())
+        .arg("--output")
+        .arg(results.to_str().unwrap())
+        .run();
+
+    assert_eq!(transcript.success(), "");
+    assert!(transcript.stderr.ends_with(
+        "line 4: invalid input 'x': input contains invalid characters\n\
+                    1 eligible, 1 not eligible, 1 with errors\n"
+    ));
+    let results = std::fs::read_to_string(&results).unwrap();
+    let lines: Vec<_> = results.lines().collect();
+    assert_eq!(
+        lines[0],
+        "line,name,birth_date,age,eligible,eligible_from,error"
+    );
+    assert!(lines[1].starts_with("2,Ada,1970-01-01,"));
+    assert!(lines[1].ends_with(",yes,1991-01-01,"));
+    assert!(lines[2].ends_with(",no,3020-01-01,"));
+    assert_eq!(
+        lines[3],
+        "4,Cy,,,,,invalid input 'x': input contains invalid characters"
+    );
+}
+
+#[test]
+fn c08_reports_an_unknown_country() {
+    let transcript = Session::new("c08")
+        .args(&["1970-01-01", "--country", "xx"])
+        .run();
+
+    assert!(transcript
+        .failure()
+        .starts_with("Error: country 'xx' is not known (known: ar, at, au,"));
+}
+
+#[test]
+fn c09_deals_sorted_hands() {
+    let transcript = Session::new("c09")
+        .args(&["--hand", "5", "--deck", "--plain"])
+        .input(&["52", "no"])
+        .run();
+
+    let output = transcript.success();
+    let hands: Vec<_> = output.split("Your hand is:\n").skip(1).collect();
+    assert_eq!(hands.len(), 2);
+    assert!(hands[0].contains("(47 cards left in the deck)"));
+    assert!(hands[0].ends_with("Too few cards are left for the hand, so a new deck is shuffled.\n"));
+    let whole_deck: Vec<_> = hands[1]
+        .lines()
+        .filter_map(|line| line.strip_prefix("  "))
+        .collect();
+    assert_eq!(whole_deck.len(), 52);
+    assert_eq!(whole_deck[0], "Ace of Hearts");
+    assert_eq!(whole_deck[51], "King of Spades");
+    assert!(hands[1].contains(
+        "It holds four Kings, four Queens, four Jacks, four Tens, four Nines, four Eights, \
+         four Sevens, four Sixes, four Fives, four Fours, four Threes, four Twos, four Aces, \
+         a flush in Hearts, a flush in Diamonds, a flush in Clubs and a flush in Spades.\n"
+    ));
+}
+
+#[test]
+fn c09_deals_from_several_decks_with_jokers() {
+    let transcript = Session::new("c09")
+        .args(&["--decks", "2", "--jokers", "--hand", "50"])
+        .input(&["yes", "yes", "no"])
+        .run();
+
+    let output = transcript.success();
+    let left: Vec<_> = output
+        .lines()
+        .filter(|line| line.ends_with("left in the deck)"))
+        .collect();
+    assert_eq!(
+        left,
+        [
+            "(58 cards left in the deck)",
+            "(8 cards left in the deck)",
+            "(58 cards left in the deck)"
+        ]
+    );
+    assert_eq!(output.matches("Too few cards are left").count(), 1);
+}
+
+#[test]
+fn c09_shows_the_odds_of_the_next_card() {
+    let transcript = Session::new("c09")
+        .args(&["--odds", "--hand", "52"])
+        .input(&["1", "no"])
+        .run();
+
+    let output = transcript.success();
+    assert!(output.contains(
+        "(0 cards left in the deck)\n\
+         No cards are left, so the next comes from a new deck.\n"
+    ));
+    let odds = output.split("Odds of the next card:\n").nth(1).unwrap();
+    let red = odds.lines().next().unwrap();
+    assert!(
+        red == "  Red: 25 in 51 (49.0%)" || red == "  Red: 26 in 51 (51.0%)",
+        "{red}"
+    );
+}
+
+#[test]
+fn c09_deals_a_whole_deck_before_repeating_a_card() {
+    let mut answers = vec!["yes"; 52];
+    answers.push("no");
+    let transcript = Session::new("c09")
+        .args(&["--deck", "--seed", "9"])
+        .input(&answers)
+        .run();
+
+    let output = transcript.success();
+    let cards: Vec<_> = output
+        .lines()
+        .filter_map(|line| line.strip_prefix("Your card is: "))
+        .collect();
+    assert_eq!(cards.len(), 53);
+    let first_deck: std::collections::HashSet<_> = cards[..52]
+        .iter()
+        .map(|card| card.split(" (").next().unwrap())
+        .collect();
+    assert_eq!(first_deck.len(), 52);
+    assert!(first_deck
+        .iter()
+        .all(|card| card.ends_with(['♥', '♦', '♣', '♠'])));
+    assert!(cards[0].ends_with("(51 cards left in the deck)"));
+    assert!(cards[50].ends_with("(1 card left in the deck)"));
+    assert!(output.contains(
+        "(0 cards left in the deck)\n\
+         Do you want another card? (yes/no, or a number of cards for a hand)\n\
+         The deck has run out, so a new one is shuffled.\n"
+    ));
+}
+
+#[test]
+fn c09_deals_the_same_cards_with_the_same_seed() {
+    let deal = |seed: &str| {
+        Session::new("c09")
+            .args(&["--deck", "--jokers", "--seed", seed])
+            .input(&["yes", "5", "yes", "no"])
+            .run()
+            .success()
+            .to_string()
+    };
+
+    assert_eq!(deal("7"), deal("7"));
+    assert_ne!(deal("7"), deal("8"));
+}
+
+#[test]
+fn c09_draws_card_faces_side_by_side() {
+    let transcript = Session::new("c09")
+        .args(&["--faces", "--hand", "3"])
+        .input(&["no"])
+        .run();
+
+    let output = transcript.success();
+    let faces: Vec<_> = output
+        .lines()
+        .skip_while(|line| *line != "Your hand is:")
+        .skip(1)
+        .take(7)
+        .collect();
+    assert_eq!(faces[0], "+-------+ +-------+ +-------+");
+    assert_eq!(faces[6], faces[0]);
+    assert!(faces[1..6]
+        .iter()
+        .all(|line| line.chars().count() == 29 && line.starts_with('|')));
+    assert_eq!(faces[3].matches(['♥', '♦', '♣', '♠']).count(), 3);
+}
@@ -0,0 +1,98 @@
+//! # Challenge Error
+//!
+//! The error type returned by challenge code that parses and validates user
+//! input. Each failure is a distinct [`ChallengeError`] variant so that callers
+//! and tests can match on what went wrong instead of comparing messages.
+use std::fmt::Display;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, ChallengeError>;
+
+#[derive(Debug, Error)]
+pub enum ChallengeError {
+    /// The input could not be parsed as the expected type.
+    #[error("invalid input '{input}': {reason}")]
+    ParseError { input: String, reason: String },
+
+    /// The input parsed but is not an acceptable value for `field`.
+    #[error("{field} {reason}")]
+    ValidationError { field: String, reason: String },
+
+    /// Reading input or writing output failed.
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    /// A number fell outside the inclusive range `min..=max`.
+    #[error("value must be between {min} and {max}")]
+    OutOfRange { min: i64, max: i64 },
+}
+
+impl ChallengeError {
+    /// Creates a [`ChallengeError::ParseError`] for `input`, keeping the
+    /// message of the error that caused it.
+    pub fn parse(input: &str, reason: impl Display) -> Self {
+        ChallengeError::ParseError {
+            input: input.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Creates a [`ChallengeError::ValidationError`].
+    pub fn validation(field: &str, reason: &str) -> Self {
+        ChallengeError::ValidationError {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Parses `input` as a `T`, reporting failure as a
+/// [`ChallengeError::ParseError`].
+pub fn parse<T>(input: &str) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    input.parse().map_err(|e| ChallengeError::parse(input, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_returns_value_for_valid_input() {
+        assert_eq!(parse::<u32>("42").unwrap(), 42);
+    }
+
+    #[test]
+    fn parse_keeps_input_and_reason() {
+        match parse::<u32>("forty") {
+            Err(ChallengeError::ParseError { input, reason }) => {
+                assert_eq!(input, "forty");
+                assert_eq!(reason, "invalid digit found in string");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn validation_error_displays_field_and_reason() {
+        let err = ChallengeError::validation("width", "must be greater than zero");
+        assert_eq!(err.to_string(), "width must be greater than zero");
+    }
+
+    #[test]
+    fn io_errors_convert_and_display_unchanged() {
+        let io = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "unexpected end of input");
+        let err = ChallengeError::from(io);
+        assert!(matches!(err, ChallengeError::IoError(_)));
+        assert_eq!(err.to_string(), "unexpected end of input");
+    }
+
+    #[test]
+    fn out_of_range_displays_bounds() {
+        let err = ChallengeError::OutOfRange { min: 0, max: 100 };
+        assert_eq!(err.to_string(), "value must be between 0 and 100");
+    }
+}
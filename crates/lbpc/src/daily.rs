@@ -0,0 +1,188 @@
+//! The `daily` command: the same puzzle for every player, once a day.
+use crate::play;
+use crate::registry;
+use challenge_storage::daily::{self, Record, GAMES};
+use std::io::Write;
+use std::path::Path;
+
+/// Returns the name a daily challenge game is shown under.
+fn name_of(game: &str) -> &'static str {
+    GAMES
+        .iter()
+        .find(|(id, _)| *id == game)
+        .map_or("Unknown", |(_, name)| name)
+}
+
+/// The options that pin `game` to its classic rules on `today`, so that no
+/// default the player set with an environment variable or in the config file
+/// changes the puzzle.
+fn pinned_args(game: &str, today: u64) -> Vec<String> {
+    let mut args = vec![
+        "--seed".to_string(),
+        today.to_string(),
+        "--difficulty".to_string(),
+        "normal".to_string(),
+    ];
+    let rules: &[&str] = match game {
+        "c16" => &["--range", "1..100"],
+        "c23" => &["--grid-size", "10"],
+        _ => &[],
+    };
+    args.extend(rules.iter().map(|arg| arg.to_string()));
+    args
+}
+
+/// Describes how `record` stands on `today`.
+fn describe(record: &Record, today: u64) -> String {
+    let result = if !record.played_on(today) {
+        "not played yet"
+    } else if record.last_won {
+        "won"
+    } else {
+        "lost"
+    };
+    format!(
+        "{}, streak {} (best {})",
+        result,
+        record.streak_on(today),
+        record.best_streak
+    )
+}
+
+/// Writes the state of `today`'s challenge in every game, using the results
+/// kept in `dir`.
+pub fn print_status<W: Write>(
+    writer: &mut W,
+    dir: &Path,
+    today: u64,
+) -> challenge_storage::Result<()> {
+    writeln!(writer, "Daily challenge for {}", daily::format_day(today))?;
+    for (game, name) in GAMES {
+        let record = daily::record_of(dir, game)?;
+        writeln!(
+            writer,
+            "  {} ({}): {}",
+            name,
+            game,
+            describe(&record, today)
+        )?;
+    }
+    writeln!(writer, "\nPlay one with `lbpc daily <GAME>`.")?;
+    Ok(())
+}
+
+/// Plays `today`'s challenge in `game`, unless it was already played, and
+/// reports the streak from the results kept in `dir`.
+pub fn play<W: Write>(
+    writer: &mut W,
+    dir: &Path,
+    game: &str,
+    today: u64,
+) -> challenge_storage::Result<()> {
+    let name = name_of(game);
+    let record = daily::record_of(dir, game)?;
+    if record.played_on(today) {
+        writeln!(
+            writer,
+            "You've already played today's {} challenge ({}). Come back tomorrow!",
+            name,
+            describe(&record, today)
+        )?;
+        return Ok(());
+    }
+
+    // The day doubles as the seed, and the rules are pinned to the classic
+    // ones, so everyone gets the same puzzle.
+    writeln!(
+        writer,
+        "{} daily challenge for {}",
+        name,
+        daily::format_day(today)
+    )?;
+    writer.flush()?;
+    let challenge = registry::find(game).ok_or_else(|| format!("unknown game '{}'", game))?;
+    std::env::set_var(daily::DAY_ENV_VAR, today.to_string());
+    // A game the player quits still counts, so the streak is shown either way.
+    let result = play::run(challenge, &pinned_args(game, today));
+
+    let record = daily::record_of(dir, game)?;
+    if record.played_on(today) {
+        writeln!(
+            writer,
+            "Daily streak: {} (best {}).",
+            record.streak, record.best_streak
+        )?;
+    } else {
+        writeln!(writer, "Today's result could not be saved.")?;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(dir: &Path, today: u64) -> String {
+        let mut output = Vec::new();
+        print_status(&mut output, dir, today).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn print_status_lists_every_daily_game() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = status(dir.path(), 20_161);
+        assert!(output.starts_with("Daily challenge for 2025-03-14\n"));
+        for (game, name) in GAMES {
+            assert!(output.contains(&format!(
+                "  {name} ({game}): not played yet, streak 0 (best 0)\n"
+            )));
+        }
+    }
+
+    #[test]
+    fn print_status_shows_todays_results_and_streaks() {
+        let dir = tempfile::tempdir().unwrap();
+        daily::record_in(dir.path(), "c26", 20_160, true).unwrap();
+        daily::record_in(dir.path(), "c26", 20_161, true).unwrap();
+        daily::record_in(dir.path(), "c23", 20_161, false).unwrap();
+
+        let output = status(dir.path(), 20_161);
+        assert!(output.contains("  Mastermind (c26): won, streak 2 (best 2)\n"));
+        assert!(output.contains("  Treasure Hunt (c23): lost, streak 0 (best 0)\n"));
+
+        let output = status(dir.path(), 20_163);
+        assert!(output.contains("  Mastermind (c26): not played yet, streak 0 (best 2)\n"));
+    }
+
+    #[test]
+    fn pinned_args_override_the_players_defaults() {
+        assert_eq!(
+            pinned_args("c16", 20_161),
+            [
+                "--seed",
+                "20161",
+                "--difficulty",
+                "normal",
+                "--range",
+                "1..100"
+            ]
+        );
+        assert_eq!(pinned_args("c23", 20_161)[4..], ["--grid-size", "10"]);
+        assert_eq!(pinned_args("c26", 20_161).len(), 4);
+    }
+
+    #[test]
+    fn play_refuses_a_second_attempt_on_the_same_day() {
+        let dir = tempfile::tempdir().unwrap();
+        daily::record_in(dir.path(), "c16", 20_161, true).unwrap();
+
+        let mut output = Vec::new();
+        play(&mut output, dir.path(), "c16", 20_161).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "You've already played today's Number Guessing challenge \
+             (won, streak 1 (best 1)). Come back tomorrow!\n"
+        );
+    }
+}
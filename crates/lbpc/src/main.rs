@@ -0,0 +1,107 @@
+//! # LBPC
+//!
+//! The launcher for the challenges. Run without a command, it lists every
+//! challenge and runs the ones picked from the list, coming back to it after
+//! each, or shows the list full-screen with `--tui`. It also gathers what the individual games keep between runs into
+//! views that span all of them.
+//!
+//! ## Commands
+//!
+//! - `list`: Every challenge with its number, name and description
+//! - `play`: Run one challenge, picked by number or name
+//! - `stats`: Combined profile of every game's scores and the achievements
+//!   unlocked so far
+//! - `daily`: A puzzle seeded from the date, the same for every player, with
+//!   a streak for each day won in a row
+//! - `serve`: The menu and every game over TCP, for players connecting with
+//!   `telnet` or `nc`
+mod daily;
+mod play;
+mod registry;
+mod serve;
+mod stats;
+#[cfg(feature = "tui")]
+mod tui;
+
+use challenge_storage::daily::GAMES as DAILY_GAMES;
+use clap::builder::PossibleValuesParser;
+use clap::{Parser, Subcommand};
+use registry::Challenge;
+
+/// Launcher for the Little Book of Programming Challenges.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Show the menu in a full-screen terminal UI
+    #[cfg(feature = "tui")]
+    #[arg(long)]
+    tui: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List every challenge
+    List,
+    /// Run a challenge
+    Play {
+        /// Number or name of the challenge, e.g. `26` or `mastermind`
+        #[arg(value_parser = parse_challenge)]
+        challenge: &'static Challenge,
+        /// Arguments passed on to the challenge
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Show your scores and achievements across all games
+    Stats,
+    /// Play today's challenge in a game, or show today's results without one
+    Daily {
+        /// Game to play today's challenge in
+        #[arg(value_parser = PossibleValuesParser::new(DAILY_GAMES.map(|(game, _)| game)))]
+        game: Option<String>,
+    },
+    /// Serve the menu and the games to players connecting over TCP
+    Serve {
+        /// Address to listen on, e.g. 0.0.0.0:2323 to let other machines
+        /// connect
+        #[arg(long, default_value = serve::DEFAULT_ADDR)]
+        addr: String,
+    },
+}
+
+fn parse_challenge(s: &str) -> Result<&'static Challenge, String> {
+    registry::find(s).ok_or_else(|| format!("no single challenge matches '{s}'"))
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let result = match args.command {
+        #[cfg(feature = "tui")]
+        None if args.tui => tui::run(),
+        None => play::menu(
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout(),
+            |challenge| play::run(challenge, &[]),
+        )
+        .map_err(Into::into),
+        Some(Command::List) => play::list(&mut std::io::stdout()).map_err(Into::into),
+        Some(Command::Play { challenge, args }) => play::run(challenge, &args),
+        Some(Command::Stats) => {
+            challenge_storage::data_dir().and_then(|dir| stats::print(&mut std::io::stdout(), &dir))
+        }
+        Some(Command::Daily { game }) => challenge_storage::data_dir().and_then(|dir| {
+            let today = challenge_storage::daily::today()?;
+            match game {
+                Some(game) => daily::play(&mut std::io::stdout(), &dir, &game, today),
+                None => daily::print_status(&mut std::io::stdout(), &dir, today),
+            }
+        }),
+        Some(Command::Serve { addr }) => serve::serve(&addr),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
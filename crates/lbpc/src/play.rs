@@ -0,0 +1,163 @@
+//! The `list` and `play` commands, and the menu shown when the launcher is
+//! run without a command.
+use crate::registry::{self, Challenge, CHALLENGES};
+use challenge_common::prompt::{self, Prompt};
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Returns the path of the `game` binary, which is expected next to the
+/// launcher. Only `serve` needs it, to give each connection a game of its
+/// own.
+pub fn game_binary(game: &str) -> challenge_storage::Result<PathBuf> {
+    let exe = std::env::current_exe()?;
+    let path = exe.with_file_name(format!("{game}{}", std::env::consts::EXE_SUFFIX));
+    if !path.exists() {
+        return Err(format!(
+            "{} not found; build all the challenges with `cargo build` first",
+            path.display()
+        )
+        .into());
+    }
+    Ok(path)
+}
+
+/// Writes every challenge with its number, name and description.
+pub fn list<W: Write>(writer: &mut W) -> std::io::Result<()> {
+    for challenge in &CHALLENGES {
+        writeln!(
+            writer,
+            "{:>3}. {}: {}",
+            challenge.number(),
+            challenge.name,
+            challenge.description
+        )?;
+    }
+    Ok(())
+}
+
+/// Runs `challenge` in-process with `args` until it finishes. The challenge
+/// shares the launcher's terminal.
+pub fn run(challenge: &Challenge, args: &[String]) -> challenge_storage::Result<()> {
+    let argv = std::iter::once(challenge.id)
+        .chain(args.iter().map(String::as_str))
+        .map(OsString::from)
+        .collect();
+    let status = (challenge.run)(argv);
+    if status != ExitCode::SUCCESS {
+        return Err(format!("{} exited with an error", challenge.id).into());
+    }
+    Ok(())
+}
+
+/// What the player picked from the menu.
+enum Choice {
+    List,
+    Play(&'static Challenge),
+}
+
+fn parse_choice(answer: &str) -> Result<Choice, String> {
+    if answer.eq_ignore_ascii_case("list") {
+        return Ok(Choice::List);
+    }
+    registry::find(answer)
+        .map(Choice::Play)
+        .ok_or_else(|| format!("No single challenge matches '{}'.", answer))
+}
+
+/// Lists the challenges and runs the ones the player picks with `launch`
+/// until they quit or the input ends. A challenge that fails is reported
+/// and the menu carries on.
+pub fn menu<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    mut launch: impl FnMut(&Challenge) -> challenge_storage::Result<()>,
+) -> std::io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    writeln!(writer, "Little Book of Programming Challenges\n")?;
+    list(writer)?;
+    let prompt = Prompt::new("\nPick a challenge by number or name (list, quit): ").inline();
+    loop {
+        let choice = match prompt.ask(reader, writer, parse_choice) {
+            Ok(choice) => choice,
+            Err(e) if prompt::is_abort(&e) || e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                return Ok(())
+            }
+            Err(e) => return Err(e),
+        };
+        match choice {
+            Choice::List => list(writer)?,
+            Choice::Play(challenge) => {
+                writeln!(writer, "\n{} ({})\n", challenge.name, challenge.id)?;
+                writer.flush()?;
+                if let Err(e) = launch(challenge) {
+                    writeln!(writer, "Error: {}", e)?;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs the menu on `input` and returns what it wrote and the challenges
+    /// it launched.
+    fn run_menu(input: &str) -> (String, Vec<&'static str>) {
+        let mut output = Vec::new();
+        let mut launched = Vec::new();
+        menu(&mut input.as_bytes(), &mut output, |challenge| {
+            launched.push(challenge.id);
+            Ok(())
+        })
+        .unwrap();
+        (String::from_utf8(output).unwrap(), launched)
+    }
+
+    #[test]
+    fn list_shows_every_challenge() {
+        let mut output = Vec::new();
+        list(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), CHALLENGES.len());
+        assert!(output.starts_with(
+            "  1. Jokes: Tell a random child-friendly joke and reveal the answer in color.\n"
+        ));
+        assert!(output.contains(" 25. Blackjack: Play a hand of Blackjack against the dealer.\n"));
+    }
+
+    #[test]
+    fn menu_launches_challenges_until_the_player_quits() {
+        let (output, launched) = run_menu("25\nhangman\nquit\n26\n");
+        assert_eq!(launched, ["c25", "c27"]);
+        assert!(output.contains("\nBlackjack (c25)\n\n"));
+        assert!(output.contains("\nHangman (c27)\n\n"));
+    }
+
+    #[test]
+    fn menu_asks_again_for_an_unknown_challenge() {
+        let (output, launched) = run_menu("chess\n3\n");
+        assert_eq!(launched, ["c03"]);
+        assert!(output.contains("No single challenge matches 'chess'.\n"));
+    }
+
+    #[test]
+    fn menu_reports_a_failed_challenge_and_carries_on() {
+        let mut output = Vec::new();
+        let mut launched = 0;
+        menu(&mut "1\n2\n".as_bytes(), &mut output, |challenge| {
+            launched += 1;
+            Err(format!("{} exited with exit status: 1", challenge.id).into())
+        })
+        .unwrap();
+        assert_eq!(launched, 2);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Error: c02 exited with exit status: 1\n"));
+    }
+}
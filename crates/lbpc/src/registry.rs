@@ -0,0 +1,268 @@
+//! Every challenge the launcher knows about.
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+/// A challenge with the name and description the launcher shows.
+#[derive(Debug, Clone, Copy)]
+pub struct Challenge {
+    /// Name of the binary, also used as the store namespace.
+    pub id: &'static str,
+    pub name: &'static str,
+    /// What the challenge does, as in its `--help`.
+    pub description: &'static str,
+    /// The challenge's program, which takes its command line and returns its
+    /// exit status.
+    pub run: fn(Vec<OsString>) -> ExitCode,
+}
+
+/// Challenges are told apart by their id.
+impl PartialEq for Challenge {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Challenge {}
+
+impl Challenge {
+    /// Returns the challenge's number in the book.
+    pub fn number(&self) -> u32 {
+        self.id[1..].parse().unwrap()
+    }
+}
+
+const fn challenge(
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    run: fn(Vec<OsString>) -> ExitCode,
+) -> Challenge {
+    Challenge {
+        id,
+        name,
+        description,
+        run,
+    }
+}
+
+/// The challenges in book order.
+pub const CHALLENGES: [Challenge; 27] = [
+    challenge(
+        "c01",
+        "Jokes",
+        "Tell a random child-friendly joke and reveal the answer in color.",
+        c01::run_cli,
+    ),
+    challenge(
+        "c02",
+        "Name Greeting",
+        "Greet the user by name.",
+        c02::run_cli,
+    ),
+    challenge(
+        "c03",
+        "Area and Volume",
+        "Calculate the area of a rectangle or the volume of a cuboid.",
+        c03::run_cli,
+    ),
+    challenge(
+        "c04",
+        "Speed and Distance",
+        "Calculate a distance or a speed from the other two quantities.",
+        c04::run_cli,
+    ),
+    challenge(
+        "c05",
+        "Age in Days",
+        "Show how many days and seconds have passed since your birth date.",
+        c05::run_cli,
+    ),
+    challenge(
+        "c06",
+        "Time Guessing",
+        "Test how well you can judge when a number of seconds has elapsed.",
+        c06::run_cli,
+    ),
+    challenge(
+        "c07",
+        "Alphabet Typing",
+        "Type the alphabet as fast as you can.",
+        c07::run_cli,
+    ),
+    challenge(
+        "c08",
+        "Voting Eligibility",
+        "Check whether you are old enough to vote.",
+        c08::run_cli,
+    ),
+    challenge(
+        "c09",
+        "Random Cards",
+        "Draw random playing cards.",
+        c09::run_cli,
+    ),
+    challenge(
+        "c10",
+        "Rock, Paper, Scissors",
+        "Play Rock, Paper, Scissors against the computer.",
+        c10::run_cli,
+    ),
+    challenge(
+        "c11",
+        "Logic Gates",
+        "Simulate a two-input logic gate.",
+        c11::run_cli,
+    ),
+    challenge(
+        "c12",
+        "Factors",
+        "List all the factors of a number.",
+        c12::run_cli,
+    ),
+    challenge(
+        "c13",
+        "Subtraction Game",
+        "Play a subtraction game against the computer.",
+        c13::run_cli,
+    ),
+    challenge(
+        "c14",
+        "Higher or Lower",
+        "Guess whether the next random number will be higher or lower.",
+        c14::run_cli,
+    ),
+    challenge(
+        "c15",
+        "Text Query",
+        "Count the words in a sentence or reverse it.",
+        c15::run_cli,
+    ),
+    challenge(
+        "c16",
+        "Number Guessing",
+        "Guess a secret number, or let the computer guess yours.",
+        c16::run_cli,
+    ),
+    challenge(
+        "c17",
+        "UMS Grades",
+        "Convert two module UMS scores into letter grades.",
+        c17::run_cli,
+    ),
+    challenge("c18", "Pyramid", "Draw a pyramid of stars.", c18::run_cli),
+    challenge(
+        "c19",
+        "Caesar Cipher",
+        "Encrypt or decrypt text with an ASCII Caesar cipher.",
+        c19::run_cli,
+    ),
+    challenge(
+        "c20",
+        "Fibonacci",
+        "Calculate the Fibonacci number at a given index.",
+        c20::run_cli,
+    ),
+    challenge(
+        "c21",
+        "Duplicate Names",
+        "Enter names and report the ones that were entered more than once.",
+        c21::run_cli,
+    ),
+    challenge(
+        "c22",
+        "Random Array",
+        "Generate a random square array and print it as numbers and as colors.",
+        c22::run_cli,
+    ),
+    challenge(
+        "c23",
+        "Treasure Hunt",
+        "Hunt for treasure hidden on a square grid.",
+        c23::run_cli,
+    ),
+    challenge(
+        "c24",
+        "Sports Results",
+        "Record match results and search them by team name.",
+        c24::run_cli,
+    ),
+    challenge(
+        "c25",
+        "Blackjack",
+        "Play a hand of Blackjack against the dealer.",
+        c25::run_cli,
+    ),
+    challenge(
+        "c26",
+        "Mastermind",
+        "Crack a secret numeric code using feedback on each guess.",
+        c26::run_cli,
+    ),
+    challenge(
+        "c27",
+        "Hangman",
+        "Play Hangman: one player picks a word, the other guesses it letter by letter.",
+        c27::run_cli,
+    ),
+];
+
+/// Finds the challenge `query` refers to: its number (`7`), its binary
+/// (`c07` or `c7`), or its name or the start of it, ignoring case
+/// (`rock`). Returns `None` if nothing or more than one name matches.
+pub fn find(query: &str) -> Option<&'static Challenge> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    let number = query.strip_prefix('c').unwrap_or(&query);
+    if let Ok(number) = number.parse::<u32>() {
+        return CHALLENGES.iter().find(|c| c.number() == number);
+    }
+
+    if let Some(challenge) = CHALLENGES.iter().find(|c| c.name.to_lowercase() == query) {
+        return Some(challenge);
+    }
+    let mut matches = CHALLENGES
+        .iter()
+        .filter(|c| c.name.to_lowercase().starts_with(&query));
+    match (matches.next(), matches.next()) {
+        (Some(challenge), None) => Some(challenge),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn challenges_are_numbered_in_order() {
+        for (i, challenge) in CHALLENGES.iter().enumerate() {
+            assert_eq!(challenge.number(), i as u32 + 1);
+        }
+    }
+
+    #[test]
+    fn find_accepts_a_number_or_a_binary_name() {
+        for query in ["7", "07", "c7", "c07", " C07 "] {
+            assert_eq!(find(query).unwrap().id, "c07");
+        }
+    }
+
+    #[test]
+    fn find_accepts_a_name_or_its_start() {
+        assert_eq!(find("mastermind").unwrap().id, "c26");
+        assert_eq!(find("Treasure Hunt").unwrap().id, "c23");
+        assert_eq!(find("rock").unwrap().id, "c10");
+    }
+
+    #[test]
+    fn find_rejects_unknown_or_ambiguous_queries() {
+        assert_eq!(find("28"), None);
+        assert_eq!(find("0"), None);
+        assert_eq!(find("chess"), None);
+        // Area and Volume, Age in Days and Alphabet Typing all start with "a".
+        assert_eq!(find("a"), None);
+        assert_eq!(find(""), None);
+    }
+}
@@ -0,0 +1,217 @@
+//! The `serve` command: the menu and every game over TCP, for playing from
+//! another machine with `telnet` or `nc`, like the door games of an old BBS.
+//!
+//! Each connection gets a menu of its own. A game picked from it runs as a
+//! child process with its stdin and stdout piped to the connection, so the
+//! games need nothing beyond the line-based input and output they already
+//! use on a terminal.
+use crate::play;
+use crate::registry::Challenge;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// Address served when `--addr` is not given: this machine alone, so that
+/// opening the games to the network is a choice.
+pub const DEFAULT_ADDR: &str = "127.0.0.1:2323";
+
+/// How often a running game is checked for having exited while waiting for
+/// the player to type.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Listens on `addr` and serves each connection on a thread of its own,
+/// until the process is stopped.
+pub fn serve(addr: &str) -> challenge_storage::Result<()> {
+    let listener = TcpListener::bind(addr).map_err(|e| format!("{addr}: {e}"))?;
+    println!("Serving the challenges on {}", listener.local_addr()?);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Warning: unable to accept a connection: {}", e);
+                continue;
+            }
+        };
+        std::thread::spawn(move || {
+            let peer = stream
+                .peer_addr()
+                .map_or_else(|_| "unknown".to_string(), |addr| addr.to_string());
+            println!("{} connected", peer);
+            if let Err(e) = session(stream) {
+                eprintln!("Warning: {}: {}", peer, e);
+            }
+            println!("{} disconnected", peer);
+        });
+    }
+    Ok(())
+}
+
+/// Runs the menu for one connection until the player quits or hangs up.
+fn session(stream: TcpStream) -> std::io::Result<()> {
+    let lines = read_lines(stream.try_clone()?);
+    let mut writer = stream.try_clone()?;
+    play::menu(&mut Lines::new(&lines), &mut writer, |challenge| {
+        run_game(challenge, &lines, &stream)
+    })?;
+    stream.shutdown(Shutdown::Both)
+}
+
+/// Reads `stream` line by line on a thread of its own. The channel closes
+/// when the player hangs up.
+fn read_lines(stream: TcpStream) -> Receiver<Vec<u8>> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        loop {
+            let mut line = Vec::new();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) if sender.send(line).is_err() => break,
+                Ok(_) => {}
+            }
+        }
+    });
+    receiver
+}
+
+/// Input for the menu, taken from the lines the player sends. Reading
+/// through the channel rather than the socket leaves every line the menu
+/// does not ask for to the game that is running.
+struct Lines<'a> {
+    receiver: &'a Receiver<Vec<u8>>,
+    line: Vec<u8>,
+    pos: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn new(receiver: &'a Receiver<Vec<u8>>) -> Self {
+        Lines {
+            receiver,
+            line: Vec::new(),
+            pos: 0,
+        }
+    }
+}
+
+impl Read for Lines<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl BufRead for Lines<'_> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        if self.pos == self.line.len() {
+            // A closed channel leaves the line empty, which reads as the end
+            // of the input.
+            self.line = self.receiver.recv().unwrap_or_default();
+            self.pos = 0;
+        }
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.line.len());
+    }
+}
+
+/// Runs `challenge` with its output going to `stream` and the player's
+/// `lines` going to its input, until it exits.
+fn run_game(
+    challenge: &Challenge,
+    lines: &Receiver<Vec<u8>>,
+    stream: &TcpStream,
+) -> challenge_storage::Result<()> {
+    let mut child = Command::new(play::game_binary(challenge.id)?)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut outputs: Vec<Box<dyn Read + Send>> = Vec::new();
+    outputs.extend(child.stdout.take().map(|out| Box::new(out) as _));
+    outputs.extend(child.stderr.take().map(|err| Box::new(err) as _));
+    let mut copiers = Vec::new();
+    for mut output in outputs {
+        let mut stream = stream.try_clone()?;
+        copiers.push(std::thread::spawn(move || {
+            let _ = std::io::copy(&mut output, &mut stream);
+        }));
+    }
+
+    let mut stdin = child.stdin.take();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        match lines.recv_timeout(POLL_INTERVAL) {
+            Ok(line) => {
+                // A game that has stopped reading just loses the line.
+                if stdin
+                    .as_mut()
+                    .is_some_and(|input| input.write_all(&line).is_err())
+                {
+                    stdin = None;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                // The player hung up: end the game's input so it stops.
+                stdin = None;
+                break child.wait()?;
+            }
+        }
+    };
+    drop(stdin);
+    for copier in copiers {
+        let _ = copier.join();
+    }
+
+    if !status.success() {
+        return Err(format!("{} exited with {}", challenge.id, status).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_reads_each_line_then_ends() {
+        let (sender, receiver) = mpsc::channel();
+        sender.send(b"25\r\n".to_vec()).unwrap();
+        sender.send(b"quit\n".to_vec()).unwrap();
+        drop(sender);
+
+        let mut lines = Lines::new(&receiver);
+        let mut text = String::new();
+        lines.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "25\r\nquit\n");
+    }
+
+    #[test]
+    fn a_connection_gets_the_menu_until_it_quits() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            session(stream).unwrap();
+        });
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        client.write_all(b"chess\r\nquit\r\n").unwrap();
+        let mut output = String::new();
+        client.read_to_string(&mut output).unwrap();
+        server.join().unwrap();
+
+        assert!(output.starts_with("Little Book of Programming Challenges\n"));
+        assert!(output.contains("No single challenge matches 'chess'.\n"));
+    }
+}
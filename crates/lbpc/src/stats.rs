@@ -0,0 +1,98 @@
+//! The `stats` command: a combined profile built from every game's store.
+use challenge_storage::achievements::{self, ACHIEVEMENTS};
+use challenge_storage::Store;
+use std::io::Write;
+use std::path::Path;
+
+/// Games that keep scores or stats, with the name shown in the profile.
+const GAMES: [(&str, &str); 9] = [
+    ("c06", "Time Guessing"),
+    ("c07", "Alphabet Typing"),
+    ("c10", "Rock, Paper, Scissors"),
+    ("c14", "Higher or Lower"),
+    ("c16", "Number Guessing"),
+    ("c23", "Treasure Hunt"),
+    ("c25", "Blackjack"),
+    ("c26", "Mastermind"),
+    ("c27", "Hangman"),
+];
+
+/// Writes the profile for the stores kept in `dir`.
+pub fn print<W: Write>(writer: &mut W, dir: &Path) -> challenge_storage::Result<()> {
+    writeln!(writer, "Player profile")?;
+
+    let mut played = false;
+    for (game, name) in GAMES {
+        let store = Store::open_in(dir, game)?;
+        if store.is_empty() {
+            continue;
+        }
+        played = true;
+        writeln!(writer, "\n{} ({})", name, game)?;
+        for (key, value) in store.entries() {
+            writeln!(writer, "  {}: {}", key.replace('_', " "), value)?;
+        }
+    }
+    if !played {
+        writeln!(writer, "\nNo games played yet.")?;
+    }
+
+    let unlocked = achievements::unlocked_in(dir)?;
+    writeln!(
+        writer,
+        "\nAchievements ({}/{})",
+        unlocked.len(),
+        ACHIEVEMENTS.len()
+    )?;
+    for achievement in ACHIEVEMENTS {
+        let mark = if unlocked.iter().any(|(a, _)| a.id == achievement.id) {
+            'x'
+        } else {
+            ' '
+        };
+        writeln!(
+            writer,
+            "  [{}] {}: {} ({})",
+            mark, achievement.name, achievement.description, achievement.game
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(dir: &Path) -> String {
+        let mut output = Vec::new();
+        print(&mut output, dir).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn print_reports_empty_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = profile(dir.path());
+        assert!(output.contains("No games played yet."));
+        assert!(output.contains("Achievements (0/3)"));
+    }
+
+    #[test]
+    fn print_lists_game_stats_and_unlocked_achievements() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c10").unwrap();
+        store.set("wins", &3u64).unwrap();
+        store.set("losses", &1u64).unwrap();
+        store.save().unwrap();
+        achievements::unlock_in(dir.path(), "first_blackjack").unwrap();
+
+        let output = profile(dir.path());
+        assert!(output.contains("\nRock, Paper, Scissors (c10)\n  losses: 1\n  wins: 3\n"));
+        assert!(!output.contains("Blackjack (c25)"));
+        assert!(output.contains("Achievements (1/3)"));
+        assert!(
+            output.contains("  [x] Twenty-One: Win a hand of blackjack holding exactly 21 (c25)")
+        );
+        assert!(output.contains("  [ ] Codebreaker:"));
+    }
+}
@@ -0,0 +1,195 @@
+//! Full-screen version of the menu, enabled with `--tui`.
+use crate::play;
+use crate::registry::{Challenge, CHALLENGES};
+use challenge_tui::crossterm::event::{KeyCode, KeyEvent};
+use challenge_tui::ratatui::layout::{Constraint, Layout};
+use challenge_tui::ratatui::text::Line;
+use challenge_tui::ratatui::widgets::{Block, Paragraph, Wrap};
+use challenge_tui::ratatui::Frame;
+use challenge_tui::widgets::{Menu, StatusBar};
+use std::io::Write;
+
+/// Challenges with a full-screen mode of their own, which the menu starts
+/// with `--tui`.
+const FULL_SCREEN: [&str; 3] = ["c23", "c25", "c26"];
+const KEYS: [(&str, &str); 3] = [("↑↓", "select"), ("enter", "play"), ("q", "quit")];
+
+/// What a key press asks the menu to do.
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    Play(&'static Challenge),
+    Quit,
+}
+
+/// The challenge list as shown on screen.
+struct Catalog {
+    labels: Vec<String>,
+    selected: usize,
+    /// How the last challenge went, shown in the status bar.
+    message: String,
+}
+
+impl Catalog {
+    fn new() -> Catalog {
+        Catalog {
+            labels: CHALLENGES
+                .iter()
+                .map(|c| format!("{:>2}. {}", c.number(), c.name))
+                .collect(),
+            selected: 0,
+            message: String::from("Pick a challenge"),
+        }
+    }
+
+    fn challenge(&self) -> &'static Challenge {
+        &CHALLENGES[self.selected]
+    }
+
+    fn on_key(&mut self, key: &KeyEvent) -> Option<Action> {
+        if challenge_tui::is_quit(key) {
+            return Some(Action::Quit);
+        }
+        let last = CHALLENGES.len() - 1;
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.checked_sub(1).unwrap_or(last)
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = if self.selected == last {
+                    0
+                } else {
+                    self.selected + 1
+                }
+            }
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Enter => return Some(Action::Play(self.challenge())),
+            _ => {}
+        }
+        None
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [list, details] =
+            Layout::horizontal([Constraint::Length(30), Constraint::Min(0)]).areas(main);
+
+        let labels: Vec<&str> = self.labels.iter().map(String::as_str).collect();
+        frame.render_widget(
+            Menu::new("Challenges", &labels).selected(self.selected),
+            list,
+        );
+
+        let challenge = self.challenge();
+        let how = if FULL_SCREEN.contains(&challenge.id) {
+            "Opens full-screen."
+        } else {
+            "Runs in the terminal, then comes back here."
+        };
+        let text = vec![
+            Line::raw(challenge.description),
+            Line::raw(""),
+            Line::raw(format!("Binary: {}", challenge.id)),
+            Line::raw(how),
+        ];
+        frame.render_widget(
+            Paragraph::new(text)
+                .wrap(Wrap { trim: true })
+                .block(Block::bordered().title(challenge.name)),
+            details,
+        );
+
+        frame.render_widget(
+            StatusBar::new("LBPC")
+                .message(self.message.as_str())
+                .keys(&KEYS),
+            status,
+        );
+    }
+}
+
+/// Runs `challenge` outside the menu's screen. A game without a full-screen
+/// mode waits for Enter when it ends so that its last words can be read.
+fn launch(challenge: &Challenge) -> challenge_storage::Result<()> {
+    if FULL_SCREEN.contains(&challenge.id) {
+        return play::run(challenge, &[String::from("--tui")]);
+    }
+    println!("{} ({})\n", challenge.name, challenge.id);
+    let result = play::run(challenge, &[]);
+    print!("\nPress Enter to return to the menu.");
+    std::io::stdout().flush()?;
+    std::io::stdin().read_line(&mut String::new())?;
+    result
+}
+
+/// Shows the menu full-screen and runs the challenges picked from it, coming
+/// back to the menu after each, until the player quits.
+pub fn run() -> challenge_storage::Result<()> {
+    let mut catalog = Catalog::new();
+    loop {
+        let action = challenge_tui::run(|terminal| loop {
+            terminal.draw(|frame| catalog.draw(frame))?;
+            if let Some(action) = catalog.on_key(&challenge_tui::next_key()?) {
+                return Ok(action);
+            }
+        })?;
+        let Action::Play(challenge) = action else {
+            return Ok(());
+        };
+        catalog.message = match launch(challenge) {
+            Ok(()) => format!("Finished {}", challenge.name),
+            Err(e) => format!("Error: {}", e),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_tui::ratatui::backend::TestBackend;
+    use challenge_tui::ratatui::Terminal;
+
+    fn press(catalog: &mut Catalog, code: KeyCode) -> Option<Action> {
+        catalog.on_key(&KeyEvent::from(code))
+    }
+
+    #[test]
+    fn arrow_keys_move_the_selection_and_wrap() {
+        let mut catalog = Catalog::new();
+        press(&mut catalog, KeyCode::Up);
+        assert_eq!(catalog.challenge().id, "c27");
+        press(&mut catalog, KeyCode::Down);
+        press(&mut catalog, KeyCode::Char('j'));
+        assert_eq!(catalog.challenge().id, "c02");
+        press(&mut catalog, KeyCode::End);
+        assert_eq!(catalog.challenge().id, "c27");
+    }
+
+    #[test]
+    fn enter_plays_the_selected_challenge_and_q_quits() {
+        let mut catalog = Catalog::new();
+        press(&mut catalog, KeyCode::Down);
+        assert_eq!(
+            press(&mut catalog, KeyCode::Enter),
+            Some(Action::Play(&CHALLENGES[1]))
+        );
+        assert_eq!(press(&mut catalog, KeyCode::Char('q')), Some(Action::Quit));
+    }
+
+    #[test]
+    fn draw_scrolls_to_the_selection_and_describes_it() {
+        let mut catalog = Catalog::new();
+        press(&mut catalog, KeyCode::End);
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal.draw(|frame| catalog.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("> 27. Hangman"));
+        assert!(!text.contains(" 1. Jokes"));
+        assert!(text.contains("one player picks a word"));
+        assert!(text.contains("Binary: c27"));
+        assert!(text.contains("Pick a challenge"));
+    }
+}
@@ -0,0 +1,561 @@
+//! # Challenge Netplay
+//!
+//! Lets two people on a LAN play a turn-based game against each other. One
+//! player hosts with `--host <ADDR>` and waits in a [`Lobby`]; the other joins
+//! with `--join <ADDR>`. The host keeps the game state: the guest submits its
+//! moves as turns, and the host sends the state back after every move, its
+//! own included.
+//!
+//! A host address that gives only a port, such as `:7878` or `7878`, listens
+//! on this machine alone unless `--bind` names the interface to open to the
+//! LAN, e.g. `--bind 0.0.0.0` for every one.
+//!
+//! ## Protocol
+//!
+//! Peers exchange [`Message`]s over TCP, one JSON object per line, tagged
+//! with a `type` field:
+//!
+//! ```text
+//! guest -> host  {"type":"hello","game":"c26","name":"Ada","version":1}
+//! host -> guest  {"type":"welcome","name":"Bob","settings":{"code_length":4}}
+//! guest -> host  {"type":"turn","turn":{"guess":"1234"}}
+//! host -> guest  {"type":"state","state":{"side":"guest","guess":"1234"}}
+//! ```
+//!
+//! A host turns away guests for another game or protocol version with a
+//! `reject` message and keeps waiting. Either side may send `bye` before
+//! leaving. The contents of `settings`, `turn` and `state` belong to each
+//! game.
+use clap::Args;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+
+/// Version of the protocol; peers must agree on it.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Port used when an address does not name one.
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// How long a new connection has to say hello before the host gives up on
+/// it and waits for another.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A message exchanged between the host and the guest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// Sent by the guest to join the host's game.
+    Hello {
+        game: String,
+        name: String,
+        version: u32,
+    },
+    /// The host's reply to a guest that may join, with the game's settings.
+    Welcome { name: String, settings: Value },
+    /// The host's reply to a guest that may not join.
+    Reject { reason: String },
+    /// A move submitted by the guest.
+    Turn { turn: Value },
+    /// The game state after a move, sent by the host.
+    State { state: Value },
+    /// Sent by either side before leaving.
+    Bye { reason: String },
+}
+
+/// Which end of the connection a player is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    Host,
+    Guest,
+}
+
+impl Side {
+    /// Returns the other player's side.
+    pub fn other(self) -> Side {
+        match self {
+            Side::Host => Side::Guest,
+            Side::Guest => Side::Host,
+        }
+    }
+
+    /// Returns the name used for a player on this side who did not give one.
+    pub fn default_name(self) -> &'static str {
+        match self {
+            Side::Host => "Player 1",
+            Side::Guest => "Player 2",
+        }
+    }
+}
+
+/// How a game was asked to run from the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// Host a game on the address.
+    Host(String),
+    /// Join the game hosted at the address.
+    Join(String),
+}
+
+/// Command-line arguments for playing over the network.
+#[derive(Args, Debug, Clone, Default)]
+pub struct NetArgs {
+    /// Host a two-player game on ADDR (e.g. :7878) and wait for a player to join
+    #[arg(long, value_name = "ADDR", conflicts_with = "join")]
+    pub host: Option<String>,
+
+    /// Interface to host on, for an ADDR of just a port: e.g. 0.0.0.0 to let
+    /// players on the LAN join [default: 127.0.0.1]
+    #[arg(long, value_name = "IP", requires = "host")]
+    pub bind: Option<IpAddr>,
+
+    /// Join the two-player game hosted at ADDR (e.g. 192.168.1.20:7878)
+    #[arg(long, value_name = "ADDR")]
+    pub join: Option<String>,
+
+    /// Name shown to the other player [default: Player 1 or Player 2]
+    #[arg(long, value_name = "NAME")]
+    pub name: Option<String>,
+}
+
+impl NetArgs {
+    /// Returns how to play over the network, or `None` to play alone.
+    /// `--bind` with a host address that names its own interface is an
+    /// error.
+    pub fn mode(&self) -> Result<Option<Mode>, String> {
+        match (&self.host, &self.join) {
+            (Some(addr), _) => match (self.bind, port_only(addr)) {
+                (Some(ip), Some(port)) => Ok(Some(Mode::Host(on_interface(ip, port)))),
+                (Some(_), None) => Err(format!(
+                    "--bind needs a --host address of just a port, e.g. :{DEFAULT_PORT}, not '{addr}'"
+                )),
+                (None, _) => Ok(Some(Mode::Host(addr.clone()))),
+            },
+            (None, Some(addr)) => Ok(Some(Mode::Join(addr.clone()))),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Returns the local player's name when playing on `side`.
+    pub fn name(&self, side: Side) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| side.default_name().to_string())
+    }
+}
+
+/// Returns the port of an address that gives nothing else, as `:PORT` or
+/// `PORT`.
+fn port_only(addr: &str) -> Option<&str> {
+    let port = addr.strip_prefix(':').unwrap_or(addr);
+    (!port.is_empty() && port.chars().all(|c| c.is_ascii_digit())).then_some(port)
+}
+
+/// Joins `ip` and `port` into an address.
+fn on_interface(ip: IpAddr, port: &str) -> String {
+    match ip {
+        IpAddr::V4(ip) => format!("{ip}:{port}"),
+        IpAddr::V6(ip) => format!("[{ip}]:{port}"),
+    }
+}
+
+/// Fills in what `addr` leaves out: the port, and for a bare port the
+/// loopback interface, so that only this machine can reach it.
+fn with_default_port(addr: &str) -> String {
+    if let Some(port) = port_only(addr) {
+        format!("127.0.0.1:{port}")
+    } else if addr.parse::<SocketAddr>().is_err() && !addr.contains(':') {
+        format!("{addr}:{DEFAULT_PORT}")
+    } else {
+        addr.to_string()
+    }
+}
+
+fn invalid_data(message: impl Into<String>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.into())
+}
+
+/// A line-based JSON connection to the other player.
+#[derive(Debug)]
+pub struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    fn new(stream: TcpStream) -> std::io::Result<Connection> {
+        stream.set_nodelay(true)?;
+        Ok(Connection {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        })
+    }
+
+    /// Sends `message` as one line.
+    pub fn send(&mut self, message: &Message) -> std::io::Result<()> {
+        let mut line = serde_json::to_string(message)?;
+        tracing::debug!(%line, "sending");
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.flush()
+    }
+
+    /// Waits for the next message. The other player leaving, with `bye` or
+    /// by closing the connection, is reported as an error.
+    pub fn recv(&mut self) -> std::io::Result<Message> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "the other player disconnected",
+            ));
+        }
+        tracing::debug!(line = line.trim_end(), "received");
+        match serde_json::from_str(&line) {
+            Ok(Message::Bye { reason }) => Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionAborted,
+                format!("the other player left: {reason}"),
+            )),
+            Ok(message) => Ok(message),
+            Err(e) => Err(invalid_data(format!("malformed message: {e}"))),
+        }
+    }
+
+    /// Tells the other player this one is leaving.
+    pub fn bye(&mut self, reason: &str) -> std::io::Result<()> {
+        self.send(&Message::Bye {
+            reason: reason.to_string(),
+        })
+    }
+}
+
+/// A hosted game waiting for a guest.
+#[derive(Debug)]
+pub struct Lobby {
+    listener: TcpListener,
+    game: String,
+    name: String,
+    handshake_timeout: Duration,
+}
+
+impl Lobby {
+    /// Listens on `addr` for guests who want to play `game` against `name`.
+    pub fn bind(addr: &str, game: &str, name: &str) -> std::io::Result<Lobby> {
+        let addr = with_default_port(addr);
+        let listener = TcpListener::bind(&addr)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("{addr}: {e}")))?;
+        Ok(Lobby {
+            listener,
+            game: game.to_string(),
+            name: name.to_string(),
+            handshake_timeout: HANDSHAKE_TIMEOUT,
+        })
+    }
+
+    /// Returns the address guests should join.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Waits for a guest to join and welcomes it with `settings`. Guests for
+    /// another game or protocol version are turned away.
+    pub fn accept<S: Serialize>(&self, settings: &S) -> std::io::Result<Host> {
+        let settings = serde_json::to_value(settings)?;
+        loop {
+            let (stream, peer) = self.listener.accept()?;
+            // A connection that never says hello must not hold up the guests
+            // behind it.
+            stream.set_read_timeout(Some(self.handshake_timeout))?;
+            let mut connection = Connection::new(stream)?;
+            let (game, name, version) = match connection.recv() {
+                Ok(Message::Hello {
+                    game,
+                    name,
+                    version,
+                }) => (game, name, version),
+                Ok(message) => {
+                    tracing::debug!(%peer, ?message, "expected hello");
+                    continue;
+                }
+                Err(e) => {
+                    tracing::debug!(%peer, error = %e, "guest dropped before joining");
+                    continue;
+                }
+            };
+
+            let reason = if version != PROTOCOL_VERSION {
+                Some(format!(
+                    "protocol version {version} is not supported, this host uses {PROTOCOL_VERSION}"
+                ))
+            } else if game != self.game {
+                Some(format!("this host is playing {}, not {game}", self.game))
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                tracing::debug!(%peer, %reason, "guest rejected");
+                let _ = connection.send(&Message::Reject { reason });
+                continue;
+            }
+
+            connection.send(&Message::Welcome {
+                name: self.name.clone(),
+                settings: settings.clone(),
+            })?;
+            // The game itself waits as long as the players take.
+            connection.writer.set_read_timeout(None)?;
+            tracing::debug!(%peer, %name, "guest joined");
+            return Ok(Host {
+                connection,
+                guest: name,
+            });
+        }
+    }
+}
+
+/// The host's end of a game in progress.
+#[derive(Debug)]
+pub struct Host {
+    connection: Connection,
+    guest: String,
+}
+
+impl Host {
+    /// Returns the guest's name.
+    pub fn guest(&self) -> &str {
+        &self.guest
+    }
+
+    /// Waits for the guest's next move.
+    pub fn recv_turn<T: DeserializeOwned>(&mut self) -> std::io::Result<T> {
+        match self.connection.recv()? {
+            Message::Turn { turn } => serde_json::from_value(turn)
+                .map_err(|e| invalid_data(format!("malformed turn: {e}"))),
+            message => Err(invalid_data(format!("expected a turn, got {message:?}"))),
+        }
+    }
+
+    /// Sends the game state to the guest.
+    pub fn send_state<S: Serialize>(&mut self, state: &S) -> std::io::Result<()> {
+        self.connection.send(&Message::State {
+            state: serde_json::to_value(state)?,
+        })
+    }
+
+    /// Returns the underlying connection.
+    pub fn connection(&mut self) -> &mut Connection {
+        &mut self.connection
+    }
+}
+
+/// The guest's end of a game in progress.
+#[derive(Debug)]
+pub struct Guest {
+    connection: Connection,
+    host: String,
+    settings: Value,
+}
+
+impl Guest {
+    /// Joins the game of `game` hosted at `addr` as `name`.
+    pub fn join(addr: &str, game: &str, name: &str) -> std::io::Result<Guest> {
+        let addr = with_default_port(addr);
+        let stream = TcpStream::connect(&addr)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("{addr}: {e}")))?;
+        let mut connection = Connection::new(stream)?;
+        connection.send(&Message::Hello {
+            game: game.to_string(),
+            name: name.to_string(),
+            version: PROTOCOL_VERSION,
+        })?;
+        match connection.recv()? {
+            Message::Welcome { name, settings } => Ok(Guest {
+                connection,
+                host: name,
+                settings,
+            }),
+            Message::Reject { reason } => Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionRefused,
+                format!("the host turned you away: {reason}"),
+            )),
+            message => Err(invalid_data(format!("expected a welcome, got {message:?}"))),
+        }
+    }
+
+    /// Returns the host's name.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the settings the host is playing with.
+    pub fn settings<S: DeserializeOwned>(&self) -> std::io::Result<S> {
+        serde_json::from_value(self.settings.clone())
+            .map_err(|e| invalid_data(format!("malformed settings: {e}")))
+    }
+
+    /// Submits a move to the host.
+    pub fn send_turn<T: Serialize>(&mut self, turn: &T) -> std::io::Result<()> {
+        self.connection.send(&Message::Turn {
+            turn: serde_json::to_value(turn)?,
+        })
+    }
+
+    /// Waits for the next game state from the host.
+    pub fn recv_state<S: DeserializeOwned>(&mut self) -> std::io::Result<S> {
+        match self.connection.recv()? {
+            Message::State { state } => serde_json::from_value(state)
+                .map_err(|e| invalid_data(format!("malformed state: {e}"))),
+            message => Err(invalid_data(format!("expected a state, got {message:?}"))),
+        }
+    }
+
+    /// Returns the underlying connection.
+    pub fn connection(&mut self) -> &mut Connection {
+        &mut self.connection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use serde_json::json;
+
+    fn lobby(game: &str) -> (Lobby, String) {
+        let lobby = Lobby::bind("127.0.0.1:0", game, "Bob").unwrap();
+        let addr = lobby.local_addr().unwrap().to_string();
+        (lobby, addr)
+    }
+
+    #[test]
+    fn message_is_tagged_with_its_type() {
+        let message = Message::Hello {
+            game: "c26".to_string(),
+            name: "Ada".to_string(),
+            version: 1,
+        };
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            json!({ "type": "hello", "game": "c26", "name": "Ada", "version": 1 })
+        );
+    }
+
+    #[test]
+    fn guest_joins_and_exchanges_turns_and_state() {
+        let (lobby, addr) = lobby("c26");
+        let host = std::thread::spawn(move || {
+            let mut host = lobby.accept(&json!({ "code_length": 4 })).unwrap();
+            assert_eq!(host.guest(), "Ada");
+            let turn: Value = host.recv_turn().unwrap();
+            host.send_state(&json!({ "echo": turn })).unwrap();
+        });
+
+        let mut guest = Guest::join(&addr, "c26", "Ada").unwrap();
+        assert_eq!(guest.host(), "Bob");
+        assert_eq!(
+            guest.settings::<Value>().unwrap(),
+            json!({ "code_length": 4 })
+        );
+        guest.send_turn(&json!({ "guess": "1234" })).unwrap();
+        assert_eq!(
+            guest.recv_state::<Value>().unwrap(),
+            json!({ "echo": { "guess": "1234" } })
+        );
+        host.join().unwrap();
+    }
+
+    #[test]
+    fn host_turns_away_guests_for_another_game() {
+        let (lobby, addr) = lobby("c26");
+        let host =
+            std::thread::spawn(move || lobby.accept(&json!({})).unwrap().guest().to_string());
+
+        let err = Guest::join(&addr, "c23", "Eve").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::ConnectionRefused);
+        assert!(err
+            .to_string()
+            .contains("this host is playing c26, not c23"));
+
+        Guest::join(&addr, "c26", "Ada").unwrap();
+        assert_eq!(host.join().unwrap(), "Ada");
+    }
+
+    #[test]
+    fn host_gives_up_on_connections_that_never_say_hello() {
+        let (mut lobby, addr) = lobby("c26");
+        lobby.handshake_timeout = Duration::from_millis(100);
+        let host =
+            std::thread::spawn(move || lobby.accept(&json!({})).unwrap().guest().to_string());
+
+        let _idle = TcpStream::connect(&addr).unwrap();
+        Guest::join(&addr, "c26", "Ada").unwrap();
+        assert_eq!(host.join().unwrap(), "Ada");
+    }
+
+    #[test]
+    fn leaving_is_reported_to_the_other_player() {
+        let (lobby, addr) = lobby("c23");
+        let host = std::thread::spawn(move || {
+            let mut host = lobby.accept(&json!({})).unwrap();
+            host.connection().bye("quit").unwrap();
+        });
+
+        let mut guest = Guest::join(&addr, "c23", "Ada").unwrap();
+        host.join().unwrap();
+        let err = guest.recv_state::<Value>().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::ConnectionAborted);
+        assert_eq!(err.to_string(), "the other player left: quit");
+        let err = guest.recv_state::<Value>().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn with_default_port_fills_in_missing_parts() {
+        assert_eq!(with_default_port("192.168.1.20"), "192.168.1.20:7878");
+        assert_eq!(with_default_port("localhost"), "localhost:7878");
+        assert_eq!(with_default_port(":9000"), "127.0.0.1:9000");
+        assert_eq!(with_default_port("9000"), "127.0.0.1:9000");
+        assert_eq!(with_default_port("10.0.0.1:9000"), "10.0.0.1:9000");
+        assert_eq!(with_default_port("[::1]:9000"), "[::1]:9000");
+    }
+
+    #[derive(Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        net: NetArgs,
+    }
+
+    #[test]
+    fn net_args_pick_the_mode_and_name() {
+        let args = TestArgs::parse_from(["test", "--join", "10.0.0.1"]).net;
+        assert_eq!(args.mode(), Ok(Some(Mode::Join("10.0.0.1".to_string()))));
+        assert_eq!(args.name(Side::Guest), "Player 2");
+
+        let args = TestArgs::parse_from(["test", "--host", ":7878", "--name", "Bob"]).net;
+        assert_eq!(args.mode(), Ok(Some(Mode::Host(":7878".to_string()))));
+        assert_eq!(args.name(Side::Host), "Bob");
+
+        let args = TestArgs::parse_from(["test", "--host", ":7878", "--bind", "0.0.0.0"]).net;
+        assert_eq!(
+            args.mode(),
+            Ok(Some(Mode::Host("0.0.0.0:7878".to_string())))
+        );
+        let args = TestArgs::parse_from(["test", "--host", ":7878", "--bind", "::"]).net;
+        assert_eq!(args.mode(), Ok(Some(Mode::Host("[::]:7878".to_string()))));
+        let args = TestArgs::parse_from(["test", "--host", "7878", "--bind", "10.0.0.1"]).net;
+        assert_eq!(
+            args.mode(),
+            Ok(Some(Mode::Host("10.0.0.1:7878".to_string())))
+        );
+        let args = TestArgs::parse_from(["test", "--host", "10.0.0.1", "--bind", "0.0.0.0"]).net;
+        assert!(args.mode().unwrap_err().contains("not '10.0.0.1'"));
+        assert!(TestArgs::try_parse_from(["test", "--bind", "0.0.0.0"]).is_err());
+
+        assert_eq!(TestArgs::parse_from(["test"]).net.mode(), Ok(None));
+        assert!(TestArgs::try_parse_from(["test", "--host", ":1", "--join", "x"]).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Milestones players can reach across all the games.
+//!
+//! A game calls [`unlock`] when the player reaches one of the milestones in
+//! [`ACHIEVEMENTS`]. Unlocked achievements are kept in their own
+//! `achievements` store together with the time they were first reached, so
+//! they can be shown in a combined profile.
+use crate::{Result, Store};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Namespace of the store unlocked achievements are kept in.
+pub const NAMESPACE: &str = "achievements";
+
+/// A milestone in one of the games.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Achievement {
+    /// Key the achievement is stored under.
+    pub id: &'static str,
+    /// Challenge the achievement belongs to, e.g. `"c25"`.
+    pub game: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// Every achievement that can be unlocked.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "alphabet_under_5s",
+        game: "c07",
+        name: "Speed Typist",
+        description: "Type the alphabet in under 5 seconds",
+    },
+    Achievement {
+        id: "first_blackjack",
+        game: "c25",
+        name: "Twenty-One",
+        description: "Win a hand of blackjack holding exactly 21",
+    },
+    Achievement {
+        id: "mastermind_in_6",
+        game: "c26",
+        name: "Codebreaker",
+        description: "Crack a Mastermind code in 6 guesses or fewer",
+    },
+];
+
+/// Returns the achievement with the given id.
+pub fn find(id: &str) -> Option<&'static Achievement> {
+    ACHIEVEMENTS.iter().find(|achievement| achievement.id == id)
+}
+
+/// Unlocks the achievement `id`. Returns it if this is the first time it was
+/// reached, or `None` if it was already unlocked.
+pub fn unlock(id: &str) -> Result<Option<&'static Achievement>> {
+    unlock_in_store(Store::open(NAMESPACE)?, id)
+}
+
+/// Like [`unlock`], but keeps the store inside `dir`.
+pub fn unlock_in(dir: &Path, id: &str) -> Result<Option<&'static Achievement>> {
+    unlock_in_store(Store::open_in(dir, NAMESPACE)?, id)
+}
+
+fn unlock_in_store(mut store: Store, id: &str) -> Result<Option<&'static Achievement>> {
+    let achievement = find(id).ok_or_else(|| format!("unknown achievement '{id}'"))?;
+    if store.get::<u64>(id)?.is_some() {
+        return Ok(None);
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    store.set(id, &now)?;
+    store.save()?;
+    tracing::debug!(id, "unlocked achievement");
+    Ok(Some(achievement))
+}
+
+/// Returns every unlocked achievement with the Unix time it was unlocked at,
+/// in the order of [`ACHIEVEMENTS`].
+pub fn unlocked() -> Result<Vec<(&'static Achievement, u64)>> {
+    unlocked_in_store(&Store::open(NAMESPACE)?)
+}
+
+/// Like [`unlocked`], but reads the store inside `dir`.
+pub fn unlocked_in(dir: &Path) -> Result<Vec<(&'static Achievement, u64)>> {
+    unlocked_in_store(&Store::open_in(dir, NAMESPACE)?)
+}
+
+fn unlocked_in_store(store: &Store) -> Result<Vec<(&'static Achievement, u64)>> {
+    let mut unlocked = Vec::new();
+    for achievement in ACHIEVEMENTS {
+        if let Some(time) = store.get(achievement.id)? {
+            unlocked.push((achievement, time));
+        }
+    }
+    Ok(unlocked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn achievement_ids_are_unique() {
+        for (i, achievement) in ACHIEVEMENTS.iter().enumerate() {
+            assert!(ACHIEVEMENTS[i + 1..].iter().all(|a| a.id != achievement.id));
+        }
+    }
+
+    #[test]
+    fn unlock_reports_only_the_first_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = unlock_in(dir.path(), "first_blackjack").unwrap();
+        assert_eq!(first.map(|a| a.name), Some("Twenty-One"));
+        assert_eq!(unlock_in(dir.path(), "first_blackjack").unwrap(), None);
+    }
+
+    #[test]
+    fn unlock_rejects_unknown_id() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(unlock_in(dir.path(), "no_such_thing").is_err());
+    }
+
+    #[test]
+    fn unlocked_lists_achievements_in_catalog_order() {
+        let dir = tempfile::tempdir().unwrap();
+        unlock_in(dir.path(), "mastermind_in_6").unwrap();
+        unlock_in(dir.path(), "alphabet_under_5s").unwrap();
+
+        let ids: Vec<_> = unlocked_in(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|(achievement, _)| achievement.id)
+            .collect();
+        assert_eq!(ids, ["alphabet_under_5s", "mastermind_in_6"]);
+    }
+}
@@ -0,0 +1,183 @@
+//! Results of the daily challenge.
+//!
+//! `lbpc daily` runs one of the [`GAMES`] seeded from the current date, so
+//! every player gets the same puzzle that day, and tells the game which day
+//! it is through [`DAY_ENV_VAR`]. At the end the game reports how it went
+//! with [`record`]. Each game's [`Record`] is kept in the `daily` store and
+//! tracks the player's streak of days won in a row.
+use crate::{Result, Store};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Namespace of the store daily results are kept in.
+pub const NAMESPACE: &str = "daily";
+
+/// Environment variable holding the day being played, as a day number (see
+/// [`today`]). It is only set while a game runs as the daily challenge.
+pub const DAY_ENV_VAR: &str = "LBPC_DAILY";
+
+/// Games that offer a daily challenge, with the name shown by the launcher.
+pub const GAMES: [(&str, &str); 3] = [
+    ("c16", "Number Guessing"),
+    ("c23", "Treasure Hunt"),
+    ("c26", "Mastermind"),
+];
+
+/// A player's daily challenge history for one game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Day the game was last played as the daily challenge.
+    pub last_day: Option<u64>,
+    /// Whether the player won on `last_day`.
+    pub last_won: bool,
+    /// Days won in a row, up to and including `last_day`.
+    pub streak: u64,
+    pub best_streak: u64,
+    pub played: u64,
+    pub wins: u64,
+}
+
+impl Record {
+    /// Returns `true` if the daily challenge has already been played on `day`.
+    pub fn played_on(&self, day: u64) -> bool {
+        self.last_day == Some(day)
+    }
+
+    /// Returns the streak as it stands on `day`. A streak survives until the
+    /// end of the day after the last win.
+    pub fn streak_on(&self, day: u64) -> u64 {
+        match self.last_day {
+            Some(last) if last + 1 >= day => self.streak,
+            _ => 0,
+        }
+    }
+
+    /// Adds the result of `day`. Only the first result of a day counts.
+    fn add(&mut self, day: u64, won: bool) {
+        if self.played_on(day) {
+            return;
+        }
+        let continues = self.last_won && self.last_day.is_some_and(|last| last + 1 == day);
+        self.streak = match (won, continues) {
+            (false, _) => 0,
+            (true, true) => self.streak + 1,
+            (true, false) => 1,
+        };
+        self.best_streak = self.best_streak.max(self.streak);
+        self.played += 1;
+        self.wins += u64::from(won);
+        self.last_day = Some(day);
+        self.last_won = won;
+    }
+}
+
+/// Returns today's day number: the number of whole days since the Unix
+/// epoch, in UTC. It doubles as the seed for the day's puzzles.
+pub fn today() -> Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() / 86_400)
+}
+
+/// Formats a day number as an ISO 8601 date, e.g. `2025-03-14`.
+pub fn format_day(day: u64) -> String {
+    // Converts days since 1970-01-01 to a civil date in the proleptic
+    // Gregorian calendar, counting in 400-year eras that start on March 1st.
+    let days = day + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day_of_month:02}")
+}
+
+/// Returns the day being played if the running game is the daily challenge.
+pub fn current() -> Option<u64> {
+    std::env::var(DAY_ENV_VAR).ok()?.trim().parse().ok()
+}
+
+/// Records the result of `game` if it is being played as the daily challenge
+/// and returns the updated record, or `None` for an ordinary game.
+pub fn record(game: &str, won: bool) -> Result<Option<Record>> {
+    match current() {
+        Some(day) => record_in(&crate::data_dir()?, game, day, won).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Records the result of `game` on `day` in the store inside `dir`.
+pub fn record_in(dir: &Path, game: &str, day: u64, won: bool) -> Result<Record> {
+    let mut store = Store::open_in(dir, NAMESPACE)?;
+    let mut record: Record = store.get_or_default(game)?;
+    record.add(day, won);
+    store.set(game, &record)?;
+    store.save()?;
+    tracing::debug!(
+        game,
+        day,
+        won,
+        streak = record.streak,
+        "recorded daily result"
+    );
+    Ok(record)
+}
+
+/// Returns the daily challenge record of `game` kept inside `dir`.
+pub fn record_of(dir: &Path, game: &str) -> Result<Record> {
+    Store::open_in(dir, NAMESPACE)?.get_or_default(game)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_day_converts_day_numbers_to_dates() {
+        assert_eq!(format_day(0), "1970-01-01");
+        assert_eq!(format_day(59), "1970-03-01");
+        assert_eq!(format_day(11_016), "2000-02-29");
+        assert_eq!(format_day(20_161), "2025-03-14");
+    }
+
+    #[test]
+    fn wins_on_consecutive_days_build_a_streak() {
+        let dir = tempfile::tempdir().unwrap();
+        record_in(dir.path(), "c26", 100, true).unwrap();
+        let record = record_in(dir.path(), "c26", 101, true).unwrap();
+        assert_eq!(record.streak, 2);
+        assert_eq!(record.best_streak, 2);
+        assert_eq!(record.streak_on(102), 2);
+        assert_eq!(record.streak_on(103), 0);
+    }
+
+    #[test]
+    fn a_loss_or_a_missed_day_resets_the_streak() {
+        let dir = tempfile::tempdir().unwrap();
+        record_in(dir.path(), "c26", 100, true).unwrap();
+        record_in(dir.path(), "c26", 101, true).unwrap();
+        assert_eq!(record_in(dir.path(), "c26", 102, false).unwrap().streak, 0);
+
+        let record = record_in(dir.path(), "c26", 105, true).unwrap();
+        assert_eq!(record.streak, 1);
+        assert_eq!(record.best_streak, 2);
+        assert_eq!((record.played, record.wins), (4, 3));
+    }
+
+    #[test]
+    fn only_the_first_result_of_a_day_counts() {
+        let dir = tempfile::tempdir().unwrap();
+        record_in(dir.path(), "c23", 100, false).unwrap();
+        let record = record_in(dir.path(), "c23", 100, true).unwrap();
+        assert_eq!((record.played, record.wins, record.streak), (1, 0, 0));
+        assert!(record.played_on(100));
+        assert_eq!(record_of(dir.path(), "c23").unwrap(), record);
+    }
+}
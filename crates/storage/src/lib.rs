@@ -0,0 +1,281 @@
+//! # Challenge Storage
+//!
+//! A small persistence layer the games use to remember high scores and stats
+//! between runs.
+//!
+//! Each game opens a [`Store`] under its own namespace. A store is a JSON
+//! object of key-value pairs kept in `<namespace>.json` inside the platform
+//! data directory (for example `~/.local/share/lbpc` on Linux). The
+//! `LBPC_DATA_DIR` environment variable overrides the directory.
+//!
+//! Milestones that span games are tracked by the [`achievements`] module, and
+//! daily challenge results and streaks by the [`daily`] module.
+pub mod achievements;
+pub mod daily;
+
+use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides the directory stores are kept in.
+pub const DATA_DIR_ENV_VAR: &str = "LBPC_DATA_DIR";
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Returns the directory stores are kept in.
+pub fn data_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os(DATA_DIR_ENV_VAR) {
+        return Ok(PathBuf::from(dir));
+    }
+    ProjectDirs::from("", "", "lbpc")
+        .map(|dirs| dirs.data_dir().to_path_buf())
+        .ok_or_else(|| "unable to determine the user data directory".into())
+}
+
+/// A namespaced key-value store persisted as a JSON file.
+#[derive(Debug)]
+pub struct Store {
+    path: PathBuf,
+    entries: Map<String, Value>,
+}
+
+impl Store {
+    /// Opens the store for `namespace` in the default data directory.
+    pub fn open(namespace: &str) -> Result<Store> {
+        Store::open_in(&data_dir()?, namespace)
+    }
+
+    /// Opens the store for `namespace` inside `dir`.
+    ///
+    /// A missing file is treated as an empty store; it is created on the
+    /// first [`Store::save`].
+    pub fn open_in(dir: &Path, namespace: &str) -> Result<Store> {
+        if namespace.is_empty() || namespace.contains(['/', '\\', '.']) {
+            return Err(format!("invalid store namespace '{namespace}'").into());
+        }
+
+        let path = dir.join(format!("{namespace}.json"));
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| format!("corrupt store {}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Map::new(),
+            Err(e) => return Err(e.into()),
+        };
+        tracing::debug!(path = %path.display(), entries = entries.len(), "opened store");
+
+        Ok(Store { path, entries })
+    }
+
+    /// Returns the path of the file backing this store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if nothing is stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns every stored key with its value, sorted by key.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Returns the value stored under `key`, if any.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        match self.entries.get(key) {
+            Some(value) => Ok(Some(T::deserialize(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the value stored under `key`, or `T::default()` if absent.
+    pub fn get_or_default<T: DeserializeOwned + Default>(&self, key: &str) -> Result<T> {
+        Ok(self.get(key)?.unwrap_or_default())
+    }
+
+    /// Stores `value` under `key`. Call [`Store::save`] to persist it.
+    pub fn set<T: Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+        self.entries
+            .insert(key.to_string(), serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    /// Adds one to the counter stored under `key` and returns the new count.
+    pub fn increment(&mut self, key: &str) -> Result<u64> {
+        self.add(key, 1)
+    }
+
+    /// Adds `amount` to the total stored under `key` and returns the new
+    /// total.
+    pub fn add(&mut self, key: &str, amount: u64) -> Result<u64> {
+        let total = self.get_or_default::<u64>(key)? + amount;
+        self.set(key, &total)?;
+        Ok(total)
+    }
+
+    /// Stores `value` under `key` if it is lower than the value already
+    /// stored there, and returns the previous value.
+    ///
+    /// This is the usual shape of a high score for "fewest guesses" or
+    /// "fastest time" style games.
+    pub fn record_min<T>(&mut self, key: &str, value: T) -> Result<Option<T>>
+    where
+        T: Serialize + DeserializeOwned + PartialOrd + Copy,
+    {
+        let previous: Option<T> = self.get(key)?;
+        if previous.is_none_or(|best| value < best) {
+            self.set(key, &value)?;
+        }
+        Ok(previous)
+    }
+
+    /// Removes the value stored under `key`.
+    pub fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+    }
+
+    /// Writes the store to disk, creating the data directory if needed.
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temporary file first so a crash never leaves a
+        // half-written store behind.
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string_pretty(&self.entries)?)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        tracing::debug!(path = %self.path.display(), "saved store");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_in_returns_empty_store_when_file_is_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::open_in(dir.path(), "c10").unwrap();
+        assert_eq!(store.get::<u32>("wins").unwrap(), None);
+        assert_eq!(store.path(), dir.path().join("c10.json"));
+    }
+
+    #[test]
+    fn saved_values_are_visible_after_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c26").unwrap();
+        store.set("best_guesses", &5u32).unwrap();
+        store.set("name", &"Ada").unwrap();
+        store.save().unwrap();
+
+        let store = Store::open_in(dir.path(), "c26").unwrap();
+        assert_eq!(store.get::<u32>("best_guesses").unwrap(), Some(5));
+        assert_eq!(store.get::<String>("name").unwrap().as_deref(), Some("Ada"));
+    }
+
+    #[test]
+    fn save_creates_missing_data_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("a").join("b");
+        let mut store = Store::open_in(&nested, "c07").unwrap();
+        store.set("best_time", &3.5f64).unwrap();
+        store.save().unwrap();
+        assert!(nested.join("c07.json").exists());
+    }
+
+    #[test]
+    fn namespaces_are_kept_in_separate_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut first = Store::open_in(dir.path(), "c10").unwrap();
+        first.set("wins", &1u32).unwrap();
+        first.save().unwrap();
+
+        let second = Store::open_in(dir.path(), "c25").unwrap();
+        assert_eq!(second.get::<u32>("wins").unwrap(), None);
+    }
+
+    #[test]
+    fn get_or_default_returns_default_for_missing_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::open_in(dir.path(), "c16").unwrap();
+        assert_eq!(store.get_or_default::<u32>("games").unwrap(), 0);
+    }
+
+    #[test]
+    fn get_reports_type_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c06").unwrap();
+        store.set("best", &"not a number").unwrap();
+        assert!(store.get::<f64>("best").is_err());
+    }
+
+    #[test]
+    fn increment_counts_from_zero() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c10").unwrap();
+        assert_eq!(store.increment("wins").unwrap(), 1);
+        assert_eq!(store.increment("wins").unwrap(), 2);
+        assert_eq!(store.get::<u64>("wins").unwrap(), Some(2));
+    }
+
+    #[test]
+    fn add_accumulates_totals() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c26").unwrap();
+        assert_eq!(store.add("seconds_played", 40).unwrap(), 40);
+        assert_eq!(store.add("seconds_played", 2).unwrap(), 42);
+    }
+
+    #[test]
+    fn record_min_keeps_lowest_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c07").unwrap();
+        assert_eq!(store.record_min("best_time", 5.0).unwrap(), None);
+        assert_eq!(store.record_min("best_time", 7.0).unwrap(), Some(5.0));
+        assert_eq!(store.record_min("best_time", 3.0).unwrap(), Some(5.0));
+        assert_eq!(store.get::<f64>("best_time").unwrap(), Some(3.0));
+    }
+
+    #[test]
+    fn remove_deletes_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c23").unwrap();
+        store.set("best", &3u32).unwrap();
+        store.remove("best");
+        assert_eq!(store.get::<u32>("best").unwrap(), None);
+    }
+
+    #[test]
+    fn entries_are_sorted_by_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::open_in(dir.path(), "c10").unwrap();
+        assert!(store.is_empty());
+        store.set("wins", &2u32).unwrap();
+        store.set("losses", &1u32).unwrap();
+
+        let keys: Vec<_> = store.entries().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["losses", "wins"]);
+    }
+
+    #[test]
+    fn open_in_rejects_corrupt_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("c25.json"), "{not json").unwrap();
+        assert!(Store::open_in(dir.path(), "c25").is_err());
+    }
+
+    #[test]
+    fn open_in_rejects_invalid_namespace() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Store::open_in(dir.path(), "").is_err());
+        assert!(Store::open_in(dir.path(), "../escape").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! # Challenge TUI
+//!
+//! Support for the optional full-screen terminal frontends of the games
+//! (enabled with `--tui`), built on `ratatui` and `crossterm`. Both are
+//! re-exported so that every game uses the same versions.
+//!
+//! A game's TUI keeps its own state, draws it with the shared [`widgets`]
+//! and reacts to keys read with [`next_key`], all inside [`run`]:
+//!
+//! ```no_run
+//! use challenge_tui::widgets::StatusBar;
+//!
+//! challenge_tui::run(|terminal| loop {
+//!     terminal.draw(|frame| {
+//!         frame.render_widget(StatusBar::new("Demo").message("Press q"), frame.area())
+//!     })?;
+//!     if challenge_tui::is_quit(&challenge_tui::next_key()?) {
+//!         return Ok(());
+//!     }
+//! })
+//! .unwrap();
+//! ```
+//!
+//! ## Modules
+//!
+//! - [`widgets`]: Status bar, menu, playing card and grid widgets
+pub mod widgets;
+
+pub use ratatui;
+pub use ratatui::crossterm;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::DefaultTerminal;
+use std::time::{Duration, Instant};
+
+/// Switches the terminal to full-screen raw mode, runs `f`, and restores the
+/// terminal afterwards, whether `f` succeeds, fails, or panics.
+pub fn run<T>(f: impl FnOnce(&mut DefaultTerminal) -> std::io::Result<T>) -> std::io::Result<T> {
+    let mut terminal = match ratatui::try_init() {
+        Ok(terminal) => terminal,
+        Err(e) => {
+            ratatui::restore();
+            return Err(e);
+        }
+    };
+    let result = f(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+/// Blocks until a key is pressed and returns it.
+pub fn next_key() -> std::io::Result<KeyEvent> {
+    loop {
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                return Ok(key);
+            }
+        }
+    }
+}
+
+/// Waits up to `timeout` for a key to be pressed and returns it, or `None`
+/// if none was, for games that keep moving while the player thinks.
+pub fn poll_key(timeout: Duration) -> std::io::Result<Option<KeyEvent>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+            return Ok(None);
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                return Ok(Some(key));
+            }
+        }
+    }
+}
+
+/// Returns `true` for the keys that leave a game: `q`, `Esc`, and `Ctrl-C`.
+pub fn is_quit(key: &KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => true,
+        KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_quit_accepts_q_escape_and_ctrl_c() {
+        assert!(is_quit(&KeyEvent::from(KeyCode::Char('q'))));
+        assert!(is_quit(&KeyEvent::from(KeyCode::Esc)));
+        assert!(is_quit(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn is_quit_rejects_other_keys() {
+        assert!(!is_quit(&KeyEvent::from(KeyCode::Char('c'))));
+        assert!(!is_quit(&KeyEvent::from(KeyCode::Enter)));
+    }
+}
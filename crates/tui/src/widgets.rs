@@ -0,0 +1,346 @@
+//! Widgets shared by the game TUIs.
+//!
+//! Each widget is a cheap, short-lived value built from the game's state on
+//! every frame, in the usual `ratatui` immediate-mode style.
+use challenge_cards::Card;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Widget};
+
+/// Width of a card drawn by [`Cards`], borders included.
+pub const CARD_WIDTH: u16 = 7;
+/// Height of a card drawn by [`Cards`], borders included.
+pub const CARD_HEIGHT: u16 = 5;
+
+/// A one-line bar with the game's title, a status message, and key hints.
+pub struct StatusBar<'a> {
+    title: &'a str,
+    message: String,
+    keys: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> StatusBar<'a> {
+    pub fn new(title: &'a str) -> Self {
+        StatusBar {
+            title,
+            message: String::new(),
+            keys: &[],
+        }
+    }
+
+    /// Sets the message shown after the title.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the key hints shown on the right, as `(key, action)` pairs.
+    pub fn keys(mut self, keys: &'a [(&'a str, &'a str)]) -> Self {
+        self.keys = keys;
+        self
+    }
+}
+
+impl Widget for StatusBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = Rect { height: 1, ..area };
+        buf.set_style(area, Style::new().add_modifier(Modifier::REVERSED));
+
+        Line::from(vec![
+            Span::styled(
+                format!(" {} ", self.title),
+                Style::new().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" {}", self.message)),
+        ])
+        .render(area, buf);
+
+        let mut keys = Vec::new();
+        for (key, action) in self.keys {
+            keys.push(Span::styled(
+                *key,
+                Style::new().add_modifier(Modifier::BOLD),
+            ));
+            keys.push(Span::raw(format!(" {action} ")));
+        }
+        Line::from(keys)
+            .alignment(Alignment::Right)
+            .render(area, buf);
+    }
+}
+
+/// A bordered list of choices with one of them highlighted. A list longer
+/// than the menu is tall scrolls to keep the highlighted item in view.
+pub struct Menu<'a> {
+    title: &'a str,
+    items: &'a [&'a str],
+    selected: usize,
+}
+
+impl<'a> Menu<'a> {
+    pub fn new(title: &'a str, items: &'a [&'a str]) -> Self {
+        Menu {
+            title,
+            items,
+            selected: 0,
+        }
+    }
+
+    /// Highlights the item at `index`.
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = index;
+        self
+    }
+}
+
+impl Widget for Menu<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(self.title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let skip = (self.selected + 1).saturating_sub(inner.height as usize);
+        let items = self.items.iter().enumerate().skip(skip);
+        for ((i, item), row) in items.zip(inner.rows()) {
+            if i == self.selected {
+                Line::styled(
+                    format!("> {item}"),
+                    Style::new().add_modifier(Modifier::REVERSED),
+                )
+                .render(row, buf);
+            } else {
+                Line::raw(format!("  {item}")).render(row, buf);
+            }
+        }
+    }
+}
+
+/// A row of playing cards, drawn left to right, face up and then any face
+/// down. Cards that do not fit in the area are left out.
+pub struct Cards<'a> {
+    cards: &'a [Card],
+    face_down: usize,
+}
+
+impl<'a> Cards<'a> {
+    pub fn new(cards: &'a [Card]) -> Self {
+        Cards {
+            cards,
+            face_down: 0,
+        }
+    }
+
+    /// Adds `count` face-down cards after the face-up ones.
+    pub fn face_down(mut self, count: usize) -> Self {
+        self.face_down = count;
+        self
+    }
+}
+
+impl Widget for Cards<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = CARD_HEIGHT.min(area.height);
+        let faces = self
+            .cards
+            .iter()
+            .map(Some)
+            .chain(std::iter::repeat_n(None, self.face_down));
+        for (i, card) in faces.enumerate() {
+            let x = area.x + i as u16 * (CARD_WIDTH + 1);
+            if x + CARD_WIDTH > area.right() {
+                break;
+            }
+            let card_area = Rect::new(x, area.y, CARD_WIDTH, height);
+            let block = Block::bordered();
+            let inner = block.inner(card_area);
+            block.render(card_area, buf);
+
+            match card {
+                Some(card) => {
+                    let color = if card.suit.is_red() {
+                        Color::Red
+                    } else {
+                        Color::Reset
+                    };
+                    let style = Style::new().fg(color);
+                    let rank = card.rank.short_name();
+                    let lines = [
+                        Line::styled(rank, style),
+                        Line::styled(card.suit.symbol().to_string(), style)
+                            .alignment(Alignment::Center),
+                        Line::styled(rank, style).alignment(Alignment::Right),
+                    ];
+                    for (line, row) in lines.into_iter().zip(inner.rows()) {
+                        line.render(row, buf);
+                    }
+                }
+                None => {
+                    for row in inner.rows() {
+                        Line::styled("░".repeat(row.width as usize), Style::new().fg(Color::Blue))
+                            .render(row, buf);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A square-celled map drawn two columns per cell, with optional marked
+/// cells and a highlighted cursor. Unmarked cells are drawn as `·`.
+pub struct Grid {
+    width: u32,
+    height: u32,
+    cells: Vec<(u32, u32, char, Color)>,
+    cursor: Option<(u32, u32)>,
+}
+
+impl Grid {
+    pub fn new(width: u32, height: u32) -> Self {
+        Grid {
+            width,
+            height,
+            cells: Vec::new(),
+            cursor: None,
+        }
+    }
+
+    /// Marks the cell at column `x`, row `y` with `symbol`.
+    pub fn cell(mut self, x: u32, y: u32, symbol: char, color: Color) -> Self {
+        self.cells.push((x, y, symbol, color));
+        self
+    }
+
+    /// Highlights the cell at column `x`, row `y`.
+    pub fn cursor(mut self, x: u32, y: u32) -> Self {
+        self.cursor = Some((x, y));
+        self
+    }
+
+    /// Returns the screen size needed to draw the whole grid.
+    pub fn size(&self) -> (u16, u16) {
+        let width = self.width.saturating_mul(2).min(u16::MAX.into()) as u16;
+        let height = self.height.min(u16::MAX.into()) as u16;
+        (width, height)
+    }
+}
+
+impl Widget for Grid {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let position = |x: u32, y: u32| -> Option<(u16, u16)> {
+            let col = u16::try_from(x.checked_mul(2)?).ok()?;
+            let row = u16::try_from(y).ok()?;
+            (x < self.width && y < self.height && col + 1 < area.width && row < area.height)
+                .then(|| (area.x + col, area.y + row))
+        };
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match position(x, y) {
+                    Some((col, row)) => {
+                        buf[(col, row)]
+                            .set_char('·')
+                            .set_style(Style::new().fg(Color::DarkGray));
+                    }
+                    None => break,
+                }
+            }
+        }
+        for &(x, y, symbol, color) in &self.cells {
+            if let Some((col, row)) = position(x, y) {
+                buf[(col, row)]
+                    .set_char(symbol)
+                    .set_style(Style::new().fg(color));
+            }
+        }
+        if let Some((col, row)) = self.cursor.and_then(|(x, y)| position(x, y)) {
+            buf.set_style(
+                Rect::new(col, row, 2, 1),
+                Style::new().add_modifier(Modifier::REVERSED),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use challenge_cards::{Rank, Suit};
+
+    fn render(widget: impl Widget, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        widget.render(buf.area, &mut buf);
+        buf
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
+    #[test]
+    fn status_bar_shows_title_message_and_keys() {
+        let buf = render(
+            StatusBar::new("Game")
+                .message("Ready")
+                .keys(&[("q", "quit")]),
+            20,
+            1,
+        );
+        assert_eq!(row(&buf, 0), " Game  Ready q quit ");
+        assert!(buf[(0, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn menu_highlights_selected_item() {
+        let buf = render(Menu::new("Move", &["Hit", "Stand"]).selected(1), 10, 4);
+        assert_eq!(row(&buf, 1), "│  Hit   │");
+        assert_eq!(row(&buf, 2), "│> Stand │");
+        assert!(buf[(1, 2)].modifier.contains(Modifier::REVERSED));
+        assert!(!buf[(1, 1)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn menu_scrolls_to_selected_item() {
+        let items = ["One", "Two", "Three", "Four"];
+        let buf = render(Menu::new("Pick", &items).selected(3), 10, 4);
+        assert_eq!(row(&buf, 1), "│  Three │");
+        assert_eq!(row(&buf, 2), "│> Four  │");
+    }
+
+    #[test]
+    fn cards_draw_face_up_and_face_down_cards() {
+        let cards = [Card::new(Rank::Ten, Suit::Hearts)];
+        let buf = render(Cards::new(&cards).face_down(1), 15, 5);
+        assert_eq!(row(&buf, 0), "┌─────┐ ┌─────┐");
+        assert_eq!(row(&buf, 1), "│10   │ │░░░░░│");
+        assert_eq!(row(&buf, 2), "│  ♥  │ │░░░░░│");
+        assert_eq!(row(&buf, 3), "│   10│ │░░░░░│");
+        assert_eq!(buf[(1, 1)].fg, Color::Red);
+    }
+
+    #[test]
+    fn cards_leave_out_cards_that_do_not_fit() {
+        let cards = [Card::new(Rank::Ace, Suit::Spades)];
+        let buf = render(Cards::new(&cards).face_down(1), 10, 5);
+        assert_eq!(row(&buf, 1), "│A    │   ");
+    }
+
+    #[test]
+    fn grid_draws_cells_and_cursor() {
+        let grid = Grid::new(3, 2).cell(2, 1, 'X', Color::Red).cursor(0, 1);
+        assert_eq!(grid.size(), (6, 2));
+        let buf = render(grid, 6, 2);
+        assert_eq!(row(&buf, 0), "· · · ");
+        assert_eq!(row(&buf, 1), "· · X ");
+        assert_eq!(buf[(4, 1)].fg, Color::Red);
+        assert!(buf[(0, 1)].modifier.contains(Modifier::REVERSED));
+        assert!(buf[(1, 1)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn grid_clips_to_area() {
+        let buf = render(Grid::new(10, 10).cell(9, 9, 'X', Color::Red), 4, 1);
+        assert_eq!(row(&buf, 0), "· · ");
+    }
+}
@@ -0,0 +1,256 @@
+//! # Challenge Web
+//!
+//! Plays the games in a browser. The games read their input line by line
+//! from a blocking reader, which a browser cannot offer, so a [`Session`]
+//! instead replays the whole game from the start each time a line is
+//! entered: it seeds a fresh RNG, feeds the game every line so far, and
+//! keeps the output produced before the game asked for more input. Replays
+//! of a short game are cheap, and the seed keeps them identical.
+//!
+//! With the `wasm` feature the crate compiles to WebAssembly and exports a
+//! session to JavaScript; `www/` holds a page that plays it in a terminal
+//! emulator:
+//!
+//! ```text
+//! wasm-pack build crates/web --target web --out-dir www/pkg --features wasm
+//! python3 -m http.server --directory crates/web/www
+//! ```
+#[cfg(feature = "wasm")]
+mod wasm;
+
+use challenge_common::cli::NumRange;
+use challenge_common::clock::GameClock;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::io::{BufRead, ErrorKind, Write};
+
+/// A game that can be played in a [`Session`].
+pub struct Game {
+    /// The game's binary name, such as `"c25"`.
+    pub name: &'static str,
+    pub title: &'static str,
+    play: fn(&mut dyn BufRead, &mut dyn Write, &mut StdRng) -> std::io::Result<()>,
+}
+
+/// Every game playable in the browser, each with the settings of its normal
+/// difficulty.
+pub const GAMES: [Game; 6] = [
+    Game {
+        name: "c10",
+        title: "Rock, Paper, Scissors",
+        play: |mut reader, mut writer, rng| c10::run(&mut reader, &mut writer, rng).map(drop),
+    },
+    Game {
+        name: "c16",
+        title: "Number Guessing",
+        play: |mut reader, mut writer, rng| {
+            c16::run(
+                &mut reader,
+                &mut writer,
+                &NumRange { min: 1, max: 100 },
+                None,
+                rng,
+            )
+            .map(drop)
+        },
+    },
+    Game {
+        name: "c23",
+        title: "Treasure Hunt",
+        play: |mut reader, mut writer, rng| {
+            c23::run(&mut reader, &mut writer, 10, &mut GameClock::start(), rng).map(drop)
+        },
+    },
+    Game {
+        name: "c25",
+        title: "Blackjack",
+        play: |mut reader, mut writer, rng| {
+            c25::run(&mut reader, &mut writer, &mut GameClock::start(), rng).map(drop)
+        },
+    },
+    Game {
+        name: "c26",
+        title: "Mastermind",
+        play: |mut reader, mut writer, rng| {
+            let target = c26::generate_code(4, rng);
+            c26::run(
+                &mut reader,
+                &mut writer,
+                &target,
+                12,
+                &mut GameClock::start(),
+            )
+            .map(drop)
+        },
+    },
+    Game {
+        name: "c27",
+        title: "Hangman",
+        // The word cannot be hidden as it is typed, so the second player
+        // should look away.
+        play: |mut reader, mut writer, _rng| {
+            c27::run(
+                &mut reader,
+                &mut writer,
+                5,
+                false,
+                false,
+                &mut GameClock::start(),
+            )
+            .map(drop)
+        },
+    },
+];
+
+/// Returns the game called `name`.
+pub fn find(name: &str) -> Option<&'static Game> {
+    GAMES.iter().find(|game| game.name == name)
+}
+
+/// A game in progress, driven one line of input at a time.
+pub struct Session {
+    game: &'static Game,
+    seed: u64,
+    input: String,
+    output: String,
+    finished: bool,
+}
+
+impl Session {
+    /// Starts `game` with the RNG seeded from `seed`.
+    pub fn new(game: &'static Game, seed: u64) -> Self {
+        let mut session = Session {
+            game,
+            seed,
+            input: String::new(),
+            output: String::new(),
+            finished: false,
+        };
+        session.replay();
+        session
+    }
+
+    /// Enters `line` and returns the output it produced. Once the game is
+    /// finished, further input is ignored.
+    pub fn send(&mut self, line: &str) -> String {
+        if self.finished {
+            return String::new();
+        }
+        self.input.push_str(line);
+        self.input.push('\n');
+        let shown = self.output.len();
+        self.replay();
+        self.output.get(shown..).unwrap_or_default().to_string()
+    }
+
+    /// Returns everything the game has written so far.
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Returns `true` once the game has ended.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn replay(&mut self) {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut reader = self.input.as_bytes();
+        let mut output = Vec::new();
+        self.finished = match (self.game.play)(&mut reader, &mut output, &mut rng) {
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => false,
+            Ok(()) => true,
+            Err(e) => {
+                let _ = writeln!(output, "Error: {}", e);
+                true
+            }
+        };
+        self.output = String::from_utf8_lossy(&output).into_owned();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_looks_games_up_by_name() {
+        assert_eq!(find("c26").map(|game| game.title), Some("Mastermind"));
+        assert_eq!(find("c27").map(|game| game.title), Some("Hangman"));
+        assert!(find("c01").is_none());
+    }
+
+    #[test]
+    fn session_waits_for_input_and_returns_only_new_output() {
+        let mut session = Session::new(find("c26").unwrap(), 7);
+        assert_eq!(session.output(), "Enter a 4-digit guess: \n");
+        assert!(!session.is_finished());
+
+        let output = session.send("12");
+        assert_eq!(
+            output,
+            "Invalid input. Please enter a 4-digit number.\nEnter a 4-digit guess: \n"
+        );
+        assert!(session.output().ends_with(&output));
+    }
+
+    #[test]
+    fn session_finishes_with_the_game() {
+        let mut session = Session::new(find("c26").unwrap(), 7);
+        for _ in 0..12 {
+            session.send("0000");
+        }
+        assert!(session.is_finished());
+        let code = session
+            .output()
+            .rsplit("The code was ")
+            .next()
+            .unwrap()
+            .trim_end()
+            .trim_end_matches('.')
+            .to_string();
+
+        // The same seed hides the same code
+        let mut session = Session::new(find("c26").unwrap(), 7);
+        let output = session.send(&code);
+        assert!(output.ends_with("Congratulations! You've guessed the code.\n"));
+        assert!(session.is_finished());
+        assert_eq!(session.send("1234"), "");
+    }
+
+    #[test]
+    fn session_plays_blackjack() {
+        let mut session = Session::new(find("c25").unwrap(), 1);
+        assert!(session
+            .output()
+            .ends_with("Do you want to hit(H) or stand(S)?\n"));
+        assert!(session.send("S").contains("Dealer hand: \n"));
+        assert!(session.is_finished());
+    }
+
+    #[test]
+    fn session_plays_hangman_with_the_word_in_view() {
+        let mut session = Session::new(find("c27").unwrap(), 1);
+        assert_eq!(
+            session.send("cat"),
+            "Word to guess: ***\nYou have 5 lives left - Letter? \n"
+        );
+        session.send("c");
+        session.send("a");
+        assert!(session
+            .send("t")
+            .ends_with("Congratulations! You've guessed the word: CAT\n"));
+        assert!(session.is_finished());
+    }
+
+    #[test]
+    fn session_lets_the_computer_guess() {
+        let mut session = Session::new(find("c16").unwrap(), 1);
+        session.send("");
+        assert!(session.send("n").contains("The computer guesses: 50\n"));
+        assert!(session
+            .send("C")
+            .ends_with("It took the computer 1 attempts to guess the number.\n"));
+        assert!(session.is_finished());
+    }
+}
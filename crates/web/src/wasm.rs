@@ -0,0 +1,44 @@
+//! JavaScript bindings for the browser page in `www/`.
+use crate::{find, Session, GAMES};
+use wasm_bindgen::prelude::*;
+
+/// A [`Session`] that JavaScript can drive.
+#[wasm_bindgen]
+pub struct WebSession(Session);
+
+#[wasm_bindgen]
+impl WebSession {
+    /// Starts the game called `name`, seeded from `seed`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: &str, seed: u32) -> Result<WebSession, JsError> {
+        let game = find(name).ok_or_else(|| JsError::new(&format!("unknown game: {name}")))?;
+        Ok(WebSession(Session::new(game, seed.into())))
+    }
+
+    /// Returns everything the game has written so far.
+    pub fn output(&self) -> String {
+        self.0.output().to_string()
+    }
+
+    /// Enters `line` and returns the output it produced.
+    pub fn send(&mut self, line: &str) -> String {
+        self.0.send(line)
+    }
+
+    #[wasm_bindgen(js_name = isFinished)]
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+/// Returns the names of the playable games.
+#[wasm_bindgen]
+pub fn games() -> Vec<String> {
+    GAMES.iter().map(|game| game.name.to_string()).collect()
+}
+
+/// Returns the title of the game called `name`.
+#[wasm_bindgen]
+pub fn title(name: &str) -> Option<String> {
+    find(name).map(|game| game.title.to_string())
+}
@@ -0,0 +1,53 @@
+// Plays the games compiled by `wasm-pack build` into ./pkg in an xterm.js
+// terminal, with a minimal line editor in front of the game's input.
+import init, { WebSession, games, title } from "./pkg/challenge_web.js";
+
+await init();
+
+const term = new Terminal({ convertEol: true });
+term.open(document.getElementById("terminal"));
+
+const select = document.getElementById("game");
+for (const name of games()) {
+  select.add(new Option(`${name}: ${title(name)}`, name));
+}
+
+let session;
+let line = "";
+
+function newGame() {
+  session?.free();
+  session = new WebSession(select.value, Math.floor(Math.random() * 2 ** 32));
+  line = "";
+  term.reset();
+  term.write(session.output());
+  term.focus();
+}
+
+term.onData((data) => {
+  if (session.isFinished()) {
+    return;
+  }
+  for (const ch of data) {
+    if (ch === "\r") {
+      term.write("\r\n");
+      term.write(session.send(line));
+      line = "";
+      if (session.isFinished()) {
+        term.write("\r\n[Game over. Press \"New game\" to play again.]\r\n");
+      }
+    } else if (ch === "\x7f") {
+      if (line.length > 0) {
+        line = line.slice(0, -1);
+        term.write("\b \b");
+      }
+    } else if (ch >= " ") {
+      line += ch;
+      term.write(ch);
+    }
+  }
+});
+
+select.addEventListener("change", newGame);
+document.getElementById("new-game").addEventListener("click", newGame);
+newGame();